@@ -1,5 +1,5 @@
 #![feature(allow_internal_unstable)]
-#![feature(bench_black_box)]
+#![cfg_attr(bootstrap, feature(bench_black_box))]
 #![feature(extend_one)]
 #![feature(min_specialization)]
 #![feature(step_trait)]
@@ -1,4 +1,4 @@
-use std::{mem, ptr};
+use std::{cmp, mem, ptr};
 
 use rand::distributions::{Alphanumeric, Standard};
 use rand::{thread_rng, Rng, SeedableRng};
@@ -216,6 +216,26 @@ fn gen_mostly_descending(len: usize) -> Vec<u64> {
     v
 }
 
+fn gen_random_runs(len: usize) -> Vec<u64> {
+    let mut rng = XorShiftRng::from_seed(SEED);
+    let mut v: Vec<u64> = (&mut rng).sample_iter(&Standard).take(len).collect();
+    let mut start = 0;
+    while start < len {
+        let end = cmp::min(len, start + rng.gen::<usize>() % 1000 + 1);
+        v[start..end].sort();
+        start = end;
+    }
+    v
+}
+
+fn gen_pipe_organ(len: usize) -> Vec<u64> {
+    (0..len as u64 / 2).chain((0..(len - len / 2) as u64).rev()).collect()
+}
+
+fn gen_sawtooth(len: usize) -> Vec<u64> {
+    (0..len as u64).map(|x| x % 97).collect()
+}
+
 fn gen_strings(len: usize) -> Vec<String> {
     let mut rng = XorShiftRng::from_seed(SEED);
     let mut v = vec![];
@@ -298,6 +318,9 @@ fn $name(b: &mut Bencher) {
 sort!(sort, sort_large_mostly_descending, gen_mostly_descending, 10000);
 sort!(sort, sort_large_random, gen_random, 10000);
 sort!(sort, sort_large_big, gen_big_random, 10000);
+sort!(sort, sort_large_random_runs, gen_random_runs, 10000);
+sort!(sort, sort_large_pipe_organ, gen_pipe_organ, 10000);
+sort!(sort, sort_large_sawtooth, gen_sawtooth, 10000);
 sort_strings!(sort, sort_large_strings, gen_strings, 10000);
 sort_expensive!(sort_by, sort_large_expensive, gen_random, 10000);
 
@@ -365,8 +388,10 @@ fn $name(b: &mut Bencher) {
 rotate!(rotate_medium_by727_u64, gen_random, 9158, 727);
 rotate!(rotate_medium_by727_bytes, gen_random_bytes, 9158, 727);
 rotate!(rotate_medium_by727_strings, gen_strings, 9158, 727);
+rotate!(rotate_medium_by727_big, gen_big_random, 9158, 727);
 rotate!(rotate_medium_half, gen_random, 9158, 9158 / 2);
 rotate!(rotate_medium_half_plus_one, gen_random, 9158, 9158 / 2 + 1);
+rotate!(rotate_medium_golden_big, gen_big_random, 9158, 5660);
 
 // Intended to use more RAM than the machine has cache
 rotate!(rotate_huge_by1, gen_random, 5 * 1024 * 1024, 1);
@@ -149,7 +149,7 @@
 };
 use core::pin::Pin;
 use core::ptr::{self, Unique};
-use core::stream::Stream;
+use core::async_iter::AsyncIterator;
 use core::task::{Context, Poll};
 
 #[cfg(not(no_global_oom_handling))]
@@ -1803,6 +1803,60 @@ fn is_empty(&self) -> bool {
 #[stable(feature = "fused", since = "1.26.0")]
 impl<I: FusedIterator + ?Sized, A: Allocator> FusedIterator for Box<I, A> {}
 
+/// An [`Iterator`] that can be cloned behind a trait object.
+///
+/// `Clone` is not object safe, so a `Box<dyn Iterator>` cannot be cloned,
+/// even if the iterator inside it could be. Every `Iterator` that implements
+/// `Clone` also implements this trait, and `Box<dyn CloneIterator>` implements
+/// both `Iterator` and `Clone`, so functions returning boxed iterators can use
+/// it to let their callers clone the result.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(clone_iterator)]
+/// use std::boxed::CloneIterator;
+///
+/// fn evens<'a>(v: &'a [u32]) -> Box<dyn CloneIterator<Item = &'a u32> + 'a> {
+///     Box::new(v.iter().filter(|&&x| x % 2 == 0))
+/// }
+///
+/// let v = [1, 2, 3, 4, 5, 6];
+/// let mut iter = evens(&v);
+/// assert_eq!(iter.next(), Some(&2));
+///
+/// let rest = iter.clone();
+/// assert_eq!(iter.collect::<Vec<_>>(), [&4, &6]);
+/// assert_eq!(rest.collect::<Vec<_>>(), [&4, &6]);
+/// ```
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "clone_iterator", issue = "none")]
+pub trait CloneIterator: Iterator {
+    /// Clones the iterator into a new box.
+    fn clone_box<'a>(&self) -> Box<dyn CloneIterator<Item = Self::Item> + 'a>
+    where
+        Self: 'a;
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "clone_iterator", issue = "none")]
+impl<I: Iterator + Clone> CloneIterator for I {
+    fn clone_box<'a>(&self) -> Box<dyn CloneIterator<Item = I::Item> + 'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "clone_iterator", issue = "none")]
+impl<'a, T: 'a> Clone for Box<dyn CloneIterator<Item = T> + 'a> {
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
 #[stable(feature = "boxed_closure_impls", since = "1.35.0")]
 impl<Args, F: FnOnce<Args> + ?Sized, A: Allocator> FnOnce<Args> for Box<F, A> {
     type Output = <F as FnOnce<Args>>::Output;
@@ -1948,8 +2002,8 @@ fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
     }
 }
 
-#[unstable(feature = "async_stream", issue = "79024")]
-impl<S: ?Sized + Stream + Unpin> Stream for Box<S> {
+#[unstable(feature = "async_iterator", issue = "79024")]
+impl<S: ?Sized + AsyncIterator + Unpin> AsyncIterator for Box<S> {
     type Item = S::Item;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
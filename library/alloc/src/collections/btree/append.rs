@@ -1,6 +1,8 @@
+use super::map::MIN_LEN;
 use super::merge_iter::MergeIterInner;
-use super::node::{self, Root};
+use super::node::{self, ForceResult::*, Root};
 use core::iter::FusedIterator;
+use core::mem;
 
 impl<K, V> Root<K, V> {
     /// Appends all key-value pairs from the union of two ascending iterators,
@@ -30,6 +32,27 @@ pub fn append_from_sorted_iters<I>(&mut self, left: I, right: I, length: &mut us
     /// `length` variable along the way. The latter makes it easier for the
     /// caller to avoid a leak when the iterator panicks.
     pub fn bulk_push<I>(&mut self, iter: I, length: &mut usize)
+    where
+        I: Iterator<Item = (K, V)>,
+    {
+        self.push_unbalanced(iter, length);
+        self.fix_right_border_of_plentiful();
+    }
+
+    /// Pushes all key-value pairs to the end of a tree that may already hold
+    /// key-value pairs, like `bulk_push`. Unlike `bulk_push`, this does not
+    /// require the nodes already in the tree to be full.
+    pub fn bulk_push_onto<I>(&mut self, iter: I, length: &mut usize)
+    where
+        I: Iterator<Item = (K, V)>,
+    {
+        self.push_unbalanced(iter, length);
+        self.fix_right_border();
+    }
+
+    /// Pushes all key-value pairs to the end of the tree, filling up nodes
+    /// completely and leaving the right border of the tree underfull.
+    fn push_unbalanced<I>(&mut self, iter: I, length: &mut usize)
     where
         I: Iterator<Item = (K, V)>,
     {
@@ -80,7 +103,117 @@ pub fn bulk_push<I>(&mut self, iter: I, length: &mut usize)
             // the appended elements even if advancing the iterator panicks.
             *length += 1;
         }
-        self.fix_right_border_of_plentiful();
+    }
+}
+
+impl<K, V> Root<K, V> {
+    /// Appends a key-value pair and then all key-value pairs of `right` to the
+    /// end of the tree. Unlike `bulk_push`, this attaches `right` as a subtree
+    /// instead of moving its pairs, and thus takes time logarithmic in the size
+    /// of the trees.
+    ///
+    /// If you want the tree to end up in a strictly ascending order, like for
+    /// a `BTreeMap`, `key` should be greater than all keys in the tree and
+    /// smaller than all keys in `right`.
+    ///
+    /// If both trees respect all `BTreeMap` tree invariants, then so does the
+    /// resulting tree.
+    pub fn append_tree(&mut self, key: K, val: V, mut right: Root<K, V>) {
+        if self.height() > right.height() {
+            self.graft_right(key, val, right);
+        } else if self.height() < right.height() {
+            mem::swap(self, &mut right);
+            self.graft_left(key, val, right);
+        } else {
+            let mut top = self.push_internal_level();
+            top.push(key, val, right);
+            let mut kv = top.first_kv().consider_for_balancing();
+            if kv.can_merge() {
+                kv.merge_tracking_parent();
+            } else {
+                // Both children used to be roots, so either may be underfull,
+                // but not both, since they don't fit in one node together.
+                let left_len = kv.left_child_len();
+                let right_len = kv.right_child_len();
+                if left_len < MIN_LEN {
+                    kv.bulk_steal_right(MIN_LEN - left_len);
+                } else if right_len < MIN_LEN {
+                    kv.bulk_steal_left(MIN_LEN - right_len);
+                }
+            }
+            self.fix_top();
+        }
+    }
+
+    /// Attaches `right`, which must be lower than the tree, to its right border.
+    fn graft_right(&mut self, key: K, val: V, mut right: Root<K, V>) {
+        // Go down the right border to the level right above `right`, then up
+        // again until there is space left, like `push_unbalanced`.
+        let mut test_node = self.borrow_mut();
+        while test_node.height() > right.height() + 1 {
+            test_node = match test_node.force() {
+                Internal(internal) => internal.last_edge().descend(),
+                Leaf(_) => unreachable!(),
+            };
+        }
+        let mut open_node;
+        loop {
+            match test_node.force() {
+                Internal(internal) if internal.len() < node::CAPACITY => {
+                    open_node = internal;
+                    break;
+                }
+                Internal(internal) => match internal.forget_type().ascend() {
+                    Ok(parent) => test_node = parent.into_node().forget_type(),
+                    Err(_) => {
+                        open_node = self.push_internal_level();
+                        break;
+                    }
+                },
+                Leaf(_) => unreachable!(),
+            }
+        }
+
+        // Raise `right` with empty nodes, which `fix_right_border` stocks up.
+        for _ in right.height()..open_node.height() - 1 {
+            right.push_internal_level();
+        }
+        open_node.push(key, val, right);
+        self.fix_right_border();
+    }
+
+    /// The symmetric clone of `graft_right`, attaching `left` to the left border.
+    fn graft_left(&mut self, key: K, val: V, mut left: Root<K, V>) {
+        let mut test_node = self.borrow_mut();
+        while test_node.height() > left.height() + 1 {
+            test_node = match test_node.force() {
+                Internal(internal) => internal.first_edge().descend(),
+                Leaf(_) => unreachable!(),
+            };
+        }
+        let mut open_node;
+        loop {
+            match test_node.force() {
+                Internal(internal) if internal.len() < node::CAPACITY => {
+                    open_node = internal;
+                    break;
+                }
+                Internal(internal) => match internal.forget_type().ascend() {
+                    Ok(parent) => test_node = parent.into_node().forget_type(),
+                    Err(_) => {
+                        open_node = self.push_internal_level();
+                        break;
+                    }
+                },
+                Leaf(_) => unreachable!(),
+            }
+        }
+
+        for _ in left.height()..open_node.height() - 1 {
+            left.push_internal_level();
+        }
+        open_node.push_front(key, val, left);
+        self.fix_left_border();
     }
 }
 
@@ -3,10 +3,10 @@
 use core::cmp::Ordering;
 use core::fmt::{self, Debug};
 use core::hash::{Hash, Hasher};
-use core::iter::{FromIterator, FusedIterator};
+use core::iter::{FromIterator, FusedIterator, GroupInsert};
 use core::marker::PhantomData;
 use core::mem::{self, ManuallyDrop};
-use core::ops::{Index, RangeBounds};
+use core::ops::{Bound, Index, RangeBounds};
 use core::ptr;
 
 use super::borrow::DormantMutRef;
@@ -241,6 +241,48 @@ fn clone_subtree<'a, K: Clone, V: Clone>(
             clone_subtree(self.root.as_ref().unwrap().reborrow()) // unwrap succeeds because not empty
         }
     }
+
+    fn clone_from(&mut self, source: &Self) {
+        if self.is_empty() {
+            *self = source.clone();
+            return;
+        }
+
+        // Drop the entries that have no counterpart in `source`, starting from the back.
+        while self.length > source.length {
+            let root = self.root.as_mut().unwrap(); // unwrap succeeds because not empty
+            let kv = root.borrow_mut().last_leaf_edge().left_kv().ok().unwrap();
+            let mut emptied_internal_root = false;
+            let (old_kv, _) =
+                kv.forget_node_type().remove_kv_tracking(|| emptied_internal_root = true);
+            self.length -= 1;
+            if emptied_internal_root {
+                self.root.as_mut().unwrap().pop_internal_level();
+            }
+            drop(old_kv);
+        }
+
+        // Overwrite the remaining entries in place, reusing their resources. The shape
+        // of the tree doesn't depend on the keys, so assigning the smallest keys of
+        // `source` in ascending order keeps the tree sorted.
+        let mut source_iter = source.iter();
+        if let Some(root) = self.root.as_mut() {
+            let mut edge = root.borrow_mut().first_leaf_edge();
+            while let Ok(mut kv) = edge.next_kv() {
+                // `self` has no more entries than `source` at this point.
+                let (src_key, src_val) = source_iter.next().unwrap();
+                let (key, val) = kv.kv_mut();
+                key.clone_from(src_key);
+                val.clone_from(src_val);
+                edge = kv.next_leaf_edge();
+            }
+        }
+
+        // The rest of `source` is greater than every key in `self`.
+        let iter = source_iter.map(|(k, v)| (k.clone(), v.clone()));
+        let root = BTreeMap::ensure_is_owned(&mut self.root);
+        root.bulk_push_onto(iter, &mut self.length);
+    }
 }
 
 impl<K, Q: ?Sized> super::Recover<Q> for BTreeMap<K, ()>
@@ -981,6 +1023,10 @@ pub fn retain<F>(&mut self, mut f: F)
 
     /// Moves all elements from `other` into `Self`, leaving `other` empty.
     ///
+    /// If all keys in one map are smaller than all keys in the other, the two
+    /// trees are joined in time logarithmic in their size. Otherwise, this
+    /// takes time linear in the combined number of elements.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1023,6 +1069,25 @@ pub fn append(&mut self, other: &mut Self)
             return;
         }
 
+        // If the keys don't overlap, we can join the trees instead of merging their elements.
+        // unwraps succeed because both maps are not empty
+        let (self_min, _) = self.first_key_value().unwrap();
+        let (self_max, _) = self.last_key_value().unwrap();
+        let (other_min, _) = other.first_key_value().unwrap();
+        let (other_max, _) = other.last_key_value().unwrap();
+        let other_is_greater = self_max < other_min;
+        if other_is_greater || other_max < self_min {
+            if !other_is_greater {
+                mem::swap(self, other);
+            }
+            let (key, val) = other.pop_first().unwrap();
+            let right_root = other.root.take().unwrap();
+            let right_len = mem::replace(&mut other.length, 0);
+            self.root.as_mut().unwrap().append_tree(key, val, right_root);
+            self.length += 1 + right_len;
+            return;
+        }
+
         let self_iter = mem::take(self).into_iter();
         let other_iter = mem::take(other).into_iter();
         let root = BTreeMap::ensure_is_owned(&mut self.root);
@@ -1058,6 +1123,23 @@ pub fn append(&mut self, other: &mut Self)
     /// }
     /// assert_eq!(Some((&5, &"b")), map.range(4..).next());
     /// ```
+    ///
+    /// Bounds that are only known at runtime, such as an exclusive start after the
+    /// last key seen, can be built from `Bound` values directly. Borrowed bounds
+    /// let a map with `String` keys be queried with `&str`:
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use std::ops::Bound::{Excluded, Unbounded};
+    ///
+    /// let map: BTreeMap<String, u32> =
+    ///     [("a", 1), ("b", 2), ("c", 3)].iter().map(|&(k, v)| (k.to_string(), v)).collect();
+    ///
+    /// let cursor = Some("a");
+    /// let start = cursor.map_or(Unbounded, Excluded);
+    /// let page: Vec<&str> = map.range::<str, _>((start, Unbounded)).map(|(k, _)| &**k).collect();
+    /// assert_eq!(page, ["b", "c"]);
+    /// ```
     #[stable(feature = "btree_range", since = "1.17.0")]
     pub fn range<T: ?Sized, R>(&self, range: R) -> Range<'_, K, V>
     where
@@ -1181,6 +1263,38 @@ pub fn entry(&mut self, key: K) -> Entry<'_, K, V>
     /// ```
     #[stable(feature = "btree_split_off", since = "1.11.0")]
     pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
+    where
+        K: Borrow<Q> + Ord,
+    {
+        self.split_off_bound(Bound::Included(key))
+    }
+
+    /// Splits the collection into two at the given bound. Returns everything
+    /// within the bound, from the bound to the end of the map.
+    ///
+    /// A bound of `Included(key)` behaves like [`split_off`], `Excluded(key)`
+    /// leaves the key itself in the map, and `Unbounded` moves out everything.
+    ///
+    /// [`split_off`]: BTreeMap::split_off
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(btree_split_off_bound)]
+    /// use std::collections::BTreeMap;
+    /// use std::ops::Bound::Excluded;
+    ///
+    /// let mut a = BTreeMap::from([(1, "a"), (2, "b"), (3, "c"), (17, "d")]);
+    ///
+    /// let b = a.split_off_bound(Excluded(&2));
+    ///
+    /// assert_eq!(a.into_iter().collect::<Vec<_>>(), [(1, "a"), (2, "b")]);
+    /// assert_eq!(b.into_iter().collect::<Vec<_>>(), [(3, "c"), (17, "d")]);
+    /// ```
+    #[unstable(feature = "btree_split_off_bound", issue = "none")]
+    pub fn split_off_bound<Q: ?Sized + Ord>(&mut self, bound: Bound<&Q>) -> Self
     where
         K: Borrow<Q> + Ord,
     {
@@ -1191,7 +1305,7 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
         let total_num = self.len();
         let left_root = self.root.as_mut().unwrap(); // unwrap succeeds because not empty
 
-        let right_root = left_root.split_off(key);
+        let right_root = left_root.split_off_bound(bound);
 
         let (new_left_len, right_len) = Root::calc_split_length(total_num, &left_root, &right_root);
         self.length = new_left_len;
@@ -1975,6 +2089,14 @@ fn extend_one(&mut self, (&k, &v): (&'a K, &'a V)) {
     }
 }
 
+#[unstable(feature = "iter_group_map_into", issue = "none")]
+impl<K: Ord, V, C: Default + Extend<V>> GroupInsert<K, V> for BTreeMap<K, C> {
+    #[inline]
+    fn group_insert(&mut self, key: K, value: V) {
+        self.entry(key).or_default().extend_one(value);
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<K: Hash, V: Hash> Hash for BTreeMap<K, V> {
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -1,6 +1,7 @@
 use core::fmt::{self, Debug};
 use core::marker::PhantomData;
 use core::mem;
+use core::ptr;
 
 use super::super::borrow::DormantMutRef;
 use super::super::node::{marker, Handle, InsertResult::*, NodeRef};
@@ -182,6 +183,36 @@ pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
         }
     }
 
+    /// Ensures a value is in the entry by inserting the result of the fallible default
+    /// function if empty, and returns a mutable reference to the value in the entry.
+    ///
+    /// If the default function fails, the entry is left empty and its error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_or_try_insert)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map: BTreeMap<&str, u32> = BTreeMap::new();
+    ///
+    /// assert!(map.entry("poneyland").or_try_insert_with(|| "x".parse::<u32>()).is_err());
+    /// assert!(map.is_empty());
+    ///
+    /// assert_eq!(map.entry("poneyland").or_try_insert_with(|| "3".parse::<u32>()), Ok(&mut 3));
+    /// assert_eq!(map["poneyland"], 3);
+    /// ```
+    #[unstable(feature = "entry_or_try_insert", issue = "none")]
+    pub fn or_try_insert_with<F, E>(self, default: F) -> Result<&'a mut V, E>
+    where
+        F: FnOnce() -> Result<V, E>,
+    {
+        match self {
+            Occupied(entry) => Ok(entry.into_mut()),
+            Vacant(entry) => Ok(entry.insert(default()?)),
+        }
+    }
+
     /// Returns a reference to this entry's key.
     ///
     /// # Examples
@@ -233,6 +264,85 @@ pub fn and_modify<F>(self, f: F) -> Self
             Vacant(entry) => Vacant(entry),
         }
     }
+
+    /// Replaces the value of an occupied entry with the result of `f`, which is given the key
+    /// and takes ownership of the old value. Vacant entries are returned unchanged.
+    ///
+    /// This allows transforming a value that cannot be cheaply cloned or defaulted without
+    /// looking the key up again. If `f` panics, the entry is removed from the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_and_replace)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
+    /// map.insert("poneyland", vec![1, 2]);
+    ///
+    /// map.entry("poneyland")
+    ///    .and_replace_entry_with(|_, mut v| { v.push(3); v })
+    ///    .or_insert_with(Vec::new);
+    /// assert_eq!(map["poneyland"], [1, 2, 3]);
+    /// ```
+    #[unstable(feature = "entry_and_replace", issue = "none")]
+    pub fn and_replace_entry_with<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&K, V) -> V,
+    {
+        // Removes the entry, without dropping its moved-out value, if `f` unwinds.
+        struct RemoveOnUnwind<'a, K: Ord, V>(Option<OccupiedEntry<'a, K, V>>);
+
+        impl<K: Ord, V> Drop for RemoveOnUnwind<'_, K, V> {
+            fn drop(&mut self) {
+                if let Some(entry) = self.0.take() {
+                    mem::forget(entry.remove_entry().1);
+                }
+            }
+        }
+
+        match self {
+            Occupied(entry) => {
+                let mut guard = RemoveOnUnwind(Some(entry));
+                let entry = guard.0.as_mut().unwrap();
+                let slot: *mut V = entry.get_mut();
+                // SAFETY: `slot` points to the initialized value of the entry. The value is
+                // moved out for the duration of `f` and the slot is refilled before the guard
+                // is disarmed; if `f` unwinds, the guard removes the entry and forgets the
+                // stale copy of the value instead of dropping it a second time.
+                unsafe {
+                    let value = f(entry.key(), ptr::read(slot));
+                    ptr::write(slot, value);
+                }
+                Occupied(guard.0.take().unwrap())
+            }
+            Vacant(entry) => Vacant(entry),
+        }
+    }
+
+    /// Sets the value of the entry, and returns an `OccupiedEntry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_entry_insert)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map: BTreeMap<&str, String> = BTreeMap::new();
+    /// let entry = map.entry("poneyland").insert_entry("hoho".to_string());
+    ///
+    /// assert_eq!(entry.key(), &"poneyland");
+    /// ```
+    #[unstable(feature = "btree_entry_insert", issue = "none")]
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V> {
+        match self {
+            Occupied(mut entry) => {
+                entry.insert(value);
+                entry
+            }
+            Vacant(entry) => entry.insert_entry(value),
+        }
+    }
 }
 
 impl<'a, K: Ord, V: Default> Entry<'a, K, V> {
@@ -312,26 +422,54 @@ pub fn into_key(self) -> K {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn insert(self, value: V) -> &'a mut V {
-        let out_ptr = match self.handle.insert_recursing(self.key, value) {
-            (Fit(_), val_ptr) => {
-                // SAFETY: We have consumed self.handle and the handle returned.
-                let map = unsafe { self.dormant_map.awaken() };
-                map.length += 1;
-                val_ptr
-            }
-            (Split(ins), val_ptr) => {
-                drop(ins.left);
-                // SAFETY: We have consumed self.handle and the reference returned.
-                let map = unsafe { self.dormant_map.awaken() };
-                let root = map.root.as_mut().unwrap();
-                root.push_internal_level().push(ins.kv.0, ins.kv.1, ins.right);
-                map.length += 1;
-                val_ptr
-            }
-        };
-        // Now that we have finished growing the tree using borrowed references,
-        // dereference the pointer to a part of it, that we picked up along the way.
-        unsafe { &mut *out_ptr }
+        let (inserted, _) = self.insert_kv(value);
+        inserted.into_val_mut()
+    }
+
+    /// Sets the value of the entry with the `VacantEntry`'s key,
+    /// and returns an `OccupiedEntry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_entry_insert)]
+    /// use std::collections::BTreeMap;
+    /// use std::collections::btree_map::Entry;
+    ///
+    /// let mut map: BTreeMap<&str, u32> = BTreeMap::new();
+    ///
+    /// if let Entry::Vacant(o) = map.entry("poneyland") {
+    ///     let mut entry = o.insert_entry(37);
+    ///     *entry.get_mut() += 1;
+    /// }
+    /// assert_eq!(map["poneyland"], 38);
+    /// ```
+    #[unstable(feature = "btree_entry_insert", issue = "none")]
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V> {
+        let (inserted, map) = self.insert_kv(value);
+        let (_, dormant_map) = DormantMutRef::new(map);
+        OccupiedEntry { handle: inserted.forget_node_type(), dormant_map, _marker: PhantomData }
+    }
+
+    // Body of `insert` and `insert_entry`. Returns a handle to the inserted key-value pair,
+    // along with the map whose tree it lives in.
+    fn insert_kv(
+        self,
+        value: V,
+    ) -> (Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::KV>, &'a mut BTreeMap<K, V>)
+    {
+        let (result, inserted) = self.handle.insert_recursing(self.key, value);
+        // SAFETY: We have consumed self.handle and the handle returned.
+        let map = unsafe { self.dormant_map.awaken() };
+        if let Split(ins) = result {
+            drop(ins.left);
+            let root = map.root.as_mut().unwrap();
+            root.push_internal_level().push(ins.kv.0, ins.kv.1, ins.right);
+        }
+        map.length += 1;
+        // Now that we have finished growing the tree using borrowed references, the handle to a
+        // part of it that we picked up along the way may be used again.
+        (inserted, map)
     }
 }
 
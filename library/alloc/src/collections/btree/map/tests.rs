@@ -1494,6 +1494,36 @@ fn test_clone() {
     map.check();
 }
 
+#[test]
+fn test_clone_from_sizes() {
+    let sizes =
+        [0, 1, 2, MIN_INSERTS_HEIGHT_1 - 1, MIN_INSERTS_HEIGHT_1, MIN_INSERTS_HEIGHT_2, 300];
+    for &dst_len in &sizes {
+        for &src_len in &sizes {
+            // Sparse maps, so that the in-place keys move around.
+            let src: BTreeMap<_, _> = (0..src_len).map(|i| (i * 3, vec![i; 2])).collect();
+            let mut dst: BTreeMap<_, _> = (0..dst_len).map(|i| (i * 2 + 1, vec![i])).collect();
+            if dst_len > 1 {
+                // Leave a less than full tree behind.
+                dst.remove(&1);
+            }
+            dst.clone_from(&src);
+            dst.check();
+            assert_eq!(dst, src, "dst_len={} src_len={}", dst_len, src_len);
+        }
+    }
+}
+
+#[test]
+fn test_clone_from_reuses_values() {
+    let src: BTreeMap<_, _> = (0..100).map(|i| (i, String::from("a"))).collect();
+    let mut dst: BTreeMap<_, _> = (0..100).map(|i| (i, String::with_capacity(64))).collect();
+    let ptrs: Vec<_> = dst.values().map(|s| s.as_ptr()).collect();
+    dst.clone_from(&src);
+    assert_eq!(dst, src);
+    assert_eq!(dst.values().map(|s| s.as_ptr()).collect::<Vec<_>>(), ptrs);
+}
+
 fn test_clone_panic_leak(size: usize) {
     for i in 0..size {
         let dummies: Vec<CrashTestDummy> = (0..size).map(|id| CrashTestDummy::new(id)).collect();
@@ -1837,6 +1867,69 @@ fn test_vacant_entry_key() {
     a.check();
 }
 
+#[test]
+fn test_vacant_entry_insert_entry() {
+    let mut a = BTreeMap::new();
+    // Enough insertions to split leaves and grow the root several times.
+    for i in (0..MIN_INSERTS_HEIGHT_2 as u32).rev() {
+        match a.entry(i) {
+            Occupied(_) => panic!(),
+            Vacant(e) => {
+                let mut e = e.insert_entry(i);
+                assert_eq!(*e.key(), i);
+                *e.get_mut() *= 2;
+            }
+        }
+        a.check();
+    }
+    assert_eq!(a.len(), MIN_INSERTS_HEIGHT_2);
+    assert!(a.iter().all(|(k, v)| *v == *k * 2));
+
+    // The returned entry can be used to remove what was just inserted.
+    let e = a.entry(u32::MAX).insert_entry(0);
+    assert_eq!(e.remove_entry(), (u32::MAX, 0));
+    assert_eq!(a.len(), MIN_INSERTS_HEIGHT_2);
+    a.check();
+}
+
+#[test]
+fn test_entry_or_try_insert_with() {
+    let mut a: BTreeMap<u32, u32> = BTreeMap::new();
+    assert_eq!(a.entry(1).or_try_insert_with(|| Err::<u32, _>("nope")), Err("nope"));
+    assert!(a.is_empty());
+    assert_eq!(a.entry(1).or_try_insert_with(|| Ok::<_, ()>(10)), Ok(&mut 10));
+    assert_eq!(a.entry(1).or_try_insert_with(|| Err(())), Ok(&mut 10));
+    assert_eq!(a.len(), 1);
+    a.check();
+}
+
+#[test]
+fn test_entry_and_replace_entry_with() {
+    let mut a: BTreeMap<u32, String> = BTreeMap::new();
+    a.insert(1, String::from("a"));
+    a.entry(1).and_replace_entry_with(|k, v| v + &k.to_string());
+    a.entry(2).and_replace_entry_with(|_, _| panic!());
+    assert_eq!(a[&1], "a1");
+    assert_eq!(a.len(), 1);
+
+    // A panic removes the entry without dropping the value twice.
+    let value = Rc::new(());
+    let mut a = BTreeMap::new();
+    a.insert(1, value.clone());
+    a.insert(2, value.clone());
+    catch_unwind(AssertUnwindSafe(|| {
+        a.entry(1).and_replace_entry_with(|_, v| {
+            drop(v);
+            panic!("panic in replace");
+        });
+    }))
+    .unwrap_err();
+    assert_eq!(a.len(), 1);
+    assert!(a.contains_key(&2));
+    assert_eq!(Rc::strong_count(&value), 2);
+    a.check();
+}
+
 #[test]
 fn test_first_last_entry() {
     let mut a = BTreeMap::new();
@@ -1963,6 +2056,33 @@ fn test_append_drop_leak() {
     assert_eq!(c.dropped(), 2);
 }
 
+#[test]
+fn test_append_disjoint() {
+    let sizes = [0, 1, 2, NODE_CAPACITY, MIN_INSERTS_HEIGHT_1, 30, MIN_INSERTS_HEIGHT_2, 150, 1000];
+    for &left_len in &sizes {
+        for &right_len in &sizes {
+            for &(compact, swap) in &[(false, false), (false, true), (true, false), (true, true)] {
+                let mut left = BTreeMap::from_iter((0..left_len).map(|i| (i, i)));
+                let mut right =
+                    BTreeMap::from_iter((left_len..left_len + right_len).map(|i| (i, i)));
+                if compact {
+                    // Full nodes, so that attaching a subtree splits all the way up.
+                    left.compact();
+                    right.compact();
+                }
+                if swap {
+                    mem::swap(&mut left, &mut right);
+                }
+                left.append(&mut right);
+                left.check();
+                right.check();
+                assert_eq!(right.len(), 0);
+                assert!(left.into_iter().eq((0..left_len + right_len).map(|i| (i, i))));
+            }
+        }
+    }
+}
+
 #[test]
 fn test_append_ord_chaos() {
     let mut map1 = BTreeMap::new();
@@ -2085,6 +2205,23 @@ fn test_split_off_large_random_sorted() {
     assert!(right.into_iter().eq(data.into_iter().filter(|x| x.0 >= key)));
 }
 
+#[test]
+fn test_split_off_bound() {
+    let data = Vec::from_iter((0..MIN_INSERTS_HEIGHT_2 as u32).map(|i| (i * 2, i)));
+    for key in [0, 1, 2, 88, 89, 176, 177] {
+        for bound in [Included(&key), Excluded(&key), Unbounded] {
+            let mut map = BTreeMap::from_iter(data.clone());
+            let right = map.split_off_bound(bound);
+            map.check();
+            right.check();
+
+            let in_right = |x: &(u32, u32)| (bound, Unbounded).contains(&x.0);
+            assert!(map.into_iter().eq(data.iter().copied().filter(|x| !in_right(x))));
+            assert!(right.into_iter().eq(data.iter().copied().filter(in_right)));
+        }
+    }
+}
+
 #[test]
 fn test_into_iter_drop_leak_height_0() {
     let a = CrashTestDummy::new(0);
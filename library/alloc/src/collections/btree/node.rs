@@ -422,6 +422,16 @@ unsafe fn reborrow_mut(&mut self) -> NodeRef<marker::Mut<'_>, K, V, Type> {
         NodeRef { height: self.height, node: self.node, _marker: PhantomData }
     }
 
+    /// Takes out another mutable reference to the same node, with a lifetime that is not tied
+    /// to `self`. Even more dangerous than `reborrow_mut`.
+    ///
+    /// The returned reference may be kept while the tree is restructured through other
+    /// references, as a marker of a node that stays put, but must not be used until all of
+    /// those other references are gone.
+    unsafe fn alias_mut<'b>(&self) -> NodeRef<marker::Mut<'b>, K, V, Type> {
+        NodeRef { height: self.height, node: self.node, _marker: PhantomData }
+    }
+
     /// Borrows exclusive access to the leaf portion of a leaf or internal node.
     fn as_leaf_mut(&mut self) -> &mut LeafNode<K, V> {
         let ptr = Self::as_leaf_ptr(self);
@@ -649,6 +659,23 @@ pub fn push(&mut self, key: K, val: V, edge: Root<K, V>) {
             Handle::new_edge(self.reborrow_mut(), idx + 1).correct_parent_link();
         }
     }
+
+    /// Adds a key-value pair, and an edge to go to the left of that pair,
+    /// to the beginning of the node.
+    pub fn push_front(&mut self, key: K, val: V, edge: Root<K, V>) {
+        assert!(edge.height == self.height - 1);
+
+        let new_len = self.len() + 1;
+        assert!(new_len <= CAPACITY);
+        unsafe {
+            slice_insert(self.key_area_mut(..new_len), 0, key);
+            slice_insert(self.val_area_mut(..new_len), 0, val);
+            slice_insert(self.edge_area_mut(..new_len + 1), 0, edge.node);
+            *self.len_mut() = new_len as u16;
+
+            self.correct_all_childrens_parent_links();
+        }
+    }
 }
 
 impl<BorrowType, K, V> NodeRef<BorrowType, K, V, marker::Leaf> {
@@ -841,8 +868,14 @@ impl<'a, K: 'a, V: 'a> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, mark
     /// this edge. This method assumes that there is enough space in the node for the new
     /// pair to fit.
     ///
-    /// The returned pointer points to the inserted value.
-    fn insert_fit(&mut self, key: K, val: V) -> *mut V {
+    /// The returned handle points to the inserted key-value pair. It aliases `self` and is not
+    /// bound to its lifetime, so it must not be used while the tree is still being modified
+    /// through other references.
+    fn insert_fit<'b>(
+        &mut self,
+        key: K,
+        val: V,
+    ) -> Handle<NodeRef<marker::Mut<'b>, K, V, marker::Leaf>, marker::KV> {
         debug_assert!(self.node.len() < CAPACITY);
         let new_len = self.node.len() + 1;
 
@@ -851,7 +884,7 @@ fn insert_fit(&mut self, key: K, val: V) -> *mut V {
             slice_insert(self.node.val_area_mut(..new_len), self.idx, val);
             *self.node.len_mut() = new_len as u16;
 
-            self.node.val_area_mut(self.idx).assume_init_mut()
+            Handle::new_kv(self.node.alias_mut(), self.idx)
         }
     }
 }
@@ -860,12 +893,19 @@ impl<'a, K: 'a, V: 'a> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, mark
     /// Inserts a new key-value pair between the key-value pairs to the right and left of
     /// this edge. This method splits the node if there isn't enough room.
     ///
-    /// The returned pointer points to the inserted value.
-    fn insert(mut self, key: K, val: V) -> (InsertResult<'a, K, V, marker::Leaf>, *mut V) {
+    /// The returned handle points to the inserted key-value pair, see `insert_fit`.
+    fn insert(
+        mut self,
+        key: K,
+        val: V,
+    ) -> (
+        InsertResult<'a, K, V, marker::Leaf>,
+        Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::KV>,
+    ) {
         if self.node.len() < CAPACITY {
-            let val_ptr = self.insert_fit(key, val);
+            let inserted = self.insert_fit(key, val);
             let kv = unsafe { Handle::new_kv(self.node, self.idx) };
-            (InsertResult::Fit(kv), val_ptr)
+            (InsertResult::Fit(kv), inserted)
         } else {
             let (middle_kv_idx, insertion) = splitpoint(self.idx);
             let middle = unsafe { Handle::new_kv(self.node, middle_kv_idx) };
@@ -878,8 +918,8 @@ fn insert(mut self, key: K, val: V) -> (InsertResult<'a, K, V, marker::Leaf>, *m
                     Handle::new_edge(result.right.borrow_mut(), insert_idx)
                 },
             };
-            let val_ptr = insertion_edge.insert_fit(key, val);
-            (InsertResult::Split(result), val_ptr)
+            let inserted = insertion_edge.insert_fit(key, val);
+            (InsertResult::Split(result), inserted)
         }
     }
 }
@@ -955,29 +995,33 @@ impl<'a, K: 'a, V: 'a> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, mark
     ///
     /// If the returned result is a `Fit`, its handle's node can be this edge's node or an ancestor.
     /// If the returned result is a `Split`, the `left` field will be the root node.
-    /// The returned pointer points to the inserted value.
+    /// The returned handle points to the inserted key-value pair. It stays valid while the
+    /// caller finishes growing the tree, but must not be used before that.
     pub fn insert_recursing(
         self,
         key: K,
         value: V,
-    ) -> (InsertResult<'a, K, V, marker::LeafOrInternal>, *mut V) {
-        let (mut split, val_ptr) = match self.insert(key, value) {
-            (InsertResult::Fit(handle), ptr) => {
-                return (InsertResult::Fit(handle.forget_node_type()), ptr);
+    ) -> (
+        InsertResult<'a, K, V, marker::LeafOrInternal>,
+        Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::KV>,
+    ) {
+        let (mut split, inserted) = match self.insert(key, value) {
+            (InsertResult::Fit(handle), inserted) => {
+                return (InsertResult::Fit(handle.forget_node_type()), inserted);
             }
-            (InsertResult::Split(split), val_ptr) => (split.forget_node_type(), val_ptr),
+            (InsertResult::Split(split), inserted) => (split.forget_node_type(), inserted),
         };
 
         loop {
             split = match split.left.ascend() {
                 Ok(parent) => match parent.insert(split.kv.0, split.kv.1, split.right) {
                     InsertResult::Fit(handle) => {
-                        return (InsertResult::Fit(handle.forget_node_type()), val_ptr);
+                        return (InsertResult::Fit(handle.forget_node_type()), inserted);
                     }
                     InsertResult::Split(split) => split.forget_node_type(),
                 },
                 Err(root) => {
-                    return (InsertResult::Split(SplitResult { left: root, ..split }), val_ptr);
+                    return (InsertResult::Split(SplitResult { left: root, ..split }), inserted);
                 }
             };
         }
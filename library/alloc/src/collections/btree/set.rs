@@ -7,7 +7,7 @@
 use core::cmp::{max, min};
 use core::fmt::{self, Debug};
 use core::iter::{FromIterator, FusedIterator, Peekable};
-use core::ops::{BitAnd, BitOr, BitXor, RangeBounds, Sub};
+use core::ops::{BitAnd, BitOr, BitXor, Bound, RangeBounds, Sub};
 
 use super::map::{BTreeMap, Keys};
 use super::merge_iter::MergeIterInner;
@@ -965,6 +965,35 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, value: &Q) -> Self
         BTreeSet { map: self.map.split_off(value) }
     }
 
+    /// Splits the collection into two at the given bound. Returns a new
+    /// collection with all elements within the bound, from the bound to the end.
+    ///
+    /// A bound of `Included(value)` behaves like [`split_off`], `Excluded(value)`
+    /// leaves the value itself in the set, and `Unbounded` moves out everything.
+    ///
+    /// [`split_off`]: BTreeSet::split_off
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_split_off_bound)]
+    /// use std::collections::BTreeSet;
+    /// use std::ops::Bound::Excluded;
+    ///
+    /// let mut a = BTreeSet::from([1, 2, 3, 17, 41]);
+    /// let b = a.split_off_bound(Excluded(&3));
+    ///
+    /// assert!(a.into_iter().eq([1, 2, 3]));
+    /// assert!(b.into_iter().eq([17, 41]));
+    /// ```
+    #[unstable(feature = "btree_split_off_bound", issue = "none")]
+    pub fn split_off_bound<Q: ?Sized + Ord>(&mut self, bound: Bound<&Q>) -> Self
+    where
+        T: Borrow<Q> + Ord,
+    {
+        BTreeSet { map: self.map.split_off_bound(bound) }
+    }
+
     /// Creates an iterator that visits all elements in ascending order and
     /// uses a closure to determine if an element should be removed.
     ///
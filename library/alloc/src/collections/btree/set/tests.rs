@@ -770,6 +770,24 @@ fn test_split_off_large_random_sorted() {
     assert!(right.into_iter().eq(data.into_iter().filter(|x| *x >= key)));
 }
 
+#[test]
+fn test_split_off_bound() {
+    let mut data = rand_data(529);
+    data.sort();
+    data.dedup();
+    let key = data[data.len() / 2];
+
+    let mut set = BTreeSet::from_iter(data.clone());
+    let right = set.split_off_bound(Bound::Excluded(&key));
+    assert!(set.into_iter().eq(data.iter().copied().filter(|x| *x <= key)));
+    assert!(right.into_iter().eq(data.iter().copied().filter(|x| *x > key)));
+
+    let mut set = BTreeSet::from_iter(data.clone());
+    let right = set.split_off_bound(Bound::Unbounded);
+    assert!(set.is_empty());
+    assert!(right.into_iter().eq(data));
+}
+
 #[test]
 fn from_array() {
     let set = BTreeSet::from([1, 2, 3, 4]);
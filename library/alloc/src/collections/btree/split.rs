@@ -1,6 +1,7 @@
 use super::node::{ForceResult::*, Root};
 use super::search::SearchResult::*;
 use core::borrow::Borrow;
+use core::ops::Bound::{self, *};
 
 impl<K, V> Root<K, V> {
     /// Calculates the length of both trees that result from splitting up
@@ -23,12 +24,12 @@ pub fn calc_split_length(
         (length_a, length_b)
     }
 
-    /// Split off a tree with key-value pairs at and after the given key.
+    /// Split off a tree with the key-value pairs within the given lower bound.
     /// The result is meaningful only if the tree is ordered by key,
     /// and if the ordering of `Q` corresponds to that of `K`.
     /// If `self` respects all `BTreeMap` tree invariants, then both
     /// `self` and the returned tree will respect those invariants.
-    pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
+    pub fn split_off_bound<Q: ?Sized + Ord>(&mut self, bound: Bound<&Q>) -> Self
     where
         K: Borrow<Q>,
     {
@@ -38,10 +39,18 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
         let mut right_node = right_root.borrow_mut();
 
         loop {
-            let mut split_edge = match left_node.search_node(key) {
-                // key is going to the right tree
-                Found(kv) => kv.left_edge(),
-                GoDown(edge) => edge,
+            let mut split_edge = match bound {
+                Included(key) => match left_node.search_node(key) {
+                    // key is going to the right tree
+                    Found(kv) => kv.left_edge(),
+                    GoDown(edge) => edge,
+                },
+                Excluded(key) => match left_node.search_node(key) {
+                    // key is staying in the left tree
+                    Found(kv) => kv.right_edge(),
+                    GoDown(edge) => edge,
+                },
+                Unbounded => left_node.first_edge(),
             };
 
             split_edge.move_suffix(&mut right_node);
@@ -146,6 +146,40 @@ fn fmt(
     }
 }
 
+/// Extend a collection with the contents of an iterator, reporting allocation
+/// failure instead of aborting.
+///
+/// This is the fallible counterpart of [`Extend`]: where `extend` panics or
+/// aborts when the collection cannot grow, `try_extend` returns a
+/// [`TryReserveError`].
+///
+/// If an error is returned, the elements taken from the iterator before the
+/// failure have been added to the collection, the element that did not fit is
+/// dropped, and the rest of the iterator is left unconsumed. The collection is
+/// always left in a valid state.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(try_extend)]
+/// use std::collections::{TryExtend, TryReserveError};
+///
+/// fn double(data: impl Iterator<Item = u32>) -> Result<Vec<u32>, TryReserveError> {
+///     let mut output = Vec::new();
+///     output.try_extend(data.map(|x| x * 2))?;
+///     Ok(output)
+/// }
+///
+/// assert_eq!(double(vec![1, 2, 3].into_iter()), Ok(vec![2, 4, 6]));
+/// ```
+#[unstable(feature = "try_extend", issue = "none")]
+pub trait TryExtend<A> {
+    /// Extends a collection with the contents of an iterator, returning an
+    /// error if the collection could not allocate room for them.
+    #[unstable(feature = "try_extend", issue = "none")]
+    fn try_extend<I: IntoIterator<Item = A>>(&mut self, iter: I) -> Result<(), TryReserveError>;
+}
+
 /// An intermediate trait for specialization of `Extend`.
 #[doc(hidden)]
 trait SpecExtend<I: IntoIterator> {
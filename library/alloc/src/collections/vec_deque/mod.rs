@@ -18,6 +18,7 @@
 use core::slice;
 
 use crate::alloc::{Allocator, Global};
+use crate::collections::TryExtend;
 use crate::collections::TryReserveError;
 use crate::collections::TryReserveErrorKind;
 use crate::raw_vec::RawVec;
@@ -54,6 +55,10 @@
 
 mod ring_slices;
 
+use self::spec_extend::SpecExtend;
+
+mod spec_extend;
+
 #[cfg(test)]
 mod tests;
 
@@ -2908,24 +2913,7 @@ fn into_iter(self) -> IterMut<'a, T> {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T, A: Allocator> Extend<T> for VecDeque<T, A> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        // This function should be the moral equivalent of:
-        //
-        //      for item in iter.into_iter() {
-        //          self.push_back(item);
-        //      }
-        let mut iter = iter.into_iter();
-        while let Some(element) = iter.next() {
-            if self.len() == self.capacity() {
-                let (lower, _) = iter.size_hint();
-                self.reserve(lower.saturating_add(1));
-            }
-
-            let head = self.head;
-            self.head = self.wrap_add(self.head, 1);
-            unsafe {
-                self.buffer_write(head, element);
-            }
-        }
+        self.spec_extend(iter.into_iter());
     }
 
     #[inline]
@@ -2942,7 +2930,7 @@ fn extend_reserve(&mut self, additional: usize) {
 #[stable(feature = "extend_ref", since = "1.2.0")]
 impl<'a, T: 'a + Copy, A: Allocator> Extend<&'a T> for VecDeque<T, A> {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
-        self.extend(iter.into_iter().cloned());
+        self.spec_extend(iter.into_iter());
     }
 
     #[inline]
@@ -2956,6 +2944,32 @@ fn extend_reserve(&mut self, additional: usize) {
     }
 }
 
+#[unstable(feature = "try_extend", issue = "none")]
+impl<T, A: Allocator> TryExtend<T> for VecDeque<T, A> {
+    fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), TryReserveError> {
+        let mut iter = iter.into_iter();
+        while let Some(element) = iter.next() {
+            if self.len() == self.capacity() {
+                let (lower, _) = iter.size_hint();
+                self.try_reserve(lower.saturating_add(1))?;
+            }
+            // There is room for `element`, so this does not grow the buffer.
+            self.push_back(element);
+        }
+        Ok(())
+    }
+}
+
+#[unstable(feature = "try_extend", issue = "none")]
+impl<'a, T: 'a + Copy, A: Allocator> TryExtend<&'a T> for VecDeque<T, A> {
+    fn try_extend<I: IntoIterator<Item = &'a T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryReserveError> {
+        self.try_extend(iter.into_iter().copied())
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: fmt::Debug, A: Allocator> fmt::Debug for VecDeque<T, A> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -309,6 +309,23 @@ pub fn try_reserve(&mut self, len: usize, additional: usize) -> Result<(), TryRe
         }
     }
 
+    /// Returns the capacity that `reserve(len, additional)` would grow the
+    /// buffer to, without allocating. This is the current capacity if it is
+    /// already sufficient.
+    pub fn capacity_after_reserve(
+        &self,
+        len: usize,
+        additional: usize,
+    ) -> Result<usize, TryReserveError> {
+        if !self.needs_to_grow(len, additional) {
+            return Ok(self.capacity());
+        }
+        let cap = self.amortized_capacity(len, additional)?;
+        let layout = Layout::array::<T>(cap).map_err(|_| CapacityOverflow)?;
+        alloc_guard(layout.size())?;
+        Ok(cap)
+    }
+
     /// Ensures that the buffer contains at least enough space to hold `len +
     /// additional` elements. If it doesn't already, will reallocate the
     /// minimum possible amount of memory necessary. Generally this will be
@@ -382,6 +399,19 @@ fn grow_amortized(&mut self, len: usize, additional: usize) -> Result<(), TryRes
         // This is ensured by the calling contexts.
         debug_assert!(additional > 0);
 
+        let cap = self.amortized_capacity(len, additional)?;
+        let new_layout = Layout::array::<T>(cap);
+
+        // `finish_grow` is non-generic over `T`.
+        let ptr = finish_grow(new_layout, self.current_memory(), &mut self.alloc)?;
+        self.set_ptr_and_cap(ptr, cap);
+        Ok(())
+    }
+
+    // The capacity `grow_amortized` grows to, shared with
+    // `capacity_after_reserve` so the two cannot disagree.
+    #[inline]
+    fn amortized_capacity(&self, len: usize, additional: usize) -> Result<usize, TryReserveError> {
         if mem::size_of::<T>() == 0 {
             // Since we return a capacity of `usize::MAX` when `elem_size` is
             // 0, getting to here necessarily means the `RawVec` is overfull.
@@ -394,14 +424,7 @@ fn grow_amortized(&mut self, len: usize, additional: usize) -> Result<(), TryRes
         // This guarantees exponential growth. The doubling cannot overflow
         // because `cap <= isize::MAX` and the type of `cap` is `usize`.
         let cap = cmp::max(self.cap * 2, required_cap);
-        let cap = cmp::max(Self::MIN_NON_ZERO_CAP, cap);
-
-        let new_layout = Layout::array::<T>(cap);
-
-        // `finish_grow` is non-generic over `T`.
-        let ptr = finish_grow(new_layout, self.current_memory(), &mut self.alloc)?;
-        self.set_ptr_and_cap(ptr, cap);
-        Ok(())
+        Ok(cmp::max(Self::MIN_NON_ZERO_CAP, cap))
     }
 
     // The constraints on this method are much the same as those on
@@ -249,6 +249,7 @@
 
 use core::any::Any;
 use core::borrow;
+use core::clone::CloneToUninit;
 use core::cell::Cell;
 use core::cmp::Ordering;
 use core::convert::{From, TryFrom};
@@ -272,7 +273,7 @@
 #[cfg(not(no_global_oom_handling))]
 use crate::alloc::handle_alloc_error;
 #[cfg(not(no_global_oom_handling))]
-use crate::alloc::{box_free, WriteCloneIntoRaw};
+use crate::alloc::box_free;
 use crate::alloc::{AllocError, Allocator, Global, Layout};
 use crate::borrow::{Cow, ToOwned};
 #[cfg(not(no_global_oom_handling))]
@@ -1108,7 +1109,7 @@ pub fn ptr_eq(this: &Self, other: &Self) -> bool {
     }
 }
 
-impl<T: Clone> Rc<T> {
+impl<T: ?Sized + CloneToUninit> Rc<T> {
     /// Makes a mutable reference into the given `Rc`.
     ///
     /// If there are other `Rc` pointers to the same allocation, then `make_mut` will
@@ -1159,31 +1160,73 @@ impl<T: Clone> Rc<T> {
     /// assert!(76 == *data);
     /// assert!(weak.upgrade().is_none());
     /// ```
+    ///
+    /// This also works for unsized contents such as slices and strings:
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    ///
+    /// let mut table: Rc<[u32]> = Rc::from(vec![1, 2, 3]);
+    /// let snapshot = Rc::clone(&table);
+    ///
+    /// Rc::make_mut(&mut table)[0] = 10; // Clones into a new allocation
+    /// assert_eq!(*table, [10, 2, 3]);
+    /// assert_eq!(*snapshot, [1, 2, 3]);
+    ///
+    /// let mut name: Rc<str> = Rc::from("config");
+    /// Rc::make_mut(&mut name).make_ascii_uppercase(); // Won't clone anything
+    /// assert_eq!(&*name, "CONFIG");
+    /// ```
     #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[stable(feature = "rc_unique", since = "1.4.0")]
     pub fn make_mut(this: &mut Self) -> &mut T {
+        // Frees a freshly allocated `RcBox` if cloning into it panics.
+        struct FreeOnUnwind {
+            mem: NonNull<u8>,
+            layout: Layout,
+        }
+
+        impl Drop for FreeOnUnwind {
+            fn drop(&mut self) {
+                // SAFETY: `mem` was allocated by `Global` with `layout`, and
+                // nothing else refers to it yet.
+                unsafe { Global.deallocate(self.mem, self.layout) }
+            }
+        }
+
         if Rc::strong_count(this) != 1 {
             // Gotta clone the data, there are other Rcs.
             // Pre-allocate memory to allow writing the cloned value directly.
-            let mut rc = Self::new_uninit();
+            let this_data_ref: &T = &**this;
             unsafe {
-                let data = Rc::get_mut_unchecked(&mut rc);
-                (**this).write_clone_into_raw(data.as_mut_ptr());
-                *this = rc.assume_init();
+                let ptr = Self::allocate_for_ptr(this_data_ref);
+                let layout = Layout::new::<RcBox<()>>()
+                    .extend(Layout::for_value(this_data_ref))
+                    .unwrap()
+                    .0
+                    .pad_to_align();
+                let guard = FreeOnUnwind { mem: NonNull::new_unchecked(ptr as *mut u8), layout };
+                this_data_ref.clone_to_uninit(ptr::addr_of_mut!((*ptr).value));
+                mem::forget(guard);
+                *this = Self::from_ptr(ptr);
             }
         } else if Rc::weak_count(this) != 0 {
             // Can just steal the data, all that's left is Weaks
-            let mut rc = Self::new_uninit();
             unsafe {
-                let data = Rc::get_mut_unchecked(&mut rc);
-                data.as_mut_ptr().copy_from_nonoverlapping(&**this, 1);
+                let this_data_ref: &T = &**this;
+                let ptr = Self::allocate_for_ptr(this_data_ref);
+                ptr::copy_nonoverlapping(
+                    this_data_ref as *const T as *const u8,
+                    ptr::addr_of_mut!((*ptr).value) as *mut u8,
+                    size_of_val(this_data_ref),
+                );
 
                 this.inner().dec_strong();
                 // Remove implicit strong-weak ref (no need to craft a fake
                 // Weak here -- we know other Weaks can clean up for us)
                 this.inner().dec_weak();
-                ptr::write(this, rc.assume_init());
+                ptr::write(this, Self::from_ptr(ptr));
             }
         }
         // This unsafety is ok because we're guaranteed that the pointer
@@ -1863,6 +1906,10 @@ fn from(v: Box<T>) -> Rc<T> {
 impl<T> From<Vec<T>> for Rc<[T]> {
     /// Allocate a reference-counted slice and move `v`'s items into it.
     ///
+    /// If `T` is at least as aligned as `usize`, the vector's allocation is
+    /// resized and reused instead of allocating a new one and freeing the
+    /// old one.
+    ///
     /// # Example
     ///
     /// ```
@@ -1872,14 +1919,49 @@ impl<T> From<Vec<T>> for Rc<[T]> {
     /// assert_eq!(vec![1, 2, 3], *shared);
     /// ```
     #[inline]
-    fn from(mut v: Vec<T>) -> Rc<[T]> {
-        unsafe {
-            let rc = Rc::copy_from_slice(&v);
+    fn from(v: Vec<T>) -> Rc<[T]> {
+        let (vec_ptr, len, cap) = v.into_raw_parts();
+        let value_layout = Layout::array::<T>(len).unwrap();
+        let (layout, offset) = Layout::new::<RcBox<()>>().extend(value_layout).unwrap();
+        let layout = layout.pad_to_align();
+
+        // The vector's allocation can only be handed to the `RcBox` if they
+        // share an alignment, which requires `T` to be at least as aligned as
+        // the reference counts.
+        if mem::size_of::<T>() == 0 || cap == 0 || layout.align() != mem::align_of::<T>() {
+            unsafe {
+                let mut v = Vec::from_raw_parts(vec_ptr, len, cap);
+                let rc = Rc::copy_from_slice(&v);
 
-            // Allow the Vec to free its memory, but not destroy its contents
-            v.set_len(0);
+                // Allow the Vec to free its memory, but not destroy its contents
+                v.set_len(0);
 
-            rc
+                return rc;
+            }
+        }
+
+        unsafe {
+            // Resize the allocation to fit the reference counts in front of
+            // the elements, then shift the elements behind them.
+            let old_layout = Layout::array::<T>(cap).unwrap_unchecked();
+            let old = NonNull::new_unchecked(vec_ptr as *mut u8);
+            let new = if layout.size() > old_layout.size() {
+                Global.grow(old, old_layout, layout)
+            } else {
+                Global.shrink(old, old_layout, layout)
+            };
+            let mem = match new {
+                Ok(mem) => mem.as_non_null_ptr().as_ptr(),
+                Err(_) => handle_alloc_error(layout),
+            };
+            ptr::copy(mem as *const T, mem.add(offset) as *mut T, len);
+
+            let inner = ptr::slice_from_raw_parts_mut(mem as *mut T, len) as *mut RcBox<[T]>;
+            debug_assert_eq!(Layout::for_value(&*inner), layout);
+            ptr::write(&mut (*inner).strong, Cell::new(1));
+            ptr::write(&mut (*inner).weak, Cell::new(1));
+
+            Self::from_ptr(inner)
         }
     }
 }
@@ -2273,6 +2355,34 @@ pub fn strong_count(&self) -> usize {
         if let Some(inner) = self.inner() { inner.strong() } else { 0 }
     }
 
+    /// Returns `true` if the value this `Weak` points to has not been dropped,
+    /// i.e. if there is at least one strong (`Rc`) pointer left.
+    ///
+    /// This is equivalent to `self.strong_count() != 0`, and avoids the
+    /// reference count traffic of `self.upgrade().is_some()`. If `self` was
+    /// created using [`Weak::new`], this will return `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(weak_is_alive)]
+    /// use std::rc::{self, Rc};
+    ///
+    /// let strong = Rc::new(5);
+    /// let weak = Rc::downgrade(&strong);
+    /// assert!(weak.is_alive());
+    ///
+    /// drop(strong);
+    /// assert!(!weak.is_alive());
+    /// assert!(!rc::Weak::<i32>::new().is_alive());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "weak_is_alive", issue = "none")]
+    pub fn is_alive(&self) -> bool {
+        self.strong_count() != 0
+    }
+
     /// Gets the number of `Weak` pointers pointing to this allocation.
     ///
     /// If no strong pointers remain, this will return zero.
@@ -110,6 +110,10 @@
 pub use core::slice::ArrayWindows;
 #[unstable(feature = "inherent_ascii_escape", issue = "77174")]
 pub use core::slice::EscapeAscii;
+#[unstable(feature = "get_disjoint_mut", issue = "none")]
+pub use core::slice::GetDisjointMutError;
+#[unstable(feature = "get_disjoint_mut_helpers", issue = "none")]
+pub use core::slice::GetDisjointMutIndex;
 #[stable(feature = "slice_get_slice", since = "1.28.0")]
 pub use core::slice::SliceIndex;
 #[stable(feature = "from_ref", since = "1.28.0")]
@@ -413,45 +417,47 @@ pub fn sort_by_cached_key<K, F>(&mut self, f: F)
         F: FnMut(&T) -> K,
         K: Ord,
     {
-        // Helper macro for indexing our vector by the smallest possible type, to reduce allocation.
-        macro_rules! sort_by_key {
-            ($t:ty, $slice:ident, $f:ident) => {{
-                let mut indices: Vec<_> =
-                    $slice.iter().map($f).enumerate().map(|(i, k)| (k, i as $t)).collect();
-                // The elements of `indices` are unique, as they are indexed, so any sort will be
-                // stable with respect to the original slice. We use `sort_unstable` here because
-                // it requires less memory allocation.
-                indices.sort_unstable();
-                for i in 0..$slice.len() {
-                    let mut index = indices[i].1;
-                    while (index as usize) < i {
-                        index = indices[index as usize].1;
-                    }
-                    indices[i].1 = index;
-                    $slice.swap(i, index as usize);
-                }
-            }};
-        }
-
-        let sz_u8 = mem::size_of::<(K, u8)>();
-        let sz_u16 = mem::size_of::<(K, u16)>();
-        let sz_u32 = mem::size_of::<(K, u32)>();
-        let sz_usize = mem::size_of::<(K, usize)>();
+        cached_key_sort(self, f, true)
+    }
 
-        let len = self.len();
-        if len < 2 {
-            return;
-        }
-        if sz_u8 < sz_u16 && len <= (u8::MAX as usize) {
-            return sort_by_key!(u8, self, f);
-        }
-        if sz_u16 < sz_u32 && len <= (u16::MAX as usize) {
-            return sort_by_key!(u16, self, f);
-        }
-        if sz_u32 < sz_usize && len <= (u32::MAX as usize) {
-            return sort_by_key!(u32, self, f);
-        }
-        sort_by_key!(usize, self, f)
+    /// Sorts the slice with a key extraction function, but might not preserve the order of
+    /// equal elements.
+    ///
+    /// During sorting, the key function is called only once per element.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements) and
+    /// *O*(*m* \* *n* + *n* \* log(*n*)) worst-case, where the key function is *O*(*m*).
+    ///
+    /// For simple key functions (e.g., functions that are property accesses or
+    /// basic operations), [`sort_unstable_by_key`](slice::sort_unstable_by_key) is likely to be
+    /// faster. Compared to [`sort_by_cached_key`](slice::sort_by_cached_key), this avoids
+    /// comparing element positions when keys are equal.
+    ///
+    /// # Current implementation
+    ///
+    /// The keys are computed up front into a `Vec<(K, usize)>` the length of the slice, which is
+    /// then sorted with [`sort_unstable_by`](slice::sort_unstable_by) and used to permute the
+    /// slice in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_sort_unstable_by_cached_key)]
+    ///
+    /// let mut v = [-5i32, 4, 32, -3, 2];
+    ///
+    /// v.sort_unstable_by_cached_key(|k| k.to_string());
+    /// assert!(v == [-3, -5, 2, 32, 4]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "slice_sort_unstable_by_cached_key", issue = "none")]
+    #[inline]
+    pub fn sort_unstable_by_cached_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        cached_key_sort(self, f, false)
     }
 
     /// Copies `self` into a new `Vec`.
@@ -865,6 +871,60 @@ fn clone_into(&self, target: &mut Vec<T>) {
 // Sorting
 ////////////////////////////////////////////////////////////////////////////////
 
+/// Shared implementation of `sort_by_cached_key` and `sort_unstable_by_cached_key`.
+///
+/// When `stable` is false, pairs with equal keys are not ordered by their original position.
+#[cfg(not(no_global_oom_handling))]
+fn cached_key_sort<T, K, F>(v: &mut [T], f: F, stable: bool)
+where
+    F: FnMut(&T) -> K,
+    K: Ord,
+{
+    // Helper macro for indexing our vector by the smallest possible type, to reduce allocation.
+    macro_rules! sort_by_key {
+        ($t:ty, $slice:ident, $f:ident) => {{
+            let mut indices: Vec<_> =
+                $slice.iter().map($f).enumerate().map(|(i, k)| (k, i as $t)).collect();
+            if stable {
+                // The elements of `indices` are unique, as they are indexed, so any sort will
+                // be stable with respect to the original slice. We use `sort_unstable` here
+                // because it requires less memory allocation.
+                indices.sort_unstable();
+            } else {
+                indices.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+            }
+            for i in 0..$slice.len() {
+                let mut index = indices[i].1;
+                while (index as usize) < i {
+                    index = indices[index as usize].1;
+                }
+                indices[i].1 = index;
+                $slice.swap(i, index as usize);
+            }
+        }};
+    }
+
+    let sz_u8 = mem::size_of::<(K, u8)>();
+    let sz_u16 = mem::size_of::<(K, u16)>();
+    let sz_u32 = mem::size_of::<(K, u32)>();
+    let sz_usize = mem::size_of::<(K, usize)>();
+
+    let len = v.len();
+    if len < 2 {
+        return;
+    }
+    if sz_u8 < sz_u16 && len <= (u8::MAX as usize) {
+        return sort_by_key!(u8, v, f);
+    }
+    if sz_u16 < sz_u32 && len <= (u16::MAX as usize) {
+        return sort_by_key!(u16, v, f);
+    }
+    if sz_u32 < sz_usize && len <= (u32::MAX as usize) {
+        return sort_by_key!(u32, v, f);
+    }
+    sort_by_key!(usize, v, f)
+}
+
 /// Inserts `v[0]` into pre-sorted sequence `v[1..]` so that whole `v[..]` becomes sorted.
 ///
 /// This is the integral subroutine of insertion sort.
@@ -1098,7 +1158,14 @@ fn merge_sort<T, F>(v: &mut [T], mut is_less: F)
     // strange decision, but consider the fact that merges more often go in the opposite direction
     // (forwards). According to benchmarks, merging forwards is slightly faster than merging
     // backwards. To conclude, identifying runs by traversing backwards improves performance.
-    let mut runs = vec![];
+    //
+    // Runs are merged following the powersort policy: every boundary between two adjacent runs
+    // is assigned the depth it would have in a nearly-optimal binary merge tree, and a boundary
+    // is merged as soon as a shallower boundary is found to its left. The depths on the stack
+    // strictly increase towards the top, so it holds at most 65 runs, and merges stay balanced
+    // even when the natural runs have very different lengths. The scratch buffer is unchanged.
+    let scale_factor = merge_tree_scale_factor(len);
+    let mut runs: Vec<Run> = vec![];
     let mut end = len;
     while end > 0 {
         // Find the next natural run, and reverse it if it's strictly descending.
@@ -1127,62 +1194,74 @@ fn merge_sort<T, F>(v: &mut [T], mut is_less: F)
             insert_head(&mut v[start..end], &mut is_less);
         }
 
+        // Merge every run on the stack whose left boundary lies deeper in the merge tree than the
+        // boundary between the new run and the run to its right.
+        let depth = match runs.last() {
+            Some(top) => merge_tree_depth(start, end, top.start + top.len, scale_factor),
+            None => 0,
+        };
+        while runs.len() >= 2 && runs[runs.len() - 1].depth >= depth {
+            unsafe { merge_top(&mut runs, v, buf.as_mut_ptr(), &mut is_less) };
+        }
+
         // Push this run onto the stack.
-        runs.push(Run { start, len: end - start });
+        runs.push(Run { start, len: end - start, depth });
         end = start;
+    }
 
-        // Merge some pairs of adjacent runs to satisfy the invariants.
-        while let Some(r) = collapse(&runs) {
-            let left = runs[r + 1];
-            let right = runs[r];
-            unsafe {
-                merge(
-                    &mut v[left.start..right.start + right.len],
-                    left.len,
-                    buf.as_mut_ptr(),
-                    &mut is_less,
-                );
-            }
-            runs[r] = Run { start: left.start, len: left.len + right.len };
-            runs.remove(r + 1);
-        }
+    // Merge the remaining runs, from the left.
+    while runs.len() >= 2 {
+        unsafe { merge_top(&mut runs, v, buf.as_mut_ptr(), &mut is_less) };
     }
 
     // Finally, exactly one run must remain in the stack.
     debug_assert!(runs.len() == 1 && runs[0].start == 0 && runs[0].len == len);
 
-    // Examines the stack of runs and identifies the next pair of runs to merge. More specifically,
-    // if `Some(r)` is returned, that means `runs[r]` and `runs[r + 1]` must be merged next. If the
-    // algorithm should continue building a new run instead, `None` is returned.
+    // Merges the two topmost runs on the stack, which are adjacent in `v`.
     //
-    // TimSort is infamous for its buggy implementations, as described here:
-    // http://envisage-project.eu/timsort-specification-and-verification/
+    // # Safety
     //
-    // The gist of the story is: we must enforce the invariants on the top four runs on the stack.
-    // Enforcing them on just top three is not sufficient to ensure that the invariants will still
-    // hold for *all* runs in the stack.
+    // `buf` must be valid for writes of `min(left.len, right.len)` elements, and the stack must
+    // hold at least two runs describing adjacent regions of `v`.
+    unsafe fn merge_top<T, F>(runs: &mut Vec<Run>, v: &mut [T], buf: *mut T, is_less: &mut F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let n = runs.len();
+        let left = runs[n - 1];
+        let right = runs[n - 2];
+        debug_assert_eq!(left.start + left.len, right.start);
+        // SAFETY: the runs are adjacent and sorted, and the caller guarantees that `buf` is large
+        // enough to hold the shorter of the two.
+        unsafe { merge(&mut v[left.start..right.start + right.len], left.len, buf, is_less) };
+        // The merged run keeps the boundary that separated `right` from the run below it.
+        runs[n - 2] = Run { start: left.start, len: left.len + right.len, depth: right.depth };
+        runs.pop();
+    }
+
+    // Returns a fixed-point factor mapping positions in a slice of length `n` onto `[0, 2^62)`.
+    #[inline]
+    fn merge_tree_scale_factor(n: usize) -> u64 {
+        ((1 << 62) + n as u64 - 1) / n as u64
+    }
+
+    // Computes the depth, in the powersort merge tree, of the boundary between the runs
+    // `left..mid` and `mid..right`.
     //
-    // This function correctly checks invariants for the top four runs. Additionally, if the top
-    // run starts at index 0, it will always demand a merge operation until the stack is fully
-    // collapsed, in order to complete the sort.
+    // The depth is the length of the common binary prefix of the two runs' midpoints, scaled to
+    // `[0, 1)`. Doubling the midpoints keeps them integral, which the scale factor accounts for.
     #[inline]
-    fn collapse(runs: &[Run]) -> Option<usize> {
-        let n = runs.len();
-        if n >= 2
-            && (runs[n - 1].start == 0
-                || runs[n - 2].len <= runs[n - 1].len
-                || (n >= 3 && runs[n - 3].len <= runs[n - 2].len + runs[n - 1].len)
-                || (n >= 4 && runs[n - 4].len <= runs[n - 3].len + runs[n - 2].len))
-        {
-            if n >= 3 && runs[n - 3].len < runs[n - 1].len { Some(n - 3) } else { Some(n - 2) }
-        } else {
-            None
-        }
+    fn merge_tree_depth(left: usize, mid: usize, right: usize, scale_factor: u64) -> u8 {
+        let x = left as u64 + mid as u64;
+        let y = mid as u64 + right as u64;
+        (scale_factor.wrapping_mul(x) ^ scale_factor.wrapping_mul(y)).leading_zeros() as u8
     }
 
     #[derive(Clone, Copy)]
     struct Run {
         start: usize,
         len: usize,
+        // Depth of the boundary between this run and the run to its right.
+        depth: u8,
     }
 }
@@ -71,6 +71,10 @@
 pub use core::str::{RSplitN, SplitN};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{RSplitTerminator, SplitTerminator};
+#[unstable(feature = "str_try_index", issue = "none")]
+pub use core::str::{StrIndexError, StrIndexErrorKind};
+#[unstable(feature = "utf8_chunks", issue = "none")]
+pub use core::str::{Utf8Chunk, Utf8Chunks};
 
 /// Note: `str` in `Concat<str>` is not meaningful here.
 /// This type parameter of the trait only exists to enable another impl.
@@ -65,7 +65,7 @@
 #[cfg(not(no_global_oom_handling))]
 use crate::borrow::{Cow, ToOwned};
 use crate::boxed::Box;
-use crate::collections::TryReserveError;
+use crate::collections::{TryExtend, TryReserveError};
 use crate::str::{self, Chars, Utf8Error};
 #[cfg(not(no_global_oom_handling))]
 use crate::str::{from_boxed_utf8_unchecked, FromStr};
@@ -585,6 +585,52 @@ pub fn from_utf8_lossy(v: &[u8]) -> Cow<'_, str> {
         Cow::Owned(res)
     }
 
+    /// Converts a vector of bytes to a `String`, including invalid characters.
+    ///
+    /// This works like [`from_utf8_lossy`], but takes ownership of the bytes.
+    /// If they are valid UTF-8, the vector's allocation is reused for the
+    /// `String` and nothing is copied; a new `String` is only allocated when
+    /// invalid sequences have to be replaced with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// [`from_utf8_lossy`]: String::from_utf8_lossy
+    /// [U+FFFD]: core::char::REPLACEMENT_CHARACTER
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(string_from_utf8_lossy_owned)]
+    /// // some bytes, in a vector
+    /// let sparkle_heart = vec![240, 159, 146, 150];
+    /// let ptr = sparkle_heart.as_ptr();
+    ///
+    /// let sparkle_heart = String::from_utf8_lossy_owned(sparkle_heart);
+    ///
+    /// assert_eq!("💖", sparkle_heart);
+    /// assert_eq!(ptr, sparkle_heart.as_ptr());
+    ///
+    /// // some invalid bytes
+    /// let input = b"Hello \xF0\x90\x80World".to_vec();
+    /// let output = String::from_utf8_lossy_owned(input);
+    ///
+    /// assert_eq!("Hello �World", output);
+    /// ```
+    #[must_use]
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "string_from_utf8_lossy_owned", issue = "none")]
+    pub fn from_utf8_lossy_owned(v: Vec<u8>) -> String {
+        if let Cow::Owned(string) = String::from_utf8_lossy(&v) {
+            string
+        } else {
+            // SAFETY: `String::from_utf8_lossy`'s contract ensures that if
+            // it returns a `Cow::Borrowed`, it is a valid UTF-8 string.
+            // Otherwise, it returns a new allocation of an owned `String`, with
+            // replacement characters for invalid sequences, which is returned
+            // above.
+            unsafe { String::from_utf8_unchecked(v) }
+        }
+    }
+
     /// Decode a UTF-16–encoded vector `v` into a `String`, returning [`Err`]
     /// if `v` contains any invalid data.
     ///
@@ -766,7 +812,8 @@ pub unsafe fn from_raw_parts(buf: *mut u8, length: usize, capacity: usize) -> St
     #[inline]
     #[must_use]
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub unsafe fn from_utf8_unchecked(bytes: Vec<u8>) -> String {
+    #[rustc_const_unstable(feature = "const_string_from_utf8_unchecked", issue = "none")]
+    pub const unsafe fn from_utf8_unchecked(bytes: Vec<u8>) -> String {
         String { vec: bytes }
     }
 
@@ -1803,6 +1850,30 @@ pub fn into_bytes(self) -> Vec<u8> {
         self.bytes
     }
 
+    /// Converts the bytes into a `String` lossily, substituting invalid UTF-8
+    /// sequences with replacement characters.
+    ///
+    /// See [`String::from_utf8_lossy`] for more details on replacement
+    /// characters, and [`String::from_utf8_lossy_owned`] for the
+    /// `String` function which corresponds to this function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(string_from_utf8_lossy_owned)]
+    /// // some invalid bytes
+    /// let input = b"Hello \xF0\x90\x80World".to_vec();
+    /// let output = String::from_utf8(input).unwrap_or_else(|e| e.into_utf8_lossy());
+    ///
+    /// assert_eq!(String::from("Hello �World"), output);
+    /// ```
+    #[must_use]
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "string_from_utf8_lossy_owned", issue = "none")]
+    pub fn into_utf8_lossy(self) -> String {
+        String::from_utf8_lossy_owned(self.bytes)
+    }
+
     /// Fetch a `Utf8Error` to get more details about the conversion failure.
     ///
     /// The [`Utf8Error`] type provided by [`std::str`] represents an error that may
@@ -1990,6 +2061,35 @@ fn extend_one(&mut self, s: &'a str) {
     }
 }
 
+impl String {
+    // Appends `s` only if there is room for all of it, so that a failed
+    // reservation never leaves part of a character behind.
+    fn try_push_str(&mut self, s: &str) -> Result<(), TryReserveError> {
+        self.vec.try_reserve(s.len())?;
+        self.vec.try_extend(s.as_bytes())
+    }
+}
+
+#[unstable(feature = "try_extend", issue = "none")]
+impl TryExtend<char> for String {
+    fn try_extend<I: IntoIterator<Item = char>>(&mut self, iter: I) -> Result<(), TryReserveError> {
+        let mut iterator = iter.into_iter();
+        let (lower_bound, _) = iterator.size_hint();
+        self.try_reserve(lower_bound)?;
+        iterator.try_for_each(move |c| self.try_push_str(c.encode_utf8(&mut [0; 4])))
+    }
+}
+
+#[unstable(feature = "try_extend", issue = "none")]
+impl<'a> TryExtend<&'a str> for String {
+    fn try_extend<I: IntoIterator<Item = &'a str>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryReserveError> {
+        iter.into_iter().try_for_each(move |s| self.try_push_str(s))
+    }
+}
+
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "box_str2", since = "1.45.0")]
 impl Extend<Box<str>> for String {
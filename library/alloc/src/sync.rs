@@ -6,6 +6,7 @@
 
 use core::any::Any;
 use core::borrow;
+use core::clone::CloneToUninit;
 use core::cmp::Ordering;
 use core::convert::{From, TryFrom};
 use core::fmt;
@@ -30,7 +31,7 @@
 #[cfg(not(no_global_oom_handling))]
 use crate::alloc::handle_alloc_error;
 #[cfg(not(no_global_oom_handling))]
-use crate::alloc::{box_free, WriteCloneIntoRaw};
+use crate::alloc::box_free;
 use crate::alloc::{AllocError, Allocator, Global, Layout};
 use crate::borrow::{Cow, ToOwned};
 use crate::boxed::Box;
@@ -1358,7 +1359,7 @@ fn deref(&self) -> &T {
 #[unstable(feature = "receiver_trait", issue = "none")]
 impl<T: ?Sized> Receiver for Arc<T> {}
 
-impl<T: Clone> Arc<T> {
+impl<T: ?Sized + CloneToUninit> Arc<T> {
     /// Makes a mutable reference into the given `Arc`.
     ///
     /// If there are other `Arc` pointers to the same allocation, then `make_mut` will
@@ -1409,10 +1410,41 @@ impl<T: Clone> Arc<T> {
     /// assert!(76 == *data);
     /// assert!(weak.upgrade().is_none());
     /// ```
+    ///
+    /// This also works for unsized contents such as slices and strings:
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// let mut table: Arc<[u32]> = Arc::from(vec![1, 2, 3]);
+    /// let snapshot = Arc::clone(&table);
+    ///
+    /// Arc::make_mut(&mut table)[0] = 10; // Clones into a new allocation
+    /// assert_eq!(*table, [10, 2, 3]);
+    /// assert_eq!(*snapshot, [1, 2, 3]);
+    ///
+    /// let mut name: Arc<str> = Arc::from("config");
+    /// Arc::make_mut(&mut name).make_ascii_uppercase(); // Won't clone anything
+    /// assert_eq!(&*name, "CONFIG");
+    /// ```
     #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[stable(feature = "arc_unique", since = "1.4.0")]
     pub fn make_mut(this: &mut Self) -> &mut T {
+        // Frees a freshly allocated `ArcInner` if cloning into it panics.
+        struct FreeOnUnwind {
+            mem: NonNull<u8>,
+            layout: Layout,
+        }
+
+        impl Drop for FreeOnUnwind {
+            fn drop(&mut self) {
+                // SAFETY: `mem` was allocated by `Global` with `layout`, and
+                // nothing else refers to it yet.
+                unsafe { Global.deallocate(self.mem, self.layout) }
+            }
+        }
+
         // Note that we hold both a strong reference and a weak reference.
         // Thus, releasing our strong reference only will not, by itself, cause
         // the memory to be deallocated.
@@ -1424,11 +1456,18 @@ pub fn make_mut(this: &mut Self) -> &mut T {
         if this.inner().strong.compare_exchange(1, 0, Acquire, Relaxed).is_err() {
             // Another strong pointer exists, so we must clone.
             // Pre-allocate memory to allow writing the cloned value directly.
-            let mut arc = Self::new_uninit();
+            let this_data_ref: &T = &**this;
             unsafe {
-                let data = Arc::get_mut_unchecked(&mut arc);
-                (**this).write_clone_into_raw(data.as_mut_ptr());
-                *this = arc.assume_init();
+                let ptr = Self::allocate_for_ptr(this_data_ref);
+                let layout = Layout::new::<ArcInner<()>>()
+                    .extend(Layout::for_value(this_data_ref))
+                    .unwrap()
+                    .0
+                    .pad_to_align();
+                let guard = FreeOnUnwind { mem: NonNull::new_unchecked(ptr as *mut u8), layout };
+                this_data_ref.clone_to_uninit(ptr::addr_of_mut!((*ptr).data));
+                mem::forget(guard);
+                *this = Self::from_ptr(ptr);
             }
         } else if this.inner().weak.load(Relaxed) != 1 {
             // Relaxed suffices in the above because this is fundamentally an
@@ -1448,11 +1487,15 @@ pub fn make_mut(this: &mut Self) -> &mut T {
             let _weak = Weak { ptr: this.ptr };
 
             // Can just steal the data, all that's left is Weaks
-            let mut arc = Self::new_uninit();
             unsafe {
-                let data = Arc::get_mut_unchecked(&mut arc);
-                data.as_mut_ptr().copy_from_nonoverlapping(&**this, 1);
-                ptr::write(this, arc.assume_init());
+                let this_data_ref: &T = &**this;
+                let ptr = Self::allocate_for_ptr(this_data_ref);
+                ptr::copy_nonoverlapping(
+                    this_data_ref as *const T as *const u8,
+                    ptr::addr_of_mut!((*ptr).data) as *mut u8,
+                    size_of_val(this_data_ref),
+                );
+                ptr::write(this, Self::from_ptr(ptr));
             }
         } else {
             // We were the sole reference of either kind; bump back up the
@@ -1916,6 +1959,39 @@ pub fn strong_count(&self) -> usize {
         if let Some(inner) = self.inner() { inner.strong.load(SeqCst) } else { 0 }
     }
 
+    /// Returns `true` if the value this `Weak` points to has not been dropped,
+    /// i.e. if there is at least one strong (`Arc`) pointer left.
+    ///
+    /// This is equivalent to `self.strong_count() != 0`, and avoids the
+    /// reference count traffic of `self.upgrade().is_some()`. If `self` was
+    /// created using [`Weak::new`], this will return `false`.
+    ///
+    /// Another thread may drop the last `Arc` right after this returns `true`,
+    /// so a `true` result only means that the value was alive at some point
+    /// during the call. A `false` result is final: once the strong count
+    /// reaches zero, the value can never be upgraded again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(weak_is_alive)]
+    /// use std::sync::{self, Arc};
+    ///
+    /// let strong = Arc::new(5);
+    /// let weak = Arc::downgrade(&strong);
+    /// assert!(weak.is_alive());
+    ///
+    /// drop(strong);
+    /// assert!(!weak.is_alive());
+    /// assert!(!sync::Weak::<i32>::new().is_alive());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "weak_is_alive", issue = "none")]
+    pub fn is_alive(&self) -> bool {
+        self.strong_count() != 0
+    }
+
     /// Gets an approximation of the number of `Weak` pointers pointing to this
     /// allocation.
     ///
@@ -2458,6 +2534,10 @@ fn from(v: Box<T>) -> Arc<T> {
 impl<T> From<Vec<T>> for Arc<[T]> {
     /// Allocate a reference-counted slice and move `v`'s items into it.
     ///
+    /// If `T` is at least as aligned as `usize`, the vector's allocation is
+    /// resized and reused instead of allocating a new one and freeing the
+    /// old one.
+    ///
     /// # Example
     ///
     /// ```
@@ -2467,14 +2547,49 @@ impl<T> From<Vec<T>> for Arc<[T]> {
     /// assert_eq!(&[1, 2, 3], &shared[..]);
     /// ```
     #[inline]
-    fn from(mut v: Vec<T>) -> Arc<[T]> {
-        unsafe {
-            let arc = Arc::copy_from_slice(&v);
+    fn from(v: Vec<T>) -> Arc<[T]> {
+        let (vec_ptr, len, cap) = v.into_raw_parts();
+        let value_layout = Layout::array::<T>(len).unwrap();
+        let (layout, offset) = Layout::new::<ArcInner<()>>().extend(value_layout).unwrap();
+        let layout = layout.pad_to_align();
+
+        // The vector's allocation can only be handed to the `ArcInner` if they
+        // share an alignment, which requires `T` to be at least as aligned as
+        // the reference counts.
+        if mem::size_of::<T>() == 0 || cap == 0 || layout.align() != mem::align_of::<T>() {
+            unsafe {
+                let mut v = Vec::from_raw_parts(vec_ptr, len, cap);
+                let arc = Arc::copy_from_slice(&v);
+
+                // Allow the Vec to free its memory, but not destroy its contents
+                v.set_len(0);
+
+                return arc;
+            }
+        }
 
-            // Allow the Vec to free its memory, but not destroy its contents
-            v.set_len(0);
+        unsafe {
+            // Resize the allocation to fit the reference counts in front of
+            // the elements, then shift the elements behind them.
+            let old_layout = Layout::array::<T>(cap).unwrap_unchecked();
+            let old = NonNull::new_unchecked(vec_ptr as *mut u8);
+            let new = if layout.size() > old_layout.size() {
+                Global.grow(old, old_layout, layout)
+            } else {
+                Global.shrink(old, old_layout, layout)
+            };
+            let mem = match new {
+                Ok(mem) => mem.as_non_null_ptr().as_ptr(),
+                Err(_) => handle_alloc_error(layout),
+            };
+            ptr::copy(mem as *const T, mem.add(offset) as *mut T, len);
+
+            let inner = ptr::slice_from_raw_parts_mut(mem as *mut T, len) as *mut ArcInner<[T]>;
+            debug_assert_eq!(Layout::for_value(&*inner), layout);
+            ptr::write(&mut (*inner).strong, atomic::AtomicUsize::new(1));
+            ptr::write(&mut (*inner).weak, atomic::AtomicUsize::new(1));
 
-            arc
+            Self::from_ptr(inner)
         }
     }
 }
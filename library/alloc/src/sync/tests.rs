@@ -242,6 +242,35 @@ fn test_cowarc_clone_weak() {
     assert!(cow1_weak.upgrade().is_none());
 }
 
+#[test]
+fn test_make_mut_unsized() {
+    use std::string::String;
+
+    let mut data: Arc<[String]> = Arc::from(vec![String::from("a"), String::from("b")]);
+    let other = Arc::clone(&data);
+    Arc::make_mut(&mut data)[0].push('!');
+    assert_eq!(*data, ["a!", "b"]);
+    assert_eq!(*other, ["a", "b"]);
+    assert_eq!(Arc::strong_count(&other), 1);
+
+    let weak = Arc::downgrade(&data);
+    let ptr = Arc::as_ptr(&data);
+    Arc::make_mut(&mut data)[1].push('?');
+    assert_eq!(*data, ["a!", "b?"]);
+    assert!(weak.upgrade().is_none());
+    assert!(!ptr::eq(ptr, Arc::as_ptr(&data)));
+
+    let ptr = Arc::as_ptr(&data);
+    Arc::make_mut(&mut data)[1].clear();
+    assert!(ptr::eq(ptr, Arc::as_ptr(&data)));
+
+    let mut s: Arc<str> = Arc::from("hello");
+    let other = Arc::clone(&s);
+    Arc::make_mut(&mut s).make_ascii_uppercase();
+    assert_eq!(&*s, "HELLO");
+    assert_eq!(&*other, "hello");
+}
+
 #[test]
 fn test_live() {
     let x = Arc::new(5);
@@ -383,6 +412,26 @@ fn test_new_weak() {
     assert!(foo.upgrade().is_none());
 }
 
+#[test]
+fn test_weak_is_alive() {
+    let a = Arc::new(1);
+    let b = Arc::new(2);
+    let mut observers =
+        vec![Arc::downgrade(&a), Arc::downgrade(&b), Weak::new(), Arc::downgrade(&a)];
+    assert!(observers[0].is_alive());
+    assert!(!observers[2].is_alive());
+
+    drop(a);
+    observers.retain(Weak::is_alive);
+    assert_eq!(observers.len(), 1);
+    assert!(observers[0].ptr_eq(&Arc::downgrade(&b)));
+    assert_eq!(observers[0].strong_count(), 1);
+
+    drop(b);
+    assert!(!observers[0].is_alive());
+    assert_eq!(observers[0].weak_count(), 0);
+}
+
 #[test]
 fn test_ptr_eq() {
     let five = Arc::new(5);
@@ -520,6 +569,30 @@ fn test_from_vec() {
     assert_eq!(&r[..], [1, 2, 3]);
 }
 
+#[test]
+fn test_from_vec_reuse() {
+    use std::string::{String, ToString};
+
+    let mut v: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+    v.reserve(10);
+    let r: Arc<[String]> = Arc::from(v);
+    assert_eq!(r.len(), 10);
+    assert!(r.iter().enumerate().all(|(i, s)| *s == i.to_string()));
+
+    let v: Vec<u64> = vec![1, 2, 3];
+    assert_eq!(v.len(), v.capacity());
+    let r: Arc<[u64]> = Arc::from(v);
+    assert_eq!(*r, [1, 2, 3]);
+
+    let v: Vec<u64> = Vec::with_capacity(8);
+    let r: Arc<[u64]> = Arc::from(v);
+    assert!(r.is_empty());
+
+    let v: Vec<()> = vec![(); 5];
+    let r: Arc<[()]> = Arc::from(v);
+    assert_eq!(r.len(), 5);
+}
+
 #[test]
 fn test_downcast() {
     use std::any::Any;
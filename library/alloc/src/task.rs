@@ -1,8 +1,9 @@
 #![stable(feature = "wake_trait", since = "1.51.0")]
 //! Types and Traits for working with asynchronous tasks.
 use core::mem::ManuallyDrop;
-use core::task::{RawWaker, RawWakerVTable, Waker};
+use core::task::{LocalWaker, RawWaker, RawWakerVTable, Waker};
 
+use crate::rc::Rc;
 use crate::sync::Arc;
 
 /// The implementation of waking a task on an executor.
@@ -145,3 +146,102 @@ unsafe fn drop_waker<W: Wake + Send + Sync + 'static>(waker: *const ()) {
         &RawWakerVTable::new(clone_waker::<W>, wake::<W>, wake_by_ref::<W>, drop_waker::<W>),
     )
 }
+
+/// An analogous trait to `Wake` but used to construct a `LocalWaker`.
+///
+/// This API works in exactly the same way as `Wake`, except that it uses an
+/// [`Rc`] instead of an [`Arc`], and the result is a `LocalWaker` instead of
+/// a `Waker`.
+///
+/// The benefits of using `LocalWaker` over `Waker` are that it allows the
+/// local waker to hold data that does not implement `Send` and `Sync`.
+/// Additionally, it saves calls to `Arc::clone`, which requires atomic
+/// synchronization.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(local_waker)]
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+/// use std::task::{LocalWake, LocalWaker};
+///
+/// struct Counter(Cell<u32>);
+///
+/// impl LocalWake for Counter {
+///     fn wake(self: Rc<Self>) {
+///         self.0.set(self.0.get() + 1);
+///     }
+/// }
+///
+/// let counter = Rc::new(Counter(Cell::new(0)));
+/// let waker = LocalWaker::from(counter.clone());
+/// waker.wake_by_ref();
+/// waker.wake();
+/// assert_eq!(counter.0.get(), 2);
+/// ```
+#[unstable(feature = "local_waker", issue = "none")]
+pub trait LocalWake {
+    /// Wake this task.
+    #[unstable(feature = "local_waker", issue = "none")]
+    fn wake(self: Rc<Self>);
+
+    /// Wake this task without consuming the local waker.
+    ///
+    /// If an executor supports a cheaper way to wake without consuming the
+    /// waker, it should override this method. By default, it clones the
+    /// [`Rc`] and calls [`wake`] on the clone.
+    ///
+    /// [`wake`]: LocalWake::wake
+    #[unstable(feature = "local_waker", issue = "none")]
+    fn wake_by_ref(self: &Rc<Self>) {
+        self.clone().wake();
+    }
+}
+
+#[unstable(feature = "local_waker", issue = "none")]
+impl<W: LocalWake + 'static> From<Rc<W>> for LocalWaker {
+    /// Use a `LocalWake`-able type as a `LocalWaker`.
+    ///
+    /// No heap allocations or atomic operations are used for this conversion.
+    fn from(waker: Rc<W>) -> LocalWaker {
+        // SAFETY: local_raw_waker safely constructs a RawWaker from Rc<W>,
+        // and a LocalWaker never leaves the current thread.
+        unsafe { LocalWaker::from_raw(local_raw_waker(waker)) }
+    }
+}
+
+// NB: This private function mirrors `raw_waker`, for the same reason.
+#[inline(always)]
+fn local_raw_waker<W: LocalWake + 'static>(waker: Rc<W>) -> RawWaker {
+    // Increment the reference count of the Rc to clone it.
+    unsafe fn clone_waker<W: LocalWake + 'static>(waker: *const ()) -> RawWaker {
+        unsafe { Rc::increment_strong_count(waker as *const W) };
+        RawWaker::new(
+            waker as *const (),
+            &RawWakerVTable::new(clone_waker::<W>, wake::<W>, wake_by_ref::<W>, drop_waker::<W>),
+        )
+    }
+
+    // Wake by value, moving the Rc into the LocalWake::wake function
+    unsafe fn wake<W: LocalWake + 'static>(waker: *const ()) {
+        let waker = unsafe { Rc::from_raw(waker as *const W) };
+        <W as LocalWake>::wake(waker);
+    }
+
+    // Wake by reference, wrap the waker in ManuallyDrop to avoid dropping it
+    unsafe fn wake_by_ref<W: LocalWake + 'static>(waker: *const ()) {
+        let waker = unsafe { ManuallyDrop::new(Rc::from_raw(waker as *const W)) };
+        <W as LocalWake>::wake_by_ref(&waker);
+    }
+
+    // Decrement the reference count of the Rc on drop
+    unsafe fn drop_waker<W: LocalWake + 'static>(waker: *const ()) {
+        unsafe { Rc::decrement_strong_count(waker as *const W) };
+    }
+
+    RawWaker::new(
+        Rc::into_raw(waker) as *const (),
+        &RawWakerVTable::new(clone_waker::<W>, wake::<W>, wake_by_ref::<W>, drop_waker::<W>),
+    )
+}
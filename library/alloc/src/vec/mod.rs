@@ -72,7 +72,7 @@
 use crate::alloc::{Allocator, Global};
 use crate::borrow::{Cow, ToOwned};
 use crate::boxed::Box;
-use crate::collections::TryReserveError;
+use crate::collections::{TryExtend, TryReserveError};
 use crate::raw_vec::RawVec;
 
 #[unstable(feature = "drain_filter", reason = "recently added", issue = "43244")]
@@ -818,6 +818,12 @@ pub fn reserve(&mut self, additional: usize) {
     /// requests. Therefore, capacity can not be relied upon to be precisely
     /// minimal. Prefer [`reserve`] if future insertions are expected.
     ///
+    /// Unlike [`reserve`], this does not amortize: reserving a few elements at
+    /// a time with `reserve_exact` can reallocate on every call, which makes
+    /// inserting `n` elements take O(n²) time. It is meant for when the final
+    /// length is known. Pushing after `reserve_exact` once the capacity is used
+    /// up grows the vector as usual, at least doubling its capacity.
+    ///
     /// [`reserve`]: Vec::reserve
     ///
     /// # Panics
@@ -915,6 +921,48 @@ pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveE
         self.buf.try_reserve_exact(self.len, additional)
     }
 
+    /// Returns the capacity that [`reserve`] or [`try_reserve`] would grow the
+    /// vector to for `additional` more elements, without allocating.
+    ///
+    /// This is the current capacity if it is already sufficient. Otherwise it
+    /// is the capacity requested from the allocator by the amortized growth
+    /// policy, which at least doubles the capacity. The growth policy is an
+    /// implementation detail and may change. As with [`reserve`], the
+    /// allocator may provide more space than requested.
+    ///
+    /// This lets code that creates many small vectors decide between
+    /// [`reserve`] and [`reserve_exact`] based on how much memory the growth
+    /// would take.
+    ///
+    /// [`reserve`]: Vec::reserve
+    /// [`try_reserve`]: Vec::try_reserve
+    /// [`reserve_exact`]: Vec::reserve_exact
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_capacity_after_reserve)]
+    /// let mut vec: Vec<u32> = Vec::with_capacity(10);
+    /// vec.extend(0..10);
+    /// assert_eq!(vec.capacity_after_reserve(1), Ok(20));
+    /// assert_eq!(vec.capacity_after_reserve(15), Ok(25));
+    ///
+    /// // Growing to twice the size is too much here, grow only a little.
+    /// if vec.capacity_after_reserve(1)? > vec.len() + 4 {
+    ///     vec.reserve_exact(4);
+    /// }
+    /// assert!(vec.capacity() >= 14);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "vec_capacity_after_reserve", issue = "none")]
+    pub fn capacity_after_reserve(&self, additional: usize) -> Result<usize, TryReserveError> {
+        self.buf.capacity_after_reserve(self.len, additional)
+    }
+
     /// Shrinks the capacity of the vector as much as possible.
     ///
     /// It will drop down as close as possible to the length but the allocator
@@ -1293,9 +1341,11 @@ pub unsafe fn set_len(&mut self, new_len: usize) {
     /// ```
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[track_caller]
     pub fn swap_remove(&mut self, index: usize) -> T {
         #[cold]
         #[inline(never)]
+        #[track_caller]
         fn assert_failed(index: usize, len: usize) -> ! {
             panic!("swap_remove index (is {}) should be < len (is {})", index, len);
         }
@@ -1316,6 +1366,106 @@ fn assert_failed(index: usize, len: usize) -> ! {
         }
     }
 
+    /// Removes all elements for which `f` returns `true`, filling each hole
+    /// with the last element of the vector.
+    ///
+    /// This is the predicate form of [`swap_remove`]: it visits every element
+    /// exactly once and runs in *O*(*n*) time, but like `swap_remove` it does
+    /// not preserve the order of the remaining elements. An element moved into
+    /// a hole is tested before the scan moves on, so no element is skipped.
+    /// If you need to preserve the element order, use [`retain`] instead.
+    ///
+    /// [`swap_remove`]: Vec::swap_remove
+    /// [`retain`]: Vec::retain
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_swap_remove_if)]
+    ///
+    /// let mut v = vec![1, 2, 3, 4, 5, 6];
+    /// v.swap_remove_if(|x| *x % 2 == 0);
+    /// assert_eq!(v, [1, 5, 3]);
+    /// ```
+    #[unstable(feature = "vec_swap_remove_if", issue = "none")]
+    pub fn swap_remove_if<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let mut i = 0;
+        while i < self.len() {
+            if f(&mut self[i]) {
+                // The vector is consistent again before the removed element
+                // is dropped, so a panicking `drop` leaks nothing.
+                drop(self.swap_remove(i));
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    /// Removes the elements at all of the given `indices`, filling each hole
+    /// with the last element of the vector.
+    ///
+    /// The indices refer to positions in the vector *before* any element is
+    /// removed and may be given in any order. Removals are performed from the
+    /// highest index down, so moving the last element into a hole never
+    /// invalidates an index that has yet to be processed. Like
+    /// [`swap_remove`], this does not preserve the order of the remaining
+    /// elements.
+    ///
+    /// [`swap_remove`]: Vec::swap_remove
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds or appears more than once. The
+    /// vector is left unchanged in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_swap_remove_if)]
+    ///
+    /// let mut v = vec!["a", "b", "c", "d", "e"];
+    /// v.swap_remove_indices(&[3, 0]);
+    /// assert_eq!(v, ["e", "b", "c"]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "vec_swap_remove_if", issue = "none")]
+    #[track_caller]
+    pub fn swap_remove_indices(&mut self, indices: &[usize]) {
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("swap_remove_indices index (is {}) should be < len (is {})", index, len);
+        }
+
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn duplicate_index(index: usize) -> ! {
+            panic!("swap_remove_indices index {} was given more than once", index);
+        }
+
+        let mut sorted = indices.to_vec();
+        sorted.sort_unstable();
+
+        let len = self.len();
+        if let Some(&max) = sorted.last() {
+            if max >= len {
+                assert_failed(max, len);
+            }
+        }
+        if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+            duplicate_index(pair[0]);
+        }
+
+        for &index in sorted.iter().rev() {
+            drop(self.swap_remove(index));
+        }
+    }
+
     /// Inserts an element at position `index` within the vector, shifting all
     /// elements after it to the right.
     ///
@@ -1334,9 +1484,11 @@ fn assert_failed(index: usize, len: usize) -> ! {
     /// ```
     #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[track_caller]
     pub fn insert(&mut self, index: usize, element: T) {
         #[cold]
         #[inline(never)]
+        #[track_caller]
         fn assert_failed(index: usize, len: usize) -> ! {
             panic!("insertion index (is {}) should be <= len (is {})", index, len);
         }
@@ -1922,12 +2074,14 @@ pub fn is_empty(&self) -> bool {
     #[inline]
     #[must_use = "use `.truncate()` if you don't need the other half"]
     #[stable(feature = "split_off", since = "1.4.0")]
+    #[track_caller]
     pub fn split_off(&mut self, at: usize) -> Self
     where
         A: Clone,
     {
         #[cold]
         #[inline(never)]
+        #[track_caller]
         fn assert_failed(at: usize, len: usize) -> ! {
             panic!("`at` split index (is {}) should be <= len (is {})", at, len);
         }
@@ -1957,6 +2111,61 @@ fn assert_failed(at: usize, len: usize) -> ! {
         other
     }
 
+    /// Moves the elements in the range `[at, len)` to the end of `other`.
+    /// After the call, `self` is left containing the elements `[0, at)` with
+    /// its previous capacity unchanged.
+    ///
+    /// This does the same as `other.append(&mut self.split_off(at))`, but
+    /// without allocating a temporary vector: the elements are moved directly,
+    /// and `other` only reallocates if its spare capacity is too small. It is
+    /// meant for reusing buffers, for example when handing complete records at
+    /// the end of a read buffer over to a long-lived output buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len` or if the new capacity of `other` exceeds
+    /// `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_split_off_into)]
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut out = Vec::with_capacity(8);
+    /// out.push(0);
+    ///
+    /// vec.split_off_into(2, &mut out);
+    /// assert_eq!(vec, [1, 2]);
+    /// assert_eq!(out, [0, 3, 4]);
+    /// ```
+    ///
+    /// [`split_off`]: Vec::split_off
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "vec_split_off_into", issue = "none")]
+    #[track_caller]
+    pub fn split_off_into(&mut self, at: usize, other: &mut Self) {
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed(at: usize, len: usize) -> ! {
+            panic!("`at` split index (is {}) should be <= len (is {})", at, len);
+        }
+
+        if at > self.len() {
+            assert_failed(at, self.len());
+        }
+
+        // SAFETY: `at <= len`, so the elements `[at, len)` are initialized.
+        // They are bitwise copied into `other`, and shrinking `self` to `at`
+        // afterwards transfers their ownership. If `append_elements` panics,
+        // it does so before copying anything and `self` still owns them.
+        unsafe {
+            other.append_elements(&self.as_slice()[at..] as _);
+            self.set_len(at);
+        }
+    }
+
     /// Resizes the `Vec` in-place so that `len` is equal to `new_len`.
     ///
     /// If `new_len` is greater than `len`, the `Vec` is extended by the
@@ -2789,6 +2998,40 @@ fn extend_reserve(&mut self, additional: usize) {
     }
 }
 
+#[unstable(feature = "try_extend", issue = "none")]
+impl<T, A: Allocator> TryExtend<T> for Vec<T, A> {
+    fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), TryReserveError> {
+        // The same loop as `extend_desugared`, with a fallible reservation.
+        let mut iterator = iter.into_iter();
+        while let Some(element) = iterator.next() {
+            let len = self.len();
+            if len == self.capacity() {
+                let (lower, _) = iterator.size_hint();
+                self.try_reserve(lower.saturating_add(1))?;
+            }
+            // SAFETY: the check above ensures there is room for one more element.
+            unsafe {
+                ptr::write(self.as_mut_ptr().add(len), element);
+                // Since next() executes user code which can panic we have to bump the length
+                // after each step.
+                self.set_len(len + 1);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fallible extension with `Copy` elements, like the `Extend<&'a T>` implementation.
+#[unstable(feature = "try_extend", issue = "none")]
+impl<'a, T: Copy + 'a, A: Allocator + 'a> TryExtend<&'a T> for Vec<T, A> {
+    fn try_extend<I: IntoIterator<Item = &'a T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryReserveError> {
+        self.try_extend(iter.into_iter().copied())
+    }
+}
+
 /// Implements comparison of vectors, [lexicographically](core::cmp::Ord#lexicographical-comparison).
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: PartialOrd, A: Allocator> PartialOrd for Vec<T, A> {
@@ -57,3 +57,19 @@ fn box_deref_lval() {
     x.set(1000);
     assert_eq!(x.get(), 1000);
 }
+
+#[test]
+fn clone_iterator() {
+    use std::boxed::CloneIterator;
+
+    let iter: Box<dyn CloneIterator<Item = i32>> = Box::new((1..6).map(|x| x * 10));
+    let mut first = iter.clone();
+    assert_eq!(first.next(), Some(10));
+
+    // Clones are independent of each other and of the original.
+    let second = first.clone();
+    assert_eq!(first.collect::<Vec<_>>(), [20, 30, 40, 50]);
+    assert_eq!(second.size_hint(), (4, Some(4)));
+    assert_eq!(second.sum::<i32>(), 140);
+    assert_eq!(iter.count(), 5);
+}
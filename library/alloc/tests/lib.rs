@@ -21,11 +21,20 @@
 #![feature(slice_group_by)]
 #![feature(slice_partition_dedup)]
 #![feature(vec_spare_capacity)]
+#![feature(vec_split_off_into)]
+#![feature(clone_iterator)]
+#![feature(vec_capacity_after_reserve)]
 #![feature(string_remove_matches)]
 #![feature(const_btree_new)]
 #![feature(const_default_impls)]
 #![feature(const_trait_impl)]
 #![feature(const_str_from_utf8)]
+#![feature(str_encode_utf16_into)]
+#![feature(str_try_index)]
+#![feature(try_extend)]
+#![feature(vec_swap_remove_if)]
+#![feature(slice_sort_unstable_by_cached_key)]
+#![feature(string_from_utf8_lossy_owned)]
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -1,4 +1,5 @@
 use std::cell::Cell;
+use std::cmp;
 use std::cmp::Ordering::{self, Equal, Greater, Less};
 use std::convert::identity;
 use std::fmt;
@@ -421,6 +422,9 @@ fn test_sort() {
                 v2.sort_by_cached_key(|x| x.to_string());
                 assert!(v1.windows(2).all(|w| w[0].to_string() <= w[1].to_string()));
                 assert!(v1 == v2);
+                let mut v3 = orig.clone();
+                v3.sort_unstable_by_cached_key(|x| x.to_string());
+                assert!(v1 == v3);
 
                 // Sort with many pre-sorted runs.
                 let mut v = orig.clone();
@@ -505,6 +509,31 @@ fn test_sort_stability() {
     }
 }
 
+#[test]
+fn test_sort_stability_natural_runs() {
+    // Miri is too slow
+    let len = if cfg!(miri) { 200 } else { 5000 };
+    let mut rng = thread_rng();
+
+    // Build ascending and descending runs of very different lengths, tagging every element with
+    // its original position so that the merge order of equal keys can be checked.
+    let mut keys = Vec::with_capacity(len);
+    while keys.len() < len {
+        let run = cmp::min(len - keys.len(), rng.gen::<usize>() % 300 + 1);
+        let mut chunk: Vec<u32> = (0..run).map(|_| rng.gen::<u32>() % 50).collect();
+        chunk.sort();
+        if rng.gen::<bool>() {
+            chunk.reverse();
+        }
+        keys.extend(chunk);
+    }
+    let orig: Vec<(u32, usize)> = keys.into_iter().enumerate().map(|(i, k)| (k, i)).collect();
+
+    let mut v = orig.clone();
+    v.sort_by_key(|&(k, _)| k);
+    assert!(v.windows(2).all(|w| w[0] <= w[1]));
+}
+
 #[test]
 fn test_rotate_left() {
     let expected: Vec<_> = (0..13).collect();
@@ -755,6 +755,38 @@ fn test_str_slicemut_rangetoinclusive_notok() {
     let _ = &mut s[..=3];
 }
 
+#[test]
+fn test_str_try_index() {
+    use std::ops::Bound;
+    use std::str::StrIndexErrorKind;
+
+    let s = "abcαβγ";
+    assert_eq!(s.try_index(..=4), Ok("abcα"));
+    assert_eq!(s.try_index((Bound::Excluded(2), Bound::Unbounded)), Ok("αβγ"));
+    assert_eq!(s.try_index(9..), Ok(""));
+
+    let kind_and_index = |r: Result<&str, _>| {
+        let e: std::str::StrIndexError = r.unwrap_err();
+        (e.kind(), e.index())
+    };
+    assert_eq!(kind_and_index(s.try_index(..=3)), (StrIndexErrorKind::NotCharBoundary, 4));
+    assert_eq!(kind_and_index(s.try_index(4..20)), (StrIndexErrorKind::OutOfBounds, 20));
+    assert_eq!(kind_and_index(s.try_index(5..3)), (StrIndexErrorKind::StartAfterEnd, 5));
+    // Out of bounds is reported before an inverted range, as when indexing.
+    assert_eq!(kind_and_index(s.try_index(20..3)), (StrIndexErrorKind::OutOfBounds, 20));
+
+    let err = s.try_index(6..).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "byte index 6 is not a char boundary; it is inside 'β' (bytes 5..7)"
+    );
+
+    let mut owned = s.to_owned();
+    owned.try_index_mut(..3).unwrap().make_ascii_uppercase();
+    assert_eq!(owned, "ABCαβγ");
+    assert!(owned.try_index_mut(..10).is_err());
+}
+
 #[test]
 fn test_is_char_boundary() {
     let s = "ศไทย中华Việt Nam β-release 🐱123";
@@ -923,6 +955,28 @@ fn from_utf8_mostly_ascii() {
     }
 }
 
+#[test]
+fn from_utf8_error_in_long_input() {
+    // Long enough to span several vector blocks, so that errors are found both within a block
+    // and on either side of a block boundary.
+    let text = "abcdé€𝄞".repeat(8);
+    assert!(from_utf8(text.as_bytes()).is_ok());
+
+    for (i, _) in text.char_indices() {
+        for invalid in [&b"\xFF"[..], b"\x80", b"\xC0\x80", b"\xED\xA0\x80", b"\xF4\x90\x80\x80"] {
+            let mut bytes = text.as_bytes().to_vec();
+            bytes.splice(i..i, invalid.iter().copied());
+            let error = from_utf8(&bytes).unwrap_err();
+            assert_eq!((error.valid_up_to(), error.error_len()), (i, Some(1)));
+        }
+
+        let mut bytes = text.as_bytes()[..i].to_vec();
+        bytes.extend_from_slice(b"\xF0\x9F\x98");
+        let error = from_utf8(&bytes).unwrap_err();
+        assert_eq!((error.valid_up_to(), error.error_len()), (i, None));
+    }
+}
+
 #[test]
 fn const_from_utf8_mostly_ascii() {
     const _: () = {
@@ -1696,6 +1750,26 @@ fn test_utf16_code_units() {
     assert_eq!("é\u{1F4A9}".encode_utf16().collect::<Vec<u16>>(), [0xE9, 0xD83D, 0xDCA9])
 }
 
+#[test]
+fn test_encode_utf16_into() {
+    let strings = ["", "hello", "Zażółć gęślą jaźń", "\u{FFFF}\u{10000}\u{10FFFF}", "é\u{1F4A9}"];
+    for s in strings {
+        let expected = s.encode_utf16().collect::<Vec<u16>>();
+        assert_eq!(s.encode_utf16_len(), expected.len());
+
+        let mut buf = vec![0; expected.len() + 1];
+        let written = s.encode_utf16_into(&mut buf);
+        assert_eq!(&buf[..written], expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_encode_utf16_into_too_small() {
+    let mut buf = [0; 2];
+    "é\u{1F4A9}".encode_utf16_into(&mut buf);
+}
+
 #[test]
 fn starts_with_in_unicode() {
     assert!(!"├── Cargo.toml".starts_with("# "));
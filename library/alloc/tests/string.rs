@@ -1,6 +1,7 @@
 use std::assert_matches::assert_matches;
 use std::borrow::Cow;
 use std::cell::Cell;
+use std::collections::TryExtend;
 use std::collections::TryReserveErrorKind::*;
 use std::ops::Bound;
 use std::ops::Bound::*;
@@ -116,6 +117,24 @@ fn test_from_utf8_lossy() {
     );
 }
 
+#[test]
+fn test_from_utf8_lossy_owned() {
+    let xs = b"hello".to_vec();
+    let ptr = xs.as_ptr();
+    let ys = String::from_utf8_lossy_owned(xs);
+    assert_eq!(ys, "hello");
+    assert_eq!(ys.as_ptr(), ptr);
+
+    let xs = b"Hello\xC2 There\xFF Goodbye".to_vec();
+    assert_eq!(String::from_utf8_lossy_owned(xs), "Hello\u{FFFD} There\u{FFFD} Goodbye");
+
+    let xs = b"\xF0\x80\x80\x80foo".to_vec();
+    let err = String::from_utf8(xs).unwrap_err();
+    assert_eq!(err.into_utf8_lossy(), "\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}foo");
+
+    assert_eq!(String::from_utf8_lossy_owned(Vec::new()), "");
+}
+
 #[test]
 fn test_from_utf16() {
     let pairs = [
@@ -678,6 +697,18 @@ fn test_reserve_exact() {
     assert!(s.capacity() >= 33)
 }
 
+#[test]
+fn test_try_extend() {
+    let mut s = String::from("ab");
+    assert_eq!(s.try_extend("cdé".chars()), Ok(()));
+    assert_eq!(s.try_extend(["fg", "", "h"].iter().copied()), Ok(()));
+    assert_eq!(s, "abcdéfgh");
+
+    let err = s.try_extend(std::iter::repeat('x').take(usize::MAX)).unwrap_err();
+    assert_matches!(err.kind(), CapacityOverflow);
+    assert_eq!(s, "abcdéfgh");
+}
+
 #[test]
 #[cfg_attr(miri, ignore)] // Miri does not support signalling OOM
 #[cfg_attr(target_os = "android", ignore)] // Android used in CI has a broken dlmalloc
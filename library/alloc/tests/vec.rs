@@ -1,6 +1,7 @@
 use std::assert_matches::assert_matches;
 use std::borrow::Cow;
 use std::cell::Cell;
+use std::collections::TryExtend;
 use std::collections::TryReserveErrorKind::*;
 use std::fmt::Debug;
 use std::iter::InPlaceIterable;
@@ -581,6 +582,50 @@ fn test_swap_remove_empty() {
     vec.swap_remove(0);
 }
 
+#[test]
+fn test_swap_remove_if() {
+    let mut vec: Vec<i32> = (0..10).collect();
+    let mut seen = Vec::new();
+    vec.swap_remove_if(|x| {
+        seen.push(*x);
+        *x % 3 != 0
+    });
+    vec.sort();
+    assert_eq!(vec, [0, 3, 6, 9]);
+    seen.sort();
+    assert_eq!(seen, (0..10).collect::<Vec<_>>());
+
+    let mut vec = vec![1, 1, 1];
+    vec.swap_remove_if(|_| true);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn test_swap_remove_indices() {
+    let mut vec: Vec<i32> = (0..6).collect();
+    vec.swap_remove_indices(&[0, 4, 5, 2]);
+    vec.sort();
+    assert_eq!(vec, [1, 3]);
+
+    let mut vec = vec![1, 2, 3];
+    vec.swap_remove_indices(&[]);
+    assert_eq!(vec, [1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_swap_remove_indices_out_of_bounds() {
+    let mut vec = vec![1, 2, 3];
+    vec.swap_remove_indices(&[0, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_swap_remove_indices_duplicate() {
+    let mut vec = vec![1, 2, 3];
+    vec.swap_remove_indices(&[1, 1]);
+}
+
 #[test]
 fn test_move_items() {
     let vec = vec![1, 2, 3];
@@ -891,6 +936,34 @@ fn test_split_off_take_all() {
     assert_eq!(vec2.as_ptr(), orig_ptr);
 }
 
+#[test]
+fn test_split_off_into() {
+    let mut vec: Vec<String> = ["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect();
+    let orig_capacity = vec.capacity();
+    let mut out = Vec::with_capacity(4);
+    out.push("x".to_string());
+    let out_ptr = out.as_ptr();
+
+    vec.split_off_into(2, &mut out);
+    assert_eq!(vec, ["a", "b"]);
+    assert_eq!(out, ["x", "c", "d"]);
+    assert_eq!(vec.capacity(), orig_capacity);
+    assert_eq!(out.as_ptr(), out_ptr);
+
+    vec.split_off_into(2, &mut out);
+    assert_eq!(vec, ["a", "b"]);
+    vec.split_off_into(0, &mut out);
+    assert_eq!(vec, [""; 0]);
+    assert_eq!(out, ["x", "c", "d", "a", "b"]);
+}
+
+#[test]
+#[should_panic]
+fn test_split_off_into_out_of_bounds() {
+    let mut vec = vec![1, 2, 3];
+    vec.split_off_into(4, &mut Vec::new());
+}
+
 #[test]
 fn test_into_iter_as_slice() {
     let vec = vec!['a', 'b', 'c'];
@@ -1474,6 +1547,43 @@ fn test_reserve_exact() {
     assert!(v.capacity() >= 33)
 }
 
+#[test]
+fn test_capacity_after_reserve() {
+    let mut v: Vec<u64> = Vec::new();
+    assert_eq!(v.capacity_after_reserve(0), Ok(0));
+    let cap = v.capacity_after_reserve(1).unwrap();
+    assert!(cap >= 1);
+    v.reserve(1);
+    assert_eq!(v.capacity(), cap);
+
+    v.extend(0..v.capacity() as u64);
+    let cap = v.capacity_after_reserve(1).unwrap();
+    assert!(cap >= 2 * v.len());
+    v.push(0);
+    assert_eq!(v.capacity(), cap);
+
+    assert_eq!(v.capacity_after_reserve(v.capacity() - v.len()), Ok(v.capacity()));
+    assert!(v.capacity_after_reserve(usize::MAX).is_err());
+    assert!(v.capacity_after_reserve(isize::MAX as usize).is_err());
+
+    let v: Vec<()> = Vec::new();
+    assert_eq!(v.capacity_after_reserve(usize::MAX), Ok(usize::MAX));
+}
+
+#[test]
+fn test_try_extend() {
+    let mut v = vec![1, 2];
+    assert_eq!(v.try_extend(3..6), Ok(()));
+    assert_eq!(v.try_extend(&[6, 7]), Ok(()));
+    assert_eq!(v, [1, 2, 3, 4, 5, 6, 7]);
+
+    // The reservation for the whole lower bound overflows before anything is added.
+    let mut v: Vec<u32> = Vec::new();
+    let err = v.try_extend(std::iter::repeat(1).take(usize::MAX)).unwrap_err();
+    assert_matches!(err.kind(), CapacityOverflow);
+    assert!(v.is_empty());
+}
+
 #[test]
 #[cfg_attr(miri, ignore)] // Miri does not support signalling OOM
 #[cfg_attr(target_os = "android", ignore)] // Android used in CI has a broken dlmalloc
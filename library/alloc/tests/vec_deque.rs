@@ -1082,6 +1082,32 @@ fn test_extend_ref() {
     assert_eq!(v[5], 6);
 }
 
+#[test]
+fn test_extend_from_slice_wrapping() {
+    // Exercise every split point of the ring buffer, both with and without growing.
+    for offset in 0..8 {
+        for len in 0..12 {
+            let mut v: VecDeque<u32> = VecDeque::with_capacity(7);
+            for i in 0..offset {
+                v.push_back(i);
+            }
+            for _ in 0..offset {
+                v.pop_front();
+            }
+            v.push_back(100);
+
+            let slice: Vec<u32> = (0..len).collect();
+            v.extend(slice.iter());
+            v.extend(&slice[..]);
+
+            let mut expected = vec![100];
+            expected.extend(&slice);
+            expected.extend(&slice);
+            assert_eq!(v, expected);
+        }
+    }
+}
+
 #[test]
 fn test_contains() {
     let mut v = VecDeque::new();
@@ -249,6 +249,13 @@ fn is_ascii_lowercase(b: u8) -> bool {
     is_ascii_control,
 }
 
+#[bench]
+fn eq_ignore_ascii_case_long(bencher: &mut Bencher) {
+    let upper = LONG.to_ascii_uppercase();
+    bencher.bytes = LONG.len() as u64;
+    bencher.iter(|| black_box(LONG).eq_ignore_ascii_case(black_box(&upper)));
+}
+
 macro_rules! repeat {
     ($s: expr) => {
         concat!($s, $s, $s, $s, $s, $s, $s, $s, $s, $s)
@@ -372,7 +372,8 @@ impl<T: Copy> SpecArrayClone for T {
 
 // The Default impls cannot be done with const generics because `[T; 0]` doesn't
 // require Default to be implemented, and having different impl blocks for
-// different numbers isn't supported yet.
+// different numbers isn't supported yet. Longer arrays of `Default` elements
+// can be built with `array::from_fn(|_| T::default())` instead.
 
 macro_rules! array_impl_default {
     {$n:expr, $t:ident $($ts:ident)*} => {
@@ -737,6 +738,76 @@ pub fn as_mut_slice(&mut self) -> &mut [T] {
     pub fn rsplit_array_mut<const M: usize>(&mut self) -> (&mut [T], &mut [T; M]) {
         (&mut self[..]).rsplit_array_mut::<M>()
     }
+
+    /// Views this array as an array-slice of `M`-element chunks.
+    ///
+    /// Unlike [`slice::as_chunks`], there is no remainder: `N` must be a
+    /// multiple of `M`, and this is checked at compile time.
+    ///
+    /// # Compile-time failures
+    ///
+    /// Using this with an `M` that is zero or does not evenly divide `N`
+    /// fails to compile once the array type is known:
+    ///
+    /// ```compile_fail
+    /// #![feature(array_chunks_exact)]
+    /// let v = [1, 2, 3, 4, 5];
+    /// let _ = v.as_chunks_exact::<2>(); // 2 does not divide 5
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(array_chunks_exact)]
+    ///
+    /// let block = [0_u8, 1, 2, 3, 4, 5, 6, 7];
+    /// let words: &[[u8; 4]] = block.as_chunks_exact();
+    /// assert_eq!(words, &[[0, 1, 2, 3], [4, 5, 6, 7]]);
+    ///
+    /// let words = block.as_chunks_exact::<4>().iter().map(|w| u32::from_le_bytes(*w));
+    /// assert_eq!(words.collect::<Vec<_>>(), [0x03020100, 0x07060504]);
+    /// ```
+    #[unstable(feature = "array_chunks_exact", issue = "none")]
+    #[inline]
+    pub fn as_chunks_exact<const M: usize>(&self) -> &[[T; M]] {
+        let () = AssertChunkSize::<N, M>::DIVIDES;
+        // SAFETY: `M` is nonzero and divides `N`, as checked at compile time
+        // above.
+        unsafe { self.as_slice().as_chunks_unchecked() }
+    }
+
+    /// Views this array as a mutable array-slice of `M`-element chunks.
+    ///
+    /// Unlike [`slice::as_chunks_mut`], there is no remainder: `N` must be a
+    /// multiple of `M`, and this is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(array_chunks_exact)]
+    ///
+    /// let mut samples = [1, 2, 3, 4, 5, 6];
+    /// for [left, right] in samples.as_chunks_exact_mut() {
+    ///     std::mem::swap(left, right);
+    /// }
+    /// assert_eq!(samples, [2, 1, 4, 3, 6, 5]);
+    /// ```
+    #[unstable(feature = "array_chunks_exact", issue = "none")]
+    #[inline]
+    pub fn as_chunks_exact_mut<const M: usize>(&mut self) -> &mut [[T; M]] {
+        let () = AssertChunkSize::<N, M>::DIVIDES;
+        // SAFETY: `M` is nonzero and divides `N`, as checked at compile time
+        // above.
+        unsafe { self.as_mut_slice().as_chunks_unchecked_mut() }
+    }
+}
+
+/// Compile-time check used by `as_chunks_exact` that `M` is a nonzero
+/// divisor of `N`.
+struct AssertChunkSize<const N: usize, const M: usize>;
+
+impl<const N: usize, const M: usize> AssertChunkSize<N, M> {
+    const DIVIDES: () = assert!(M != 0 && N % M == 0, "chunk size must be a nonzero divisor of N");
 }
 
 /// Pulls `N` items from `iter` and returns them as an array. If the iterator
@@ -782,8 +853,8 @@ unsafe fn try_collect_into_array_unchecked<I, T, R, const N: usize>(iter: &mut I
 }
 
 /// Pulls `N` items from `iter` and returns them as an array. If the iterator
-/// yields fewer than `N` items, `None` is returned and all already yielded
-/// items are dropped.
+/// yields fewer than `N` items, `Err` is returned containing an iterator over
+/// the already yielded items.
 ///
 /// Since the iterator is passed as a mutable reference and this function calls
 /// `next` at most `N` times, the iterator can still be used afterwards to
@@ -791,7 +862,9 @@ unsafe fn try_collect_into_array_unchecked<I, T, R, const N: usize>(iter: &mut I
 ///
 /// If `iter.next()` panicks, all items already yielded by the iterator are
 /// dropped.
-fn try_collect_into_array<I, T, R, const N: usize>(iter: &mut I) -> Option<R::TryType>
+fn try_collect_into_array<I, T, R, const N: usize>(
+    iter: &mut I,
+) -> Result<R::TryType, IntoIter<T, N>>
 where
     I: Iterator,
     I::Item: Try<Output = T, Residual = R>,
@@ -799,7 +872,7 @@ fn try_collect_into_array<I, T, R, const N: usize>(iter: &mut I) -> Option<R::Tr
 {
     if N == 0 {
         // SAFETY: An empty array is always inhabited and has no validity invariants.
-        return unsafe { Some(Try::from_output(mem::zeroed())) };
+        return unsafe { Ok(Try::from_output(mem::zeroed())) };
     }
 
     struct Guard<'a, T, const N: usize> {
@@ -826,7 +899,7 @@ fn drop(&mut self) {
     while let Some(item_rslt) = iter.next() {
         let item = match item_rslt.branch() {
             ControlFlow::Break(r) => {
-                return Some(FromResidual::from_residual(r));
+                return Ok(FromResidual::from_residual(r));
             }
             ControlFlow::Continue(elem) => elem,
         };
@@ -846,12 +919,31 @@ fn drop(&mut self) {
             // SAFETY: the condition above asserts that all elements are
             // initialized.
             let out = unsafe { MaybeUninit::array_assume_init(array) };
-            return Some(Try::from_output(out));
+            return Ok(Try::from_output(out));
         }
     }
 
     // This is only reached if the iterator is exhausted before
-    // `guard.initialized` reaches `N`. Also note that `guard` is dropped here,
-    // dropping all already initialized elements.
-    None
+    // `guard.initialized` reaches `N`. Hand the already initialized elements
+    // over to an `IntoIter` instead of dropping them.
+    let alive = 0..guard.initialized;
+    mem::forget(guard);
+
+    // SAFETY: the array was initialized up to `alive.end` by the loop above.
+    Err(unsafe { IntoIter::new_unchecked(array, alive) })
+}
+
+/// Returns the next chunk of `N` items from the iterator, or `Err` with an
+/// iterator over the remainder if the iterator has fewer than `N` items left.
+///
+/// Used for [`Iterator::next_chunk`].
+#[inline]
+pub(crate) fn iter_next_chunk<I, const N: usize>(
+    iter: &mut I,
+) -> Result<[I::Item; N], IntoIter<I::Item, N>>
+where
+    I: Iterator,
+{
+    let mut map = iter.map(NeverShortCircuit);
+    try_collect_into_array(&mut map).map(|NeverShortCircuit(arr)| arr)
 }
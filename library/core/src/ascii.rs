@@ -6,6 +6,10 @@
 //!
 //! The [`escape_default`] function provides an iterator over the bytes of an
 //! escaped version of the character given.
+//!
+//! The [`ByteSet`] type builds byte classification tables at compile time.
+//!
+//! The [`Char`] type is a single character that is known to be ASCII.
 
 #![stable(feature = "core_ascii", since = "1.26.0")]
 
@@ -14,6 +18,14 @@
 use crate::ops::Range;
 use crate::str::from_utf8_unchecked;
 
+mod ascii_char;
+mod byte_set;
+
+#[unstable(feature = "ascii_char", issue = "none")]
+pub use self::ascii_char::Char;
+#[unstable(feature = "ascii_byte_set", issue = "none")]
+pub use self::byte_set::ByteSet;
+
 /// An iterator over the escaped version of a byte.
 ///
 /// This `struct` is created by the [`escape_default`] function. See its
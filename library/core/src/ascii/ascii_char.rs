@@ -0,0 +1,122 @@
+use crate::fmt;
+use crate::slice;
+use crate::str;
+
+/// A single ASCII character, a byte in the range `0x00..=0x7F`.
+///
+/// Having the range in the type means an `ascii::Char` can always be turned
+/// into a `u8`, a `char` or a `&str` without any checks, and that
+/// `Option<ascii::Char>` is the same size as `u8`.
+///
+/// An `ascii::Char` is most easily obtained from a [`char`] with
+/// [`char::as_ascii`], or from a byte with [`Char::from_u8`].
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ascii_char)]
+/// use std::ascii;
+///
+/// let a = 'a'.as_ascii().unwrap();
+/// assert_eq!(a.to_u8(), b'a');
+/// assert_eq!(a.to_char(), 'a');
+/// assert_eq!(a.as_str(), "a");
+///
+/// assert_eq!('é'.as_ascii(), None);
+/// assert_eq!(ascii::Char::from_u8(0x80), None);
+/// assert_eq!(std::mem::size_of::<Option<ascii::Char>>(), 1);
+/// ```
+#[unstable(feature = "ascii_char", issue = "none")]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+#[rustc_layout_scalar_valid_range_end(0x7F)]
+pub struct Char(u8);
+
+impl Char {
+    /// Creates an ASCII character from a byte, or returns `None` if the byte
+    /// is not ASCII.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[rustc_const_unstable(feature = "ascii_char", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_u8(b: u8) -> Option<Self> {
+        if b.is_ascii() {
+            // SAFETY: we just checked that `b` is ASCII.
+            Some(unsafe { Self::from_u8_unchecked(b) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates an ASCII character from a byte, without checking that the
+    /// byte is ASCII.
+    ///
+    /// # Safety
+    ///
+    /// `b` must be in the range `0x00..=0x7F`.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[rustc_const_unstable(feature = "ascii_char", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const unsafe fn from_u8_unchecked(b: u8) -> Self {
+        // SAFETY: the caller must guarantee that `b` is ASCII.
+        unsafe { Char(b) }
+    }
+
+    /// Returns the character as a byte.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[rustc_const_unstable(feature = "ascii_char", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the character as a `char`.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[rustc_const_unstable(feature = "ascii_char", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn to_char(self) -> char {
+        self.0 as char
+    }
+
+    /// Returns the character as a one-byte string slice.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: an ASCII byte on its own is valid UTF-8.
+        unsafe { str::from_utf8_unchecked(slice::from_ref(&self.0)) }
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "none")]
+impl From<Char> for u8 {
+    #[inline]
+    fn from(c: Char) -> u8 {
+        c.to_u8()
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "none")]
+impl From<Char> for char {
+    #[inline]
+    fn from(c: Char) -> char {
+        c.to_char()
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "none")]
+impl fmt::Display for Char {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "none")]
+impl fmt::Debug for Char {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_char(), f)
+    }
+}
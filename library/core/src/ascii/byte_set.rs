@@ -0,0 +1,296 @@
+use crate::fmt;
+
+/// A set of bytes, stored as a 256-bit bitmap.
+///
+/// `ByteSet` is meant for building byte classification tables, such as the
+/// character classes of a lexer, at compile time. All of its constructors and
+/// queries are `const fn`, and it comes with one predefined set for each of
+/// the `is_ascii_*` predicates on [`u8`].
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ascii_byte_set)]
+/// use std::ascii::ByteSet;
+///
+/// const IDENT_START: ByteSet = ByteSet::ASCII_ALPHABETIC.with(b'_');
+/// const IDENT_CONTINUE: ByteSet = IDENT_START.union(ByteSet::ASCII_DIGIT);
+/// const OPERATOR: ByteSet = ByteSet::from_bytes(b"+-*/=<>!&|^%");
+///
+/// // The sets can be queried at compile time, too.
+/// const _: () = assert!(IDENT_CONTINUE.contains(b'7') && !IDENT_START.contains(b'7'));
+///
+/// fn token_len(input: &[u8]) -> usize {
+///     match input.first() {
+///         Some(&b) if IDENT_START.contains(b) => {
+///             1 + input[1..].iter().take_while(|&&b| IDENT_CONTINUE.contains(b)).count()
+///         }
+///         Some(&b) if OPERATOR.contains(b) => 1,
+///         _ => 0,
+///     }
+/// }
+///
+/// assert_eq!(token_len(b"foo_1 + bar"), 5);
+/// assert_eq!(token_len(b"+ bar"), 1);
+/// assert_eq!(token_len(b"1foo"), 0);
+/// ```
+#[unstable(feature = "ascii_byte_set", issue = "none")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ByteSet {
+    bits: [u64; 4],
+}
+
+// Builds the set of bytes for which the given `u8` method returns `true`.
+macro_rules! byte_set_matching {
+    ($method:ident) => {{
+        let mut set = ByteSet::EMPTY;
+        let mut byte = 0u8;
+        loop {
+            if byte.$method() {
+                set = set.with(byte);
+            }
+            if byte == u8::MAX {
+                break;
+            }
+            byte += 1;
+        }
+        set
+    }};
+}
+
+impl ByteSet {
+    /// The set containing no bytes.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const EMPTY: ByteSet = ByteSet { bits: [0; 4] };
+
+    /// The set containing every byte.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ALL: ByteSet = ByteSet { bits: [u64::MAX; 4] };
+
+    /// The bytes for which [`u8::is_ascii`] returns `true`.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ASCII: ByteSet = byte_set_matching!(is_ascii);
+
+    /// The bytes for which [`u8::is_ascii_alphabetic`] returns `true`.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ASCII_ALPHABETIC: ByteSet = byte_set_matching!(is_ascii_alphabetic);
+
+    /// The bytes for which [`u8::is_ascii_uppercase`] returns `true`.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ASCII_UPPERCASE: ByteSet = byte_set_matching!(is_ascii_uppercase);
+
+    /// The bytes for which [`u8::is_ascii_lowercase`] returns `true`.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ASCII_LOWERCASE: ByteSet = byte_set_matching!(is_ascii_lowercase);
+
+    /// The bytes for which [`u8::is_ascii_alphanumeric`] returns `true`.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ASCII_ALPHANUMERIC: ByteSet = byte_set_matching!(is_ascii_alphanumeric);
+
+    /// The bytes for which [`u8::is_ascii_digit`] returns `true`.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ASCII_DIGIT: ByteSet = byte_set_matching!(is_ascii_digit);
+
+    /// The bytes for which [`u8::is_ascii_hexdigit`] returns `true`.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ASCII_HEXDIGIT: ByteSet = byte_set_matching!(is_ascii_hexdigit);
+
+    /// The bytes for which [`u8::is_ascii_punctuation`] returns `true`.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ASCII_PUNCTUATION: ByteSet = byte_set_matching!(is_ascii_punctuation);
+
+    /// The bytes for which [`u8::is_ascii_graphic`] returns `true`.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ASCII_GRAPHIC: ByteSet = byte_set_matching!(is_ascii_graphic);
+
+    /// The bytes for which [`u8::is_ascii_whitespace`] returns `true`.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ASCII_WHITESPACE: ByteSet = byte_set_matching!(is_ascii_whitespace);
+
+    /// The bytes for which [`u8::is_ascii_control`] returns `true`.
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const ASCII_CONTROL: ByteSet = byte_set_matching!(is_ascii_control);
+
+    /// Creates a set containing exactly the given bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_byte_set)]
+    /// use std::ascii::ByteSet;
+    ///
+    /// const QUOTES: ByteSet = ByteSet::from_bytes(b"'\"`");
+    /// assert!(QUOTES.contains(b'`'));
+    /// assert_eq!(QUOTES.len(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn from_bytes(bytes: &[u8]) -> ByteSet {
+        ByteSet::EMPTY.with_bytes(bytes)
+    }
+
+    /// Creates a set containing every byte from `start` to `end`, inclusive.
+    ///
+    /// The set is empty if `start > end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_byte_set)]
+    /// use std::ascii::ByteSet;
+    ///
+    /// const OCTAL: ByteSet = ByteSet::from_range(b'0', b'7');
+    /// assert!(OCTAL.contains(b'7') && !OCTAL.contains(b'8'));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn from_range(start: u8, end: u8) -> ByteSet {
+        ByteSet::EMPTY.with_range(start, end)
+    }
+
+    /// Returns `true` if the set contains `byte`.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn contains(&self, byte: u8) -> bool {
+        self.bits[(byte / 64) as usize] & (1 << (byte % 64)) != 0
+    }
+
+    /// Returns a copy of this set with `byte` added.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn with(mut self, byte: u8) -> ByteSet {
+        self.bits[(byte / 64) as usize] |= 1 << (byte % 64);
+        self
+    }
+
+    /// Returns a copy of this set with `byte` removed.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn without(mut self, byte: u8) -> ByteSet {
+        self.bits[(byte / 64) as usize] &= !(1 << (byte % 64));
+        self
+    }
+
+    /// Returns a copy of this set with all of `bytes` added.
+    #[must_use]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn with_bytes(mut self, bytes: &[u8]) -> ByteSet {
+        let mut i = 0;
+        while i < bytes.len() {
+            self = self.with(bytes[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns a copy of this set with every byte from `start` to `end`,
+    /// inclusive, added.
+    #[must_use]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn with_range(mut self, start: u8, end: u8) -> ByteSet {
+        if start > end {
+            return self;
+        }
+        let mut byte = start;
+        loop {
+            self = self.with(byte);
+            if byte == end {
+                return self;
+            }
+            byte += 1;
+        }
+    }
+
+    /// Returns the set of bytes contained in `self` or `other`.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn union(self, other: ByteSet) -> ByteSet {
+        let [a0, a1, a2, a3] = self.bits;
+        let [b0, b1, b2, b3] = other.bits;
+        ByteSet { bits: [a0 | b0, a1 | b1, a2 | b2, a3 | b3] }
+    }
+
+    /// Returns the set of bytes contained in both `self` and `other`.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn intersection(self, other: ByteSet) -> ByteSet {
+        let [a0, a1, a2, a3] = self.bits;
+        let [b0, b1, b2, b3] = other.bits;
+        ByteSet { bits: [a0 & b0, a1 & b1, a2 & b2, a3 & b3] }
+    }
+
+    /// Returns the set of bytes contained in `self` but not in `other`.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn difference(self, other: ByteSet) -> ByteSet {
+        self.intersection(other.complement())
+    }
+
+    /// Returns the set of bytes not contained in `self`.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn complement(self) -> ByteSet {
+        let [a0, a1, a2, a3] = self.bits;
+        ByteSet { bits: [!a0, !a1, !a2, !a3] }
+    }
+
+    /// Returns the number of bytes in the set.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn len(&self) -> usize {
+        let [a0, a1, a2, a3] = self.bits;
+        (a0.count_ones() + a1.count_ones() + a2.count_ones() + a3.count_ones()) as usize
+    }
+
+    /// Returns `true` if the set contains no bytes.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn is_empty(&self) -> bool {
+        let [a0, a1, a2, a3] = self.bits;
+        a0 | a1 | a2 | a3 == 0
+    }
+
+    /// Expands the set into a 256-entry lookup table indexed by byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_byte_set)]
+    /// use std::ascii::ByteSet;
+    ///
+    /// static IS_SPACE: [bool; 256] = ByteSet::ASCII_WHITESPACE.to_table();
+    /// assert!(IS_SPACE[b'\t' as usize]);
+    /// assert!(!IS_SPACE[b'x' as usize]);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "ascii_byte_set", issue = "none")]
+    pub const fn to_table(&self) -> [bool; 256] {
+        let mut table = [false; 256];
+        let mut i = 0;
+        while i < 256 {
+            table[i] = self.contains(i as u8);
+            i += 1;
+        }
+        table
+    }
+}
+
+#[unstable(feature = "ascii_byte_set", issue = "none")]
+impl fmt::Debug for ByteSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Bytes are shown as the `char` with the same value, so ASCII bytes read naturally.
+        let bytes = (0..=u8::MAX).filter(|&b| self.contains(b)).map(char::from);
+        f.debug_set().entries(bytes).finish()
+    }
+}
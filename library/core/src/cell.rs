@@ -810,6 +810,7 @@ pub fn replace_with<F: FnOnce(&mut T) -> T>(&self, f: F) -> T {
     /// ```
     #[inline]
     #[stable(feature = "refcell_swap", since = "1.24.0")]
+    #[track_caller]
     pub fn swap(&self, other: &Self) {
         mem::swap(&mut *self.borrow_mut(), &mut *other.borrow_mut())
     }
@@ -1,5 +1,6 @@
 //! impl char {}
 
+use crate::ascii;
 use crate::slice;
 use crate::str::from_utf8_unchecked_mut;
 use crate::unicode::printable::is_printable;
@@ -28,6 +29,34 @@ impl char {
     #[stable(feature = "assoc_char_consts", since = "1.52.0")]
     pub const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
 
+    /// The maximum number of bytes required to [encode](char::encode_utf8) a
+    /// `char` to UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(char_max_len)]
+    /// let mut buf = [0; char::MAX_UTF8_LEN];
+    /// for c in ['a', 'ß', '€', '𝄞'] {
+    ///     assert_eq!(c.encode_utf8(&mut buf).len(), c.len_utf8());
+    /// }
+    /// ```
+    #[unstable(feature = "char_max_len", issue = "none")]
+    pub const MAX_UTF8_LEN: usize = 4;
+
+    /// The maximum number of `u16`s required to [encode](char::encode_utf16)
+    /// a `char` to UTF-16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(char_max_len)]
+    /// let mut buf = [0; char::MAX_UTF16_LEN];
+    /// assert_eq!('𝄞'.encode_utf16(&mut buf).len(), 2);
+    /// ```
+    #[unstable(feature = "char_max_len", issue = "none")]
+    pub const MAX_UTF16_LEN: usize = 2;
+
     /// The version of [Unicode](https://www.unicode.org/) that the Unicode parts of
     /// `char` and `str` methods are based on.
     ///
@@ -914,6 +943,103 @@ pub fn is_numeric(self) -> bool {
         }
     }
 
+    /// Returns `true` if this `char` has one of the general categories for letters.
+    ///
+    /// The general categories for letters (`Lu` for uppercase letters, `Ll` for lowercase letters,
+    /// `Lt` for titlecase letters, `Lm` for modifier letters, and `Lo` for other letters) are
+    /// specified in the [Unicode Character Database][ucd] [`UnicodeData.txt`].
+    ///
+    /// This is narrower than [`is_alphabetic`], which also includes letter-like numbers and
+    /// some combining marks.
+    ///
+    /// [`is_alphabetic`]: char::is_alphabetic
+    /// [ucd]: https://www.unicode.org/reports/tr44/
+    /// [`UnicodeData.txt`]: https://www.unicode.org/Public/UCD/latest/ucd/UnicodeData.txt
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(char_general_category)]
+    /// assert!('a'.is_letter());
+    /// assert!('京'.is_letter());
+    /// assert!('ʰ'.is_letter());
+    /// assert!(!'Ⅻ'.is_letter());
+    /// assert!(!'7'.is_letter());
+    /// ```
+    #[must_use]
+    #[unstable(feature = "char_general_category", issue = "none")]
+    #[inline]
+    pub fn is_letter(self) -> bool {
+        match self {
+            'a'..='z' | 'A'..='Z' => true,
+            c => c > '\x7f' && unicode::L(c),
+        }
+    }
+
+    /// Returns `true` if this `char` has one of the general categories for marks.
+    ///
+    /// The general categories for marks (`Mn` for nonspacing marks, `Mc` for spacing combining
+    /// marks, and `Me` for enclosing marks) are specified in the [Unicode Character
+    /// Database][ucd] [`UnicodeData.txt`].
+    ///
+    /// [ucd]: https://www.unicode.org/reports/tr44/
+    /// [`UnicodeData.txt`]: https://www.unicode.org/Public/UCD/latest/ucd/UnicodeData.txt
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(char_general_category)]
+    /// assert!('\u{301}'.is_mark());
+    /// assert!('\u{94d}'.is_mark());
+    /// assert!('\u{20dd}'.is_mark());
+    /// assert!(!'a'.is_mark());
+    /// assert!(!'´'.is_mark());
+    /// ```
+    #[must_use]
+    #[unstable(feature = "char_general_category", issue = "none")]
+    #[inline]
+    pub fn is_mark(self) -> bool {
+        self > '\x7f' && unicode::M(self)
+    }
+
+    /// Returns `true` if this `char` has one of the general categories for punctuation.
+    ///
+    /// The general categories for punctuation (`Pc` for connectors, `Pd` for dashes, `Ps` and
+    /// `Pe` for opening and closing brackets, `Pi` and `Pf` for initial and final quotes, and `Po`
+    /// for other punctuation) are specified in the [Unicode Character Database][ucd]
+    /// [`UnicodeData.txt`].
+    ///
+    /// Note that this differs from [`is_ascii_punctuation`] for ASCII characters: symbols such as
+    /// `$`, `+` and `^` belong to the general categories for symbols, not punctuation.
+    ///
+    /// [`is_ascii_punctuation`]: char::is_ascii_punctuation
+    /// [ucd]: https://www.unicode.org/reports/tr44/
+    /// [`UnicodeData.txt`]: https://www.unicode.org/Public/UCD/latest/ucd/UnicodeData.txt
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(char_general_category)]
+    /// assert!('!'.is_punctuation());
+    /// assert!('_'.is_punctuation());
+    /// assert!('«'.is_punctuation());
+    /// assert!('、'.is_punctuation());
+    /// assert!(!'+'.is_punctuation());
+    /// assert!(!'a'.is_punctuation());
+    /// ```
+    #[must_use]
+    #[unstable(feature = "char_general_category", issue = "none")]
+    #[inline]
+    pub fn is_punctuation(self) -> bool {
+        unicode::P(self)
+    }
+
     /// Returns an iterator that yields the lowercase mapping of this `char` as one or more
     /// `char`s.
     ///
@@ -1092,6 +1218,29 @@ pub const fn is_ascii(&self) -> bool {
         *self as u32 <= 0x7F
     }
 
+    /// Returns the character as an [ASCII character](ascii::Char), or `None`
+    /// if it is not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// assert_eq!('a'.as_ascii().map(|c| c.to_u8()), Some(b'a'));
+    /// assert_eq!('❤'.as_ascii(), None);
+    /// ```
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[rustc_const_unstable(feature = "ascii_char", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_ascii(&self) -> Option<ascii::Char> {
+        if self.is_ascii() {
+            // SAFETY: we just checked that `self` is ASCII.
+            Some(unsafe { ascii::Char::from_u8_unchecked(*self as u8) })
+        } else {
+            None
+        }
+    }
+
     /// Makes a copy of the value in its ASCII upper case equivalent.
     ///
     /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z',
@@ -165,6 +165,128 @@ pub struct AssertParamIsCopy<T: Copy + ?Sized> {
     _field: crate::marker::PhantomData<T>,
 }
 
+/// A generalization of [`Clone`] to dynamically-sized types stored in
+/// arbitrary containers.
+///
+/// This trait is implemented for all types implementing [`Clone`], for
+/// [slices](slice) of all such types, and for [`str`]. It lets containers
+/// such as `Rc` and `Arc` clone their contents directly into a newly
+/// allocated buffer, even when the contents are unsized.
+///
+/// # Safety
+///
+/// Implementations must ensure that when `.clone_to_uninit(dst)` returns
+/// normally rather than panicking, it always leaves `*dst` initialized as a
+/// valid value of type `Self`.
+#[unstable(feature = "clone_to_uninit", issue = "none")]
+pub unsafe trait CloneToUninit {
+    /// Performs copy-assignment from `self` to `dst`.
+    ///
+    /// This is analogous to `std::ptr::write(dst, self.clone())`, except
+    /// that `self` may be a dynamically-sized type ([`!Sized`](Sized)).
+    ///
+    /// Before this function is called, `dst` may point to uninitialized
+    /// memory. After this function is called, `dst` will point to
+    /// initialized memory; it will be sound to create a `&Self` reference
+    /// from the pointer.
+    ///
+    /// # Safety
+    ///
+    /// Behavior is undefined if any of the following conditions are
+    /// violated:
+    ///
+    /// * `dst` must be [valid] for writes.
+    /// * `dst` must be properly aligned.
+    /// * `dst` must have the same [pointer metadata] (slice length or
+    ///   `dyn` vtable) as `self`.
+    ///
+    /// [valid]: crate::ptr#safety
+    /// [pointer metadata]: crate::ptr::metadata()
+    ///
+    /// # Panics
+    ///
+    /// This function may panic. (For example, it might panic if memory
+    /// allocation for a clone of a value owned by `self` fails.) If the
+    /// call panics, then `*dst` should be treated as uninitialized memory;
+    /// it must not be read or dropped, because even if it was previously
+    /// valid, it may have been partially overwritten.
+    ///
+    /// Callers should treat `dst` the same way as if it had been passed to
+    /// [`ptr::write`](crate::ptr::write).
+    unsafe fn clone_to_uninit(&self, dst: *mut Self);
+}
+
+#[unstable(feature = "clone_to_uninit", issue = "none")]
+unsafe impl<T: Clone> CloneToUninit for T {
+    #[inline]
+    default unsafe fn clone_to_uninit(&self, dst: *mut Self) {
+        // SAFETY: the caller guarantees that `dst` is valid for writes and
+        // properly aligned.
+        unsafe { crate::ptr::write(dst, self.clone()) }
+    }
+}
+
+// Specialized implementation for types that are [`Copy`], not just [`Clone`],
+// and can therefore be copied bitwise.
+#[unstable(feature = "clone_to_uninit", issue = "none")]
+unsafe impl<T: Copy> CloneToUninit for T {
+    #[inline]
+    unsafe fn clone_to_uninit(&self, dst: *mut Self) {
+        // SAFETY: the caller guarantees that `dst` is valid for writes and
+        // properly aligned, and `self` cannot overlap freshly allocated memory.
+        unsafe { crate::ptr::copy_nonoverlapping(self, dst, 1) }
+    }
+}
+
+#[unstable(feature = "clone_to_uninit", issue = "none")]
+unsafe impl<T: Clone> CloneToUninit for [T] {
+    #[inline]
+    default unsafe fn clone_to_uninit(&self, dst: *mut Self) {
+        // Drops the elements written so far if cloning an element panics.
+        struct Guard<T> {
+            start: *mut T,
+            initialized: usize,
+        }
+
+        impl<T> Drop for Guard<T> {
+            fn drop(&mut self) {
+                let initialized =
+                    crate::ptr::slice_from_raw_parts_mut(self.start, self.initialized);
+                // SAFETY: exactly `self.initialized` elements have been written.
+                unsafe { crate::ptr::drop_in_place(initialized) }
+            }
+        }
+
+        let mut guard = Guard { start: dst as *mut T, initialized: 0 };
+        for elem in self {
+            // SAFETY: the caller guarantees that `dst` has the same length as
+            // `self`, so it has room for every element.
+            unsafe { guard.start.add(guard.initialized).write(elem.clone()) };
+            guard.initialized += 1;
+        }
+        crate::mem::forget(guard);
+    }
+}
+
+#[unstable(feature = "clone_to_uninit", issue = "none")]
+unsafe impl<T: Copy> CloneToUninit for [T] {
+    #[inline]
+    unsafe fn clone_to_uninit(&self, dst: *mut Self) {
+        // SAFETY: the caller guarantees that `dst` is valid for writes of
+        // `self.len()` elements and properly aligned.
+        unsafe { crate::ptr::copy_nonoverlapping(self.as_ptr(), dst as *mut T, self.len()) }
+    }
+}
+
+#[unstable(feature = "clone_to_uninit", issue = "none")]
+unsafe impl CloneToUninit for str {
+    #[inline]
+    unsafe fn clone_to_uninit(&self, dst: *mut Self) {
+        // SAFETY: `str` has the same layout as `[u8]`.
+        unsafe { self.as_bytes().clone_to_uninit(dst as *mut [u8]) }
+    }
+}
+
 /// Implementations of `Clone` for primitive types.
 ///
 /// Implementations that cannot be described in Rust
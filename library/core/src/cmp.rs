@@ -562,7 +562,12 @@ pub const fn then(self, other: Ordering) -> Ordering {
     #[inline]
     #[must_use]
     #[stable(feature = "ordering_chaining", since = "1.17.0")]
-    pub fn then_with<F: FnOnce() -> Ordering>(self, f: F) -> Ordering {
+    #[rustc_const_unstable(feature = "const_ordering_then_with", issue = "none")]
+    pub const fn then_with<F>(self, f: F) -> Ordering
+    where
+        F: ~const FnOnce() -> Ordering,
+        F: ~const Drop,
+    {
         match self {
             Equal => f(),
             _ => self,
@@ -284,6 +284,10 @@ fn deref_mut(&mut self) -> &mut VaListImpl<'f> {
 // improving this.
 mod sealed_trait {
     /// Trait which permits the allowed types to be used with [super::VaListImpl::arg].
+    ///
+    /// C promotes variadic arguments smaller than `int` to `int`, and `float`
+    /// to `double`, so those types can never be read from a variable argument
+    /// list. Read a `c_int` or `c_double` and convert it instead.
     #[unstable(
         feature = "c_variadic",
         reason = "the `c_variadic` feature has not been properly tested on \
@@ -305,8 +309,8 @@ impl sealed_trait::VaArgSafe for $t {}
     }
 }
 
-impl_va_arg_safe! {i8, i16, i32, i64, usize}
-impl_va_arg_safe! {u8, u16, u32, u64, isize}
+impl_va_arg_safe! {i32, i64, usize}
+impl_va_arg_safe! {u32, u64, isize}
 impl_va_arg_safe! {f64}
 
 #[unstable(
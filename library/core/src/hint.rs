@@ -46,11 +46,216 @@
 #[stable(feature = "unreachable", since = "1.27.0")]
 #[rustc_const_stable(feature = "const_unreachable_unchecked", since = "1.57.0")]
 pub const unsafe fn unreachable_unchecked() -> ! {
+    debug_assert!(false, "hint::unreachable_unchecked must never be reached");
     // SAFETY: the safety contract for `intrinsics::unreachable` must
     // be upheld by the caller.
     unsafe { intrinsics::unreachable() }
 }
 
+/// Makes a *soundness* promise to the compiler that `cond` holds.
+///
+/// This may allow the optimizer to simplify things, but it might also make
+/// the generated code slower. Either way, calling it will most likely make
+/// compilation take longer.
+///
+/// You may know this from other places as [`llvm.assume`] or, in C,
+/// [`__builtin_assume`].
+///
+/// [`llvm.assume`]: https://llvm.org/docs/LangRef.html#llvm-assume-intrinsic
+/// [`__builtin_assume`]: https://clang.llvm.org/docs/LanguageExtensions.html#builtin-assume
+///
+/// This promotes a correctness requirement to a soundness requirement.
+/// Don't do that without very good reason.
+///
+/// # Usage
+///
+/// This is a situational tool for micro-optimization, and is allowed to do
+/// nothing. Any use should come with a repeatable benchmark to show the
+/// value, with the expectation to drop it later should the optimizer get
+/// smarter and no longer need it.
+///
+/// The more complicated the condition, the less likely this is to be useful.
+/// For example, `assert_unchecked(foo.is_sorted())` is a complex enough
+/// value that the compiler is unlikely to be able to take advantage of it.
+///
+/// There's also no need to `assert_unchecked` basic properties of things.
+/// For example, the compiler already knows the range of `count_ones`, so
+/// there is no benefit to `let n = u32::count_ones(x); assert_unchecked(n
+/// <= u32::BITS);`.
+///
+/// `assert_unchecked` is logically equivalent to
+/// `if !cond { unreachable_unchecked(); }`. If ever you are tempted to write
+/// the latter, you should instead use `assert_unchecked(cond)`.
+///
+/// # Safety
+///
+/// `cond` must be `true`. It is immediate UB to call this with `false`.
+///
+/// When the standard library is built with debug assertions, a `false`
+/// condition panics instead, which can help track down a violated invariant
+/// in testing. This check must not be relied upon.
+///
+/// # Example
+///
+/// ```
+/// #![feature(hint_assert_unchecked)]
+/// # #![deny(unsafe_op_in_unsafe_fn)]
+/// use core::hint;
+///
+/// /// # Safety
+/// ///
+/// /// `p` must be nonnull and valid
+/// pub unsafe fn next_value(p: *const i32) -> i32 {
+///     // SAFETY: caller invariants guarantee that `p` is not null
+///     unsafe { hint::assert_unchecked(!p.is_null()) }
+///
+///     if p.is_null() {
+///         -1
+///     } else {
+///         // SAFETY: caller invariants guarantee that `p` is valid
+///         unsafe { *p + 1 }
+///     }
+/// }
+///
+/// assert_eq!(unsafe { next_value(&41) }, 42);
+/// ```
+///
+/// Without the `assert_unchecked`, the above function produces the following
+/// with optimizations enabled:
+///
+/// ```asm
+/// next_value:
+///         test    rdi, rdi
+///         je      .LBB0_1
+///         mov     eax, dword ptr [rdi]
+///         inc     eax
+///         ret
+/// .LBB0_1:
+///         mov     eax, -1
+///         ret
+/// ```
+///
+/// Adding the assertion allows the optimizer to remove the extra check:
+///
+/// ```asm
+/// next_value:
+///         mov     eax, dword ptr [rdi]
+///         inc     eax
+///         ret
+/// ```
+#[inline(always)]
+#[track_caller]
+#[unstable(feature = "hint_assert_unchecked", issue = "none")]
+#[rustc_const_unstable(feature = "hint_assert_unchecked", issue = "none")]
+pub const unsafe fn assert_unchecked(cond: bool) {
+    debug_assert!(cond, "hint::assert_unchecked must never be called with a false condition");
+    if !cond {
+        // SAFETY: the caller must guarantee that `cond` is true.
+        unsafe { intrinsics::unreachable() }
+    }
+}
+
+/// Hints to the compiler that `b` is expected to be `true`, and returns `b`.
+///
+/// This lets the optimizer lay out the code so that the path taken when `b` is
+/// `true` is the fast one, for example by placing it directly after the
+/// branch. The hint has no effect on the program's behavior and may be
+/// ignored, for instance when optimizations are disabled.
+///
+/// The hint is only reliable when the result is used directly as the
+/// condition of an `if` or `while`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(hint_likely)]
+/// use std::hint::likely;
+///
+/// fn parse_digit(b: u8) -> Option<u8> {
+///     if likely(b.is_ascii_digit()) { Some(b - b'0') } else { None }
+/// }
+///
+/// assert_eq!(parse_digit(b'7'), Some(7));
+/// assert_eq!(parse_digit(b'x'), None);
+/// ```
+#[inline(always)]
+#[must_use]
+#[unstable(feature = "hint_likely", issue = "none")]
+#[rustc_const_unstable(feature = "hint_likely", issue = "none")]
+pub const fn likely(b: bool) -> bool {
+    intrinsics::likely(b)
+}
+
+/// Hints to the compiler that `b` is expected to be `false`, and returns `b`.
+///
+/// This is the counterpart of [`likely`], typically used for error checks
+/// that are rarely taken. The hint has no effect on the program's behavior and
+/// may be ignored.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(hint_likely)]
+/// use std::hint::unlikely;
+///
+/// fn sum(values: &[u32]) -> Option<u32> {
+///     let mut total = 0u32;
+///     for &v in values {
+///         let (next, overflowed) = total.overflowing_add(v);
+///         if unlikely(overflowed) {
+///             return None;
+///         }
+///         total = next;
+///     }
+///     Some(total)
+/// }
+///
+/// assert_eq!(sum(&[1, 2, 3]), Some(6));
+/// assert_eq!(sum(&[u32::MAX, 1]), None);
+/// ```
+#[inline(always)]
+#[must_use]
+#[unstable(feature = "hint_likely", issue = "none")]
+#[rustc_const_unstable(feature = "hint_likely", issue = "none")]
+pub const fn unlikely(b: bool) -> bool {
+    intrinsics::unlikely(b)
+}
+
+/// Hints to the compiler that the code path calling this function is rarely
+/// taken.
+///
+/// Calling `cold_path` inside a branch has the same effect as moving the body
+/// of that branch into a separate `#[cold]` function, without having to write
+/// one. It works for any kind of branch, including `match` arms, where
+/// [`likely`] and [`unlikely`] cannot be used. Like those functions, it has no
+/// effect on the program's behavior.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(hint_likely)]
+/// use std::hint::cold_path;
+///
+/// fn classify(b: u8) -> &'static str {
+///     match b {
+///         b'a'..=b'z' => "lower",
+///         b'0'..=b'9' => "digit",
+///         _ => {
+///             cold_path();
+///             "other"
+///         }
+///     }
+/// }
+///
+/// assert_eq!(classify(b'q'), "lower");
+/// assert_eq!(classify(b'%'), "other");
+/// ```
+#[inline]
+#[cold]
+#[unstable(feature = "hint_likely", issue = "none")]
+#[rustc_const_unstable(feature = "hint_likely", issue = "none")]
+pub const fn cold_path() {}
+
 /// Emits a machine instruction to signal the processor that it is running in
 /// a busy-wait spin-loop ("spin lock").
 ///
@@ -70,6 +275,14 @@
 /// **Note**: On platforms that do not support receiving spin-loop hints this
 /// function does not do anything at all.
 ///
+/// # Platform-specific behavior
+///
+/// This function currently emits `pause` on x86 and x86_64 (with SSE2), `isb sy` on AArch64,
+/// `yield` on ARMv6 and later, and `pause` on RISC-V. Note that this [may change in the
+/// future][changes].
+///
+/// [changes]: ../../std/io/index.html#platform-specific-behavior
+///
 /// # Examples
 ///
 /// ```
@@ -156,9 +369,34 @@ pub fn spin_loop() {
 /// extent to which it can block optimisations may vary depending upon the platform and code-gen
 /// backend used. Programs cannot rely on `black_box` for *correctness* in any way.
 ///
+/// In particular, `black_box` makes no guarantees about timing, so it must not be used to
+/// implement constant-time code, and it does not make any memory access volatile or atomic.
+///
 /// [`std::convert::identity`]: crate::convert::identity
+///
+/// # Examples
+///
+/// Without `black_box`, the optimizer may evaluate the whole loop at compile time and reduce the
+/// benchmark to returning a constant:
+///
+/// ```
+/// use std::hint::black_box;
+/// use std::time::Instant;
+///
+/// fn sum(values: &[u64]) -> u64 {
+///     values.iter().sum()
+/// }
+///
+/// let values = [1, 2, 3, 4, 5, 6, 7, 8];
+/// let start = Instant::now();
+/// for _ in 0..1000 {
+///     // Hide both the input and the result from the optimizer so that `sum` is really called.
+///     black_box(sum(black_box(&values)));
+/// }
+/// let _elapsed = start.elapsed();
+/// ```
 #[inline]
-#[unstable(feature = "bench_black_box", issue = "64102")]
+#[stable(feature = "bench_black_box", since = "1.59.0")]
 #[rustc_const_unstable(feature = "const_black_box", issue = "none")]
 pub const fn black_box<T>(dummy: T) -> T {
     crate::intrinsics::black_box(dummy)
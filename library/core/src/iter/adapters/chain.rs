@@ -37,6 +37,21 @@ pub(in super::super) fn new(a: A, b: B) -> Chain<A, B> {
     }
 }
 
+#[stable(feature = "default_iters", since = "1.59.0")]
+impl<A: Default, B: Default> Default for Chain<A, B> {
+    /// Creates a `Chain` from the default values for `A` and `B`.
+    ///
+    /// ```
+    /// # use core::iter::Chain;
+    /// # use core::slice;
+    /// let iter: Chain<slice::Iter<'_, u8>, slice::Iter<'_, u8>> = Default::default();
+    /// assert_eq!(iter.count(), 0);
+    /// ```
+    fn default() -> Self {
+        Chain::new(Default::default(), Default::default())
+    }
+}
+
 /// Fuse the iterator if the expression is `None`.
 macro_rules! fuse {
     ($self:ident . $iter:ident . $($call:tt)+) => {
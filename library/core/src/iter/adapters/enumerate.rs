@@ -24,6 +24,21 @@ pub(in crate::iter) fn new(iter: I) -> Enumerate<I> {
     }
 }
 
+#[stable(feature = "default_iters", since = "1.59.0")]
+impl<I: Default> Default for Enumerate<I> {
+    /// Creates an `Enumerate` iterator from the default value of `I`.
+    ///
+    /// ```
+    /// # use core::slice;
+    /// # use std::iter::Enumerate;
+    /// let iter: Enumerate<slice::Iter<'_, u8>> = Default::default();
+    /// assert_eq!(iter.len(), 0);
+    /// ```
+    fn default() -> Self {
+        Enumerate::new(Default::default())
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<I> Iterator for Enumerate<I>
 where
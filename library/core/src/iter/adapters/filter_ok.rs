@@ -0,0 +1,131 @@
+use crate::fmt;
+use crate::iter::FusedIterator;
+use crate::ops::Try;
+
+/// An iterator that filters the [`Ok`] values of `iter` with `predicate`,
+/// passing [`Err`] values through unchanged.
+///
+/// This `struct` is created by the [`filter_ok`] method on [`Iterator`]. See its
+/// documentation for more.
+///
+/// [`filter_ok`]: Iterator::filter_ok
+/// [`Iterator`]: trait.Iterator.html
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+#[derive(Clone)]
+pub struct FilterOk<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> FilterOk<I, P> {
+    pub(in crate::iter) fn new(iter: I, predicate: P) -> FilterOk<I, P> {
+        FilterOk { iter, predicate }
+    }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<I: fmt::Debug, P> fmt::Debug for FilterOk<I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FilterOk").field("iter", &self.iter).finish()
+    }
+}
+
+fn filter_ok_keep<T, E>(predicate: &mut impl FnMut(&T) -> bool, item: &Result<T, E>) -> bool {
+    match item {
+        Ok(x) => predicate(x),
+        Err(_) => true,
+    }
+}
+
+fn filter_ok_fold<T, E, Acc>(
+    mut predicate: impl FnMut(&T) -> bool,
+    mut fold: impl FnMut(Acc, Result<T, E>) -> Acc,
+) -> impl FnMut(Acc, Result<T, E>) -> Acc {
+    move |acc, item| if filter_ok_keep(&mut predicate, &item) { fold(acc, item) } else { acc }
+}
+
+fn filter_ok_try_fold<'a, T, E, Acc, R: Try<Output = Acc>>(
+    predicate: &'a mut impl FnMut(&T) -> bool,
+    mut fold: impl FnMut(Acc, Result<T, E>) -> R + 'a,
+) -> impl FnMut(Acc, Result<T, E>) -> R + 'a {
+    move |acc, item| if filter_ok_keep(predicate, &item) { fold(acc, item) } else { try { acc } }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<T, E, I, P> Iterator for FilterOk<I, P>
+where
+    I: Iterator<Item = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<T, E>> {
+        let predicate = &mut self.predicate;
+        self.iter.find(|item| filter_ok_keep(predicate, item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper) // can't know a lower bound, due to the predicate
+    }
+
+    #[inline]
+    fn try_fold<Acc, Fold, R>(&mut self, init: Acc, fold: Fold) -> R
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> R,
+        R: Try<Output = Acc>,
+    {
+        self.iter.try_fold(init, filter_ok_try_fold(&mut self.predicate, fold))
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, fold: Fold) -> Acc
+    where
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, filter_ok_fold(self.predicate, fold))
+    }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<T, E, I, P> DoubleEndedIterator for FilterOk<I, P>
+where
+    I: DoubleEndedIterator<Item = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Result<T, E>> {
+        let predicate = &mut self.predicate;
+        self.iter.rfind(|item| filter_ok_keep(predicate, item))
+    }
+
+    #[inline]
+    fn try_rfold<Acc, Fold, R>(&mut self, init: Acc, fold: Fold) -> R
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> R,
+        R: Try<Output = Acc>,
+    {
+        self.iter.try_rfold(init, filter_ok_try_fold(&mut self.predicate, fold))
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, fold: Fold) -> Acc
+    where
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.rfold(init, filter_ok_fold(self.predicate, fold))
+    }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<T, E, I, P> FusedIterator for FilterOk<I, P>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+}
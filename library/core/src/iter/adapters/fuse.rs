@@ -1,4 +1,4 @@
-use crate::intrinsics;
+use crate::hint;
 use crate::iter::adapters::zip::try_get_unchecked;
 use crate::iter::{
     DoubleEndedIterator, ExactSizeIterator, FusedIterator, TrustedLen, TrustedRandomAccess,
@@ -26,6 +26,21 @@ pub(in crate::iter) fn new(iter: I) -> Fuse<I> {
     }
 }
 
+#[stable(feature = "default_iters", since = "1.59.0")]
+impl<I: Default> Default for Fuse<I> {
+    /// Creates a `Fuse` iterator from the default value of `I`.
+    ///
+    /// ```
+    /// # use core::slice;
+    /// # use std::iter::Fuse;
+    /// let iter: Fuse<slice::Iter<'_, u8>> = Default::default();
+    /// assert_eq!(iter.len(), 0);
+    /// ```
+    fn default() -> Self {
+        Fuse { iter: Some(Default::default()) }
+    }
+}
+
 #[stable(feature = "fused", since = "1.26.0")]
 impl<I> FusedIterator for Fuse<I> where I: Iterator {}
 
@@ -139,7 +154,7 @@ unsafe fn __iterator_get_unchecked(&mut self, idx: usize) -> Self::Item
             // `Iterator::__iterator_get_unchecked`.
             Some(ref mut iter) => unsafe { try_get_unchecked(iter, idx) },
             // SAFETY: the caller asserts there is an item at `i`, so we're not exhausted.
-            None => unsafe { intrinsics::unreachable() },
+            None => unsafe { hint::unreachable_unchecked() },
         }
     }
 }
@@ -0,0 +1,132 @@
+use crate::fmt;
+use crate::iter::FusedIterator;
+use crate::ops::Try;
+
+/// An iterator that maps the [`Err`] values of `iter` with `f`, passing
+/// [`Ok`] values through unchanged.
+///
+/// This `struct` is created by the [`map_err`] method on [`Iterator`]. See its
+/// documentation for more.
+///
+/// [`map_err`]: Iterator::map_err
+/// [`Iterator`]: trait.Iterator.html
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+#[derive(Clone)]
+pub struct MapErr<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> MapErr<I, F> {
+    pub(in crate::iter) fn new(iter: I, f: F) -> MapErr<I, F> {
+        MapErr { iter, f }
+    }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<I: fmt::Debug, F> fmt::Debug for MapErr<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapErr").field("iter", &self.iter).finish()
+    }
+}
+
+fn map_err_fold<T, E, U, Acc, R>(
+    mut f: impl FnMut(E) -> U,
+    mut g: impl FnMut(Acc, Result<T, U>) -> R,
+) -> impl FnMut(Acc, Result<T, E>) -> R {
+    move |acc, elt| g(acc, elt.map_err(&mut f))
+}
+
+fn map_err_try_fold<'a, T, E, U, Acc, R>(
+    f: &'a mut impl FnMut(E) -> U,
+    mut g: impl FnMut(Acc, Result<T, U>) -> R + 'a,
+) -> impl FnMut(Acc, Result<T, E>) -> R + 'a {
+    move |acc, elt| g(acc, elt.map_err(&mut *f))
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<T, E, U, I, F> Iterator for MapErr<I, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+    type Item = Result<T, U>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<T, U>> {
+        self.iter.next().map(|elt| elt.map_err(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn try_fold<Acc, G, R>(&mut self, init: Acc, g: G) -> R
+    where
+        Self: Sized,
+        G: FnMut(Acc, Self::Item) -> R,
+        R: Try<Output = Acc>,
+    {
+        self.iter.try_fold(init, map_err_try_fold(&mut self.f, g))
+    }
+
+    fn fold<Acc, G>(self, init: Acc, g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, map_err_fold(self.f, g))
+    }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<T, E, U, I, F> DoubleEndedIterator for MapErr<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Result<T, U>> {
+        self.iter.next_back().map(|elt| elt.map_err(&mut self.f))
+    }
+
+    fn try_rfold<Acc, G, R>(&mut self, init: Acc, g: G) -> R
+    where
+        Self: Sized,
+        G: FnMut(Acc, Self::Item) -> R,
+        R: Try<Output = Acc>,
+    {
+        self.iter.try_rfold(init, map_err_try_fold(&mut self.f, g))
+    }
+
+    fn rfold<Acc, G>(self, init: Acc, g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.rfold(init, map_err_fold(self.f, g))
+    }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<T, E, U, I, F> ExactSizeIterator for MapErr<I, F>
+where
+    I: ExactSizeIterator<Item = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.iter.is_empty()
+    }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<T, E, U, I, F> FusedIterator for MapErr<I, F>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+}
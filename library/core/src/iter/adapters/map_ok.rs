@@ -0,0 +1,132 @@
+use crate::fmt;
+use crate::iter::FusedIterator;
+use crate::ops::Try;
+
+/// An iterator that maps the [`Ok`] values of `iter` with `f`, passing
+/// [`Err`] values through unchanged.
+///
+/// This `struct` is created by the [`map_ok`] method on [`Iterator`]. See its
+/// documentation for more.
+///
+/// [`map_ok`]: Iterator::map_ok
+/// [`Iterator`]: trait.Iterator.html
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+#[derive(Clone)]
+pub struct MapOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> MapOk<I, F> {
+    pub(in crate::iter) fn new(iter: I, f: F) -> MapOk<I, F> {
+        MapOk { iter, f }
+    }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<I: fmt::Debug, F> fmt::Debug for MapOk<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapOk").field("iter", &self.iter).finish()
+    }
+}
+
+fn map_ok_fold<T, E, U, Acc, R>(
+    mut f: impl FnMut(T) -> U,
+    mut g: impl FnMut(Acc, Result<U, E>) -> R,
+) -> impl FnMut(Acc, Result<T, E>) -> R {
+    move |acc, elt| g(acc, elt.map(&mut f))
+}
+
+fn map_ok_try_fold<'a, T, E, U, Acc, R>(
+    f: &'a mut impl FnMut(T) -> U,
+    mut g: impl FnMut(Acc, Result<U, E>) -> R + 'a,
+) -> impl FnMut(Acc, Result<T, E>) -> R + 'a {
+    move |acc, elt| g(acc, elt.map(&mut *f))
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<T, E, U, I, F> Iterator for MapOk<I, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    type Item = Result<U, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<U, E>> {
+        self.iter.next().map(|elt| elt.map(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn try_fold<Acc, G, R>(&mut self, init: Acc, g: G) -> R
+    where
+        Self: Sized,
+        G: FnMut(Acc, Self::Item) -> R,
+        R: Try<Output = Acc>,
+    {
+        self.iter.try_fold(init, map_ok_try_fold(&mut self.f, g))
+    }
+
+    fn fold<Acc, G>(self, init: Acc, g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, map_ok_fold(self.f, g))
+    }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<T, E, U, I, F> DoubleEndedIterator for MapOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Result<U, E>> {
+        self.iter.next_back().map(|elt| elt.map(&mut self.f))
+    }
+
+    fn try_rfold<Acc, G, R>(&mut self, init: Acc, g: G) -> R
+    where
+        Self: Sized,
+        G: FnMut(Acc, Self::Item) -> R,
+        R: Try<Output = Acc>,
+    {
+        self.iter.try_rfold(init, map_ok_try_fold(&mut self.f, g))
+    }
+
+    fn rfold<Acc, G>(self, init: Acc, g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.rfold(init, map_ok_fold(self.f, g))
+    }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<T, E, U, I, F> ExactSizeIterator for MapOk<I, F>
+where
+    I: ExactSizeIterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.iter.is_empty()
+    }
+}
+
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+impl<T, E, U, I, F> FusedIterator for MapOk<I, F>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+}
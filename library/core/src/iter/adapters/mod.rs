@@ -8,11 +8,14 @@
 mod enumerate;
 mod filter;
 mod filter_map;
+mod filter_ok;
 mod flatten;
 mod fuse;
 mod inspect;
 mod intersperse;
 mod map;
+mod map_err;
+mod map_ok;
 mod map_while;
 mod peekable;
 mod rev;
@@ -48,6 +51,9 @@
 #[stable(feature = "iter_map_while", since = "1.57.0")]
 pub use self::map_while::MapWhile;
 
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+pub use self::{filter_ok::FilterOk, map_err::MapErr, map_ok::MapOk};
+
 #[unstable(feature = "trusted_random_access", issue = "none")]
 pub use self::zip::TrustedRandomAccess;
 
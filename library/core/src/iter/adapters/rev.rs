@@ -21,6 +21,21 @@ pub(in crate::iter) fn new(iter: T) -> Rev<T> {
     }
 }
 
+#[stable(feature = "default_iters", since = "1.59.0")]
+impl<T: Default> Default for Rev<T> {
+    /// Creates a `Rev` iterator from the default value of `T`.
+    ///
+    /// ```
+    /// # use core::slice;
+    /// # use core::iter::Rev;
+    /// let iter: Rev<slice::Iter<'_, u8>> = Default::default();
+    /// assert_eq!(iter.len(), 0);
+    /// ```
+    fn default() -> Self {
+        Rev::new(Default::default())
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<I> Iterator for Rev<I>
 where
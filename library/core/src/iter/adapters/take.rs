@@ -235,7 +235,15 @@ fn advance_back_by(&mut self, n: usize) -> Result<(), usize> {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<I> ExactSizeIterator for Take<I> where I: ExactSizeIterator {}
+impl<I> ExactSizeIterator for Take<I>
+where
+    I: ExactSizeIterator,
+{
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.n == 0 || self.iter.is_empty()
+    }
+}
 
 #[stable(feature = "fused", since = "1.26.0")]
 impl<I> FusedIterator for Take<I> where I: FusedIterator {}
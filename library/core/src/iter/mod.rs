@@ -377,9 +377,15 @@
 pub use self::sources::{repeat_with, RepeatWith};
 #[stable(feature = "iter_successors", since = "1.34.0")]
 pub use self::sources::{successors, Successors};
+#[unstable(feature = "iter_unfold", issue = "none")]
+pub use self::sources::{unfold, Unfold};
 
 #[stable(feature = "fused", since = "1.26.0")]
 pub use self::traits::FusedIterator;
+#[unstable(feature = "iter_fsum", issue = "none")]
+pub use self::traits::AccurateSum;
+#[unstable(feature = "iter_group_map_into", issue = "none")]
+pub use self::traits::GroupInsert;
 #[unstable(issue = "none", feature = "inplace_iteration")]
 pub use self::traits::InPlaceIterable;
 #[unstable(feature = "trusted_len", issue = "37572")]
@@ -414,10 +420,13 @@
     Chain, Cycle, Enumerate, Filter, FilterMap, FlatMap, Fuse, Inspect, Map, Peekable, Rev, Scan,
     Skip, SkipWhile, Take, TakeWhile, Zip,
 };
+#[unstable(feature = "iter_result_adapters", issue = "none")]
+pub use self::adapters::{FilterOk, MapErr, MapOk};
 #[unstable(feature = "iter_intersperse", reason = "recently added", issue = "79524")]
 pub use self::adapters::{Intersperse, IntersperseWith};
 
 pub(crate) use self::adapters::process_results;
+pub(crate) use self::traits::{SpecIterCmp, SpecIterEq};
 
 mod adapters;
 mod range;
@@ -5,6 +5,7 @@
 mod repeat;
 mod repeat_with;
 mod successors;
+mod unfold;
 
 pub use self::repeat::{repeat, Repeat};
 
@@ -25,3 +26,6 @@
 
 #[stable(feature = "iter_once_with", since = "1.43.0")]
 pub use self::once_with::{once_with, OnceWith};
+
+#[unstable(feature = "iter_unfold", issue = "none")]
+pub use self::unfold::{unfold, Unfold};
@@ -0,0 +1,144 @@
+use crate::fmt;
+
+/// Creates a new iterator that threads a state value through the provided
+/// closure `F: FnMut(&mut St) -> Option<T>`.
+///
+/// The iterator owns `initial_state` and passes a mutable reference to it to
+/// the closure on every call to [`next`]. The closure updates the state as
+/// needed and returns the next item, or [`None`] to signal the end.
+///
+/// Unlike [`from_fn`], the state does not have to be captured by the closure,
+/// so it can be inspected through [`Unfold::state`] and the closure can be a
+/// plain function. Unlike [`successors`], the state can be of a different
+/// type than the items.
+///
+/// Note that the `Unfold` iterator doesn’t make assumptions about the behavior
+/// of the closure, and therefore conservatively does not implement
+/// [`FusedIterator`], or override [`Iterator::size_hint()`] from its default
+/// `(0, None)`.
+///
+/// [`next`]: Iterator::next
+/// [`from_fn`]: crate::iter::from_fn
+/// [`successors`]: crate::iter::successors
+/// [`FusedIterator`]: crate::iter::FusedIterator
+///
+/// # Examples
+///
+/// Decoding a stream of LEB128-encoded integers:
+///
+/// ```
+/// #![feature(iter_unfold)]
+/// use std::iter;
+///
+/// fn decode(input: &[u8]) -> impl Iterator<Item = u64> + '_ {
+///     iter::unfold(input, |input| {
+///         let mut value = 0;
+///         for shift in (0..64).step_by(7) {
+///             let (&byte, rest) = input.split_first()?;
+///             *input = rest;
+///             value |= u64::from(byte & 0x7f) << shift;
+///             if byte & 0x80 == 0 {
+///                 return Some(value);
+///             }
+///         }
+///         None
+///     })
+/// }
+///
+/// let values: Vec<u64> = decode(&[0x05, 0xe5, 0x8e, 0x26, 0x7f]).collect();
+/// assert_eq!(values, [5, 624_485, 127]);
+/// ```
+///
+/// The Fibonacci numbers, with the pair of previous numbers as the state:
+///
+/// ```
+/// #![feature(iter_unfold)]
+/// use std::iter;
+///
+/// let fib = iter::unfold((0_u32, 1_u32), |(a, b)| {
+///     let next = *a;
+///     *a = *b;
+///     *b = b.checked_add(next)?;
+///     Some(next)
+/// });
+/// assert_eq!(fib.take(8).collect::<Vec<_>>(), [0, 1, 1, 2, 3, 5, 8, 13]);
+/// ```
+#[inline]
+#[unstable(feature = "iter_unfold", issue = "none")]
+pub fn unfold<St, T, F>(initial_state: St, f: F) -> Unfold<St, F>
+where
+    F: FnMut(&mut St) -> Option<T>,
+{
+    Unfold { state: initial_state, f }
+}
+
+/// An iterator where each iteration calls the provided closure
+/// `F: FnMut(&mut St) -> Option<T>` with the iterator's state.
+///
+/// This `struct` is created by the [`iter::unfold()`] function.
+/// See its documentation for more.
+///
+/// [`iter::unfold()`]: unfold
+#[derive(Clone)]
+#[unstable(feature = "iter_unfold", issue = "none")]
+pub struct Unfold<St, F> {
+    state: St,
+    f: F,
+}
+
+impl<St, F> Unfold<St, F> {
+    /// Returns a reference to the current state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_unfold)]
+    ///
+    /// let mut counter = std::iter::unfold(0, |n| {
+    ///     *n += 1;
+    ///     Some(*n)
+    /// });
+    /// counter.next();
+    /// counter.next();
+    /// assert_eq!(*counter.state(), 2);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_unfold", issue = "none")]
+    pub fn state(&self) -> &St {
+        &self.state
+    }
+
+    /// Returns a mutable reference to the current state.
+    #[inline]
+    #[unstable(feature = "iter_unfold", issue = "none")]
+    pub fn state_mut(&mut self) -> &mut St {
+        &mut self.state
+    }
+
+    /// Consumes the iterator, returning the current state.
+    #[inline]
+    #[unstable(feature = "iter_unfold", issue = "none")]
+    pub fn into_state(self) -> St {
+        self.state
+    }
+}
+
+#[unstable(feature = "iter_unfold", issue = "none")]
+impl<St, T, F> Iterator for Unfold<St, F>
+where
+    F: FnMut(&mut St) -> Option<T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.f)(&mut self.state)
+    }
+}
+
+#[unstable(feature = "iter_unfold", issue = "none")]
+impl<St: fmt::Debug, F> fmt::Debug for Unfold<St, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Unfold").field("state", &self.state).finish_non_exhaustive()
+    }
+}
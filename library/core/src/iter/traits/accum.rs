@@ -34,6 +34,22 @@ pub trait Product<A = Self>: Sized {
     fn product<I: Iterator<Item = A>>(iter: I) -> Self;
 }
 
+/// Trait to represent types that can be created by accurately summing up an
+/// iterator.
+///
+/// This trait is used to implement [`Iterator::fsum()`]. Unlike [`Sum`], an
+/// implementation is expected to keep the rounding error of the result small
+/// even for long iterators, at some extra cost per element.
+///
+/// [`fsum()`]: Iterator::fsum
+#[unstable(feature = "iter_fsum", issue = "none")]
+pub trait AccurateSum<A = Self>: Sized {
+    /// Method which takes an iterator and generates `Self` from the elements by
+    /// summing them up accurately.
+    #[unstable(feature = "iter_fsum", issue = "none")]
+    fn accurate_sum<I: Iterator<Item = A>>(iter: I) -> Self;
+}
+
 macro_rules! integer_sum_product {
     (@impls $zero:expr, $one:expr, #[$attr:meta], $($a:ty)*) => ($(
         #[$attr]
@@ -138,8 +154,39 @@ fn product<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
     )*)
 }
 
+macro_rules! float_accurate_sum {
+    ($($a:ident)*) => ($(
+        #[unstable(feature = "iter_fsum", issue = "none")]
+        impl AccurateSum for $a {
+            fn accurate_sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+                let abs = |x: $a| <$a>::from_bits(x.to_bits() & !(-0.0 as $a).to_bits());
+                // Neumaier's variant of Kahan summation: `compensation` collects the
+                // low-order bits that each addition to `sum` rounds away.
+                let (sum, compensation) = iter.fold(
+                    (0.0, 0.0),
+                    |(sum, compensation): ($a, $a), x| {
+                        let t = sum + x;
+                        let lost = if abs(sum) >= abs(x) { (sum - t) + x } else { (x - t) + sum };
+                        (t, compensation + lost)
+                    },
+                );
+                // Once the sum is infinite or NaN the compensation is meaningless.
+                if sum.is_finite() { sum + compensation } else { sum }
+            }
+        }
+
+        #[unstable(feature = "iter_fsum", issue = "none")]
+        impl<'a> AccurateSum<&'a $a> for $a {
+            fn accurate_sum<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
+                AccurateSum::accurate_sum(iter.copied())
+            }
+        }
+    )*)
+}
+
 integer_sum_product! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
 float_sum_product! { f32 f64 }
+float_accurate_sum! { f32 f64 }
 
 #[stable(feature = "iter_arith_traits_result", since = "1.16.0")]
 impl<T, U, E> Sum<Result<U, E>> for Result<T, E>
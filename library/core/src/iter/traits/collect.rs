@@ -420,3 +420,65 @@ fn extend_reserve(&mut self, additional: usize) {
         self.1.extend_reserve(additional);
     }
 }
+
+#[stable(feature = "from_iterator_for_tuple", since = "1.59.0")]
+impl<A, B, FromA, FromB> FromIterator<(A, B)> for (FromA, FromB)
+where
+    FromA: Default + Extend<A>,
+    FromB: Default + Extend<B>,
+{
+    /// Collects an iterator of pairs into a pair of collections.
+    ///
+    /// This is equivalent to [`Iterator::unzip`], but also works wherever a
+    /// [`FromIterator`] is expected.
+    ///
+    /// # Examples
+    /// ```
+    /// let records = [("alice", (31, 'a')), ("bob", (27, 'b'))];
+    ///
+    /// let (names, (ages, initials)): (Vec<_>, (Vec<_>, String)) =
+    ///     records.iter().copied().collect();
+    /// assert_eq!(names, ["alice", "bob"]);
+    /// assert_eq!(ages, [31, 27]);
+    /// assert_eq!(initials, "ab");
+    /// ```
+    fn from_iter<I: IntoIterator<Item = (A, B)>>(iter: I) -> Self {
+        let mut res = (FromA::default(), FromB::default());
+        res.extend(iter);
+        res
+    }
+}
+
+/// A map that sorts values into groups by key.
+///
+/// This trait is used by [`Iterator::group_map_into`] to build a map of
+/// groups, such as a `HashMap<K, Vec<V>>`, in a single pass over an iterator.
+///
+/// # Examples
+///
+/// A map that only counts the values in each group:
+///
+/// ```
+/// #![feature(iter_group_map_into)]
+/// use std::collections::HashMap;
+/// use std::iter::GroupInsert;
+///
+/// #[derive(Default)]
+/// struct Counts(HashMap<bool, usize>);
+///
+/// impl<T> GroupInsert<bool, T> for Counts {
+///     fn group_insert(&mut self, key: bool, _value: T) {
+///         *self.0.entry(key).or_default() += 1;
+///     }
+/// }
+///
+/// let Counts(counts) = (1..=10).group_map_into(|n| n % 3 == 0);
+/// assert_eq!(counts[&true], 3);
+/// assert_eq!(counts[&false], 7);
+/// ```
+#[unstable(feature = "iter_group_map_into", issue = "none")]
+pub trait GroupInsert<K, V> {
+    /// Adds `value` to the group for `key`, creating the group if there is
+    /// none yet.
+    fn group_insert(&mut self, key: K, value: V);
+}
@@ -1,10 +1,12 @@
+use crate::array;
 use crate::cmp::{self, Ordering};
 use crate::ops::{ChangeOutputType, ControlFlow, FromResidual, Residual, Try};
 
 use super::super::TrustedRandomAccessNoCoerce;
+use super::super::{AccurateSum, FilterOk, MapErr, MapOk};
 use super::super::{Chain, Cloned, Copied, Cycle, Enumerate, Filter, FilterMap, Fuse};
 use super::super::{FlatMap, Flatten};
-use super::super::{FromIterator, Intersperse, IntersperseWith, Product, Sum, Zip};
+use super::super::{FromIterator, GroupInsert, Intersperse, IntersperseWith, Product, Sum, Zip};
 use super::super::{
     Inspect, Map, MapWhile, Peekable, Rev, Scan, Skip, SkipWhile, StepBy, Take, TakeWhile,
 };
@@ -91,6 +93,71 @@ pub trait Iterator {
     #[stable(feature = "rust1", since = "1.0.0")]
     fn next(&mut self) -> Option<Self::Item>;
 
+    /// Advances the iterator and returns an array containing the next `N` values.
+    ///
+    /// If there are not enough elements to fill the array then `Err` is returned
+    /// containing an iterator over the remaining elements.
+    ///
+    /// This lets fixed-size data be read straight into an array, without
+    /// collecting into a [`Vec`] first and converting it with `try_into`.
+    ///
+    /// [`Vec`]: ../../std/vec/struct.Vec.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(iter_next_chunk)]
+    ///
+    /// let mut iter = "lorem".chars();
+    ///
+    /// assert_eq!(iter.next_chunk().unwrap(), ['l', 'o']);              // N is inferred as 2
+    /// assert_eq!(iter.next_chunk().unwrap(), ['r', 'e', 'm']);         // N is inferred as 3
+    /// assert_eq!(iter.next_chunk::<4>().unwrap_err().as_slice(), &[]); // N is explicitly 4
+    /// ```
+    ///
+    /// Split a string and get the first three items.
+    ///
+    /// ```
+    /// #![feature(iter_next_chunk)]
+    ///
+    /// let quote = "not all those who wander are lost";
+    /// let [first, second, third] = quote.split_whitespace().next_chunk().unwrap();
+    /// assert_eq!(first, "not");
+    /// assert_eq!(second, "all");
+    /// assert_eq!(third, "those");
+    /// ```
+    ///
+    /// Parse a fixed number of fields, rejecting both missing and extra ones.
+    ///
+    /// ```
+    /// #![feature(iter_next_chunk)]
+    ///
+    /// fn parse_rgb(s: &str) -> Option<[u8; 3]> {
+    ///     let mut fields = s.split(',').map(|f| f.trim().parse().ok());
+    ///     let [r, g, b] = fields.next_chunk().ok()?;
+    ///     if fields.next().is_some() {
+    ///         return None;
+    ///     }
+    ///     Some([r?, g?, b?])
+    /// }
+    ///
+    /// assert_eq!(parse_rgb("255, 128, 0"), Some([255, 128, 0]));
+    /// assert_eq!(parse_rgb("255, 128"), None);
+    /// assert_eq!(parse_rgb("255, 128, 0, 7"), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_next_chunk", issue = "none")]
+    fn next_chunk<const N: usize>(
+        &mut self,
+    ) -> Result<[Self::Item; N], array::IntoIter<Self::Item, N>>
+    where
+        Self: Sized,
+    {
+        array::iter_next_chunk(self)
+    }
+
     /// Returns the bounds on the remaining length of the iterator.
     ///
     /// Specifically, `size_hint()` returns a tuple where the first element
@@ -1206,6 +1273,115 @@ fn map_while<B, P>(self, predicate: P) -> MapWhile<Self, P>
         MapWhile::new(self, predicate)
     }
 
+    /// Creates an iterator over [`Result`]s which calls a closure on each
+    /// [`Ok`] value, and passes each [`Err`] value through unchanged.
+    ///
+    /// `map_ok(f)` is equivalent to `map(|x| x.map(f))`. It is useful for
+    /// transforming the successful items of a fallible stream, such as the
+    /// lines of a file, before collecting it into a `Result` or otherwise
+    /// stopping at the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_result_adapters)]
+    ///
+    /// let input = ["1", "2", "x", "4"];
+    ///
+    /// let mut iter = input.iter().map(|s| s.parse::<i32>()).map_ok(|n| n * 10);
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(10)));
+    /// assert_eq!(iter.next(), Some(Ok(20)));
+    /// assert!(iter.next().unwrap().is_err());
+    /// assert_eq!(iter.next(), Some(Ok(40)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// Collecting into a `Result` stops at the first error:
+    ///
+    /// ```
+    /// #![feature(iter_result_adapters)]
+    ///
+    /// let sum: Result<i32, _> =
+    ///     ["1", "2", "3"].iter().map(|s| s.parse::<i32>()).map_ok(|n| n * n).sum();
+    /// assert_eq!(sum, Ok(14));
+    ///
+    /// let all: Result<Vec<i32>, _> =
+    ///     ["1", "x", "3"].iter().map(|s| s.parse::<i32>()).map_ok(|n| n * n).collect();
+    /// assert!(all.is_err());
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_result_adapters", issue = "none")]
+    fn map_ok<T, E, U, F>(self, f: F) -> MapOk<Self, F>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        F: FnMut(T) -> U,
+    {
+        MapOk::new(self, f)
+    }
+
+    /// Creates an iterator over [`Result`]s which calls a closure on each
+    /// [`Err`] value, and passes each [`Ok`] value through unchanged.
+    ///
+    /// `map_err(f)` is equivalent to `map(|x| x.map_err(f))`. It is commonly
+    /// used to convert the errors of a fallible stream into another error type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_result_adapters)]
+    ///
+    /// let parsed: Vec<Result<i32, String>> = ["1", "x"]
+    ///     .iter()
+    ///     .map(|s| s.parse::<i32>())
+    ///     .map_err(|e| e.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(parsed, [Ok(1), Err("invalid digit found in string".to_string())]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_result_adapters", issue = "none")]
+    fn map_err<T, E, U, F>(self, f: F) -> MapErr<Self, F>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        F: FnMut(E) -> U,
+    {
+        MapErr::new(self, f)
+    }
+
+    /// Creates an iterator over [`Result`]s which uses a closure to determine
+    /// if an [`Ok`] value should be yielded. Every [`Err`] value is yielded,
+    /// so that a consumer stopping at the first error still sees it.
+    ///
+    /// The closure is only called with the values inside [`Ok`], and the
+    /// value is kept if the closure returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_result_adapters)]
+    ///
+    /// let evens: Result<Vec<i32>, _> = ["1", "2", "3", "4"]
+    ///     .iter()
+    ///     .map(|s| s.parse::<i32>())
+    ///     .filter_ok(|n| n % 2 == 0)
+    ///     .collect();
+    /// assert_eq!(evens, Ok(vec![2, 4]));
+    ///
+    /// let mut iter = ["1", "x"].iter().map(|s| s.parse::<i32>()).filter_ok(|n| n % 2 == 0);
+    /// assert!(iter.next().unwrap().is_err());
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_result_adapters", issue = "none")]
+    fn filter_ok<T, E, P>(self, predicate: P) -> FilterOk<Self, P>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        P: FnMut(&T) -> bool,
+    {
+        FilterOk::new(self, predicate)
+    }
+
     /// Creates an iterator that skips the first `n` elements.
     ///
     /// `skip(n)` skips elements until `n` elements are skipped or the end of the
@@ -1800,6 +1976,58 @@ fn extend<'a, T, B: Extend<T>>(
         (left, right)
     }
 
+    /// Consumes an iterator, sorting its elements into groups by the key that
+    /// `f` returns for each of them.
+    ///
+    /// The groups are collected into a map type `M`, typically a
+    /// [`HashMap`] or [`BTreeMap`] of [`Vec`]s. Within a group, elements keep
+    /// the order in which the iterator returned them. Any map implementing
+    /// [`GroupInsert`] can be used; the standard maps implement it for every
+    /// group type that implements [`Default`] and [`Extend`].
+    ///
+    /// [`HashMap`]: ../../std/collections/struct.HashMap.html
+    /// [`BTreeMap`]: ../../std/collections/struct.BTreeMap.html
+    /// [`Vec`]: ../../std/vec/struct.Vec.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_group_map_into)]
+    /// use std::collections::{BTreeMap, HashMap};
+    ///
+    /// let words = ["apple", "bob", "cat", "avocado", "bee"];
+    ///
+    /// let by_initial: HashMap<char, Vec<&str>> =
+    ///     words.iter().copied().group_map_into(|w| w.chars().next().unwrap());
+    /// assert_eq!(by_initial[&'a'], ["apple", "avocado"]);
+    /// assert_eq!(by_initial[&'b'], ["bob", "bee"]);
+    /// assert_eq!(by_initial[&'c'], ["cat"]);
+    ///
+    /// let by_len: BTreeMap<usize, String> = "añb€".chars().group_map_into(|c| c.len_utf8());
+    /// assert_eq!(by_len[&1], "ab");
+    /// assert_eq!(by_len[&2], "ñ");
+    /// assert_eq!(by_len[&3], "€");
+    /// ```
+    #[unstable(feature = "iter_group_map_into", issue = "none")]
+    fn group_map_into<M, K, F>(self, f: F) -> M
+    where
+        Self: Sized,
+        M: Default + GroupInsert<K, Self::Item>,
+        F: FnMut(&Self::Item) -> K,
+    {
+        #[inline]
+        fn insert<'a, T, K, M: GroupInsert<K, T>>(
+            mut f: impl FnMut(&T) -> K + 'a,
+            groups: &'a mut M,
+        ) -> impl FnMut((), T) + 'a {
+            move |(), x| groups.group_insert(f(&x), x)
+        }
+
+        let mut groups = M::default();
+        self.fold((), insert(f, &mut groups));
+        groups
+    }
+
     /// Reorders the elements of this iterator *in-place* according to the given predicate,
     /// such that all those that return `true` precede all those that return `false`.
     /// Returns the number of `true` elements found.
@@ -2891,6 +3119,199 @@ fn fold<T>(mut compare: impl FnMut(&T, &T) -> Ordering) -> impl FnMut(T, T) -> T
         self.reduce(fold(compare))
     }
 
+    /// Returns the index of the maximum element of an iterator.
+    ///
+    /// If several elements are equally maximum, the index of the last one is
+    /// returned, matching [`max`]. If the iterator is empty, [`None`] is
+    /// returned.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so if there are more
+    /// than [`usize::MAX`] elements, it either produces the wrong result or
+    /// panics. If debug assertions are enabled, a panic is guaranteed.
+    ///
+    /// [`max`]: Iterator::max
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_position_extremum)]
+    /// let a = [1, 3, 2, 3];
+    /// let b: [u32; 0] = [];
+    ///
+    /// assert_eq!(a.iter().position_max(), Some(3));
+    /// assert_eq!(b.iter().position_max(), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_position_extremum", issue = "none")]
+    fn position_max(self) -> Option<usize>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        self.position_max_by(Ord::cmp)
+    }
+
+    /// Returns the index of the minimum element of an iterator.
+    ///
+    /// If several elements are equally minimum, the index of the first one is
+    /// returned, matching [`min`]. If the iterator is empty, [`None`] is
+    /// returned.
+    ///
+    /// See [`position_max`] for the overflow behavior.
+    ///
+    /// [`min`]: Iterator::min
+    /// [`position_max`]: Iterator::position_max
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_position_extremum)]
+    /// let a = [2, 1, 3, 1];
+    ///
+    /// assert_eq!(a.iter().position_min(), Some(1));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_position_extremum", issue = "none")]
+    fn position_min(self) -> Option<usize>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        self.position_min_by(Ord::cmp)
+    }
+
+    /// Returns the index of the element that gives the maximum value from the
+    /// specified function.
+    ///
+    /// If several elements are equally maximum, the index of the last one is
+    /// returned, matching [`max_by_key`]. If the iterator is empty, [`None`]
+    /// is returned.
+    ///
+    /// [`max_by_key`]: Iterator::max_by_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_position_extremum)]
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_max_by_key(|x| x.abs()), Some(4));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_position_extremum", issue = "none")]
+    fn position_max_by_key<B: Ord, F>(self, f: F) -> Option<usize>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> B,
+    {
+        #[inline]
+        fn key<T, B>(mut f: impl FnMut(&T) -> B) -> impl FnMut(T) -> B {
+            move |x| f(&x)
+        }
+
+        self.map(key(f)).position_max()
+    }
+
+    /// Returns the index of the element that gives the maximum value with
+    /// respect to the specified comparison function.
+    ///
+    /// If several elements are equally maximum, the index of the last one is
+    /// returned, matching [`max_by`]. If the iterator is empty, [`None`] is
+    /// returned.
+    ///
+    /// [`max_by`]: Iterator::max_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_position_extremum)]
+    /// let a = [2.4, 1.3, 7.1, 0.0];
+    /// assert_eq!(a.iter().position_max_by(|x, y| x.partial_cmp(y).unwrap()), Some(2));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_position_extremum", issue = "none")]
+    fn position_max_by<F>(self, compare: F) -> Option<usize>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        #[inline]
+        fn by_item<T>(
+            mut compare: impl FnMut(&T, &T) -> Ordering,
+        ) -> impl FnMut(&(usize, T), &(usize, T)) -> Ordering {
+            move |(_, x), (_, y)| compare(x, y)
+        }
+
+        let (i, _) = self.enumerate().max_by(by_item(compare))?;
+        Some(i)
+    }
+
+    /// Returns the index of the element that gives the minimum value from the
+    /// specified function.
+    ///
+    /// If several elements are equally minimum, the index of the first one is
+    /// returned, matching [`min_by_key`]. If the iterator is empty, [`None`]
+    /// is returned.
+    ///
+    /// [`min_by_key`]: Iterator::min_by_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_position_extremum)]
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_min_by_key(|x| x.abs()), Some(1));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_position_extremum", issue = "none")]
+    fn position_min_by_key<B: Ord, F>(self, f: F) -> Option<usize>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> B,
+    {
+        #[inline]
+        fn key<T, B>(mut f: impl FnMut(&T) -> B) -> impl FnMut(T) -> B {
+            move |x| f(&x)
+        }
+
+        self.map(key(f)).position_min()
+    }
+
+    /// Returns the index of the element that gives the minimum value with
+    /// respect to the specified comparison function.
+    ///
+    /// If several elements are equally minimum, the index of the first one is
+    /// returned, matching [`min_by`]. If the iterator is empty, [`None`] is
+    /// returned.
+    ///
+    /// [`min_by`]: Iterator::min_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_position_extremum)]
+    /// let a = [2.4, 1.3, 7.1, 1.3];
+    /// assert_eq!(a.iter().position_min_by(|x, y| x.partial_cmp(y).unwrap()), Some(1));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_position_extremum", issue = "none")]
+    fn position_min_by<F>(self, compare: F) -> Option<usize>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        #[inline]
+        fn by_item<T>(
+            mut compare: impl FnMut(&T, &T) -> Ordering,
+        ) -> impl FnMut(&(usize, T), &(usize, T)) -> Ordering {
+            move |(_, x), (_, y)| compare(x, y)
+        }
+
+        let (i, _) = self.enumerate().min_by(by_item(compare))?;
+        Some(i)
+    }
+
     /// Reverses an iterator's direction.
     ///
     /// Usually, iterators iterate from left to right. After using `rev()`,
@@ -3088,6 +3509,45 @@ fn sum<S>(self) -> S
         Sum::sum(self)
     }
 
+    /// Sums the elements of an iterator of floating-point numbers, keeping the
+    /// rounding error small.
+    ///
+    /// [`sum()`] adds the elements one after the other, and every addition
+    /// rounds the running total. Over many elements, or elements of very
+    /// different magnitude, these errors add up and can swamp the result.
+    /// `fsum()` uses compensated (Kahan–Babuška–Neumaier) summation instead,
+    /// which tracks the error of each addition and adds it back at the end.
+    /// This keeps the error of the result close to that of a single rounding,
+    /// however many elements there are, at the cost of a few more operations
+    /// per element.
+    ///
+    /// An empty iterator returns `0.0`. If any element is NaN or the sum
+    /// overflows, the result is the same as that of [`sum()`].
+    ///
+    /// [`sum()`]: Iterator::sum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_fsum)]
+    ///
+    /// let values = [1e100, 1.0, -1e100];
+    /// assert_eq!(values.iter().sum::<f64>(), 0.0);
+    /// assert_eq!(values.iter().fsum::<f64>(), 1.0);
+    ///
+    /// let tenths = [0.1f64; 10];
+    /// assert_ne!(tenths.iter().sum::<f64>(), 1.0);
+    /// assert_eq!(tenths.iter().fsum::<f64>(), 1.0);
+    /// ```
+    #[unstable(feature = "iter_fsum", issue = "none")]
+    fn fsum<S>(self) -> S
+    where
+        Self: Sized,
+        S: AccurateSum<Self::Item>,
+    {
+        AccurateSum::accurate_sum(self)
+    }
+
     /// Iterates over the entire iterator, multiplying all the elements
     ///
     /// An empty iterator returns the one value of the type.
@@ -3136,7 +3596,7 @@ fn cmp<I>(self, other: I) -> Ordering
         Self::Item: Ord,
         Self: Sized,
     {
-        self.cmp_by(other, |x, y| x.cmp(&y))
+        SpecIterCmp::spec_iter_cmp(self, other.into_iter())
     }
 
     /// [Lexicographically](Ord#lexicographical-comparison) compares the elements of this [`Iterator`] with those
@@ -3292,7 +3752,7 @@ fn eq<I>(self, other: I) -> bool
         Self::Item: PartialEq<I::Item>,
         Self: Sized,
     {
-        self.eq_by(other, |x, y| x == y)
+        SpecIterEq::spec_iter_eq(self, other.into_iter())
     }
 
     /// Determines if the elements of this [`Iterator`] are equal to those of
@@ -3558,6 +4018,42 @@ unsafe fn __iterator_get_unchecked(&mut self, _idx: usize) -> Self::Item
     }
 }
 
+/// Specialization trait for [`Iterator::eq`].
+///
+/// Iterators over contiguous memory, such as `slice::Iter` and `str::Chars`,
+/// override this to compare their remaining elements in one go instead of
+/// element by element.
+pub(crate) trait SpecIterEq<B: Iterator>: Iterator {
+    fn spec_iter_eq(self, other: B) -> bool;
+}
+
+impl<A: Iterator, B: Iterator> SpecIterEq<B> for A
+where
+    A::Item: PartialEq<B::Item>,
+{
+    #[inline]
+    default fn spec_iter_eq(self, other: B) -> bool {
+        self.eq_by(other, |x, y| x == y)
+    }
+}
+
+/// Specialization trait for [`Iterator::cmp`].
+///
+/// See [`SpecIterEq`] for the rationale.
+pub(crate) trait SpecIterCmp<B: Iterator>: Iterator {
+    fn spec_iter_cmp(self, other: B) -> Ordering;
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>> SpecIterCmp<B> for A
+where
+    A::Item: Ord,
+{
+    #[inline]
+    default fn spec_iter_cmp(self, other: B) -> Ordering {
+        self.cmp_by(other, |x, y| x.cmp(&y))
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<I: Iterator + ?Sized> Iterator for &mut I {
     type Item = I::Item;
@@ -5,12 +5,17 @@
 mod iterator;
 mod marker;
 
+#[unstable(feature = "iter_fsum", issue = "none")]
+pub use self::accum::AccurateSum;
 pub use self::accum::{Product, Sum};
+#[unstable(feature = "iter_group_map_into", issue = "none")]
+pub use self::collect::GroupInsert;
 pub use self::collect::{Extend, FromIterator, IntoIterator};
 pub use self::double_ended::DoubleEndedIterator;
 pub use self::exact_size::ExactSizeIterator;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::iterator::Iterator;
+pub(crate) use self::iterator::{SpecIterCmp, SpecIterEq};
 #[unstable(issue = "none", feature = "inplace_iteration")]
 pub use self::marker::InPlaceIterable;
 #[unstable(feature = "trusted_step", issue = "85731")]
@@ -97,6 +97,7 @@
 #![allow(explicit_outlives_requirements)]
 //
 // Library features for const fns:
+#![feature(ascii_char)]
 #![feature(const_align_offset)]
 #![feature(const_align_of_val)]
 #![feature(const_alloc_layout)]
@@ -167,7 +168,7 @@
 #![feature(const_precise_live_drops)]
 #![feature(const_refs_to_cell)]
 #![feature(decl_macro)]
-#![feature(derive_default_enum)]
+#![cfg_attr(bootstrap, feature(derive_default_enum))]
 #![feature(doc_cfg)]
 #![feature(doc_notable_trait)]
 #![feature(rustdoc_internals)]
@@ -302,6 +303,8 @@ pub mod assert_matches {
 pub mod default;
 pub mod marker;
 pub mod ops;
+#[unstable(feature = "new_range_api", issue = "none")]
+pub mod range;
 
 /* Core types and methods on primitives */
 
@@ -319,8 +322,8 @@ pub mod assert_matches {
 pub mod panicking;
 pub mod pin;
 pub mod result;
-#[unstable(feature = "async_stream", issue = "79024")]
-pub mod stream;
+#[unstable(feature = "async_iterator", issue = "79024")]
+pub mod async_iter;
 pub mod sync;
 
 pub mod fmt;
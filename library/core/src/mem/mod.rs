@@ -1070,3 +1070,61 @@ pub const fn discriminant<T>(v: &T) -> Discriminant<T> {
 pub const fn variant_count<T>() -> usize {
     intrinsics::variant_count::<T>()
 }
+
+/// Expands to the offset in bytes of a field from the beginning of the given type.
+///
+/// The field may be nested, as in `offset_of!(Type, field.inner)`, and tuple
+/// fields are named by their index, as in `offset_of!((u8, u32), 1)`. Only
+/// fields of the type itself and of its (nested) fields are accepted: the path
+/// is never followed through a [`Deref`](crate::ops::Deref) implementation.
+///
+/// The offset is computed in a constant and can be used in constant contexts.
+/// As a consequence, `Type` cannot refer to generic parameters of an enclosing
+/// item.
+///
+/// Unlike the common approach of taking the address of a field behind a null
+/// or dangling pointer, this macro does not dereference an invalid pointer and
+/// is sound to use.
+///
+/// Note that the layout of a type, and thus the offsets of its fields, is
+/// only guaranteed to be stable across compilations if the type has a defined
+/// representation, such as `#[repr(C)]`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(offset_of)]
+/// use std::mem;
+///
+/// #[repr(C)]
+/// struct Header {
+///     tag: u8,
+///     len: u32,
+///     range: (u16, u16),
+/// }
+///
+/// assert_eq!(mem::offset_of!(Header, tag), 0);
+/// assert_eq!(mem::offset_of!(Header, len), 4);
+/// assert_eq!(mem::offset_of!(Header, range.1), 10);
+///
+/// const LEN_OFFSET: usize = mem::offset_of!(Header, len);
+/// assert_eq!(LEN_OFFSET, 4);
+/// ```
+#[unstable(feature = "offset_of", issue = "none")]
+#[rustc_macro_transparency = "semitransparent"]
+#[allow_internal_unstable(const_ptr_offset_from)]
+pub macro offset_of($Container:ty, $($fields:tt).+ $(,)?) {{
+    const OFFSET: usize = {
+        let uninit = $crate::mem::MaybeUninit::<$Container>::uninit();
+        let base = uninit.as_ptr();
+        // SAFETY: `addr_of!` computes the address of the field without reading
+        // it, and the field lies within `uninit`, so both pointers are derived
+        // from the same allocation. Field accesses on a place behind a raw
+        // pointer are never auto-dereferenced, so no other memory is touched.
+        unsafe {
+            let field = $crate::ptr::addr_of!((*base).$($fields).+);
+            (field as *const u8).offset_from(base as *const u8) as usize
+        }
+    };
+    OFFSET
+}}
@@ -60,9 +60,56 @@ macro_rules! int_impl {
         /// ```
         #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_str_radix(\"A\", 16), Ok(10));")]
         /// ```
+        ///
+        /// This function can be used in a `const` context, for example to parse
+        /// a number given at compile time:
+        ///
+        /// ```
+        /// #![feature(const_int_from_str)]
+        #[doc = concat!("const LIMIT: ", stringify!($SelfT), " = match ", stringify!($SelfT), "::from_str_radix(\"7f\", 16) {")]
+        ///     Ok(limit) => limit,
+        ///     Err(_) => panic!("invalid limit"),
+        /// };
+        /// assert_eq!(LIMIT, 127);
+        /// ```
         #[stable(feature = "rust1", since = "1.0.0")]
-        pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
-            from_str_radix(src, radix)
+        #[rustc_const_unstable(feature = "const_int_from_str", issue = "none")]
+        pub const fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+            from_str_radix_impl!(Self, src, radix)
+        }
+
+        /// Parses an integer literal at the start of a string slice, returning
+        /// the value and the number of bytes it spans.
+        ///
+        /// The literal is an optional `+` or `-` sign, followed by an optional radix prefix
+        /// (`0x` for hexadecimal, `0o` for octal or `0b` for binary; the
+        /// number is decimal otherwise), followed by digits in that radix.
+        /// As in Rust integer literals, the prefix must be lowercase, `_` may be
+        /// used anywhere among the digits as a separator, and a decimal number
+        /// must start with a digit. Parsing stops at the first byte that is not
+        /// a digit or `_`, so trailing text is left to the caller.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if `src` is empty, if no digits follow the sign and
+        /// prefix, or if the value does not fit in the type. Unlike Rust
+        /// literals, a type suffix such as `u8` is not recognized; it ends the
+        /// number like any other non-digit.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(int_from_str_prefixed)]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_str_with_radix_prefix(\"0x7f\"), Ok((127, 4)));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_str_with_radix_prefix(\"0b0110_0100\"), Ok((100, 11)));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_str_with_radix_prefix(\"12, 13\"), Ok((12, 2)));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_str_with_radix_prefix(\"-0o17\"), Ok((-15, 5)));")]
+        #[doc = concat!("assert!(", stringify!($SelfT), "::from_str_with_radix_prefix(\"0x\").is_err());")]
+        /// ```
+        #[unstable(feature = "int_from_str_prefixed", issue = "none")]
+        #[rustc_const_unstable(feature = "const_int_from_str", issue = "none")]
+        pub const fn from_str_with_radix_prefix(src: &str) -> Result<(Self, usize), ParseIntError> {
+            from_str_prefixed_impl!(Self, src)
         }
 
         /// Returns the number of ones in the binary representation of `self`.
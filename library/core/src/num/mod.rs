@@ -24,6 +24,160 @@ macro_rules! unlikely {
     };
 }
 
+// Parses `$src` as a `$T` in base `$radix`, as the body of `from_str_radix`.
+// This is a macro rather than a generic function so that `from_str_radix` can
+// be `const`, which trait methods cannot be.
+macro_rules! from_str_radix_impl {
+    ($T:ty, $src:expr, $radix:expr) => {{
+        use crate::num::IntErrorKind::*;
+        use crate::num::ParseIntError as PIE;
+
+        let src: &str = $src;
+        let radix: u32 = $radix;
+        if radix < 2 || radix > 36 {
+            crate::num::from_str_radix_panic(radix);
+        }
+
+        if src.is_empty() {
+            return Err(PIE { kind: Empty });
+        }
+
+        let is_signed_ty = <$T>::MIN != 0;
+
+        // all valid digits are ascii, so we will just iterate over the utf8 bytes
+        // and cast them to chars. .to_digit() will safely return None for anything
+        // other than a valid ascii digit for the given radix, including the first-byte
+        // of multi-byte sequences
+        let src = src.as_bytes();
+
+        // The digits are `src[i..]`.
+        let (is_positive, mut i) = match src[0] {
+            b'+' | b'-' if src.len() == 1 => {
+                return Err(PIE { kind: InvalidDigit });
+            }
+            b'+' => (true, 1),
+            b'-' if is_signed_ty => (false, 1),
+            _ => (true, 0),
+        };
+
+        let mut result: $T = 0;
+        if is_positive {
+            // The number is positive
+            while i < src.len() {
+                let x = match (src[i] as char).to_digit(radix) {
+                    Some(x) => x,
+                    None => return Err(PIE { kind: InvalidDigit }),
+                };
+                result = match result.checked_mul(radix as $T) {
+                    Some(result) => result,
+                    None => return Err(PIE { kind: PosOverflow }),
+                };
+                result = match result.checked_add(x as $T) {
+                    Some(result) => result,
+                    None => return Err(PIE { kind: PosOverflow }),
+                };
+                i += 1;
+            }
+        } else {
+            // The number is negative
+            while i < src.len() {
+                let x = match (src[i] as char).to_digit(radix) {
+                    Some(x) => x,
+                    None => return Err(PIE { kind: InvalidDigit }),
+                };
+                result = match result.checked_mul(radix as $T) {
+                    Some(result) => result,
+                    None => return Err(PIE { kind: NegOverflow }),
+                };
+                result = match result.checked_sub(x as $T) {
+                    Some(result) => result,
+                    None => return Err(PIE { kind: NegOverflow }),
+                };
+                i += 1;
+            }
+        }
+        Ok(result)
+    }};
+}
+
+// Parses an integer literal at the start of `$src` as a `$T`, as the body of
+// `from_str_with_radix_prefix`. The radix comes from an optional `0x`, `0o`
+// or `0b` prefix, and the number ends at the first byte that is neither a
+// digit in that radix nor `_`.
+macro_rules! from_str_prefixed_impl {
+    ($T:ty, $src:expr) => {{
+        use crate::num::IntErrorKind::*;
+        use crate::num::ParseIntError as PIE;
+
+        let src: &[u8] = $src.as_bytes();
+        if src.is_empty() {
+            return Err(PIE { kind: Empty });
+        }
+
+        let is_signed_ty = <$T>::MIN != 0;
+
+        // The prefix and digits are `src[i..]`.
+        let (is_positive, mut i) = match src[0] {
+            b'+' => (true, 1),
+            b'-' if is_signed_ty => (false, 1),
+            _ => (true, 0),
+        };
+
+        let radix: u32 = if i + 1 < src.len() && src[i] == b'0' {
+            match src[i + 1] {
+                b'x' => 16,
+                b'o' => 8,
+                b'b' => 2,
+                _ => 10,
+            }
+        } else {
+            10
+        };
+        if radix != 10 {
+            i += 2;
+        }
+
+        let overflow = if is_positive { PosOverflow } else { NegOverflow };
+        let mut result: $T = 0;
+        let mut has_digits = false;
+        while i < src.len() {
+            if src[i] == b'_' {
+                // As in literals, a number without a prefix must start with a
+                // digit, but `_` may follow a prefix directly.
+                if radix == 10 && !has_digits {
+                    break;
+                }
+                i += 1;
+                continue;
+            }
+            let x = match (src[i] as char).to_digit(radix) {
+                Some(x) => x,
+                None => break,
+            };
+            result = match result.checked_mul(radix as $T) {
+                Some(result) => result,
+                None => return Err(PIE { kind: overflow }),
+            };
+            let next = if is_positive {
+                result.checked_add(x as $T)
+            } else {
+                result.checked_sub(x as $T)
+            };
+            result = match next {
+                Some(result) => result,
+                None => return Err(PIE { kind: overflow }),
+            };
+            has_digits = true;
+            i += 1;
+        }
+
+        if !has_digits {
+            return Err(PIE { kind: InvalidDigit });
+        }
+        Ok((result, i))
+    }};
+}
+
 // All these modules are technically private and only exposed for coretests:
 #[cfg(not(no_fp_fmt_parse))]
 pub mod bignum;
@@ -939,117 +1093,28 @@ pub enum FpCategory {
     Normal,
 }
 
-#[doc(hidden)]
-trait FromStrRadixHelper: PartialOrd + Copy {
-    fn min_value() -> Self;
-    fn max_value() -> Self;
-    fn from_u32(u: u32) -> Self;
-    fn checked_mul(&self, other: u32) -> Option<Self>;
-    fn checked_sub(&self, other: u32) -> Option<Self>;
-    fn checked_add(&self, other: u32) -> Option<Self>;
-}
-
 macro_rules! from_str_radix_int_impl {
     ($($t:ty)*) => {$(
         #[stable(feature = "rust1", since = "1.0.0")]
         impl FromStr for $t {
             type Err = ParseIntError;
             fn from_str(src: &str) -> Result<Self, ParseIntError> {
-                from_str_radix(src, 10)
+                <$t>::from_str_radix(src, 10)
             }
         }
     )*}
 }
 from_str_radix_int_impl! { isize i8 i16 i32 i64 i128 usize u8 u16 u32 u64 u128 }
 
-macro_rules! doit {
-    ($($t:ty)*) => ($(impl FromStrRadixHelper for $t {
-        #[inline]
-        fn min_value() -> Self { Self::MIN }
-        #[inline]
-        fn max_value() -> Self { Self::MAX }
-        #[inline]
-        fn from_u32(u: u32) -> Self { u as Self }
-        #[inline]
-        fn checked_mul(&self, other: u32) -> Option<Self> {
-            Self::checked_mul(*self, other as Self)
-        }
-        #[inline]
-        fn checked_sub(&self, other: u32) -> Option<Self> {
-            Self::checked_sub(*self, other as Self)
-        }
-        #[inline]
-        fn checked_add(&self, other: u32) -> Option<Self> {
-            Self::checked_add(*self, other as Self)
-        }
-    })*)
-}
-doit! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
-
-fn from_str_radix<T: FromStrRadixHelper>(src: &str, radix: u32) -> Result<T, ParseIntError> {
-    use self::IntErrorKind::*;
-    use self::ParseIntError as PIE;
-
-    assert!(
-        (2..=36).contains(&radix),
-        "from_str_radix_int: must lie in the range `[2, 36]` - found {}",
-        radix
-    );
-
-    if src.is_empty() {
-        return Err(PIE { kind: Empty });
+#[cold]
+#[rustc_allow_const_fn_unstable(const_eval_select)]
+const fn from_str_radix_panic(radix: u32) -> ! {
+    const fn panic_ct(_radix: u32) -> ! {
+        panic!("from_str_radix_int: must lie in the range `[2, 36]`");
     }
-
-    let is_signed_ty = T::from_u32(0) > T::min_value();
-
-    // all valid digits are ascii, so we will just iterate over the utf8 bytes
-    // and cast them to chars. .to_digit() will safely return None for anything
-    // other than a valid ascii digit for the given radix, including the first-byte
-    // of multi-byte sequences
-    let src = src.as_bytes();
-
-    let (is_positive, digits) = match src[0] {
-        b'+' | b'-' if src[1..].is_empty() => {
-            return Err(PIE { kind: InvalidDigit });
-        }
-        b'+' => (true, &src[1..]),
-        b'-' if is_signed_ty => (false, &src[1..]),
-        _ => (true, src),
-    };
-
-    let mut result = T::from_u32(0);
-    if is_positive {
-        // The number is positive
-        for &c in digits {
-            let x = match (c as char).to_digit(radix) {
-                Some(x) => x,
-                None => return Err(PIE { kind: InvalidDigit }),
-            };
-            result = match result.checked_mul(radix) {
-                Some(result) => result,
-                None => return Err(PIE { kind: PosOverflow }),
-            };
-            result = match result.checked_add(x) {
-                Some(result) => result,
-                None => return Err(PIE { kind: PosOverflow }),
-            };
-        }
-    } else {
-        // The number is negative
-        for &c in digits {
-            let x = match (c as char).to_digit(radix) {
-                Some(x) => x,
-                None => return Err(PIE { kind: InvalidDigit }),
-            };
-            result = match result.checked_mul(radix) {
-                Some(result) => result,
-                None => return Err(PIE { kind: NegOverflow }),
-            };
-            result = match result.checked_sub(x) {
-                Some(result) => result,
-                None => return Err(PIE { kind: NegOverflow }),
-            };
-        }
+    fn panic_rt(radix: u32) -> ! {
+        panic!("from_str_radix_int: must lie in the range `[2, 36]` - found {}", radix);
     }
-    Ok(result)
+    // SAFETY: both functions panic, the runtime one also shows the radix.
+    unsafe { intrinsics::const_eval_select((radix,), panic_ct, panic_rt) }
 }
@@ -4,7 +4,6 @@
 use crate::ops::{BitOr, BitOrAssign, Div, Rem};
 use crate::str::FromStr;
 
-use super::from_str_radix;
 use super::{IntErrorKind, ParseIntError};
 use crate::intrinsics;
 
@@ -175,7 +174,7 @@ macro_rules! from_str_radix_nzint_impl {
         impl FromStr for $t {
             type Err = ParseIntError;
             fn from_str(src: &str) -> Result<Self, Self::Err> {
-                Self::new(from_str_radix(src, 10)?)
+                Self::new(src.parse()?)
                     .ok_or(ParseIntError {
                         kind: IntErrorKind::Zero
                     })
@@ -60,9 +60,55 @@ macro_rules! uint_impl {
         /// ```
         #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_str_radix(\"A\", 16), Ok(10));")]
         /// ```
+        ///
+        /// This function can be used in a `const` context, for example to parse
+        /// a number given at compile time:
+        ///
+        /// ```
+        /// #![feature(const_int_from_str)]
+        #[doc = concat!("const LIMIT: ", stringify!($SelfT), " = match ", stringify!($SelfT), "::from_str_radix(\"7f\", 16) {")]
+        ///     Ok(limit) => limit,
+        ///     Err(_) => panic!("invalid limit"),
+        /// };
+        /// assert_eq!(LIMIT, 127);
+        /// ```
         #[stable(feature = "rust1", since = "1.0.0")]
-        pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
-            from_str_radix(src, radix)
+        #[rustc_const_unstable(feature = "const_int_from_str", issue = "none")]
+        pub const fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+            from_str_radix_impl!(Self, src, radix)
+        }
+
+        /// Parses an integer literal at the start of a string slice, returning
+        /// the value and the number of bytes it spans.
+        ///
+        /// The literal is an optional `+` sign, followed by an optional radix prefix
+        /// (`0x` for hexadecimal, `0o` for octal or `0b` for binary; the
+        /// number is decimal otherwise), followed by digits in that radix.
+        /// As in Rust integer literals, the prefix must be lowercase, `_` may be
+        /// used anywhere among the digits as a separator, and a decimal number
+        /// must start with a digit. Parsing stops at the first byte that is not
+        /// a digit or `_`, so trailing text is left to the caller.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if `src` is empty, if no digits follow the sign and
+        /// prefix, or if the value does not fit in the type. Unlike Rust
+        /// literals, a type suffix such as `u8` is not recognized; it ends the
+        /// number like any other non-digit.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(int_from_str_prefixed)]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_str_with_radix_prefix(\"0x7f\"), Ok((127, 4)));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_str_with_radix_prefix(\"0b0110_0100\"), Ok((100, 11)));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_str_with_radix_prefix(\"12, 13\"), Ok((12, 2)));")]
+        #[doc = concat!("assert!(", stringify!($SelfT), "::from_str_with_radix_prefix(\"0x\").is_err());")]
+        /// ```
+        #[unstable(feature = "int_from_str_prefixed", issue = "none")]
+        #[rustc_const_unstable(feature = "const_int_from_str", issue = "none")]
+        pub const fn from_str_with_radix_prefix(src: &str) -> Result<(Self, usize), ParseIntError> {
+            from_str_prefixed_impl!(Self, src)
         }
 
         /// Returns the number of ones in the binary representation of `self`.
@@ -236,6 +282,135 @@ pub const fn rotate_right(self, n: u32) -> Self {
             intrinsics::rotate_right(self, n as $SelfT)
         }
 
+        /// Performs a left funnel shift: concatenates `self` and `rhs`, with
+        /// `self` as the most significant half, shifts the combined value left
+        /// by `n`, and returns the most significant half.
+        ///
+        /// `x.funnel_shl(x, n)` is the same as `x.rotate_left(n)`.
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("This function will panic if `n` is not less than `", stringify!($SelfT), "::BITS`.")]
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(uint_bit_ops)]
+        #[doc = concat!("assert_eq!(1", stringify!($SelfT), ".funnel_shl(", stringify!($SelfT), "::MAX, 1), 0b11);")]
+        #[doc = concat!("assert_eq!(1", stringify!($SelfT), ".funnel_shl(", stringify!($SelfT), "::MAX, 0), 1);")]
+        /// ```
+        #[unstable(feature = "uint_bit_ops", issue = "none")]
+        #[rustc_const_unstable(feature = "uint_bit_ops", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn funnel_shl(self, rhs: Self, n: u32) -> Self {
+            assert!(n < Self::BITS, "attempt to funnel shift left with overflow");
+            if n == 0 {
+                self
+            } else {
+                (self << n) | (rhs >> (Self::BITS - n))
+            }
+        }
+
+        /// Performs a right funnel shift: concatenates `self` and `rhs`, with
+        /// `self` as the most significant half, shifts the combined value right
+        /// by `n`, and returns the least significant half.
+        ///
+        /// `x.funnel_shr(x, n)` is the same as `x.rotate_right(n)`.
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("This function will panic if `n` is not less than `", stringify!($SelfT), "::BITS`.")]
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(uint_bit_ops)]
+        #[doc = concat!("assert_eq!(1", stringify!($SelfT), ".funnel_shr(0, 1), 1 << (", stringify!($SelfT), "::BITS - 1));")]
+        #[doc = concat!("assert_eq!(1", stringify!($SelfT), ".funnel_shr(2, 0), 2);")]
+        /// ```
+        #[unstable(feature = "uint_bit_ops", issue = "none")]
+        #[rustc_const_unstable(feature = "uint_bit_ops", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn funnel_shr(self, rhs: Self, n: u32) -> Self {
+            assert!(n < Self::BITS, "attempt to funnel shift right with overflow");
+            if n == 0 {
+                rhs
+            } else {
+                (rhs >> n) | (self << (Self::BITS - n))
+            }
+        }
+
+        /// Performs a carry-less multiplication of `self` and `rhs`, returning
+        /// the low half of the product.
+        ///
+        /// This is multiplication of polynomials over GF(2), where each bit is
+        /// a coefficient, and partial products are combined with xor instead of
+        /// addition. It is the core operation of CRC and GHASH computations.
+        ///
+        /// The running time does not depend on the values of the operands.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(uint_bit_ops)]
+        /// // (x + 1) * (x + 1) = x^2 + 1
+        #[doc = concat!("assert_eq!(0b11", stringify!($SelfT), ".carryless_mul(0b11), 0b101);")]
+        /// // (x^2 + 1) * (x^2 + x) = x^4 + x^3 + x^2 + x
+        #[doc = concat!("assert_eq!(0b101", stringify!($SelfT), ".carryless_mul(0b110), 0b11110);")]
+        /// // Bits shifted out of the top are discarded.
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.carryless_mul(2), ", stringify!($SelfT), "::MAX - 1);")]
+        /// ```
+        #[unstable(feature = "uint_bit_ops", issue = "none")]
+        #[rustc_const_unstable(feature = "uint_bit_ops", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn carryless_mul(self, rhs: Self) -> Self {
+            let mut result: Self = 0;
+            let mut i = 0;
+            while i < Self::BITS {
+                // A mask rather than a branch, so that the running time does
+                // not depend on `rhs`.
+                let mask = ((rhs >> i) & 1).wrapping_neg();
+                result ^= (self << i) & mask;
+                i += 1;
+            }
+            result
+        }
+
+        /// Selects bits from `if_set` where the corresponding bit of `self`
+        /// is set, and from `if_clear` where it is clear.
+        ///
+        /// This is the same as `(if_set & self) | (if_clear & !self)`.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(uint_bit_ops)]
+        #[doc = concat!("let mask = 0b1100", stringify!($SelfT), ";")]
+        /// assert_eq!(mask.bit_select(0b1010, 0b0101), 0b1001);
+        /// ```
+        #[unstable(feature = "uint_bit_ops", issue = "none")]
+        #[rustc_const_unstable(feature = "uint_bit_ops", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn bit_select(self, if_set: Self, if_clear: Self) -> Self {
+            (if_set & self) | (if_clear & !self)
+        }
+
         /// Reverses the byte order of the integer.
         ///
         /// # Examples
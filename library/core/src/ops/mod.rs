@@ -146,6 +146,7 @@
 mod function;
 mod generator;
 mod index;
+mod overflow;
 mod range;
 mod try_trait;
 mod unsize;
@@ -175,6 +176,13 @@
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::index::{Index, IndexMut};
 
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub use self::overflow::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub use self::overflow::{SaturatingAdd, SaturatingDiv, SaturatingMul, SaturatingSub};
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub use self::overflow::{WrappingAdd, WrappingDiv, WrappingMul, WrappingSub};
+
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::range::{Range, RangeFrom, RangeFull, RangeTo};
 
@@ -184,6 +192,9 @@
 #[unstable(feature = "one_sided_range", issue = "69780")]
 pub use self::range::OneSidedRange;
 
+#[unstable(feature = "range_into_bounds", issue = "none")]
+pub use self::range::IntoBounds;
+
 #[unstable(feature = "try_trait_v2", issue = "84277")]
 pub use self::try_trait::{FromResidual, Try};
 
@@ -0,0 +1,252 @@
+//! Traits for the wrapping, saturating and checked flavors of arithmetic.
+//!
+//! The primitive integer types provide inherent methods such as
+//! [`u8::wrapping_add`] and [`i32::checked_mul`]. The traits in this module
+//! expose those same operations as trait hooks, in the manner of [`Add`] and
+//! friends, so that generic code and numeric wrapper types (fixed-point
+//! numbers, units of measure, ...) can be written once for every flavor of
+//! overflow handling.
+//!
+//! [`Add`]: crate::ops::Add
+
+/// Addition that wraps around at the boundary of the type.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(overflow_ops)]
+/// use std::ops::WrappingAdd;
+///
+/// #[derive(Debug, Copy, Clone, PartialEq)]
+/// struct Meters(u8);
+///
+/// impl WrappingAdd for Meters {
+///     type Output = Self;
+///
+///     fn wrapping_add(self, rhs: Self) -> Self {
+///         Meters(WrappingAdd::wrapping_add(self.0, rhs.0))
+///     }
+/// }
+///
+/// assert_eq!(Meters(250).wrapping_add(Meters(10)), Meters(4));
+/// ```
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait WrappingAdd<Rhs = Self> {
+    /// The resulting type after applying the addition.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs wrapping addition.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn wrapping_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Subtraction that wraps around at the boundary of the type.
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait WrappingSub<Rhs = Self> {
+    /// The resulting type after applying the subtraction.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs wrapping subtraction.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn wrapping_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Multiplication that wraps around at the boundary of the type.
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait WrappingMul<Rhs = Self> {
+    /// The resulting type after applying the multiplication.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs wrapping multiplication.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn wrapping_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Division that wraps around at the boundary of the type.
+///
+/// For the primitive integers this only differs from `/` for `MIN / -1` on
+/// signed types. Division by zero still panics.
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait WrappingDiv<Rhs = Self> {
+    /// The resulting type after applying the division.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs wrapping division.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn wrapping_div(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Addition that saturates at the numeric bounds instead of overflowing.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(overflow_ops)]
+/// use std::ops::SaturatingAdd;
+///
+/// fn sum<T: SaturatingAdd<Output = T> + Copy>(values: &[T], zero: T) -> T {
+///     values.iter().fold(zero, |acc, &x| acc.saturating_add(x))
+/// }
+///
+/// assert_eq!(sum(&[100u8, 100, 100], 0), u8::MAX);
+/// assert_eq!(sum(&[-100i8, -100], 0), i8::MIN);
+/// ```
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait SaturatingAdd<Rhs = Self> {
+    /// The resulting type after applying the addition.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs saturating addition.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn saturating_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Subtraction that saturates at the numeric bounds instead of overflowing.
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait SaturatingSub<Rhs = Self> {
+    /// The resulting type after applying the subtraction.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs saturating subtraction.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn saturating_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Multiplication that saturates at the numeric bounds instead of overflowing.
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait SaturatingMul<Rhs = Self> {
+    /// The resulting type after applying the multiplication.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs saturating multiplication.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn saturating_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Division that saturates at the numeric bounds instead of overflowing.
+///
+/// For the primitive integers this only differs from `/` for `MIN / -1` on
+/// signed types. Division by zero still panics.
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait SaturatingDiv<Rhs = Self> {
+    /// The resulting type after applying the division.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs saturating division.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn saturating_div(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Addition that returns `None` instead of overflowing.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(overflow_ops)]
+/// use std::ops::CheckedAdd;
+///
+/// assert_eq!(CheckedAdd::checked_add(250u8, 5), Some(255));
+/// assert_eq!(CheckedAdd::checked_add(250u8, 6), None);
+/// ```
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait CheckedAdd<Rhs = Self> {
+    /// The resulting type after applying the addition.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs checked addition, returning `None` if it would overflow.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn checked_add(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Subtraction that returns `None` instead of overflowing.
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait CheckedSub<Rhs = Self> {
+    /// The resulting type after applying the subtraction.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs checked subtraction, returning `None` if it would overflow.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn checked_sub(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Multiplication that returns `None` instead of overflowing.
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait CheckedMul<Rhs = Self> {
+    /// The resulting type after applying the multiplication.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs checked multiplication, returning `None` if it would overflow.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn checked_mul(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Division that returns `None` instead of overflowing or dividing by zero.
+#[unstable(feature = "overflow_ops", issue = "none")]
+pub trait CheckedDiv<Rhs = Self> {
+    /// The resulting type after applying the division.
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    type Output;
+
+    /// Performs checked division, returning `None` if `rhs` is zero or the
+    /// division would overflow.
+    #[must_use]
+    #[unstable(feature = "overflow_ops", issue = "none")]
+    fn checked_div(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+macro_rules! overflow_impl {
+    ($($Trait:ident $method:ident -> $Ret:ident;)*) => ($(
+        overflow_impl! { @impl $Trait $method $Ret
+            usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+    )*);
+    (@impl $Trait:ident $method:ident $Ret:ident $($t:ty)*) => ($(
+        #[unstable(feature = "overflow_ops", issue = "none")]
+        impl $Trait for $t {
+            type Output = $t;
+
+            #[inline]
+            fn $method(self, rhs: $t) -> overflow_impl!(@ret $Ret $t) {
+                <$t>::$method(self, rhs)
+            }
+        }
+    )*);
+    (@ret Value $t:ty) => ($t);
+    (@ret Option $t:ty) => (Option<$t>);
+}
+
+overflow_impl! {
+    WrappingAdd wrapping_add -> Value;
+    WrappingSub wrapping_sub -> Value;
+    WrappingMul wrapping_mul -> Value;
+    WrappingDiv wrapping_div -> Value;
+    SaturatingAdd saturating_add -> Value;
+    SaturatingSub saturating_sub -> Value;
+    SaturatingMul saturating_mul -> Value;
+    SaturatingDiv saturating_div -> Value;
+    CheckedAdd checked_add -> Option;
+    CheckedSub checked_sub -> Option;
+    CheckedMul checked_mul -> Option;
+    CheckedDiv checked_div -> Option;
+}
@@ -122,7 +122,8 @@ pub const fn caller() -> &'static Location<'static> {
     /// ```
     #[must_use]
     #[stable(feature = "panic_hooks", since = "1.10.0")]
-    pub fn file(&self) -> &str {
+    #[rustc_const_unstable(feature = "const_location_fields", issue = "none")]
+    pub const fn file(&self) -> &str {
         self.file
     }
 
@@ -145,7 +146,8 @@ pub fn file(&self) -> &str {
     /// ```
     #[must_use]
     #[stable(feature = "panic_hooks", since = "1.10.0")]
-    pub fn line(&self) -> u32 {
+    #[rustc_const_unstable(feature = "const_location_fields", issue = "none")]
+    pub const fn line(&self) -> u32 {
         self.line
     }
 
@@ -168,7 +170,8 @@ pub fn line(&self) -> u32 {
     /// ```
     #[must_use]
     #[stable(feature = "panic_col", since = "1.25.0")]
-    pub fn column(&self) -> u32 {
+    #[rustc_const_unstable(feature = "const_location_fields", issue = "none")]
+    pub const fn column(&self) -> u32 {
         self.col
     }
 }
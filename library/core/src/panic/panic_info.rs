@@ -29,6 +29,7 @@
 #[derive(Debug)]
 pub struct PanicInfo<'a> {
     payload: &'a (dyn Any + Send),
+    payload_str: Option<&'a str>,
     message: Option<&'a fmt::Arguments<'a>>,
     location: &'a Location<'a>,
 }
@@ -46,7 +47,7 @@ pub fn internal_constructor(
         location: &'a Location<'a>,
     ) -> Self {
         struct NoPayload;
-        PanicInfo { location, message, payload: &NoPayload }
+        PanicInfo { location, message, payload: &NoPayload, payload_str: None }
     }
 
     #[unstable(
@@ -58,6 +59,18 @@ pub fn internal_constructor(
     #[inline]
     pub fn set_payload(&mut self, info: &'a (dyn Any + Send)) {
         self.payload = info;
+        self.payload_str = None;
+    }
+
+    #[unstable(
+        feature = "panic_internals",
+        reason = "internal details of the implementation of the `panic!` and related macros",
+        issue = "none"
+    )]
+    #[doc(hidden)]
+    #[inline]
+    pub fn set_payload_str(&mut self, payload_str: &'a str) {
+        self.payload_str = Some(payload_str);
     }
 
     /// Returns the payload associated with the panic.
@@ -87,6 +100,39 @@ pub fn payload(&self) -> &(dyn Any + Send) {
         self.payload
     }
 
+    /// Returns the payload associated with the panic, if it is a string.
+    ///
+    /// This returns the payload if it is of type `&'static str` or [`String`],
+    /// which covers the payloads of `panic!` invocations with and without
+    /// format arguments, and `None` otherwise.
+    ///
+    /// [`String`]: ../../std/string/struct.String.html
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// #![feature(panic_payload_as_str)]
+    /// use std::panic;
+    ///
+    /// panic::set_hook(Box::new(|panic_info| {
+    ///     if let Some(s) = panic_info.payload_as_str() {
+    ///         println!("panic occurred: {:?}", s);
+    ///     } else {
+    ///         println!("panic occurred");
+    ///     }
+    /// }));
+    ///
+    /// panic!("Normal panic");
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "panic_payload_as_str", issue = "none")]
+    pub fn payload_as_str(&self) -> Option<&str> {
+        // `String` lives in `alloc`, so std records its contents separately
+        // when it sets the payload.
+        if let Some(s) = self.payload.downcast_ref::<&str>() { Some(s) } else { self.payload_str }
+    }
+
     /// If the `panic!` macro from the `core` crate (not from `std`)
     /// was used with a formatting string and some additional arguments,
     /// returns that message ready to be used for example with [`fmt::write`]
@@ -103,6 +103,10 @@
 #[unstable(feature = "ptr_internals", issue = "none")]
 pub use unique::Unique;
 
+mod volatile;
+#[unstable(feature = "ptr_volatile", issue = "none")]
+pub use volatile::Volatile;
+
 mod const_ptr;
 mod mut_ptr;
 
@@ -1004,6 +1008,9 @@ pub const fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T] {
 /// (e.g., if a zero-sized type is passed to `read_volatile`) are noops
 /// and may be ignored.
 ///
+/// For memory-mapped I/O, [`Volatile`] wraps a pointer so that it is only ever
+/// accessed with volatile operations.
+///
 /// [c11]: http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1570.pdf
 ///
 /// # Safety
@@ -1082,6 +1089,9 @@ pub unsafe fn read_volatile<T>(src: *const T) -> T {
 /// (e.g., if a zero-sized type is passed to `write_volatile`) are noops
 /// and may be ignored.
 ///
+/// For memory-mapped I/O, [`Volatile`] wraps a pointer so that it is only ever
+/// accessed with volatile operations.
+///
 /// [c11]: http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1570.pdf
 ///
 /// # Safety
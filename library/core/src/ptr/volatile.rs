@@ -0,0 +1,138 @@
+use crate::fmt;
+use crate::ptr::{self, NonNull};
+
+/// A pointer to a value that is only ever accessed with volatile operations.
+///
+/// This is meant for memory-mapped I/O, where reading or writing a device
+/// register has side effects that the compiler must not remove, duplicate or
+/// reorder relative to other volatile accesses. Every access goes through
+/// [`ptr::read_volatile`] or [`ptr::write_volatile`], and no reference to the
+/// pointed-to memory is ever created: a `&T` or `&mut T` to device memory
+/// allows the compiler to insert spurious reads, which is not sound for such
+/// memory.
+///
+/// `Volatile<T>` is a `Copy` wrapper around a raw pointer, so it can be passed
+/// around and stored freely. The obligation to point at valid memory is taken
+/// on once, by the `unsafe` [`Volatile::new`], after which [`read`] and
+/// [`write`] are safe. Values are accessed as a whole, so `T` is required to be
+/// `Copy`; a register is usually a fixed-size integer or a `#[repr(transparent)]`
+/// wrapper around one.
+///
+/// Like raw pointers, `Volatile<T>` is neither `Send` nor `Sync`.
+///
+/// [`read`]: Volatile::read
+/// [`write`]: Volatile::write
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_volatile)]
+/// use std::ptr::{NonNull, Volatile};
+///
+/// // Stand-in for a device register at a fixed address.
+/// let mut register = 0u32;
+///
+/// // SAFETY: `register` outlives `reg` and is only accessed through it.
+/// let reg = unsafe { Volatile::new(NonNull::from(&mut register)) };
+/// reg.write(0x10);
+/// reg.update(|v| v | 0x1);
+/// assert_eq!(reg.read(), 0x11);
+/// ```
+#[unstable(feature = "ptr_volatile", issue = "none")]
+#[repr(transparent)]
+pub struct Volatile<T> {
+    pointer: NonNull<T>,
+}
+
+impl<T> Volatile<T> {
+    /// Creates a new `Volatile` accessing the value behind `pointer`.
+    ///
+    /// # Safety
+    ///
+    /// For as long as the returned value or any copy of it is used, `pointer`
+    /// must be [valid] for reads and writes of `T` and properly aligned, as
+    /// required by [`ptr::read_volatile`] and [`ptr::write_volatile`].
+    ///
+    /// [valid]: crate::ptr#safety
+    #[unstable(feature = "ptr_volatile", issue = "none")]
+    #[rustc_const_unstable(feature = "ptr_volatile", issue = "none")]
+    #[inline]
+    pub const unsafe fn new(pointer: NonNull<T>) -> Self {
+        Volatile { pointer }
+    }
+
+    /// Returns the underlying pointer.
+    #[unstable(feature = "ptr_volatile", issue = "none")]
+    #[rustc_const_unstable(feature = "ptr_volatile", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_ptr(self) -> *mut T {
+        self.pointer.as_ptr()
+    }
+}
+
+impl<T: Copy> Volatile<T> {
+    /// Performs a volatile read of the value.
+    ///
+    /// See [`ptr::read_volatile`] for what a volatile read does and does not
+    /// guarantee.
+    #[unstable(feature = "ptr_volatile", issue = "none")]
+    #[inline]
+    pub fn read(self) -> T {
+        // SAFETY: the caller of `new` guaranteed that the pointer is valid
+        // for reads.
+        unsafe { ptr::read_volatile(self.pointer.as_ptr()) }
+    }
+
+    /// Performs a volatile write of `value`.
+    ///
+    /// See [`ptr::write_volatile`] for what a volatile write does and does not
+    /// guarantee.
+    #[unstable(feature = "ptr_volatile", issue = "none")]
+    #[inline]
+    pub fn write(self, value: T) {
+        // SAFETY: the caller of `new` guaranteed that the pointer is valid
+        // for writes.
+        unsafe { ptr::write_volatile(self.pointer.as_ptr(), value) }
+    }
+
+    /// Reads the value, passes it to `f`, and writes back the result.
+    ///
+    /// This is a volatile read followed by a volatile write; it is *not* an
+    /// atomic read-modify-write.
+    #[unstable(feature = "ptr_volatile", issue = "none")]
+    #[inline]
+    pub fn update<F>(self, f: F)
+    where
+        F: FnOnce(T) -> T,
+    {
+        self.write(f(self.read()));
+    }
+}
+
+#[unstable(feature = "ptr_volatile", issue = "none")]
+impl<T> Clone for Volatile<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "ptr_volatile", issue = "none")]
+impl<T> Copy for Volatile<T> {}
+
+/// Formats the pointer, never the pointed-to value, since reading it could
+/// have side effects.
+#[unstable(feature = "ptr_volatile", issue = "none")]
+impl<T> fmt::Debug for Volatile<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Volatile").field(&self.pointer).finish()
+    }
+}
+
+#[unstable(feature = "ptr_volatile", issue = "none")]
+impl<T> fmt::Pointer for Volatile<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.pointer, f)
+    }
+}
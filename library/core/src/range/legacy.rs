@@ -0,0 +1,14 @@
+//! # Legacy range types
+//!
+//! The types within this module will be replaced by the types
+//! [`Range`], [`RangeInclusive`], and [`RangeFrom`] in the parent
+//! module, [`core::range`].
+//!
+//! The types here are equivalent to those in [`core::ops`].
+//!
+//! [`Range`]: crate::range::Range
+//! [`RangeInclusive`]: crate::range::RangeInclusive
+//! [`RangeFrom`]: crate::range::RangeFrom
+
+#[doc(inline)]
+pub use crate::ops::{Range, RangeFrom, RangeInclusive};
@@ -25,7 +25,7 @@ pub fn is_ascii(&self) -> bool {
     #[must_use]
     #[inline]
     pub fn eq_ignore_ascii_case(&self, other: &[u8]) -> bool {
-        self.len() == other.len() && iter::zip(self, other).all(|(a, b)| a.eq_ignore_ascii_case(b))
+        self.len() == other.len() && eq_ignore_ascii_case(self, other)
     }
 
     /// Converts this slice to its ASCII upper case equivalent in-place.
@@ -62,6 +62,60 @@ pub fn make_ascii_lowercase(&mut self) {
         }
     }
 
+    /// Returns the index of the first occurrence of `needle` in this slice,
+    /// or `None` if it does not occur.
+    ///
+    /// This is the byte-level counterpart of [`str::find`] with a `&str`
+    /// pattern, and uses the same search routines: a [`memchr`]-style scan for
+    /// a single byte and the two-way algorithm for longer needles. It runs in
+    /// linear time and does not allocate. An empty needle matches at index 0.
+    ///
+    /// [`memchr`]: crate::slice::memchr::memchr
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_find_bytes)]
+    ///
+    /// let packet = b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\n";
+    /// assert_eq!(packet.find(b"\r\n"), Some(14));
+    /// assert_eq!(packet.find(b"\r\n\r\n"), Some(33));
+    /// assert_eq!(packet.find(b"/"), Some(4));
+    /// assert_eq!(packet.find(b"POST"), None);
+    /// ```
+    #[unstable(feature = "slice_find_bytes", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn find(&self, needle: &[u8]) -> Option<usize> {
+        crate::str::pattern::find_bytes(self, needle)
+    }
+
+    /// Returns the index of the last occurrence of `needle` in this slice,
+    /// or `None` if it does not occur.
+    ///
+    /// This is the reverse of [`find`]. An empty needle matches at the end of
+    /// the slice.
+    ///
+    /// [`find`]: slice::find
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_find_bytes)]
+    ///
+    /// let path = b"/usr/local/bin";
+    /// assert_eq!(path.rfind(b"/"), Some(10));
+    /// assert_eq!(path.rfind(b"/local/"), Some(4));
+    /// assert_eq!(path.rfind(b""), Some(path.len()));
+    /// assert_eq!(path.rfind(b"sbin"), None);
+    /// ```
+    #[unstable(feature = "slice_find_bytes", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn rfind(&self, needle: &[u8]) -> Option<usize> {
+        crate::str::pattern::rfind_bytes(self, needle)
+    }
+
     /// Returns an iterator that produces an escaped version of this slice,
     /// treating it as an ASCII string.
     ///
@@ -154,6 +208,28 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// Compares two slices of equal length chunk by chunk.
+///
+/// Stopping at the first mismatching byte keeps the compiler from vectorizing
+/// the comparison, so each chunk is compared as a whole, without branches, and
+/// only then checked. The case conversions themselves are branchless already,
+/// which is why `make_ascii_lowercase` and friends can stay simple loops.
+#[inline]
+fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    const CHUNK_SIZE: usize = 16;
+
+    debug_assert_eq!(a.len(), b.len());
+    let mut a_chunks = a.chunks_exact(CHUNK_SIZE);
+    let mut b_chunks = b.chunks_exact(CHUNK_SIZE);
+    for (a, b) in iter::zip(&mut a_chunks, &mut b_chunks) {
+        let eq = iter::zip(a, b).fold(true, |eq, (a, b)| eq & a.eq_ignore_ascii_case(b));
+        if !eq {
+            return false;
+        }
+    }
+    iter::zip(a_chunks.remainder(), b_chunks.remainder()).all(|(a, b)| a.eq_ignore_ascii_case(b))
+}
+
 /// Returns `true` if any byte in the word `v` is nonascii (>= 128). Snarfed
 /// from `../str/mod.rs`, which does something similar for utf8 validation.
 #[inline]
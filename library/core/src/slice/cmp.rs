@@ -206,7 +206,7 @@ fn compare(left: &[Self], right: &[Self]) -> Ordering {
 
 // Hack to allow specializing on `Eq` even though `Eq` has a method.
 #[rustc_unsafe_specialization_marker]
-trait MarkerEq<T>: PartialEq<T> {}
+pub(crate) trait MarkerEq<T>: PartialEq<T> {}
 
 impl<T: Eq> MarkerEq<T> for T {}
 
@@ -214,7 +214,7 @@ impl<T: Eq> MarkerEq<T> for T {}
 /// Trait implemented for types that can be compared for equality using
 /// their bytewise representation
 #[rustc_specialization_trait]
-trait BytewiseEquality<T>: MarkerEq<T> + Copy {}
+pub(crate) trait BytewiseEquality<T>: MarkerEq<T> + Copy {}
 
 macro_rules! impl_marker_for {
     ($traitname:ident, $($ty:ty)*) => {
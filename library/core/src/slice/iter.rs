@@ -8,11 +8,13 @@
 use crate::fmt;
 use crate::intrinsics::{assume, exact_div, unchecked_sub};
 use crate::iter::{FusedIterator, TrustedLen, TrustedRandomAccess, TrustedRandomAccessNoCoerce};
+use crate::iter::SpecIterEq;
 use crate::marker::{PhantomData, Send, Sized, Sync};
 use crate::mem;
 use crate::num::NonZeroUsize;
 use crate::ptr::NonNull;
 
+use super::cmp::BytewiseEquality;
 use super::{from_raw_parts, from_raw_parts_mut};
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -157,6 +159,32 @@ fn as_ref(&self) -> &[T] {
     }
 }
 
+#[stable(feature = "default_iters", since = "1.59.0")]
+impl<T> Default for Iter<'_, T> {
+    /// Creates an empty slice iterator.
+    ///
+    /// ```
+    /// # use core::slice::Iter;
+    /// let iter: Iter<'_, u8> = Default::default();
+    /// assert_eq!(iter.len(), 0);
+    /// ```
+    fn default() -> Self {
+        (&[]).into_iter()
+    }
+}
+
+// Compare the remaining elements as slices, which uses `memcmp` when the
+// element types allow it.
+impl<'a, 'b, T, U> SpecIterEq<Iter<'b, U>> for Iter<'a, T>
+where
+    T: BytewiseEquality<U>,
+{
+    #[inline]
+    fn spec_iter_eq(self, other: Iter<'b, U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
 /// Mutable slice iterator.
 ///
 /// This struct is created by the [`iter_mut`] method on [slices].
@@ -313,6 +341,20 @@ fn as_ref(&self) -> &[T] {
     }
 }
 
+#[stable(feature = "default_iters", since = "1.59.0")]
+impl<T> Default for IterMut<'_, T> {
+    /// Creates an empty slice iterator.
+    ///
+    /// ```
+    /// # use core::slice::IterMut;
+    /// let iter: IterMut<'_, u8> = Default::default();
+    /// assert_eq!(iter.len(), 0);
+    /// ```
+    fn default() -> Self {
+        (&mut []).into_iter()
+    }
+}
+
 iterator! {struct IterMut -> *mut T, &'a mut T, mut, {mut}, {}}
 
 /// An internal abstraction over the splitting iterators, so that
@@ -12,7 +12,7 @@
 ///
 /// # Algorithm
 ///
-/// Algorithm 1 is used for small values of `left + right` or for large `T`. The elements are moved
+/// Algorithm 1 is used for small values of `left + right`. The elements are moved
 /// into their final positions one at a time starting at `mid - left` and advancing by `right` steps
 /// modulo `left + right`, such that only one temporary is needed. Eventually, we arrive back at
 /// `mid - left`. However, if `gcd(left + right, right)` is not 1, the above steps skipped over
@@ -48,8 +48,11 @@
 /// Algorithms that can be vectorized outperform the above once `left + right` becomes large enough.
 /// Algorithm 1 can be vectorized by chunking and performing many rounds at once, but there are too
 /// few rounds on average until `left + right` is enormous, and the worst case of a single
-/// round is always there. Instead, algorithm 3 utilizes repeated swapping of
-/// `min(left, right)` elements until a smaller rotate problem is left.
+/// round is always there. Its accesses also stride through the whole range, so once the range no
+/// longer fits in cache, every step is likely a cache miss. This is true for large `T` as well:
+/// there are fewer elements to move, but each step still touches a new cache line. Instead,
+/// algorithm 3 utilizes repeated swapping of `min(left, right)` elements until a smaller rotate
+/// problem is left, which only ever walks linearly through memory.
 ///
 /// ```text
 /// left = 11, right = 4
@@ -71,12 +74,13 @@ pub unsafe fn ptr_rotate<T>(mut left: usize, mut mid: *mut T, mut right: usize)
         if (right == 0) || (left == 0) {
             return;
         }
-        if (left + right < 24) || (mem::size_of::<T>() > mem::size_of::<[usize; 4]>()) {
+        if left + right < 24 {
             // Algorithm 1
             // Microbenchmarks indicate that the average performance for random shifts is better all
             // the way until about `left + right == 32`, but the worst case performance breaks even
-            // around 16. 24 was chosen as middle ground. If the size of `T` is larger than 4
-            // `usize`s, this algorithm also outperforms other algorithms.
+            // around 16. 24 was chosen as middle ground. This holds for large `T` too: past this
+            // point, swapping blocks of `[usize; 5]` or `[u64; 16]` is 2 to 4 times faster than
+            // moving them one at a time, and much faster still once the slice exceeds the cache.
             // SAFETY: callers must ensure `mid - left` is valid for reading and writing.
             let x = unsafe { mid.sub(left) };
             // beginning of first round
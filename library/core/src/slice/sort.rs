@@ -178,6 +178,127 @@ fn insertion_sort<T, F>(v: &mut [T], is_less: &mut F)
     }
 }
 
+/// Returns `true` if elements of type `T` are small enough that the branchless sorting networks
+/// below are cheaper than insertion sort.
+const fn use_sorting_network<T>() -> bool {
+    mem::size_of::<T>() <= 2 * mem::size_of::<usize>()
+}
+
+/// Swaps `v[a]` and `v[b]` if `v[b]` is less than `v[a]`, without branching on the comparison.
+///
+/// # Safety
+///
+/// `a` and `b` must be distinct, in-bounds indices of the slice starting at `v`.
+#[inline(always)]
+unsafe fn swap_if_less<T, F>(v: *mut T, a: usize, b: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    // SAFETY: the caller guarantees that both indices are in bounds and distinct. The comparison,
+    // which may panic, happens before any element is moved, and the moves themselves cannot
+    // panic, so every element is still present exactly once if `is_less` unwinds.
+    unsafe {
+        let a_ptr = v.add(a);
+        let b_ptr = v.add(b);
+
+        // Selecting the pointers with the comparison result instead of branching on it lets the
+        // compiler use conditional moves, which avoids branch mispredictions on random data.
+        let should_swap = is_less(&*b_ptr, &*a_ptr);
+        let a_src = if should_swap { b_ptr } else { a_ptr };
+        let b_src = if should_swap { a_ptr } else { b_ptr };
+
+        let tmp = mem::ManuallyDrop::new(ptr::read(b_src));
+        ptr::copy(a_src, a_ptr, 1);
+        ptr::copy_nonoverlapping(&*tmp, b_ptr, 1);
+    }
+}
+
+/// Sorts the first four elements of `v` with an optimal sorting network.
+///
+/// # Safety
+///
+/// `v` must point to at least four elements.
+unsafe fn sort4_network<T, F>(v: *mut T, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    // SAFETY: all indices are below 4, which the caller guarantees are in bounds.
+    unsafe {
+        swap_if_less(v, 0, 1, is_less);
+        swap_if_less(v, 2, 3, is_less);
+        swap_if_less(v, 0, 2, is_less);
+        swap_if_less(v, 1, 3, is_less);
+        swap_if_less(v, 1, 2, is_less);
+    }
+}
+
+/// Sorts the first eight elements of `v` with an optimal sorting network.
+///
+/// # Safety
+///
+/// `v` must point to at least eight elements.
+unsafe fn sort8_network<T, F>(v: *mut T, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    const NETWORK: [(usize, usize); 19] = [
+        (0, 2),
+        (1, 3),
+        (4, 6),
+        (5, 7),
+        (0, 4),
+        (1, 5),
+        (2, 6),
+        (3, 7),
+        (0, 1),
+        (2, 3),
+        (4, 5),
+        (6, 7),
+        (2, 4),
+        (3, 5),
+        (1, 4),
+        (3, 6),
+        (1, 2),
+        (3, 4),
+        (5, 6),
+    ];
+
+    for &(a, b) in NETWORK.iter() {
+        // SAFETY: all indices are below 8, which the caller guarantees are in bounds.
+        unsafe { swap_if_less(v, a, b, is_less) };
+    }
+}
+
+/// Sorts a short slice.
+///
+/// For small element types a prefix of the slice is sorted with a branchless sorting network
+/// before the remaining elements are inserted, which is considerably faster than plain insertion
+/// sort on random data. Other types use insertion sort directly.
+fn small_sort<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+    if !use_sorting_network::<T>() || len < 4 {
+        insertion_sort(v, is_less);
+        return;
+    }
+
+    let presorted = if len >= 8 {
+        // SAFETY: `v` has at least eight elements.
+        unsafe { sort8_network(v.as_mut_ptr(), is_less) };
+        8
+    } else {
+        // SAFETY: `v` has at least four elements.
+        unsafe { sort4_network(v.as_mut_ptr(), is_less) };
+        4
+    };
+
+    for i in presorted..len {
+        shift_tail(&mut v[..i + 1], is_less);
+    }
+}
+
 /// Sorts `v` using heapsort, which guarantees *O*(*n* \* log(*n*)) worst-case.
 #[cold]
 #[unstable(feature = "sort_internals", reason = "internal to sort module", issue = "none")]
@@ -721,7 +842,7 @@ fn recurse<'a, T, F>(mut v: &'a mut [T], is_less: &mut F, mut pred: Option<&'a T
 where
     F: FnMut(&T, &T) -> bool,
 {
-    // Slices of up to this length get sorted using insertion sort.
+    // Slices of up to this length get sorted using `small_sort`.
     const MAX_INSERTION: usize = 20;
 
     // True if the last partitioning was reasonably balanced.
@@ -732,9 +853,9 @@ fn recurse<'a, T, F>(mut v: &'a mut [T], is_less: &mut F, mut pred: Option<&'a T
     loop {
         let len = v.len();
 
-        // Very short slices get sorted using insertion sort.
+        // Very short slices get sorted using sorting networks and insertion sort.
         if len <= MAX_INSERTION {
-            insertion_sort(v, is_less);
+            small_sort(v, is_less);
             return;
         }
 
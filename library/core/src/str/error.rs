@@ -136,3 +136,95 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         "provided string was not `true` or `false`".fmt(f)
     }
 }
+
+/// An error returned by [`str::try_index`] and [`str::try_index_mut`] when a
+/// range cannot be used to slice a string.
+///
+/// The error records why slicing failed and the offending byte index, so
+/// that it can be reported without panicking.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(str_try_index)]
+/// use std::str::StrIndexErrorKind;
+///
+/// let s = "🗻∈🌏";
+/// let err = s.try_index(1..).unwrap_err();
+/// assert_eq!(err.kind(), StrIndexErrorKind::NotCharBoundary);
+/// assert_eq!(err.index(), 1);
+/// assert_eq!(
+///     err.to_string(),
+///     "byte index 1 is not a char boundary; it is inside '🗻' (bytes 0..4)",
+/// );
+/// ```
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+#[unstable(feature = "str_try_index", issue = "none")]
+pub struct StrIndexError {
+    pub(super) kind: StrIndexErrorKind,
+    pub(super) index: usize,
+    /// The string length, the range end, or the start of the enclosing
+    /// `char`, depending on `kind`.
+    pub(super) context: usize,
+    pub(super) ch: char,
+}
+
+/// The reason a [`StrIndexError`] was returned.
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+#[non_exhaustive]
+#[unstable(feature = "str_try_index", issue = "none")]
+pub enum StrIndexErrorKind {
+    /// A bound of the range is past the end of the string.
+    #[unstable(feature = "str_try_index", issue = "none")]
+    OutOfBounds,
+    /// The start of the range is after its end.
+    #[unstable(feature = "str_try_index", issue = "none")]
+    StartAfterEnd,
+    /// A bound of the range falls inside the UTF-8 encoding of a `char`.
+    #[unstable(feature = "str_try_index", issue = "none")]
+    NotCharBoundary,
+}
+
+impl StrIndexError {
+    /// Returns the reason slicing failed.
+    #[unstable(feature = "str_try_index", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> StrIndexErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte index that caused slicing to fail.
+    ///
+    /// For [`StrIndexErrorKind::StartAfterEnd`] this is the start of the
+    /// range.
+    #[unstable(feature = "str_try_index", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+#[unstable(feature = "str_try_index", issue = "none")]
+impl fmt::Display for StrIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            StrIndexErrorKind::OutOfBounds => write!(
+                f,
+                "byte index {} is out of bounds of string of length {}",
+                self.index, self.context
+            ),
+            StrIndexErrorKind::StartAfterEnd => {
+                write!(f, "begin <= end ({} <= {}) is not satisfied", self.index, self.context)
+            }
+            StrIndexErrorKind::NotCharBoundary => write!(
+                f,
+                "byte index {} is not a char boundary; it is inside {:?} (bytes {:?})",
+                self.index,
+                self.ch,
+                self.context..self.context + self.ch.len_utf8()
+            ),
+        }
+    }
+}
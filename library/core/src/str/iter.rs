@@ -1,9 +1,11 @@
 //! Iterators for `str` methods.
 
 use crate::char;
+use crate::cmp;
 use crate::fmt::{self, Write};
 use crate::iter::{Chain, FlatMap, Flatten};
 use crate::iter::{Copied, Filter, FusedIterator, Map, TrustedLen};
+use crate::iter::{SpecIterCmp, SpecIterEq};
 use crate::iter::{TrustedRandomAccess, TrustedRandomAccessNoCoerce};
 use crate::ops::Try;
 use crate::option;
@@ -89,6 +91,22 @@ fn next_back(&mut self) -> Option<char> {
 #[stable(feature = "fused", since = "1.26.0")]
 impl FusedIterator for Chars<'_> {}
 
+// UTF-8 preserves code point order, so the remaining characters can be
+// compared through their bytes.
+impl<'a, 'b> SpecIterEq<Chars<'b>> for Chars<'a> {
+    #[inline]
+    fn spec_iter_eq(self, other: Chars<'b>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<'a, 'b> SpecIterCmp<Chars<'b>> for Chars<'a> {
+    #[inline]
+    fn spec_iter_cmp(self, other: Chars<'b>) -> cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
 impl<'a> Chars<'a> {
     /// Views the underlying data as a subslice of the original data.
     ///
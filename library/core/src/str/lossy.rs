@@ -1,5 +1,6 @@
 use crate::char;
 use crate::fmt::{self, Write};
+use crate::iter::FusedIterator;
 use crate::mem;
 
 use super::from_utf8_unchecked;
@@ -27,6 +28,7 @@ pub fn chunks(&self) -> Utf8LossyChunksIter<'_> {
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 #[unstable(feature = "str_internals", issue = "none")]
 #[allow(missing_debug_implementations)]
+#[derive(Clone)]
 pub struct Utf8LossyChunksIter<'a> {
     source: &'a [u8],
 }
@@ -140,6 +142,142 @@ fn safe_get(xs: &[u8], i: usize) -> u8 {
     }
 }
 
+/// An item returned by the [`Utf8Chunks`] iterator.
+///
+/// A `Utf8Chunk` stores a sequence of [`u8`] up to the first broken character
+/// when decoding a UTF-8 string.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(utf8_chunks)]
+///
+/// use std::str::Utf8Chunks;
+///
+/// // An invalid UTF-8 string
+/// let bytes = b"foo\xF1\x80bar";
+///
+/// // Decode the first `Utf8Chunk`
+/// let chunk = Utf8Chunks::new(bytes).next().unwrap();
+///
+/// // The first three characters are valid UTF-8
+/// assert_eq!("foo", chunk.valid());
+///
+/// // The fourth character is broken
+/// assert_eq!(b"\xF1\x80", chunk.invalid());
+/// ```
+#[unstable(feature = "utf8_chunks", issue = "none")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Utf8Chunk<'a> {
+    valid: &'a str,
+    invalid: &'a [u8],
+}
+
+impl<'a> Utf8Chunk<'a> {
+    /// Returns the next validated UTF-8 substring.
+    ///
+    /// This substring can be empty at the start of the string or between
+    /// broken UTF-8 characters.
+    #[must_use]
+    #[unstable(feature = "utf8_chunks", issue = "none")]
+    pub fn valid(&self) -> &'a str {
+        self.valid
+    }
+
+    /// Returns the invalid sequence that caused a failure.
+    ///
+    /// The returned slice will have a maximum length of 3 and starts after the
+    /// substring given by [`valid`]. Decoding will resume after this sequence.
+    ///
+    /// If empty, this is the last chunk in the string. If non-empty, an
+    /// unexpected byte was encountered or the end of the input was reached
+    /// unexpectedly.
+    ///
+    /// Lossy decoding would replace this sequence with [`U+FFFD REPLACEMENT
+    /// CHARACTER`].
+    ///
+    /// [`valid`]: Self::valid
+    /// [`U+FFFD REPLACEMENT CHARACTER`]: crate::char::REPLACEMENT_CHARACTER
+    #[must_use]
+    #[unstable(feature = "utf8_chunks", issue = "none")]
+    pub fn invalid(&self) -> &'a [u8] {
+        self.invalid
+    }
+}
+
+/// An iterator used to decode a slice of mostly UTF-8 bytes to string slices
+/// ([`&str`]) and byte slices ([`&[u8]`][byteslice]).
+///
+/// If you want a simple conversion from UTF-8 byte slices to string slices,
+/// [`from_utf8`] is easier to use.
+///
+/// Unlike [`String::from_utf8_lossy`], this iterator does not allocate, which
+/// makes it suitable for replacing or reporting invalid sequences in
+/// mostly-valid input.
+///
+/// [byteslice]: slice
+/// [`from_utf8`]: super::from_utf8
+/// [`String::from_utf8_lossy`]: ../../std/string/struct.String.html#method.from_utf8_lossy
+///
+/// # Examples
+///
+/// This can be used to create functionality similar to
+/// [`String::from_utf8_lossy`] without allocating heap memory:
+///
+/// ```
+/// #![feature(utf8_chunks)]
+///
+/// use std::str::Utf8Chunks;
+///
+/// fn from_utf8_lossy<F>(input: &[u8], mut push: F) where F: FnMut(&str) {
+///     for chunk in Utf8Chunks::new(input) {
+///         push(chunk.valid());
+///
+///         if !chunk.invalid().is_empty() {
+///             push("\u{FFFD}");
+///         }
+///     }
+/// }
+///
+/// let mut out = String::new();
+/// from_utf8_lossy(b"Hello \xF0\x90\x80World", |s| out.push_str(s));
+/// assert_eq!(out, "Hello \u{FFFD}World");
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "utf8_chunks", issue = "none")]
+#[derive(Clone)]
+pub struct Utf8Chunks<'a> {
+    iter: Utf8LossyChunksIter<'a>,
+}
+
+impl<'a> Utf8Chunks<'a> {
+    /// Creates a new iterator to decode the bytes.
+    #[unstable(feature = "utf8_chunks", issue = "none")]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { iter: Utf8Lossy::from_bytes(bytes).chunks() }
+    }
+}
+
+#[unstable(feature = "utf8_chunks", issue = "none")]
+impl<'a> Iterator for Utf8Chunks<'a> {
+    type Item = Utf8Chunk<'a>;
+
+    fn next(&mut self) -> Option<Utf8Chunk<'a>> {
+        let Utf8LossyChunk { valid, broken } = self.iter.next()?;
+        Some(Utf8Chunk { valid, invalid: broken })
+    }
+}
+
+#[unstable(feature = "utf8_chunks", issue = "none")]
+impl FusedIterator for Utf8Chunks<'_> {}
+
+#[unstable(feature = "utf8_chunks", issue = "none")]
+impl fmt::Debug for Utf8Chunks<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf8Chunks").field("source", &self.iter.source).finish()
+    }
+}
+
 impl fmt::Display for Utf8Lossy {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // If we're the empty string then our iterator won't actually yield
@@ -148,19 +286,57 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             return "".fmt(f);
         }
 
-        for Utf8LossyChunk { valid, broken } in self.chunks() {
-            // If we successfully decoded the whole chunk as a valid string then
-            // we can return a direct formatting of the string which will also
-            // respect various formatting flags if possible.
-            if valid.len() == self.bytes.len() {
-                assert!(broken.is_empty());
-                return valid.fmt(f);
-            }
+        // If we successfully decode the whole string as a valid string then
+        // we can return a direct formatting of the string which will also
+        // respect various formatting flags if possible.
+        if let Some(Utf8LossyChunk { valid, broken: [] }) = self.chunks().next() {
+            debug_assert_eq!(valid.len(), self.bytes.len());
+            return valid.fmt(f);
+        }
 
-            f.write_str(valid)?;
-            if !broken.is_empty() {
-                f.write_char(char::REPLACEMENT_CHARACTER)?;
+        // Otherwise every broken sequence is written as one replacement
+        // character, which counts as one character for width and precision.
+        let write_lossy = |f: &mut fmt::Formatter<'_>, mut max_chars: usize| {
+            for Utf8LossyChunk { valid, broken } in self.chunks() {
+                if let Some((i, _)) = valid.char_indices().nth(max_chars) {
+                    return f.write_str(&valid[..i]);
+                }
+                f.write_str(valid)?;
+                max_chars -= valid.chars().count();
+                if !broken.is_empty() {
+                    if max_chars == 0 {
+                        break;
+                    }
+                    f.write_char(char::REPLACEMENT_CHARACTER)?;
+                    max_chars -= 1;
+                }
             }
+            Ok(())
+        };
+
+        let max_chars = f.precision().unwrap_or(usize::MAX);
+        let width = match f.width() {
+            Some(width) => width,
+            None => return write_lossy(f, max_chars),
+        };
+
+        let chars: usize = self
+            .chunks()
+            .map(|chunk| chunk.valid.chars().count() + (!chunk.broken.is_empty()) as usize)
+            .sum();
+        let padding = width.saturating_sub(chars.min(max_chars));
+        let (pre_pad, post_pad) = match f.align() {
+            None | Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, (padding + 1) / 2),
+        };
+        let fill = f.fill();
+        for _ in 0..pre_pad {
+            f.write_char(fill)?;
+        }
+        write_lossy(f, max_chars)?;
+        for _ in 0..post_pad {
+            f.write_char(fill)?;
         }
         Ok(())
     }
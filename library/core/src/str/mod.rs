@@ -17,6 +17,7 @@
 
 use crate::char::{self, EscapeDebugExtArgs};
 use crate::mem;
+use crate::ops;
 use crate::slice::{self, SliceIndex};
 
 pub mod pattern;
@@ -34,6 +35,9 @@
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use error::{ParseBoolError, Utf8Error};
 
+#[unstable(feature = "str_try_index", issue = "none")]
+pub use error::{StrIndexError, StrIndexErrorKind};
+
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use traits::FromStr;
 
@@ -71,6 +75,9 @@
 #[unstable(feature = "str_internals", issue = "none")]
 pub use validations::{next_code_point, utf8_char_width};
 
+#[unstable(feature = "utf8_chunks", issue = "none")]
+pub use lossy::{Utf8Chunk, Utf8Chunks};
+
 use iter::MatchIndicesInternal;
 use iter::SplitInternal;
 use iter::{MatchesInternal, SplitNInternal};
@@ -117,6 +124,56 @@ fn slice_error_fail(s: &str, begin: usize, end: usize) -> ! {
     );
 }
 
+/// Resolves `range` against `s` as `str::try_index` does, checking the same
+/// conditions as `slice_error_fail` in the same order.
+fn try_index_range<R: ops::RangeBounds<usize>>(
+    s: &str,
+    range: R,
+) -> Result<ops::Range<usize>, StrIndexError> {
+    let len = s.len();
+    let begin = match range.start_bound() {
+        ops::Bound::Included(&begin) => begin,
+        ops::Bound::Excluded(&begin) => begin.saturating_add(1),
+        ops::Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        ops::Bound::Included(&end) => end.saturating_add(1),
+        ops::Bound::Excluded(&end) => end,
+        ops::Bound::Unbounded => len,
+    };
+    let error = |kind, index, context| StrIndexError { kind, index, context, ch: '\0' };
+
+    // 1. out of bounds
+    if begin > len || end > len {
+        let index = if begin > len { begin } else { end };
+        return Err(error(StrIndexErrorKind::OutOfBounds, index, len));
+    }
+
+    // 2. begin <= end
+    if begin > end {
+        return Err(error(StrIndexErrorKind::StartAfterEnd, begin, end));
+    }
+
+    // 3. character boundary
+    if !s.is_char_boundary(begin) || !s.is_char_boundary(end) {
+        let index = if !s.is_char_boundary(begin) { begin } else { end };
+        let mut char_start = index;
+        while !s.is_char_boundary(char_start) {
+            char_start -= 1;
+        }
+        // `char_start` must be less than len and a char boundary
+        let ch = s[char_start..].chars().next().unwrap();
+        return Err(StrIndexError {
+            kind: StrIndexErrorKind::NotCharBoundary,
+            index,
+            context: char_start,
+            ch,
+        });
+    }
+
+    Ok(begin..end)
+}
+
 #[lang = "str"]
 #[cfg(not(test))]
 impl str {
@@ -386,6 +443,70 @@ pub fn get_mut<I: SliceIndex<str>>(&mut self, i: I) -> Option<&mut I::Output> {
         i.get_mut(self)
     }
 
+    /// Returns a subslice of `str`, or an error describing why the range is
+    /// invalid.
+    ///
+    /// This is like [`get`](str::get), but instead of [`None`] it returns a
+    /// [`StrIndexError`] that tells whether a bound was out of bounds, the
+    /// range was inverted, or a bound fell inside a `char`, along with the
+    /// offending byte index. The conditions are checked in the same order as
+    /// indexing does, so the error describes what the equivalent indexing
+    /// operation would have panicked with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_try_index)]
+    /// use std::str::StrIndexErrorKind;
+    ///
+    /// let v = "🗻∈🌏";
+    ///
+    /// assert_eq!(v.try_index(0..4), Ok("🗻"));
+    ///
+    /// let err = v.try_index(..8).unwrap_err();
+    /// assert_eq!(err.kind(), StrIndexErrorKind::NotCharBoundary);
+    /// assert_eq!(err.index(), 8);
+    ///
+    /// let err = v.try_index(4..42).unwrap_err();
+    /// assert_eq!(err.kind(), StrIndexErrorKind::OutOfBounds);
+    /// assert_eq!(err.index(), 42);
+    /// ```
+    #[unstable(feature = "str_try_index", issue = "none")]
+    #[inline]
+    pub fn try_index<R: ops::RangeBounds<usize>>(&self, range: R) -> Result<&str, StrIndexError> {
+        let range = try_index_range(self, range)?;
+        // SAFETY: `try_index_range` checked that the range is in bounds and on
+        // char boundaries.
+        Ok(unsafe { self.get_unchecked(range) })
+    }
+
+    /// Returns a mutable subslice of `str`, or an error describing why the
+    /// range is invalid.
+    ///
+    /// See [`try_index`](str::try_index) for the errors that can be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_try_index)]
+    /// let mut v = String::from("hello");
+    ///
+    /// v.try_index_mut(..2).unwrap().make_ascii_uppercase();
+    /// assert_eq!(v, "HEllo");
+    /// assert!(v.try_index_mut(3..2).is_err());
+    /// ```
+    #[unstable(feature = "str_try_index", issue = "none")]
+    #[inline]
+    pub fn try_index_mut<R: ops::RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> Result<&mut str, StrIndexError> {
+        let range = try_index_range(self, range)?;
+        // SAFETY: `try_index_range` checked that the range is in bounds and on
+        // char boundaries.
+        Ok(unsafe { self.get_unchecked_mut(range) })
+    }
+
     /// Returns an unchecked subslice of `str`.
     ///
     /// This is the unchecked alternative to indexing the `str`.
@@ -935,6 +1056,69 @@ pub fn encode_utf16(&self) -> EncodeUtf16<'_> {
         EncodeUtf16 { chars: self.chars(), extra: 0 }
     }
 
+    /// Returns the number of `u16` code units needed to encode this string as UTF-16.
+    ///
+    /// This is the length of the sequence returned by [`encode_utf16`], computed without
+    /// decoding the string, so it can be used to allocate an exactly sized buffer for
+    /// [`encode_utf16_into`].
+    ///
+    /// [`encode_utf16`]: str::encode_utf16
+    /// [`encode_utf16_into`]: str::encode_utf16_into
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_encode_utf16_into)]
+    ///
+    /// let text = "ab\u{1F4A9}";
+    ///
+    /// assert_eq!(text.encode_utf16_len(), 4);
+    /// assert_eq!(text.encode_utf16_len(), text.encode_utf16().count());
+    /// ```
+    #[must_use]
+    #[unstable(feature = "str_encode_utf16_into", issue = "none")]
+    #[inline]
+    pub fn encode_utf16_len(&self) -> usize {
+        // Every char takes one code unit, except those encoded in four UTF-8 bytes, which are
+        // outside the BMP and take a surrogate pair.
+        self.as_bytes()
+            .iter()
+            .map(|&b| (!validations::utf8_is_cont_byte(b)) as usize + (b >= 0xF0) as usize)
+            .sum()
+    }
+
+    /// Encodes this string as UTF-16 into the provided buffer, and returns the number of `u16`
+    /// code units written.
+    ///
+    /// The buffer must be at least [`encode_utf16_len`] units long.
+    ///
+    /// [`encode_utf16_len`]: str::encode_utf16_len
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough. The part of the buffer that was written before
+    /// the panic is left in an unspecified state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_encode_utf16_into)]
+    ///
+    /// let text = "é\u{1F4A9}";
+    /// let mut buf = vec![0; text.encode_utf16_len()];
+    ///
+    /// let written = text.encode_utf16_into(&mut buf);
+    /// assert_eq!(&buf[..written], [0xE9, 0xD83D, 0xDCA9]);
+    /// ```
+    #[unstable(feature = "str_encode_utf16_into", issue = "none")]
+    pub fn encode_utf16_into(&self, dst: &mut [u16]) -> usize {
+        let mut written = 0;
+        for c in self.chars() {
+            written += c.encode_utf16(&mut dst[written..]).len();
+        }
+        written
+    }
+
     /// Returns `true` if the given pattern matches a sub-slice of
     /// this string slice.
     ///
@@ -1200,6 +1200,39 @@ fn next_match_back(&mut self) -> Option<(usize, usize)> {
     }
 }
 
+/// Returns the index of the first occurrence of `needle` in `haystack`.
+///
+/// This is the byte-level search behind `<[u8]>::find`: `memchr` for a single byte, and the
+/// two-way algorithm for anything longer.
+pub(crate) fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    match needle.len() {
+        0 => Some(0),
+        1 => memchr::memchr(needle[0], haystack),
+        len if len > haystack.len() => None,
+        _ => {
+            let mut searcher = TwoWaySearcher::new(needle, haystack.len());
+            let is_long = searcher.memory == usize::MAX;
+            searcher.next::<MatchOnly>(haystack, needle, is_long).map(|(a, _)| a)
+        }
+    }
+}
+
+/// Returns the index of the last occurrence of `needle` in `haystack`.
+///
+/// The reverse of [`find_bytes`], behind `<[u8]>::rfind`.
+pub(crate) fn rfind_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    match needle.len() {
+        0 => Some(haystack.len()),
+        1 => memchr::memrchr(needle[0], haystack),
+        len if len > haystack.len() => None,
+        _ => {
+            let mut searcher = TwoWaySearcher::new(needle, haystack.len());
+            let is_long = searcher.memory == usize::MAX;
+            searcher.next_back::<MatchOnly>(haystack, needle, is_long).map(|(a, _)| a)
+        }
+    }
+}
+
 /// The internal state of the two-way substring search algorithm.
 #[derive(Clone, Debug)]
 struct TwoWaySearcher {
@@ -4,6 +4,12 @@
 
 use super::Utf8Error;
 
+#[cfg(any(
+    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "ssse3"),
+    all(target_arch = "aarch64", target_feature = "neon", target_endian = "little"),
+))]
+mod simd;
+
 /// Returns the initial codepoint accumulator for the first byte.
 /// The first byte is special, only want bottom 5 bits for width 2, 4 bits
 /// for width 3, and 3 bits for width 4.
@@ -123,9 +129,39 @@ const fn contains_nonascii(x: usize) -> bool {
 
 /// Walks through `v` checking that it's a valid UTF-8 sequence,
 /// returning `Ok(())` in that case, or, if it is invalid, `Err(err)`.
+///
+/// At runtime this uses a SIMD implementation on targets that statically
+/// support the required instructions (SSSE3 on x86, NEON on AArch64).
 #[inline(always)]
 #[rustc_const_unstable(feature = "str_internals", issue = "none")]
 pub(super) const fn run_utf8_validation(v: &[u8]) -> Result<(), Utf8Error> {
+    #[cfg(any(
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "ssse3"),
+        all(target_arch = "aarch64", target_feature = "neon", target_endian = "little"),
+    ))]
+    // SAFETY: `simd::run_utf8_validation` returns the same result as
+    // `run_utf8_validation_scalar`, which it falls back to for finding the
+    // exact location of an error.
+    return unsafe {
+        crate::intrinsics::const_eval_select(
+            (v,),
+            run_utf8_validation_scalar,
+            simd::run_utf8_validation,
+        )
+    };
+
+    #[cfg(not(any(
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "ssse3"),
+        all(target_arch = "aarch64", target_feature = "neon", target_endian = "little"),
+    )))]
+    run_utf8_validation_scalar(v)
+}
+
+/// Walks through `v` checking that it's a valid UTF-8 sequence one
+/// character at a time, skipping ahead a word at a time through ASCII.
+#[inline(always)]
+#[rustc_const_unstable(feature = "str_internals", issue = "none")]
+const fn run_utf8_validation_scalar(v: &[u8]) -> Result<(), Utf8Error> {
     let mut index = 0;
     let len = v.len();
 
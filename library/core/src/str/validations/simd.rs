@@ -0,0 +1,397 @@
+//! SIMD fast path for UTF-8 validation.
+//!
+//! This implements the "lookup" algorithm of John Keiser and Daniel Lemire, described in
+//! [Validating UTF-8 In Less Than One Instruction Per Byte][paper]. Every pair of adjacent bytes
+//! is classified with three 16-entry table lookups indexed by nibbles, which together flag all
+//! invalid two-byte combinations. A separate check makes sure that the third and fourth bytes of
+//! longer sequences are continuation bytes.
+//!
+//! The vector code only tells whether a block contains an error. Once one is found, the scalar
+//! validator is rerun from the last character boundary before that block, so that the returned
+//! [`Utf8Error`] is exactly the one the scalar validator would have produced.
+//!
+//! [paper]: https://arxiv.org/abs/2010.03090
+
+use super::{run_utf8_validation_scalar, utf8_is_cont_byte};
+use crate::str::Utf8Error;
+
+#[cfg(target_arch = "aarch64")]
+use crate::arch::aarch64::*;
+#[cfg(target_arch = "x86")]
+use crate::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use crate::arch::x86_64::*;
+
+/// Number of bytes checked per iteration.
+const BLOCK: usize = 16;
+
+// Error classes of the lookup tables. A bit of `BYTE_1_HIGH & BYTE_1_LOW & BYTE_2_HIGH` is set
+// if the previous and the current byte form an invalid sequence of that class, except for
+// `TWO_CONTS`, which is set whenever two continuation bytes follow each other. That is only
+// correct for the third and fourth byte of a sequence, which is checked separately.
+const TOO_SHORT: u8 = 1 << 0;
+const TOO_LONG: u8 = 1 << 1;
+const OVERLONG_3: u8 = 1 << 2;
+const TOO_LARGE: u8 = 1 << 3;
+const SURROGATE: u8 = 1 << 4;
+const OVERLONG_2: u8 = 1 << 5;
+const TOO_LARGE_1000: u8 = 1 << 6;
+const OVERLONG_4: u8 = 1 << 6;
+const TWO_CONTS: u8 = 1 << 7;
+const CARRY: u8 = TOO_SHORT | TOO_LONG | TWO_CONTS;
+
+/// Error classes indexed by the high nibble of the previous byte.
+#[rustfmt::skip]
+const BYTE_1_HIGH: [u8; 16] = [
+    // 0___: ASCII
+    TOO_LONG, TOO_LONG, TOO_LONG, TOO_LONG, TOO_LONG, TOO_LONG, TOO_LONG, TOO_LONG,
+    // 10__: continuation
+    TWO_CONTS, TWO_CONTS, TWO_CONTS, TWO_CONTS,
+    // 1100: two-byte lead, possibly overlong
+    TOO_SHORT | OVERLONG_2,
+    // 1101: two-byte lead
+    TOO_SHORT,
+    // 1110: three-byte lead
+    TOO_SHORT | OVERLONG_3 | SURROGATE,
+    // 1111: four-byte lead
+    TOO_SHORT | TOO_LARGE | TOO_LARGE_1000 | OVERLONG_4,
+];
+
+/// Error classes indexed by the low nibble of the previous byte.
+#[rustfmt::skip]
+const BYTE_1_LOW: [u8; 16] = [
+    // 0000
+    CARRY | OVERLONG_3 | OVERLONG_2 | OVERLONG_4,
+    // 0001
+    CARRY | OVERLONG_2,
+    // 001_
+    CARRY,
+    CARRY,
+    // 0100
+    CARRY | TOO_LARGE,
+    // 0101 to 1100
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    // 1101
+    CARRY | TOO_LARGE | TOO_LARGE_1000 | SURROGATE,
+    // 111_
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+];
+
+/// Error classes indexed by the high nibble of the current byte.
+#[rustfmt::skip]
+const BYTE_2_HIGH: [u8; 16] = [
+    // 0___: ASCII
+    TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT,
+    // 1000: continuation
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | OVERLONG_3 | TOO_LARGE_1000 | OVERLONG_4,
+    // 1001: continuation
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | OVERLONG_3 | TOO_LARGE,
+    // 101_: continuation
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | SURROGATE | TOO_LARGE,
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | SURROGATE | TOO_LARGE,
+    // 11__: lead
+    TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT,
+];
+
+/// The largest last bytes of a block that does not end in the middle of a sequence.
+#[rustfmt::skip]
+const INCOMPLETE_MAX: [u8; 16] = [
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xF0 - 1, 0xE0 - 1, 0xC0 - 1,
+];
+
+/// A vector of `BLOCK` bytes.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[derive(Clone, Copy)]
+struct Vector(__m128i);
+
+// All intrinsics used below are at most SSSE3, which the `cfg` on this module requires to be
+// enabled at compile time.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl Vector {
+    #[inline(always)]
+    fn splat(byte: u8) -> Self {
+        // SAFETY: requires SSE2, see above.
+        Self(unsafe { _mm_set1_epi8(byte as i8) })
+    }
+
+    /// Loads `BLOCK` bytes from `ptr`, which does not need to be aligned.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of `BLOCK` bytes.
+    #[inline(always)]
+    unsafe fn load(ptr: *const u8) -> Self {
+        // SAFETY: requires SSE2, see above. The caller guarantees that `ptr` is readable.
+        Self(unsafe { _mm_loadu_si128(ptr as *const __m128i) })
+    }
+
+    #[inline(always)]
+    fn and(self, other: Self) -> Self {
+        // SAFETY: requires SSE2, see above.
+        Self(unsafe { _mm_and_si128(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn or(self, other: Self) -> Self {
+        // SAFETY: requires SSE2, see above.
+        Self(unsafe { _mm_or_si128(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn xor(self, other: Self) -> Self {
+        // SAFETY: requires SSE2, see above.
+        Self(unsafe { _mm_xor_si128(self.0, other.0) })
+    }
+
+    /// Shifts every byte right by four bits.
+    #[inline(always)]
+    fn shr4(self) -> Self {
+        // SAFETY: requires SSE2, see above. There is no 8-bit shift, so shift 16-bit lanes and
+        // clear the bits shifted in from the neighbouring byte.
+        Self(unsafe { _mm_srli_epi16(self.0, 4) }).and(Self::splat(0x0F))
+    }
+
+    /// Replaces every byte, which must be less than 16, with the element of `table` it indexes.
+    #[inline(always)]
+    fn lookup(self, table: Self) -> Self {
+        // SAFETY: requires SSSE3, see above.
+        Self(unsafe { _mm_shuffle_epi8(table.0, self.0) })
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self {
+        // SAFETY: requires SSE2, see above.
+        Self(unsafe { _mm_subs_epu8(self.0, other.0) })
+    }
+
+    /// Returns the bytes of `self` shifted by one, with the last byte of `prev` in front.
+    #[inline(always)]
+    fn prev1(self, prev: Self) -> Self {
+        // SAFETY: requires SSSE3, see above.
+        Self(unsafe { _mm_alignr_epi8(self.0, prev.0, 15) })
+    }
+
+    /// Returns the bytes of `self` shifted by two, with the last two bytes of `prev` in front.
+    #[inline(always)]
+    fn prev2(self, prev: Self) -> Self {
+        // SAFETY: requires SSSE3, see above.
+        Self(unsafe { _mm_alignr_epi8(self.0, prev.0, 14) })
+    }
+
+    /// Returns the bytes of `self` shifted by three, with the last three bytes of `prev` in
+    /// front.
+    #[inline(always)]
+    fn prev3(self, prev: Self) -> Self {
+        // SAFETY: requires SSSE3, see above.
+        Self(unsafe { _mm_alignr_epi8(self.0, prev.0, 13) })
+    }
+
+    #[inline(always)]
+    fn is_ascii(self) -> bool {
+        // SAFETY: requires SSE2, see above.
+        unsafe { _mm_movemask_epi8(self.0) == 0 }
+    }
+
+    /// Returns `true` if any byte is not zero.
+    #[inline(always)]
+    fn any(self) -> bool {
+        // SAFETY: requires SSE2, see above.
+        unsafe { _mm_movemask_epi8(_mm_cmpeq_epi8(self.0, _mm_setzero_si128())) != 0xFFFF }
+    }
+}
+
+/// A vector of `BLOCK` bytes.
+#[cfg(target_arch = "aarch64")]
+#[derive(Clone, Copy)]
+struct Vector(uint8x16_t);
+
+// All intrinsics used below only require NEON, which the `cfg` on this module requires to be
+// enabled at compile time.
+#[cfg(target_arch = "aarch64")]
+impl Vector {
+    #[inline(always)]
+    fn splat(byte: u8) -> Self {
+        // SAFETY: requires NEON, see above.
+        Self(unsafe { vdupq_n_u8(byte) })
+    }
+
+    /// Loads `BLOCK` bytes from `ptr`, which does not need to be aligned.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of `BLOCK` bytes.
+    #[inline(always)]
+    unsafe fn load(ptr: *const u8) -> Self {
+        // SAFETY: requires NEON, see above. The caller guarantees that `ptr` is readable.
+        Self(unsafe { vld1q_u8(ptr) })
+    }
+
+    #[inline(always)]
+    fn and(self, other: Self) -> Self {
+        // SAFETY: requires NEON, see above.
+        Self(unsafe { vandq_u8(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn or(self, other: Self) -> Self {
+        // SAFETY: requires NEON, see above.
+        Self(unsafe { vorrq_u8(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn xor(self, other: Self) -> Self {
+        // SAFETY: requires NEON, see above.
+        Self(unsafe { veorq_u8(self.0, other.0) })
+    }
+
+    /// Shifts every byte right by four bits.
+    #[inline(always)]
+    fn shr4(self) -> Self {
+        // SAFETY: requires NEON, see above.
+        Self(unsafe { vshrq_n_u8(self.0, 4) })
+    }
+
+    /// Replaces every byte, which must be less than 16, with the element of `table` it indexes.
+    #[inline(always)]
+    fn lookup(self, table: Self) -> Self {
+        // SAFETY: requires NEON, see above.
+        Self(unsafe { vqtbl1q_u8(table.0, self.0) })
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self {
+        // SAFETY: requires NEON, see above.
+        Self(unsafe { vqsubq_u8(self.0, other.0) })
+    }
+
+    /// Returns the bytes of `self` shifted by one, with the last byte of `prev` in front.
+    #[inline(always)]
+    fn prev1(self, prev: Self) -> Self {
+        // SAFETY: requires NEON, see above.
+        Self(unsafe { vextq_u8(prev.0, self.0, 15) })
+    }
+
+    /// Returns the bytes of `self` shifted by two, with the last two bytes of `prev` in front.
+    #[inline(always)]
+    fn prev2(self, prev: Self) -> Self {
+        // SAFETY: requires NEON, see above.
+        Self(unsafe { vextq_u8(prev.0, self.0, 14) })
+    }
+
+    /// Returns the bytes of `self` shifted by three, with the last three bytes of `prev` in
+    /// front.
+    #[inline(always)]
+    fn prev3(self, prev: Self) -> Self {
+        // SAFETY: requires NEON, see above.
+        Self(unsafe { vextq_u8(prev.0, self.0, 13) })
+    }
+
+    #[inline(always)]
+    fn is_ascii(self) -> bool {
+        // SAFETY: requires NEON, see above.
+        unsafe { vmaxvq_u8(self.0) < 0x80 }
+    }
+
+    /// Returns `true` if any byte is not zero.
+    #[inline(always)]
+    fn any(self) -> bool {
+        // SAFETY: requires NEON, see above.
+        unsafe { vmaxvq_u8(self.0) != 0 }
+    }
+}
+
+impl Vector {
+    #[inline(always)]
+    fn from_array(array: &[u8; BLOCK]) -> Self {
+        // SAFETY: `array` is `BLOCK` bytes long.
+        unsafe { Self::load(array.as_ptr()) }
+    }
+
+    /// Returns a non-zero vector if `input` contains an error, or if a sequence that starts at
+    /// the end of `prev` is not completed correctly by `input`.
+    #[inline(always)]
+    fn check(input: Self, prev: Self) -> Self {
+        let prev1 = input.prev1(prev);
+        let byte_1_high = prev1.shr4().lookup(Self::from_array(&BYTE_1_HIGH));
+        let byte_1_low = prev1.and(Self::splat(0x0F)).lookup(Self::from_array(&BYTE_1_LOW));
+        let byte_2_high = input.shr4().lookup(Self::from_array(&BYTE_2_HIGH));
+        let special_cases = byte_1_high.and(byte_1_low).and(byte_2_high);
+
+        // Only bytes following a three- or four-byte lead at the right distance get their high
+        // bit set here, and those must be exactly the ones flagged as `TWO_CONTS` above.
+        let is_third_byte = input.prev2(prev).saturating_sub(Self::splat(0xE0 - 0x80));
+        let is_fourth_byte = input.prev3(prev).saturating_sub(Self::splat(0xF0 - 0x80));
+        let must_be_cont = is_third_byte.or(is_fourth_byte).and(Self::splat(TWO_CONTS));
+        must_be_cont.xor(special_cases)
+    }
+
+    /// Returns a non-zero vector if `block` ends in the middle of a multi-byte sequence.
+    #[inline(always)]
+    fn is_incomplete(block: Self) -> Self {
+        block.saturating_sub(Self::from_array(&INCOMPLETE_MAX))
+    }
+}
+
+/// Walks through `v` checking that it's a valid UTF-8 sequence, returning the same result as
+/// `run_utf8_validation_scalar`.
+pub(super) fn run_utf8_validation(v: &[u8]) -> Result<(), Utf8Error> {
+    // Short inputs are usually faster on the scalar path, which also has its own fast path for
+    // runs of ASCII.
+    if v.len() < 2 * BLOCK {
+        return run_utf8_validation_scalar(v);
+    }
+
+    let mut prev = Vector::splat(0);
+    let mut offset = 0;
+    while offset + BLOCK <= v.len() {
+        // SAFETY: `offset + BLOCK <= v.len()`, so the block is in bounds.
+        let input = unsafe { Vector::load(v.as_ptr().add(offset)) };
+        let error =
+            if input.is_ascii() { Vector::is_incomplete(prev) } else { Vector::check(input, prev) };
+        if error.any() {
+            return locate_error(v, offset);
+        }
+        prev = input;
+        offset += BLOCK;
+    }
+
+    // Check the rest padded with ASCII zeros, which also catches a sequence that is cut off at
+    // the end of the input.
+    let mut last = [0; BLOCK];
+    last[..v.len() - offset].copy_from_slice(&v[offset..]);
+    if Vector::check(Vector::from_array(&last), prev).any() {
+        return locate_error(v, offset);
+    }
+    Ok(())
+}
+
+/// Finds the exact error once the block at `offset` was flagged.
+///
+/// All blocks before `offset` have been checked, so everything up to the character that may
+/// continue into the flagged block is valid, and the scalar validator can take over from its
+/// first byte.
+#[cold]
+#[inline(never)]
+fn locate_error(v: &[u8], offset: usize) -> Result<(), Utf8Error> {
+    let mut start = offset;
+    while start > 0 && offset - start < 4 {
+        start -= 1;
+        if !utf8_is_cont_byte(v[start]) {
+            break;
+        }
+    }
+
+    run_utf8_validation_scalar(&v[start..]).map_err(|error| Utf8Error {
+        valid_up_to: start + error.valid_up_to,
+        error_len: error.error_len,
+    })
+}
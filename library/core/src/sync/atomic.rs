@@ -55,6 +55,10 @@
 //! * ARM targets with `thumbv6m` only provide `load` and `store` operations,
 //!   and do not support Compare and Swap (CAS) operations, such as `swap`,
 //!   `fetch_add`, etc.
+//! * x86_64 does not have `AtomicU128` or `AtomicI128`, even with the
+//!   `cmpxchg16b` target feature. The only 16-byte atomic load available there
+//!   is a `cmpxchg16b`, which always writes to memory and so faults on
+//!   read-only mappings, unlike every other atomic `load`.
 //!
 //! [operating system support]: https://www.kernel.org/doc/Documentation/arm/kernel_user_helpers.txt
 //!
@@ -1269,6 +1273,285 @@ pub fn fetch_update<F>(
         }
         Err(prev)
     }
+
+    /// Offsets the pointer's address by adding `val` (in units of `T`),
+    /// returning the previous pointer.
+    ///
+    /// This is equivalent to using [`wrapping_add`] to atomically perform the
+    /// equivalent of `ptr = ptr.wrapping_add(val);`.
+    ///
+    /// The new pointer keeps the provenance of the previous one, so it can
+    /// still be used to access the original allocation once it is back in
+    /// bounds. This is not the case when round-tripping through
+    /// [`AtomicUsize`].
+    ///
+    /// `fetch_ptr_add` takes an [`Ordering`] argument which describes the
+    /// memory ordering of this operation. All ordering modes are possible.
+    /// Note that using [`Acquire`] makes the store part of this operation
+    /// [`Relaxed`], and using [`Release`] makes the load part [`Relaxed`].
+    ///
+    /// **Note**: This method is only available on platforms that support
+    /// atomic operations on pointers.
+    ///
+    /// [`wrapping_add`]: pointer::wrapping_add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_ptr_arith)]
+    /// use core::sync::atomic::{AtomicPtr, Ordering};
+    ///
+    /// let mut array = [1i64, 2, 3];
+    /// let atom = AtomicPtr::new(array.as_mut_ptr());
+    /// assert_eq!(atom.fetch_ptr_add(1, Ordering::Relaxed), array.as_mut_ptr());
+    /// // Note: units of `size_of::<i64>()`.
+    /// assert_eq!(unsafe { *atom.load(Ordering::Relaxed) }, 2);
+    /// ```
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_ptr_arith", issue = "none")]
+    pub fn fetch_ptr_add(&self, val: usize, order: Ordering) -> *mut T {
+        self.fetch_byte_add(val.wrapping_mul(crate::mem::size_of::<T>()), order)
+    }
+
+    /// Offsets the pointer's address by subtracting `val` (in units of `T`),
+    /// returning the previous pointer.
+    ///
+    /// This is equivalent to using [`wrapping_sub`] to atomically perform the
+    /// equivalent of `ptr = ptr.wrapping_sub(val);`. See
+    /// [`AtomicPtr::fetch_ptr_add`] for how provenance and orderings work.
+    ///
+    /// **Note**: This method is only available on platforms that support
+    /// atomic operations on pointers.
+    ///
+    /// [`wrapping_sub`]: pointer::wrapping_sub
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_ptr_arith)]
+    /// use core::sync::atomic::{AtomicPtr, Ordering};
+    ///
+    /// let mut array = [1i64, 2, 3];
+    /// let atom = AtomicPtr::new(array.as_mut_ptr().wrapping_add(2));
+    /// atom.fetch_ptr_sub(1, Ordering::Relaxed);
+    /// assert_eq!(unsafe { *atom.load(Ordering::Relaxed) }, 2);
+    /// ```
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_ptr_arith", issue = "none")]
+    pub fn fetch_ptr_sub(&self, val: usize, order: Ordering) -> *mut T {
+        self.fetch_byte_sub(val.wrapping_mul(crate::mem::size_of::<T>()), order)
+    }
+
+    /// Offsets the pointer's address by adding `val` *bytes*, returning the
+    /// previous pointer.
+    ///
+    /// This is equivalent to atomically performing
+    /// `ptr = ptr.cast::<u8>().wrapping_add(val).cast::<T>();`. See
+    /// [`AtomicPtr::fetch_ptr_add`] for how provenance and orderings work.
+    ///
+    /// **Note**: This method is only available on platforms that support
+    /// atomic operations on pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_ptr_arith)]
+    /// use core::sync::atomic::{AtomicPtr, Ordering};
+    ///
+    /// let atom = AtomicPtr::<i64>::new(core::ptr::null_mut());
+    /// atom.fetch_byte_add(1, Ordering::Relaxed);
+    /// assert_eq!(atom.load(Ordering::Relaxed) as usize, 1);
+    /// ```
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_ptr_arith", issue = "none")]
+    pub fn fetch_byte_add(&self, val: usize, order: Ordering) -> *mut T {
+        self.fetch_map_addr(order, |addr| addr.wrapping_add(val))
+    }
+
+    /// Offsets the pointer's address by subtracting `val` *bytes*, returning
+    /// the previous pointer.
+    ///
+    /// This is equivalent to atomically performing
+    /// `ptr = ptr.cast::<u8>().wrapping_sub(val).cast::<T>();`. See
+    /// [`AtomicPtr::fetch_ptr_add`] for how provenance and orderings work.
+    ///
+    /// **Note**: This method is only available on platforms that support
+    /// atomic operations on pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_ptr_arith)]
+    /// use core::sync::atomic::{AtomicPtr, Ordering};
+    ///
+    /// let atom = AtomicPtr::<i64>::new(8 as *mut i64);
+    /// atom.fetch_byte_sub(1, Ordering::Relaxed);
+    /// assert_eq!(atom.load(Ordering::Relaxed) as usize, 7);
+    /// ```
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_ptr_arith", issue = "none")]
+    pub fn fetch_byte_sub(&self, val: usize, order: Ordering) -> *mut T {
+        self.fetch_map_addr(order, |addr| addr.wrapping_sub(val))
+    }
+
+    /// Performs a bitwise "or" operation on the address of the current
+    /// pointer and `val`, and stores a pointer with the same provenance and
+    /// the resulting address, returning the previous pointer.
+    ///
+    /// This is mostly useful for setting tag bits in the low bits of a
+    /// pointer that alignment leaves zero, without going through
+    /// [`AtomicUsize`] and losing track of what the pointer points to. See
+    /// [`AtomicPtr::fetch_ptr_add`] for how orderings work.
+    ///
+    /// **Note**: This method is only available on platforms that support
+    /// atomic operations on pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_ptr_arith)]
+    /// use core::sync::atomic::{AtomicPtr, Ordering};
+    ///
+    /// let pointer = &mut 3i64 as *mut i64;
+    /// let atom = AtomicPtr::<i64>::new(pointer);
+    /// // Tag the bottom bit of the pointer.
+    /// assert_eq!(atom.fetch_or(1, Ordering::Relaxed) as usize & 1, 0);
+    /// // Extract and untag.
+    /// let tagged = atom.load(Ordering::Relaxed);
+    /// assert_eq!(tagged as usize & 1, 1);
+    /// assert_eq!(tagged.cast::<u8>().wrapping_sub(1).cast::<i64>(), pointer);
+    /// ```
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_ptr_arith", issue = "none")]
+    pub fn fetch_or(&self, val: usize, order: Ordering) -> *mut T {
+        self.fetch_map_addr(order, |addr| addr | val)
+    }
+
+    /// Performs a bitwise "and" operation on the address of the current
+    /// pointer and `val`, and stores a pointer with the same provenance and
+    /// the resulting address, returning the previous pointer.
+    ///
+    /// This is mostly useful for clearing tag bits set with
+    /// [`AtomicPtr::fetch_or`].
+    ///
+    /// **Note**: This method is only available on platforms that support
+    /// atomic operations on pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_ptr_arith)]
+    /// use core::sync::atomic::{AtomicPtr, Ordering};
+    ///
+    /// let pointer = &mut 3i64 as *mut i64;
+    /// // A tagged pointer
+    /// let atom = AtomicPtr::<i64>::new(pointer.cast::<u8>().wrapping_add(1).cast());
+    /// // Untag, and extract the previously tagged pointer.
+    /// let untagged = atom.fetch_and(!1, Ordering::Relaxed);
+    /// assert_eq!(untagged as usize & 1, 1);
+    /// assert_eq!(atom.load(Ordering::Relaxed), pointer);
+    /// ```
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_ptr_arith", issue = "none")]
+    pub fn fetch_and(&self, val: usize, order: Ordering) -> *mut T {
+        self.fetch_map_addr(order, |addr| addr & val)
+    }
+
+    /// Performs a bitwise "xor" operation on the address of the current
+    /// pointer and `val`, and stores a pointer with the same provenance and
+    /// the resulting address, returning the previous pointer.
+    ///
+    /// This is mostly useful for toggling tag bits; see
+    /// [`AtomicPtr::fetch_or`].
+    ///
+    /// **Note**: This method is only available on platforms that support
+    /// atomic operations on pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_ptr_arith)]
+    /// use core::sync::atomic::{AtomicPtr, Ordering};
+    ///
+    /// let pointer = &mut 3i64 as *mut i64;
+    /// let atom = AtomicPtr::<i64>::new(pointer);
+    ///
+    /// // Toggle a tag bit on the pointer.
+    /// atom.fetch_xor(1, Ordering::Relaxed);
+    /// assert_eq!(atom.load(Ordering::Relaxed) as usize & 1, 1);
+    /// atom.fetch_xor(1, Ordering::Relaxed);
+    /// assert_eq!(atom.load(Ordering::Relaxed), pointer);
+    /// ```
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_ptr_arith", issue = "none")]
+    pub fn fetch_xor(&self, val: usize, order: Ordering) -> *mut T {
+        self.fetch_map_addr(order, |addr| addr ^ val)
+    }
+
+    /// Atomically replaces the pointer's address with `f(address)`, keeping
+    /// its provenance, and returns the previous pointer.
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    fn fetch_map_addr(&self, order: Ordering, f: impl Fn(usize) -> usize) -> *mut T {
+        // The new pointer is derived from the previous one with wrapping
+        // pointer arithmetic instead of being cast from an integer, which is
+        // what keeps its provenance. The atomic intrinsics only operate on
+        // integers, so this has to be a compare-exchange loop.
+        let failure = strongest_failure_ordering(order);
+        let mut prev = self.load(Relaxed);
+        loop {
+            let addr = prev as usize;
+            let next = prev.cast::<u8>().wrapping_add(f(addr).wrapping_sub(addr)).cast::<T>();
+            match self.compare_exchange_weak(prev, next, order, failure) {
+                Ok(prev) => return prev,
+                Err(actual) => prev = actual,
+            }
+        }
+    }
+
+    /// Returns a mutable pointer to the underlying pointer.
+    ///
+    /// Doing non-atomic reads and writes on the resulting pointer can be a data race.
+    /// This method is mostly useful for FFI, where the function signature may use
+    /// `*mut *mut T` instead of `&AtomicPtr<T>`.
+    ///
+    /// Returning an `*mut` pointer from a shared reference to this atomic is safe because the
+    /// atomic types work with interior mutability. All modifications of an atomic change the value
+    /// through a shared reference, and can do so safely as long as they use atomic operations. Any
+    /// use of the returned raw pointer requires an `unsafe` block and still has to uphold the same
+    /// restriction: operations on it must be atomic.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore (extern-declaration)
+    /// # fn main() {
+    /// use std::sync::atomic::AtomicPtr;
+    ///
+    /// extern "C" {
+    ///     fn my_atomic_op(arg: *mut *mut u32);
+    /// }
+    ///
+    /// let mut value = 17;
+    /// let atomic = AtomicPtr::new(&mut value);
+    ///
+    /// // SAFETY: Safe as long as `my_atomic_op` is atomic.
+    /// unsafe {
+    ///     my_atomic_op(atomic.as_mut_ptr());
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[unstable(feature = "atomic_mut_ptr", reason = "recently added", issue = "66893")]
+    pub fn as_mut_ptr(&self) -> *mut *mut T {
+        self.p.get()
+    }
 }
 
 #[cfg(target_has_atomic_load_store = "8")]
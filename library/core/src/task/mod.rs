@@ -9,6 +9,8 @@
 mod wake;
 #[stable(feature = "futures_api", since = "1.36.0")]
 pub use self::wake::{Context, RawWaker, RawWakerVTable, Waker};
+#[unstable(feature = "local_waker", issue = "none")]
+pub use self::wake::{ContextBuilder, LocalWaker};
 
 mod ready;
 #[unstable(feature = "ready_macro", issue = "70922")]
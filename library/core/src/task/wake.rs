@@ -1,7 +1,9 @@
 #![stable(feature = "futures_api", since = "1.36.0")]
 
+use crate::any::Any;
 use crate::fmt;
 use crate::marker::{PhantomData, Unpin};
+use crate::panic::AssertUnwindSafe;
 
 /// A `RawWaker` allows the implementor of a task executor to create a [`Waker`]
 /// which provides customized wakeup behavior.
@@ -142,27 +144,47 @@ pub const fn new(
     }
 }
 
+/// Extension data carried by a [`Context`], if any.
+enum ExtData<'a> {
+    Some(&'a mut dyn Any),
+    None(()),
+}
+
 /// The `Context` of an asynchronous task.
 ///
-/// Currently, `Context` only serves to provide access to a `&Waker`
-/// which can be used to wake the current task.
+/// `Context` provides access to a [`Waker`] which can be used to wake the
+/// current task from any thread, a [`LocalWaker`] which can only wake it
+/// from the thread polling it, and optional executor-specific extension
+/// data (see [`Context::ext`]). A `Context` other than the one created by
+/// [`Context::from_waker`] is built with a [`ContextBuilder`].
+///
+/// Since it may carry thread-local data, `Context` is neither `Send` nor
+/// `Sync`.
 #[stable(feature = "futures_api", since = "1.36.0")]
 pub struct Context<'a> {
     waker: &'a Waker,
+    local_waker: &'a LocalWaker,
+    ext: AssertUnwindSafe<ExtData<'a>>,
     // Ensure we future-proof against variance changes by forcing
     // the lifetime to be invariant (argument-position lifetimes
     // are contravariant while return-position lifetimes are
     // covariant).
     _marker: PhantomData<fn(&'a ()) -> &'a ()>,
+    // Ensure `Context` is `!Send` and `!Sync` in order to allow
+    // for future `!Send` and / or `!Sync` fields.
+    _marker2: PhantomData<*mut ()>,
 }
 
 impl<'a> Context<'a> {
     /// Create a new `Context` from a `&Waker`.
+    ///
+    /// The [`LocalWaker`] of the new context wakes through the same `waker`,
+    /// and it carries no extension data.
     #[stable(feature = "futures_api", since = "1.36.0")]
     #[must_use]
     #[inline]
     pub fn from_waker(waker: &'a Waker) -> Self {
-        Context { waker, _marker: PhantomData }
+        ContextBuilder::from_waker(waker).build()
     }
 
     /// Returns a reference to the `Waker` for the current task.
@@ -172,6 +194,32 @@ pub fn from_waker(waker: &'a Waker) -> Self {
     pub fn waker(&self) -> &'a Waker {
         &self.waker
     }
+
+    /// Returns a reference to the [`LocalWaker`] for the current task.
+    ///
+    /// A `LocalWaker` cannot be sent to other threads, which lets
+    /// single-threaded executors wake tasks without synchronization.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "none")]
+    pub fn local_waker(&self) -> &'a LocalWaker {
+        &self.local_waker
+    }
+
+    /// Returns a reference to the extension data for the current task.
+    ///
+    /// Executors can attach arbitrary data with [`ContextBuilder::ext`],
+    /// which futures tied to that executor can then downcast. If no
+    /// extension data was attached, this returns a reference to `()`.
+    #[inline]
+    #[unstable(feature = "context_ext", issue = "none")]
+    pub fn ext(&mut self) -> &mut dyn Any {
+        // FIXME: this field makes Context extra-weird about unwind safety
+        // can we justify AssertUnwindSafe if we stabilize this? do we care?
+        match &mut self.ext.0 {
+            ExtData::Some(data) => *data,
+            ExtData::None(unit) => unit,
+        }
+    }
 }
 
 #[stable(feature = "futures_api", since = "1.36.0")]
@@ -181,6 +229,124 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// A Builder used to construct a `Context` instance
+/// with support for `LocalWaker` and extension data.
+///
+/// # Examples
+/// ```
+/// #![feature(local_waker)]
+/// #![feature(context_ext)]
+/// use std::sync::Arc;
+/// use std::task::{ContextBuilder, LocalWaker, Wake, Waker};
+///
+/// struct NoopWaker;
+///
+/// impl Wake for NoopWaker {
+///     fn wake(self: Arc<Self>) {}
+/// }
+///
+/// let waker = Waker::from(Arc::new(NoopWaker));
+/// let local_waker: &LocalWaker = waker.as_ref();
+/// let mut reactor_id = 7_u32;
+///
+/// let mut cx = ContextBuilder::from_waker(&waker)
+///     .local_waker(local_waker)
+///     .ext(&mut reactor_id)
+///     .build();
+///
+/// assert_eq!(cx.ext().downcast_ref::<u32>(), Some(&7));
+/// ```
+#[unstable(feature = "local_waker", issue = "none")]
+#[derive(Debug)]
+pub struct ContextBuilder<'a> {
+    waker: &'a Waker,
+    local_waker: &'a LocalWaker,
+    ext: ExtData<'a>,
+    // Ensure we future-proof against variance changes by forcing
+    // the lifetime to be invariant (argument-position lifetimes
+    // are contravariant while return-position lifetimes are
+    // covariant).
+    _marker: PhantomData<fn(&'a ()) -> &'a ()>,
+    // Ensure `Context` is `!Send` and `!Sync` in order to allow
+    // for future `!Send` and / or `!Sync` fields.
+    _marker2: PhantomData<*mut ()>,
+}
+
+impl<'a> ContextBuilder<'a> {
+    /// Create a ContextBuilder from a Waker.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "none")]
+    pub fn from_waker(waker: &'a Waker) -> Self {
+        // SAFETY: LocalWaker is just Waker without thread safety
+        let local_waker = unsafe { &*(waker as *const Waker as *const LocalWaker) };
+        ContextBuilder {
+            waker,
+            local_waker,
+            ext: ExtData::None(()),
+            _marker: PhantomData,
+            _marker2: PhantomData,
+        }
+    }
+
+    /// Create a ContextBuilder from an existing Context.
+    ///
+    /// The builder starts with the wakers and extension data of `cx`, so a
+    /// combinator can change only the parts it cares about.
+    #[inline]
+    #[unstable(feature = "context_ext", issue = "none")]
+    pub fn from(cx: &'a mut Context<'_>) -> Self {
+        let ext = match &mut cx.ext.0 {
+            ExtData::Some(ext) => ExtData::Some(*ext),
+            ExtData::None(()) => ExtData::None(()),
+        };
+        ContextBuilder {
+            waker: cx.waker,
+            local_waker: cx.local_waker,
+            ext,
+            _marker: PhantomData,
+            _marker2: PhantomData,
+        }
+    }
+
+    /// This method is used to set the value for the waker on `Context`.
+    #[inline]
+    #[unstable(feature = "context_ext", issue = "none")]
+    pub fn waker(self, waker: &'a Waker) -> Self {
+        Self { waker, ..self }
+    }
+
+    /// This method is used to set the value for the local waker on `Context`.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "none")]
+    pub fn local_waker(self, local_waker: &'a LocalWaker) -> Self {
+        Self { local_waker, ..self }
+    }
+
+    /// This method is used to set the value for the extension data on `Context`.
+    #[inline]
+    #[unstable(feature = "context_ext", issue = "none")]
+    pub fn ext(self, data: &'a mut dyn Any) -> Self {
+        Self { ext: ExtData::Some(data), ..self }
+    }
+
+    /// Builds the `Context`.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "none")]
+    pub fn build(self) -> Context<'a> {
+        let ContextBuilder { waker, local_waker, ext, _marker, _marker2 } = self;
+        Context { waker, local_waker, ext: AssertUnwindSafe(ext), _marker, _marker2 }
+    }
+}
+
+impl fmt::Debug for ExtData<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtData::Some(_) => f.write_str("Some(..)"),
+            ExtData::None(()) => f.write_str("None"),
+        }
+    }
+}
+
 /// A `Waker` is a handle for waking up a task by notifying its executor that it
 /// is ready to be run.
 ///
@@ -296,3 +462,138 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             .finish()
     }
 }
+
+#[unstable(feature = "local_waker", issue = "none")]
+impl AsRef<LocalWaker> for Waker {
+    fn as_ref(&self) -> &LocalWaker {
+        // SAFETY: LocalWaker is just Waker without thread safety
+        unsafe { &*(self as *const Waker as *const LocalWaker) }
+    }
+}
+
+/// A `LocalWaker` is analogous to a [`Waker`], but it does not implement
+/// [`Send`] or [`Sync`].
+///
+/// This handle encapsulates a [`RawWaker`] instance, which defines the
+/// executor-specific wakeup behavior. Because it never leaves the thread it
+/// was created on, a `LocalWaker` may be backed by data that is not
+/// thread-safe, such as an `Rc`, letting single-threaded executors avoid
+/// atomic operations and locks.
+///
+/// A `LocalWaker` can be obtained from a [`Context`] with
+/// [`Context::local_waker`], and every [`Waker`] can be used as a
+/// `LocalWaker` through its `AsRef` implementation.
+///
+/// Implements [`Clone`], but neither [`Send`] nor [`Sync`].
+#[unstable(feature = "local_waker", issue = "none")]
+#[repr(transparent)]
+pub struct LocalWaker {
+    waker: RawWaker,
+}
+
+#[unstable(feature = "local_waker", issue = "none")]
+impl Unpin for LocalWaker {}
+
+impl LocalWaker {
+    /// Wake up the task associated with this `LocalWaker`.
+    ///
+    /// As long as the executor keeps running and the task is not finished, it is
+    /// guaranteed that each invocation of [`wake()`](Self::wake) (or
+    /// [`wake_by_ref()`](Self::wake_by_ref)) will be followed by at least one
+    /// [`poll()`] of the task to which this `LocalWaker` belongs.
+    ///
+    /// [`poll()`]: crate::future::Future::poll
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "none")]
+    pub fn wake(self) {
+        // The actual wakeup call is delegated through a virtual function call
+        // to the implementation which is defined by the executor.
+        let wake = self.waker.vtable.wake;
+        let data = self.waker.data;
+
+        // Don't call `drop` -- the waker will be consumed by `wake`.
+        crate::mem::forget(self);
+
+        // SAFETY: This is safe because `LocalWaker::from_raw` is the only way
+        // to initialize `wake` and `data` requiring the user to acknowledge
+        // that the contract of `RawWaker` is upheld.
+        unsafe { (wake)(data) };
+    }
+
+    /// Wake up the task associated with this `LocalWaker` without consuming the `LocalWaker`.
+    ///
+    /// This is similar to [`wake()`](Self::wake), but may be slightly less efficient in
+    /// the case where an owned `Waker` is available. This method should be preferred to
+    /// calling `waker.clone().wake()`.
+    #[inline]
+    #[unstable(feature = "local_waker", issue = "none")]
+    pub fn wake_by_ref(&self) {
+        // The actual wakeup call is delegated through a virtual function call
+        // to the implementation which is defined by the executor.
+
+        // SAFETY: see `wake`
+        unsafe { (self.waker.vtable.wake_by_ref)(self.waker.data) }
+    }
+
+    /// Returns `true` if this `LocalWaker` and another `LocalWaker` would awake the same task.
+    ///
+    /// This function works on a best-effort basis, and may return false even
+    /// when the `Waker`s would awaken the same task. However, if this function
+    /// returns `true`, it is guaranteed that the `Waker`s will awaken the same task.
+    ///
+    /// This function is primarily used for optimization purposes.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "local_waker", issue = "none")]
+    pub fn will_wake(&self, other: &LocalWaker) -> bool {
+        self.waker == other.waker
+    }
+
+    /// Creates a new `LocalWaker` from [`RawWaker`].
+    ///
+    /// The behavior of the returned `LocalWaker` is undefined if the contract defined
+    /// in [`RawWaker`]'s and [`RawWakerVTable`]'s documentation is not upheld.
+    /// Unlike for [`Waker::from_raw`], the `RawWaker` need not be thread-safe,
+    /// since a `LocalWaker` never leaves the thread it was created on.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "local_waker", issue = "none")]
+    pub unsafe fn from_raw(waker: RawWaker) -> LocalWaker {
+        Self { waker }
+    }
+}
+
+#[unstable(feature = "local_waker", issue = "none")]
+impl Clone for LocalWaker {
+    #[inline]
+    fn clone(&self) -> Self {
+        LocalWaker {
+            // SAFETY: This is safe because `LocalWaker::from_raw` is the only way
+            // to initialize `clone` and `data` requiring the user to acknowledge
+            // that the contract of [`RawWaker`] is upheld.
+            waker: unsafe { (self.waker.vtable.clone)(self.waker.data) },
+        }
+    }
+}
+
+#[unstable(feature = "local_waker", issue = "none")]
+impl Drop for LocalWaker {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: This is safe because `LocalWaker::from_raw` is the only way
+        // to initialize `drop` and `data` requiring the user to acknowledge
+        // that the contract of `RawWaker` is upheld.
+        unsafe { (self.waker.vtable.drop)(self.waker.data) }
+    }
+}
+
+#[unstable(feature = "local_waker", issue = "none")]
+impl fmt::Debug for LocalWaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vtable_ptr = self.waker.vtable as *const RawWakerVTable;
+        f.debug_struct("LocalWaker")
+            .field("data", &self.waker.data)
+            .field("vtable", &vtable_ptr)
+            .finish()
+    }
+}
@@ -581,6 +581,34 @@ pub const fn saturating_sub(self, rhs: Duration) -> Duration {
         }
     }
 
+    /// Computes the absolute difference between `self` and `other`.
+    ///
+    /// Unlike subtraction, this never panics or saturates: it is always the
+    /// distance between the two durations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_abs_diff)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(100, 0).abs_diff(Duration::new(80, 0)), Duration::new(20, 0));
+    /// assert_eq!(Duration::new(80, 0).abs_diff(Duration::new(100, 0)), Duration::new(20, 0));
+    /// ```
+    #[unstable(feature = "duration_abs_diff", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn abs_diff(self, other: Duration) -> Duration {
+        match self.checked_sub(other) {
+            Some(res) => res,
+            None => match other.checked_sub(self) {
+                Some(res) => res,
+                None => Duration::ZERO,
+            },
+        }
+    }
+
     /// Checked `Duration` multiplication. Computes `self * other`, returning
     /// [`None`] if overflow occurred.
     ///
@@ -923,6 +951,101 @@ pub const fn div_f32(self, rhs: f32) -> Duration {
         Duration::from_secs_f32(self.as_secs_f32() / rhs)
     }
 
+    /// Checked multiplication by `f64`. Computes `self * rhs`, returning
+    /// [`None`] if the result is not finite, negative or overflows `Duration`.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_checked_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.checked_mul_f64(3.14), Some(Duration::new(8, 478_000_000)));
+    /// assert_eq!(dur.checked_mul_f64(-1.0), None);
+    /// assert_eq!(Duration::MAX.checked_mul_f64(2.0), None);
+    /// ```
+    #[unstable(feature = "duration_checked_float", issue = "83400")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_mul_f64(self, rhs: f64) -> Option<Duration> {
+        match Duration::try_from_secs_f64(rhs * self.as_secs_f64()) {
+            Ok(res) => Some(res),
+            Err(_) => None,
+        }
+    }
+
+    /// Checked multiplication by `f32`. Computes `self * rhs`, returning
+    /// [`None`] if the result is not finite, negative or overflows `Duration`.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_checked_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.checked_mul_f32(3.14), Some(Duration::new(8, 478_000_640)));
+    /// assert_eq!(dur.checked_mul_f32(f32::NAN), None);
+    /// ```
+    #[unstable(feature = "duration_checked_float", issue = "83400")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_mul_f32(self, rhs: f32) -> Option<Duration> {
+        match Duration::try_from_secs_f32(rhs * self.as_secs_f32()) {
+            Ok(res) => Some(res),
+            Err(_) => None,
+        }
+    }
+
+    /// Checked division by `f64`. Computes `self / rhs`, returning [`None`]
+    /// if the result is not finite, negative or overflows `Duration`, which
+    /// includes dividing a nonzero duration by zero.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_checked_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.checked_div_f64(3.14), Some(Duration::new(0, 859_872_611)));
+    /// assert_eq!(dur.checked_div_f64(0.0), None);
+    /// ```
+    #[unstable(feature = "duration_checked_float", issue = "83400")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_div_f64(self, rhs: f64) -> Option<Duration> {
+        match Duration::try_from_secs_f64(self.as_secs_f64() / rhs) {
+            Ok(res) => Some(res),
+            Err(_) => None,
+        }
+    }
+
+    /// Checked division by `f32`. Computes `self / rhs`, returning [`None`]
+    /// if the result is not finite, negative or overflows `Duration`, which
+    /// includes dividing a nonzero duration by zero.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_checked_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.checked_div_f32(3.14), Some(Duration::new(0, 859_872_576)));
+    /// assert_eq!(dur.checked_div_f32(-2.0), None);
+    /// ```
+    #[unstable(feature = "duration_checked_float", issue = "83400")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_div_f32(self, rhs: f32) -> Option<Duration> {
+        match Duration::try_from_secs_f32(self.as_secs_f32() / rhs) {
+            Ok(res) => Some(res),
+            Err(_) => None,
+        }
+    }
+
     /// Divide `Duration` by `Duration` and return `f64`.
     ///
     /// # Examples
@@ -25,7 +25,10 @@
 pub(crate) use unicode_data::alphabetic::lookup as Alphabetic;
 pub(crate) use unicode_data::cc::lookup as Cc;
 pub(crate) use unicode_data::grapheme_extend::lookup as Grapheme_Extend;
+pub(crate) use unicode_data::l::lookup as L;
 pub(crate) use unicode_data::lowercase::lookup as Lowercase;
+pub(crate) use unicode_data::m::lookup as M;
 pub(crate) use unicode_data::n::lookup as N;
+pub(crate) use unicode_data::p::lookup as P;
 pub(crate) use unicode_data::uppercase::lookup as Uppercase;
 pub(crate) use unicode_data::white_space::lookup as White_Space;
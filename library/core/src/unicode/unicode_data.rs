@@ -316,6 +316,76 @@ pub fn lookup(c: char) -> bool {
     }
 }
 
+#[rustfmt::skip]
+pub mod l {
+    static SHORT_OFFSET_RUNS: [u32; 50] = [
+        706, 33559113, 725620333, 805314326, 981478400, 1130377221, 1205875712, 1252019648,
+        1254139021, 1258333709, 1266735012, 1480653056, 1491139182, 1493237054, 1543570048,
+        1625360183, 1719733888, 1868635136, 2174820064, 2225152922, 2235641744, 2241934383,
+        2248229888, 2250327623, 2252433408, 2254531129, 2256629312, 2288093176, 2315357398,
+        2319560688, 2325852451, 2340532988, 2353118208, 2355221504, 2371999398, 2449596160,
+        2497831168, 2502025872, 2514610144, 2523000320, 2552365056, 2690819808, 2692921145,
+        2697121442, 2705517537, 2709714944, 2711812638, 2713911296, 2716013387, 2719224651,
+    ];
+    static OFFSETS: [u8; 1297] = [
+        65, 26, 6, 26, 47, 1, 10, 1, 4, 1, 5, 23, 1, 31, 1, 0, 4, 12, 14, 5, 7, 1, 1, 1, 129, 5, 1,
+        2, 2, 4, 1, 1, 6, 1, 1, 3, 1, 1, 1, 20, 1, 83, 1, 139, 8, 166, 1, 38, 2, 1, 6, 41, 71, 27,
+        4, 4, 45, 43, 35, 2, 1, 99, 1, 1, 15, 2, 7, 2, 10, 3, 2, 1, 16, 1, 1, 30, 29, 89, 11, 1, 24,
+        33, 9, 2, 4, 1, 5, 22, 4, 1, 9, 1, 3, 1, 23, 25, 7, 11, 5, 24, 1, 6, 17, 42, 58, 54, 3, 1,
+        18, 1, 7, 10, 15, 16, 4, 8, 2, 2, 2, 22, 1, 7, 1, 1, 3, 4, 3, 1, 16, 1, 13, 2, 1, 3, 14, 2,
+        10, 1, 8, 6, 4, 2, 2, 22, 1, 7, 1, 2, 1, 2, 1, 2, 31, 4, 1, 1, 19, 3, 16, 9, 1, 3, 1, 22, 1,
+        7, 1, 2, 1, 5, 3, 1, 18, 1, 15, 2, 23, 1, 11, 8, 2, 2, 2, 22, 1, 7, 1, 2, 1, 5, 3, 1, 30, 2,
+        1, 3, 15, 1, 17, 1, 1, 6, 3, 3, 1, 4, 3, 2, 1, 1, 1, 2, 3, 2, 3, 3, 3, 12, 22, 1, 52, 8, 1,
+        3, 1, 23, 1, 16, 3, 1, 26, 3, 2, 1, 2, 2, 30, 1, 4, 8, 1, 3, 1, 23, 1, 10, 1, 5, 3, 1, 31,
+        2, 1, 2, 15, 2, 17, 9, 1, 3, 1, 41, 2, 1, 16, 1, 5, 3, 8, 3, 24, 6, 5, 18, 3, 24, 1, 9, 1,
+        1, 2, 7, 58, 48, 1, 2, 12, 7, 58, 2, 1, 1, 1, 5, 1, 24, 1, 1, 1, 10, 1, 2, 9, 1, 2, 5, 1, 1,
+        21, 4, 32, 1, 63, 8, 1, 36, 27, 5, 115, 43, 20, 1, 16, 6, 4, 4, 3, 1, 3, 2, 7, 3, 4, 13, 12,
+        1, 17, 38, 1, 1, 5, 1, 2, 43, 1, 0, 1, 4, 2, 7, 1, 1, 1, 4, 2, 41, 1, 4, 2, 33, 1, 4, 2, 7,
+        1, 1, 1, 4, 2, 15, 1, 57, 1, 4, 2, 67, 37, 16, 16, 86, 2, 6, 3, 0, 2, 17, 1, 26, 5, 75, 6,
+        8, 7, 18, 13, 19, 14, 18, 14, 13, 1, 3, 15, 52, 35, 1, 4, 1, 67, 89, 7, 5, 2, 34, 1, 1, 5,
+        70, 10, 31, 49, 30, 2, 5, 11, 44, 4, 26, 54, 23, 9, 53, 82, 1, 93, 47, 17, 8, 54, 30, 13, 2,
+        10, 44, 26, 36, 41, 3, 10, 36, 2, 9, 7, 43, 2, 3, 41, 4, 1, 6, 1, 2, 3, 1, 5, 192, 64, 0, 2,
+        6, 2, 38, 2, 6, 2, 8, 1, 1, 1, 1, 1, 1, 1, 31, 2, 53, 1, 7, 1, 1, 3, 3, 1, 7, 3, 4, 2, 6, 4,
+        13, 5, 3, 1, 7, 116, 1, 13, 1, 16, 13, 101, 1, 4, 1, 2, 10, 1, 1, 3, 5, 6, 1, 1, 1, 1, 1, 1,
+        4, 1, 11, 2, 4, 5, 5, 4, 1, 52, 2, 0, 229, 6, 4, 3, 2, 12, 38, 1, 1, 5, 1, 2, 56, 7, 1, 16,
+        23, 9, 7, 1, 7, 1, 7, 1, 7, 1, 7, 1, 7, 1, 7, 1, 7, 80, 1, 0, 2, 42, 5, 5, 2, 4, 86, 6, 3,
+        1, 90, 1, 4, 5, 43, 1, 94, 17, 32, 48, 16, 0, 0, 64, 0, 67, 46, 2, 0, 3, 16, 10, 2, 20, 47,
+        16, 31, 2, 70, 49, 9, 2, 103, 2, 64, 5, 2, 1, 1, 1, 5, 24, 16, 1, 3, 1, 4, 1, 23, 29, 52,
+        14, 50, 62, 6, 3, 1, 1, 2, 11, 28, 10, 23, 25, 29, 7, 47, 28, 1, 16, 5, 1, 10, 10, 5, 1, 41,
+        23, 3, 1, 8, 20, 23, 3, 1, 3, 50, 1, 1, 3, 2, 2, 5, 2, 1, 1, 1, 24, 3, 2, 11, 7, 3, 12, 6,
+        2, 6, 2, 6, 9, 7, 1, 7, 1, 43, 1, 14, 6, 115, 29, 0, 12, 23, 4, 49, 0, 0, 2, 106, 38, 7, 12,
+        5, 5, 1, 1, 10, 1, 13, 1, 5, 1, 1, 1, 2, 1, 2, 1, 108, 33, 0, 18, 64, 2, 54, 40, 12, 116, 5,
+        1, 135, 36, 26, 6, 26, 11, 89, 3, 6, 2, 6, 2, 6, 2, 3, 35, 12, 1, 26, 1, 19, 1, 2, 1, 15, 2,
+        14, 34, 123, 0, 29, 3, 49, 47, 32, 13, 20, 1, 8, 6, 38, 10, 30, 2, 36, 4, 8, 48, 158, 18,
+        36, 4, 36, 4, 40, 8, 52, 12, 11, 1, 15, 1, 7, 1, 2, 1, 11, 1, 15, 1, 7, 1, 2, 67, 0, 9, 22,
+        10, 8, 24, 6, 1, 42, 1, 9, 69, 6, 2, 1, 1, 44, 1, 2, 3, 1, 2, 23, 10, 23, 9, 31, 65, 19, 1,
+        2, 10, 22, 10, 26, 70, 56, 6, 2, 64, 1, 15, 4, 1, 3, 1, 29, 42, 29, 3, 29, 35, 8, 1, 28, 27,
+        54, 10, 22, 10, 19, 13, 18, 110, 73, 55, 51, 13, 51, 13, 36, 0, 42, 6, 2, 78, 29, 10, 1, 8,
+        22, 42, 18, 46, 21, 27, 23, 12, 53, 57, 2, 2, 1, 13, 45, 32, 25, 26, 36, 29, 1, 2, 1, 8, 35,
+        3, 1, 12, 48, 14, 4, 21, 1, 1, 1, 35, 18, 1, 25, 84, 7, 1, 1, 1, 4, 1, 15, 1, 10, 7, 47, 38,
+        8, 2, 2, 2, 22, 1, 7, 1, 2, 1, 5, 3, 1, 18, 1, 12, 5, 158, 53, 18, 4, 20, 3, 30, 48, 20, 2,
+        1, 1, 184, 47, 41, 4, 36, 48, 20, 1, 59, 43, 13, 1, 71, 27, 37, 7, 185, 44, 116, 64, 31, 8,
+        2, 1, 2, 8, 1, 2, 1, 24, 15, 1, 1, 1, 94, 8, 2, 39, 16, 1, 1, 1, 28, 1, 10, 40, 7, 1, 21, 1,
+        11, 46, 19, 1, 18, 73, 0, 9, 1, 37, 17, 1, 49, 30, 112, 7, 1, 2, 1, 38, 21, 1, 25, 6, 1, 2,
+        1, 32, 14, 1, 0, 19, 189, 1, 79, 0, 230, 196, 0, 97, 15, 0, 0, 0, 0, 0, 7, 31, 17, 79, 17,
+        30, 18, 48, 16, 4, 31, 21, 5, 19, 0, 64, 128, 75, 5, 1, 66, 13, 64, 2, 1, 1, 28, 0, 8, 0,
+        42, 9, 0, 4, 1, 7, 1, 2, 1, 0, 45, 3, 17, 4, 8, 0, 0, 107, 5, 13, 3, 9, 7, 10, 0, 85, 1, 71,
+        1, 2, 2, 1, 2, 2, 2, 4, 1, 12, 1, 1, 1, 7, 1, 65, 1, 4, 2, 8, 1, 7, 1, 28, 1, 4, 1, 5, 1, 1,
+        3, 7, 1, 0, 2, 25, 1, 25, 1, 31, 1, 25, 1, 31, 1, 25, 1, 31, 1, 25, 1, 31, 1, 25, 1, 8, 0,
+        31, 0, 45, 10, 7, 16, 1, 0, 30, 18, 44, 0, 7, 1, 4, 1, 2, 1, 15, 1, 197, 59, 68, 7, 1, 0, 4,
+        1, 27, 1, 2, 1, 1, 2, 1, 1, 10, 1, 4, 1, 1, 1, 1, 6, 1, 4, 1, 1, 1, 1, 1, 1, 3, 1, 2, 1, 1,
+        2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 1, 2, 4, 1, 7, 1, 4, 1, 4, 1, 1, 1, 10, 1, 17, 5, 3,
+        1, 5, 1, 17, 0, 0, 32, 0, 7, 222, 2, 0, 14, 0, 0, 0, 0, 0, 0,
+    ];
+    pub fn lookup(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
 #[rustfmt::skip]
 pub mod lowercase {
     static BITSET_CHUNKS_MAP: [u8; 123] = [
@@ -420,6 +490,48 @@ pub fn lookup(c: char) -> bool {
     }
 }
 
+#[rustfmt::skip]
+pub mod m {
+    static SHORT_OFFSET_RUNS: [u32; 31] = [
+        768, 2098307, 6292881, 10490717, 497030930, 501227728, 627059951, 631255082, 643868271,
+        652257282, 669055774, 782302720, 786498045, 794888705, 807472420, 832638635, 836835372,
+        1000414255, 1063329523, 1113680624, 1117876047, 1126284445, 1147260672, 1151455589,
+        1159846400, 1185013760, 1210179888, 1231151790, 1235347664, 1244528896, 1254031856,
+    ];
+    static OFFSETS: [u8; 599] = [
+        0, 112, 0, 7, 0, 45, 1, 1, 1, 2, 1, 2, 1, 1, 72, 11, 48, 21, 16, 1, 101, 7, 2, 6, 2, 2, 1,
+        4, 35, 1, 30, 27, 91, 11, 58, 9, 9, 1, 24, 4, 1, 9, 1, 3, 1, 5, 43, 3, 60, 8, 42, 24, 1, 33,
+        54, 3, 1, 18, 1, 7, 10, 2, 29, 3, 56, 1, 1, 7, 2, 2, 2, 3, 9, 1, 10, 2, 26, 1, 2, 3, 56, 1,
+        1, 5, 4, 2, 2, 3, 3, 1, 30, 2, 3, 1, 11, 3, 56, 1, 1, 8, 1, 3, 1, 3, 20, 2, 22, 6, 1, 3, 56,
+        1, 1, 7, 2, 2, 2, 3, 7, 3, 10, 2, 30, 1, 59, 5, 3, 3, 1, 4, 9, 1, 40, 5, 55, 1, 1, 7, 1, 3,
+        1, 4, 7, 2, 11, 2, 29, 3, 56, 1, 1, 7, 1, 3, 1, 4, 7, 2, 11, 2, 28, 4, 55, 2, 1, 7, 1, 3, 1,
+        4, 9, 1, 10, 2, 29, 3, 70, 1, 4, 6, 1, 1, 1, 8, 18, 2, 61, 1, 2, 7, 12, 8, 98, 1, 2, 9, 11,
+        6, 74, 2, 27, 1, 1, 1, 1, 1, 4, 2, 49, 20, 1, 2, 5, 11, 1, 36, 9, 1, 100, 20, 23, 4, 4, 3,
+        1, 3, 2, 7, 3, 4, 13, 12, 1, 1, 10, 4, 0, 3, 0, 4, 28, 3, 29, 2, 30, 2, 64, 32, 9, 1, 45, 3,
+        1, 1, 117, 2, 34, 1, 118, 12, 4, 12, 219, 5, 57, 10, 1, 29, 2, 1, 48, 31, 49, 5, 47, 17, 38,
+        9, 12, 3, 30, 13, 56, 14, 48, 20, 152, 3, 1, 21, 4, 1, 6, 1, 2, 3, 198, 64, 0, 33, 0, 3,
+        141, 1, 96, 32, 0, 6, 105, 2, 0, 4, 1, 10, 32, 2, 80, 2, 0, 1, 3, 1, 4, 1, 23, 5, 4, 1, 83,
+        2, 50, 18, 26, 18, 13, 1, 38, 8, 25, 13, 44, 4, 47, 14, 36, 1, 67, 14, 12, 1, 8, 2, 45, 3,
+        50, 1, 1, 3, 2, 2, 5, 2, 1, 1, 41, 5, 5, 2, 236, 8, 1, 2, 0, 1, 0, 16, 16, 16, 0, 1, 226, 1,
+        149, 5, 0, 3, 1, 2, 5, 4, 40, 3, 4, 1, 165, 2, 0, 4, 0, 2, 153, 11, 49, 4, 122, 3, 53, 15,
+        41, 1, 2, 2, 10, 4, 45, 11, 7, 1, 61, 3, 36, 14, 16, 2, 44, 1, 12, 3, 48, 14, 8, 4, 1, 2,
+        92, 12, 6, 1, 160, 12, 21, 4, 55, 2, 1, 7, 2, 2, 2, 3, 9, 1, 10, 2, 2, 7, 3, 5, 192, 18, 23,
+        1, 81, 20, 235, 7, 2, 9, 27, 2, 82, 17, 106, 13, 101, 15, 0, 15, 245, 6, 1, 2, 2, 4, 1, 1,
+        1, 2, 141, 7, 2, 7, 3, 1, 28, 10, 40, 7, 1, 4, 8, 1, 9, 11, 46, 16, 0, 8, 1, 8, 82, 22, 1,
+        14, 122, 6, 3, 1, 1, 2, 1, 7, 1, 1, 66, 5, 1, 2, 1, 5, 0, 4, 0, 5, 59, 7, 0, 1, 1, 55, 7, 4,
+        81, 1, 11, 2, 0, 2, 0, 46, 2, 23, 0, 5, 3, 6, 8, 8, 2, 7, 30, 4, 148, 3, 0, 55, 4, 50, 8, 1,
+        14, 1, 22, 5, 1, 15, 0, 7, 1, 17, 2, 7, 1, 2, 1, 5, 0, 7, 0, 1, 61, 4, 0, 7, 109, 7, 0, 240,
+        0,
+    ];
+    pub fn lookup(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
 #[rustfmt::skip]
 pub mod n {
     static SHORT_OFFSET_RUNS: [u32; 38] = [
@@ -452,6 +564,41 @@ pub fn lookup(c: char) -> bool {
     }
 }
 
+#[rustfmt::skip]
+pub mod p {
+    static SHORT_OFFSET_RUNS: [u32; 38] = [
+        894, 65013082, 73402724, 144706679, 165678580, 174068576, 220206702, 228596036, 257957904,
+        304096008, 329262952, 337652099, 350235897, 362819585, 388015358, 417375757, 421570676,
+        438369598, 488702208, 560006047, 564200815, 572590167, 576784976, 589369005, 610341963,
+        669063492, 711007297, 731979511, 740368383, 744563824, 748761073, 752970350, 757165719,
+        773943266, 778157215, 782359175, 786557278, 791865696,
+    ];
+    static OFFSETS: [u8; 379] = [
+        33, 3, 1, 6, 1, 4, 10, 2, 3, 2, 26, 3, 1, 1, 27, 1, 1, 1, 35, 1, 5, 1, 3, 1, 10, 2, 3, 1,
+        3, 1, 0, 1, 8, 1, 0, 6, 41, 2, 51, 1, 1, 1, 2, 1, 2, 1, 44, 2, 20, 2, 1, 2, 13, 1, 1, 3, 74,
+        4, 102, 1, 43, 14, 233, 3, 54, 15, 31, 1, 0, 2, 10, 1, 140, 1, 120, 1, 121, 1, 0, 1, 12, 1,
+        0, 1, 90, 1, 10, 2, 168, 15, 1, 1, 37, 4, 71, 1, 74, 5, 4, 2, 111, 6, 171, 1, 0, 9, 151, 1,
+        0, 1, 44, 2, 78, 3, 71, 2, 157, 3, 1, 3, 37, 11, 0, 2, 216, 2, 128, 7, 1, 6, 172, 7, 28, 2,
+        125, 4, 59, 5, 62, 2, 64, 8, 11, 1, 0, 24, 8, 20, 1, 13, 1, 12, 30, 2, 14, 2, 0, 4, 29, 2,
+        0, 14, 79, 2, 31, 10, 0, 22, 63, 4, 32, 2, 0, 4, 1, 2, 112, 1, 143, 47, 1, 32, 2, 12, 0, 3,
+        4, 10, 2, 12, 16, 1, 12, 1, 98, 1, 90, 1, 0, 2, 0, 3, 99, 1, 10, 1, 115, 6, 0, 4, 86, 2, 40,
+        3, 1, 1, 49, 2, 47, 1, 97, 13, 16, 2, 124, 4, 126, 2, 16, 2, 249, 1, 0, 2, 208, 10, 22, 35,
+        1, 14, 1, 1, 4, 1, 1, 2, 149, 3, 1, 6, 1, 4, 10, 2, 3, 2, 26, 3, 1, 1, 27, 1, 1, 1, 1, 7, 0,
+        3, 0, 1, 48, 1, 0, 1, 0, 1, 199, 1, 31, 1, 0, 9, 38, 1, 112, 7, 66, 7, 89, 4, 0, 1, 167, 5,
+        44, 4, 189, 7, 109, 2, 1, 4, 126, 4, 48, 2, 79, 4, 4, 1, 13, 1, 1, 3, 88, 6, 107, 1, 0, 5,
+        10, 2, 1, 1, 104, 1, 250, 23, 105, 3, 28, 13, 76, 1, 130, 3, 252, 1, 0, 3, 155, 1, 92, 8,
+        83, 3, 1, 5, 0, 5, 42, 2, 0, 2, 0, 1, 0, 5, 0, 2, 0, 2, 133, 1, 65, 5, 8, 1, 0, 4, 0, 1, 0,
+        1, 0, 5, 0, 2, 0,
+    ];
+    pub fn lookup(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
 #[rustfmt::skip]
 pub mod uppercase {
     static BITSET_CHUNKS_MAP: [u8; 125] = [
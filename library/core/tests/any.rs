@@ -127,3 +127,29 @@ fn type_name_of_val<T>(_: T) -> &'static str {
 
     assert_ne!(type_name_of_val(Velocity), type_name_of_val(Velocity(0.0, -9.8)),);
 }
+
+#[test]
+fn provider_requests() {
+    struct SomeConcreteType {
+        name: &'static str,
+        code: u32,
+    }
+
+    impl Provider for SomeConcreteType {
+        fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+            demand
+                .provide_ref::<str>(self.name)
+                .provide_value::<u32, _>(|| self.code)
+                // Shadowed by the earlier `u32`, so this is never called.
+                .provide_value::<u32, _>(|| unreachable!());
+        }
+    }
+
+    let value = SomeConcreteType { name: "hello", code: 7 };
+    let provider = &value as &dyn Provider;
+
+    assert_eq!(request_ref::<str, _>(provider), Some("hello"));
+    assert_eq!(request_value::<u32, _>(provider), Some(7));
+    assert_eq!(request_value::<u8, _>(provider), None);
+    assert_eq!(request_ref::<u32, _>(provider), None);
+}
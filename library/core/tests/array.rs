@@ -487,6 +487,22 @@ fn array_rsplit_array_mut() {
     }
 }
 
+#[test]
+fn array_as_chunks_exact() {
+    let mut v = [1, 2, 3, 4, 5, 6];
+    assert_eq!(v.as_chunks_exact::<1>(), &[[1], [2], [3], [4], [5], [6]]);
+    assert_eq!(v.as_chunks_exact::<3>(), &[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(v.as_chunks_exact::<6>(), &[[1, 2, 3, 4, 5, 6]]);
+
+    for chunk in v.as_chunks_exact_mut::<2>() {
+        chunk.reverse();
+    }
+    assert_eq!(v, [2, 1, 4, 3, 6, 5]);
+
+    let empty: [u8; 0] = [];
+    assert!(empty.as_chunks_exact::<4>().is_empty());
+}
+
 #[should_panic]
 #[test]
 fn array_split_array_ref_out_of_bounds() {
@@ -1,3 +1,4 @@
+use core::ascii::{self, ByteSet};
 use core::char::from_u32;
 
 #[test]
@@ -112,6 +113,22 @@ fn test_eq_ignore_ascii_case() {
     }
 }
 
+#[test]
+fn test_eq_ignore_ascii_case_long() {
+    let lower: Vec<u8> = (0..100).map(|i| b'a' + i % 26).collect();
+    let upper = lower.to_ascii_uppercase();
+    for len in 0..lower.len() {
+        assert!(lower[..len].eq_ignore_ascii_case(&upper[..len]));
+        // A mismatch at every position, inside a chunk or in the remainder.
+        for i in 0..len {
+            let mut other = upper[..len].to_vec();
+            other[i] = b'@';
+            assert!(!lower[..len].eq_ignore_ascii_case(&other));
+        }
+    }
+    assert!(!lower.eq_ignore_ascii_case(&upper[1..]));
+}
+
 #[test]
 fn inference_works() {
     let x = "a".to_string();
@@ -461,3 +478,72 @@ pub fn run() {
         is_ascii_control      => [false, false, false, false, false];
     }
 }
+
+#[test]
+fn test_byte_set() {
+    const IDENT: ByteSet = ByteSet::ASCII_ALPHANUMERIC.with(b'_');
+    const _: () = assert!(IDENT.contains(b'_') && !IDENT.contains(b'-'));
+
+    for b in 0..=u8::MAX {
+        assert_eq!(ByteSet::ASCII.contains(b), b.is_ascii());
+        assert_eq!(ByteSet::ASCII_ALPHABETIC.contains(b), b.is_ascii_alphabetic());
+        assert_eq!(ByteSet::ASCII_DIGIT.contains(b), b.is_ascii_digit());
+        assert_eq!(ByteSet::ASCII_WHITESPACE.contains(b), b.is_ascii_whitespace());
+        assert_eq!(ByteSet::ASCII_CONTROL.contains(b), b.is_ascii_control());
+        assert_eq!(IDENT.contains(b), b.is_ascii_alphanumeric() || b == b'_');
+    }
+
+    assert!(ByteSet::EMPTY.is_empty());
+    assert_eq!(ByteSet::ALL.len(), 256);
+    assert_eq!(ByteSet::ASCII.len(), 128);
+    assert_eq!(ByteSet::ASCII.complement().len(), 128);
+    assert_eq!(ByteSet::default(), ByteSet::EMPTY);
+    assert_eq!(ByteSet::EMPTY.complement(), ByteSet::ALL);
+
+    assert_eq!(ByteSet::from_range(b'0', b'9'), ByteSet::ASCII_DIGIT);
+    assert_eq!(ByteSet::from_range(b'9', b'0'), ByteSet::EMPTY);
+    assert_eq!(ByteSet::from_range(0, u8::MAX), ByteSet::ALL);
+    assert_eq!(ByteSet::ASCII_UPPERCASE.union(ByteSet::ASCII_LOWERCASE), ByteSet::ASCII_ALPHABETIC);
+    assert_eq!(
+        ByteSet::ASCII_ALPHANUMERIC.intersection(ByteSet::ASCII_HEXDIGIT),
+        ByteSet::ASCII_HEXDIGIT
+    );
+    assert_eq!(
+        ByteSet::ASCII_ALPHANUMERIC.difference(ByteSet::ASCII_ALPHABETIC),
+        ByteSet::ASCII_DIGIT
+    );
+    assert_eq!(ByteSet::from_bytes(b"abc").without(b'b'), ByteSet::from_bytes(b"ca"));
+
+    let table = ByteSet::ASCII_HEXDIGIT.to_table();
+    assert!(table.iter().enumerate().all(|(b, &hex)| hex == (b as u8).is_ascii_hexdigit()));
+
+    assert_eq!(format!("{:?}", ByteSet::from_bytes(b"b\na")), "{'\\n', 'a', 'b'}");
+}
+
+#[test]
+fn test_ascii_char() {
+    const A: Option<ascii::Char> = ascii::Char::from_u8(b'A');
+    assert_eq!(A.map(ascii::Char::to_u8), Some(b'A'));
+
+    for b in 0..=u8::MAX {
+        match ascii::Char::from_u8(b) {
+            Some(c) => {
+                assert!(b.is_ascii());
+                assert_eq!(c.to_u8(), b);
+                assert_eq!(c.to_char(), b as char);
+                assert_eq!(c.as_str().as_bytes(), [b]);
+                assert_eq!((b as char).as_ascii(), Some(c));
+            }
+            None => assert!(!b.is_ascii()),
+        }
+    }
+    assert_eq!('\u{10ffff}'.as_ascii(), None);
+
+    let c = 'x'.as_ascii().unwrap();
+    assert_eq!(u8::from(c), b'x');
+    assert_eq!(char::from(c), 'x');
+    assert_eq!(format!("{:>3}", c), "  x");
+    assert_eq!(format!("{:?}", '\n'.as_ascii().unwrap()), "'\\n'");
+    assert!(c < 'y'.as_ascii().unwrap());
+    assert_eq!(core::mem::size_of::<Option<ascii::Char>>(), 1);
+}
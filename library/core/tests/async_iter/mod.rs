@@ -0,0 +1,27 @@
+use core::async_iter::{self, AsyncIterator};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::sync::Arc;
+use std::task::{Wake, Waker};
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+#[test]
+fn into_async_iter() {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    let mut iter = async_iter::from_iter(vec![1, 2, 3]);
+
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(Pin::new(&mut iter).poll_next(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(Pin::new(&mut iter).poll_next(&mut cx), Poll::Ready(Some(2)));
+
+    // Boxed and borrowed async iterators forward to the inner one.
+    let mut boxed: Box<dyn AsyncIterator<Item = i32> + Unpin + '_> = Box::new(&mut iter);
+    assert_eq!(Pin::new(&mut boxed).poll_next(&mut cx), Poll::Ready(Some(3)));
+    assert_eq!(Pin::new(&mut boxed).poll_next(&mut cx), Poll::Ready(None));
+}
@@ -127,6 +127,90 @@ fn int_max() {
     assert_eq!(x.load(SeqCst), 0xf731);
 }
 
+#[test]
+fn ptr_add_null() {
+    let atom = AtomicPtr::<i64>::new(core::ptr::null_mut());
+    assert_eq!(atom.fetch_ptr_add(1, SeqCst) as usize, 0);
+    assert_eq!(atom.load(SeqCst) as usize, 8);
+
+    assert_eq!(atom.fetch_byte_add(1, SeqCst) as usize, 8);
+    assert_eq!(atom.load(SeqCst) as usize, 9);
+
+    assert_eq!(atom.fetch_ptr_sub(1, SeqCst) as usize, 9);
+    assert_eq!(atom.load(SeqCst) as usize, 1);
+
+    assert_eq!(atom.fetch_byte_sub(1, SeqCst) as usize, 1);
+    assert_eq!(atom.load(SeqCst) as usize, 0);
+}
+
+#[test]
+fn ptr_add_data() {
+    let num = 0i64;
+    let n = &num as *const i64 as *mut _;
+    let atom = AtomicPtr::<i64>::new(n);
+    assert_eq!(atom.fetch_ptr_add(1, SeqCst), n);
+    assert_eq!(atom.load(SeqCst), n.wrapping_add(1));
+
+    assert_eq!(atom.fetch_ptr_sub(1, SeqCst), n.wrapping_add(1));
+    assert_eq!(atom.load(SeqCst), n);
+    let bytes_from_n = |b| n.cast::<u8>().wrapping_add(b).cast::<i64>();
+
+    assert_eq!(atom.fetch_byte_add(1, SeqCst), n);
+    assert_eq!(atom.load(SeqCst), bytes_from_n(1));
+
+    assert_eq!(atom.fetch_byte_add(5, SeqCst), bytes_from_n(1));
+    assert_eq!(atom.load(SeqCst), bytes_from_n(6));
+
+    assert_eq!(atom.fetch_byte_sub(1, SeqCst), bytes_from_n(6));
+    assert_eq!(atom.load(SeqCst), bytes_from_n(5));
+
+    assert_eq!(atom.fetch_byte_sub(5, SeqCst), bytes_from_n(5));
+    assert_eq!(atom.load(SeqCst), n);
+}
+
+#[test]
+fn ptr_bitops() {
+    let atom = AtomicPtr::<i64>::new(core::ptr::null_mut());
+    assert_eq!(atom.fetch_or(0b0111, SeqCst) as usize, 0);
+    assert_eq!(atom.load(SeqCst) as usize, 0b0111);
+
+    assert_eq!(atom.fetch_and(0b1101, SeqCst) as usize, 0b0111);
+    assert_eq!(atom.load(SeqCst) as usize, 0b0101);
+
+    assert_eq!(atom.fetch_xor(0b1111, SeqCst) as usize, 0b0101);
+    assert_eq!(atom.load(SeqCst) as usize, 0b1010);
+}
+
+#[test]
+fn ptr_bitops_tagging() {
+    #[repr(align(16))]
+    struct Tagme(u128);
+
+    let tagme = Tagme(1000);
+    let ptr = &tagme as *const Tagme as *mut Tagme;
+    let atom: AtomicPtr<Tagme> = AtomicPtr::new(ptr);
+
+    const MASK_TAG: usize = 0b1111;
+    const MASK_PTR: usize = !MASK_TAG;
+
+    assert_eq!(ptr as usize & MASK_TAG, 0);
+
+    assert_eq!(atom.fetch_or(0b0111, SeqCst), ptr);
+    assert_eq!(atom.load(SeqCst) as usize, ptr as usize | 0b0111);
+
+    assert_eq!(atom.fetch_and(MASK_PTR | 0b0010, SeqCst) as usize, ptr as usize | 0b0111);
+    assert_eq!(atom.load(SeqCst) as usize, ptr as usize | 0b0010);
+
+    assert_eq!(atom.fetch_xor(0b1011, SeqCst) as usize, ptr as usize | 0b0010);
+    assert_eq!(atom.load(SeqCst) as usize, ptr as usize | 0b1001);
+
+    assert_eq!(atom.fetch_and(MASK_PTR, SeqCst) as usize, ptr as usize | 0b1001);
+    assert_eq!(atom.load(SeqCst), ptr);
+
+    // The untagged pointer still points at the original allocation.
+    assert_eq!(unsafe { (*atom.load(SeqCst)).0 }, 1000);
+}
+
 static S_FALSE: AtomicBool = AtomicBool::new(false);
 static S_TRUE: AtomicBool = AtomicBool::new(true);
 static S_INT: AtomicIsize = AtomicIsize::new(0);
@@ -161,6 +245,36 @@ fn atomic_access_bool() {
     }
 }
 
+#[test]
+fn atomic_as_mut_ptr() {
+    let atomic = AtomicUsize::new(1);
+    unsafe { *atomic.as_mut_ptr() = 2 };
+    assert_eq!(atomic.load(SeqCst), 2);
+
+    let atomic = AtomicBool::new(false);
+    unsafe { *atomic.as_mut_ptr() = true };
+    assert!(atomic.load(SeqCst));
+
+    let mut a = 1;
+    let mut b = 2;
+    let atomic = AtomicPtr::new(&mut a as *mut i32);
+    unsafe { *atomic.as_mut_ptr() = &mut b };
+    assert_eq!(unsafe { *atomic.load(SeqCst) }, 2);
+}
+
+#[test]
+fn atomic_fetch_update() {
+    let x = AtomicUsize::new(7);
+    assert_eq!(x.fetch_update(SeqCst, SeqCst, |_| None), Err(7));
+    assert_eq!(x.fetch_update(SeqCst, SeqCst, |x| Some(x + 1)), Ok(7));
+    assert_eq!(x.fetch_update(SeqCst, SeqCst, |x| x.checked_sub(10)), Err(8));
+    assert_eq!(x.load(SeqCst), 8);
+
+    let b = AtomicBool::new(false);
+    assert_eq!(b.fetch_update(SeqCst, SeqCst, |b| Some(!b)), Ok(false));
+    assert!(b.load(SeqCst));
+}
+
 #[test]
 fn atomic_alignment() {
     use std::mem::{align_of, size_of};
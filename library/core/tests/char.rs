@@ -180,6 +180,36 @@ fn test_is_numeric() {
     assert!(!'Q'.is_numeric());
 }
 
+#[test]
+fn test_general_category() {
+    assert!('a'.is_letter());
+    assert!('Ǆ'.is_letter());
+    assert!('ʰ'.is_letter());
+    assert!('京'.is_letter());
+    assert!(!'Ⅻ'.is_letter());
+    assert!(!'\u{301}'.is_letter());
+    assert!(!'1'.is_letter());
+
+    assert!('\u{301}'.is_mark());
+    assert!('\u{903}'.is_mark());
+    assert!('\u{20dd}'.is_mark());
+    assert!(!'a'.is_mark());
+    assert!(!'\u{0}'.is_mark());
+
+    assert!('-'.is_punctuation());
+    assert!('('.is_punctuation());
+    assert!('»'.is_punctuation());
+    assert!('‿'.is_punctuation());
+    assert!(!'$'.is_punctuation());
+    assert!(!'~'.is_punctuation());
+    assert!(!'a'.is_punctuation());
+
+    for c in '\0'..='\x7f' {
+        assert_eq!(c.is_letter(), c.is_ascii_alphabetic());
+        assert!(!c.is_mark());
+    }
+}
+
 #[test]
 fn test_escape_debug() {
     fn string(c: char) -> String {
@@ -272,6 +302,21 @@ fn check(input: char, expect: &[u8]) {
     check('\u{1f4a9}', &[0xf0, 0x9f, 0x92, 0xa9]);
 }
 
+#[test]
+fn test_max_len() {
+    // `char` is the `core::char` module in this file, so name the type explicitly.
+    let chars =
+        ['\0', '\u{7f}', '\u{80}', '\u{7ff}', '\u{800}', '\u{ffff}', '\u{10000}', '\u{10ffff}'];
+    let mut max_utf8 = 0;
+    let mut max_utf16 = 0;
+    for c in chars {
+        max_utf8 = max_utf8.max(c.len_utf8());
+        max_utf16 = max_utf16.max(c.len_utf16());
+    }
+    assert_eq!(<char>::MAX_UTF8_LEN, max_utf8);
+    assert_eq!(<char>::MAX_UTF16_LEN, max_utf16);
+}
+
 #[test]
 fn test_encode_utf16() {
     fn check(input: char, expect: &[u16]) {
@@ -131,6 +131,19 @@ fn ordering_const() {
 
     const THEN: Ordering = Equal.then(ORDERING);
     assert_eq!(THEN, Greater);
+
+    const fn less() -> Ordering {
+        Less
+    }
+
+    const THEN_WITH: Ordering = Equal.then_with(less);
+    assert_eq!(THEN_WITH, Less);
+
+    const THEN_WITH_SKIPPED: Ordering = ORDERING.then_with(less);
+    assert_eq!(THEN_WITH_SKIPPED, Greater);
+
+    const IS_LE: bool = ORDERING.reverse().is_le();
+    assert!(IS_LE);
 }
 
 #[test]
@@ -1,4 +1,4 @@
-use std::future::{join, Future};
+use std::future::{self, join, Future};
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll, Wake};
@@ -99,6 +99,34 @@ fn join_is_able_to_handle_temporaries() {
     }
 }
 
+#[test]
+fn test_ready_into_inner() {
+    assert_eq!(future::ready(7).into_inner(), 7);
+}
+
+#[test]
+fn test_poll_fn_with_ready_macro() {
+    use std::task::ready;
+
+    // `ready!` returns early from the closure while the inner future is pending.
+    let mut inner = poll_n(3, 2);
+    let mut outer = Box::pin(future::poll_fn(move |cx| {
+        let val = ready!(Pin::new(&mut inner).poll(cx));
+        Poll::Ready(val * 2)
+    }));
+
+    let waker = Arc::new(NoopWaker).into();
+    let mut cx = Context::from_waker(&waker);
+    assert_eq!(outer.as_mut().poll(&mut cx), Poll::Pending);
+    assert_eq!(outer.as_mut().poll(&mut cx), Poll::Ready(6));
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
 fn block_on(fut: impl Future) {
     struct Waker;
     impl Wake for Waker {
@@ -278,3 +278,10 @@ fn next_back(&mut self) -> Option<i32> {
     assert_eq!(CrazyIterator::new().chain(0..10).rev().last(), Some(0));
     assert!((0..10).chain(CrazyIterator::new()).rev().any(|i| i == 0));
 }
+
+#[test]
+fn test_chain_default() {
+    let mut it: Chain<core::slice::Iter<'_, u8>, Empty<&u8>> = Default::default();
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+}
@@ -0,0 +1,30 @@
+use core::iter::*;
+
+#[test]
+fn test_filter_ok() {
+    let xs = [Ok(1), Ok(2), Err("a"), Ok(3), Ok(4), Err("b")];
+    let evens = |it: &mut dyn Iterator<Item = Result<i32, &'static str>>| {
+        it.filter_ok(|x| x % 2 == 0).collect::<Vec<_>>()
+    };
+    assert_eq!(evens(&mut xs.iter().copied()), [Ok(2), Err("a"), Ok(4), Err("b")]);
+    assert_eq!(evens(&mut xs.iter().copied().rev()), [Err("b"), Ok(4), Err("a"), Ok(2)]);
+
+    let mut it = xs.iter().copied().filter_ok(|x| x % 2 == 1);
+    assert_eq!(it.size_hint(), (0, Some(6)));
+    assert_eq!(it.next(), Some(Ok(1)));
+    assert_eq!(it.next_back(), Some(Err("b")));
+    assert_eq!(it.next_back(), Some(Ok(3)));
+    assert_eq!(it.next(), Some(Err("a")));
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_filter_ok_folds() {
+    let xs = [Ok(1), Ok(2), Err(10), Ok(3), Ok(4)];
+    let it = || xs.iter().copied().filter_ok(|x| x % 2 == 0);
+    let sum = |acc: i32, x: Result<i32, i32>| acc + x.unwrap_or_else(|e| e);
+    assert_eq!(it().fold(0, sum), 16);
+    assert_eq!(it().rfold(0, sum), 16);
+    assert_eq!(it().try_fold(0, |acc, x| x.map(|x| acc + x)), Err(10));
+    assert_eq!(it().try_rfold(0, |acc, x| x.map(|x| acc + x)), Err(10));
+}
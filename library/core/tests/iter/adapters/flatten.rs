@@ -168,3 +168,10 @@ fn assert_trusted_len<T: TrustedLen>(_: &T) {}
     assert_trusted_len(&iter);
     assert_eq!(iter.size_hint(), (20, Some(20)));
 }
+
+#[test]
+fn test_flatten_default() {
+    let mut it: Flatten<core::slice::Iter<'_, [u8; 4]>> = Default::default();
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+}
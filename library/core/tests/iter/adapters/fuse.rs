@@ -73,3 +73,16 @@ fn test_fuse() {
     assert_eq!(it.next(), None);
     assert_eq!(it.len(), 0);
 }
+
+#[test]
+fn test_fuse_default() {
+    #[derive(Default)]
+    struct Columns<'a> {
+        names: Fuse<Rev<core::slice::Iter<'a, &'a str>>>,
+        ids: Enumerate<Copied<core::slice::Iter<'a, u32>>>,
+    }
+
+    let mut cols = Columns::default();
+    assert_eq!(cols.names.next(), None);
+    assert_eq!(cols.ids.len(), 0);
+}
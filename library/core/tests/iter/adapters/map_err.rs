@@ -0,0 +1,17 @@
+use core::iter::*;
+
+#[test]
+fn test_map_err() {
+    let xs = [Ok(1), Err(2), Ok(3), Err(4)];
+    let mut it = xs.iter().copied().map_err(|e| e * 10);
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.next(), Some(Ok(1)));
+    assert_eq!(it.next(), Some(Err(20)));
+    assert_eq!(it.next_back(), Some(Err(40)));
+    assert_eq!(it.next(), Some(Ok(3)));
+    assert_eq!(it.next(), None);
+
+    let mapped: Vec<Result<i32, String>> =
+        xs.iter().copied().map_err(|e: i32| e.to_string()).rev().collect();
+    assert_eq!(mapped, [Err("4".to_string()), Ok(3), Err("2".to_string()), Ok(1)]);
+}
@@ -0,0 +1,42 @@
+use core::iter::*;
+
+#[test]
+fn test_map_ok() {
+    let xs = [Ok(1), Err("a"), Ok(3)];
+    let mut it = xs.iter().copied().map_ok(|x| x * 10);
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next(), Some(Ok(10)));
+    assert_eq!(it.next_back(), Some(Ok(30)));
+    assert_eq!(it.next(), Some(Err("a")));
+    assert_eq!(it.next(), None);
+
+    let it = xs.iter().copied().map_ok(|x| x * 10);
+    assert_eq!(it.rev().collect::<Vec<_>>(), [Ok(30), Err("a"), Ok(10)]);
+}
+
+#[test]
+fn test_map_ok_collect() {
+    let all: Result<Vec<i32>, &str> = [Ok(1), Ok(2)].iter().copied().map_ok(|x| x + 1).collect();
+    assert_eq!(all, Ok(vec![2, 3]));
+
+    let mut calls = 0;
+    let first_err: Result<Vec<i32>, &str> = [Ok(1), Err("a"), Ok(3), Err("b")]
+        .iter()
+        .copied()
+        .map_ok(|x| {
+            calls += 1;
+            x
+        })
+        .collect();
+    assert_eq!(first_err, Err("a"));
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn test_map_ok_try_folds() {
+    let f = &|acc: i32, x: Result<i32, i32>| acc.checked_add(x.unwrap_or_else(|e| -e));
+    let src = || (0..10).map(|x| if x % 3 == 0 { Err(x) } else { Ok(x) });
+    assert_eq!(src().map_ok(|x| x * 2).try_fold(7, f), Some(7 + 2 * 27 - 18));
+    assert_eq!(src().map_ok(|x| x * 2).try_rfold(7, f), Some(7 + 2 * 27 - 18));
+    assert_eq!(src().map_ok(|x| x * 2).fold(0, |acc, x| acc + x.unwrap_or(0)), 54);
+}
@@ -5,12 +5,15 @@
 mod enumerate;
 mod filter;
 mod filter_map;
+mod filter_ok;
 mod flat_map;
 mod flatten;
 mod fuse;
 mod inspect;
 mod intersperse;
 mod map;
+mod map_err;
+mod map_ok;
 mod peekable;
 mod scan;
 mod skip;
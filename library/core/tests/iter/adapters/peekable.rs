@@ -270,3 +270,16 @@ fn test_peekable_non_fused() {
     assert_eq!(iter.peek(), None);
     assert_eq!(iter.next_back(), None);
 }
+
+#[test]
+fn test_peekable_is_empty() {
+    let mut iter = [1, 2].iter().peekable();
+    assert!(!iter.is_empty());
+    assert_eq!(iter.peek(), Some(&&1));
+    assert!(!iter.is_empty());
+    iter.next();
+    iter.next();
+    assert!(iter.is_empty());
+    assert_eq!(iter.peek(), None);
+    assert!(iter.is_empty());
+}
@@ -146,3 +146,16 @@ fn test_take_try_folds() {
     assert_eq!(iter.try_for_each(Err), Err(2));
     assert_eq!(iter.try_for_each(Err), Ok(()));
 }
+
+#[test]
+fn test_take_is_empty() {
+    let mut iter = [1, 2, 3].iter().take(2);
+    assert!(!iter.is_empty());
+    iter.next();
+    iter.next();
+    assert!(iter.is_empty());
+    assert_eq!(iter.len(), 0);
+
+    assert!([1, 2, 3].iter().take(0).is_empty());
+    assert!([0u8; 0].iter().take(5).is_empty());
+}
@@ -77,6 +77,24 @@ fn test_successors() {
     assert_eq!(empty.next(), None);
 }
 
+#[test]
+fn test_unfold() {
+    let mut it = unfold(1_u32, |n| {
+        let item = *n;
+        *n = n.checked_mul(3)?;
+        Some(item)
+    });
+    assert_eq!(it.by_ref().take(4).collect::<Vec<_>>(), [1, 3, 9, 27]);
+    assert_eq!(*it.state(), 81);
+    *it.state_mut() = 2;
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.into_state(), 6);
+
+    let it = unfold(vec![1, 2, 3], Vec::pop);
+    assert_eq!(it.collect::<Vec<_>>(), [3, 2, 1]);
+    assert_eq!(format!("{:?}", unfold(5, |_: &mut i32| None::<()>)), "Unfold { state: 5, .. }");
+}
+
 #[test]
 fn test_once() {
     let mut it = once(42);
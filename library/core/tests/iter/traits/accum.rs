@@ -8,6 +8,20 @@ fn test_iterator_sum() {
     assert_eq!(v[..0].iter().cloned().sum::<i32>(), 0);
 }
 
+#[test]
+fn test_iterator_fsum() {
+    assert_eq!([1e100, 1.0, -1e100].iter().fsum::<f64>(), 1.0);
+    assert_eq!([0.1f64; 10].iter().fsum::<f64>(), 1.0);
+    assert_eq!(repeat(0.1f64).take(1_000_000).fsum::<f64>(), 100_000.0);
+    assert_eq!([1e30f32, 1.0, -1e30].iter().fsum::<f32>(), 1.0);
+    assert_eq!(empty::<f64>().fsum::<f64>(), 0.0);
+
+    assert_eq!([f64::INFINITY, 1.0].iter().fsum::<f64>(), f64::INFINITY);
+    assert_eq!([f64::MAX, f64::MAX].iter().fsum::<f64>(), f64::INFINITY);
+    assert!([f64::INFINITY, f64::NEG_INFINITY].iter().fsum::<f64>().is_nan());
+    assert!([1.0, f64::NAN].iter().fsum::<f64>().is_nan());
+}
+
 #[test]
 fn test_iterator_sum_result() {
     let v: &[Result<i32, ()>] = &[Ok(1), Ok(2), Ok(3), Ok(4)];
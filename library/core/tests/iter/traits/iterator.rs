@@ -124,6 +124,33 @@ fn test_eq_by() {
     assert!(xs().take(3).eq_by(ys().take(3), f));
 }
 
+#[test]
+fn test_eq_cmp_contiguous() {
+    use core::cmp::Ordering;
+
+    let xs: &[u8] = &[1, 2, 3, 4];
+    let mut it = xs.iter();
+    it.next();
+    assert!(it.clone().eq([2, 3, 4].iter()));
+    assert!(!it.clone().eq(xs.iter()));
+    assert_eq!(it.clone().cmp(xs.iter()), Ordering::Greater);
+    assert_eq!(xs.iter().cmp(xs[..3].iter()), Ordering::Greater);
+    assert_eq!(xs.iter().cmp(xs.iter()), Ordering::Equal);
+
+    let ys: &[i32] = &[-1, 0, 1];
+    assert!(ys.iter().eq([-1, 0, 1].iter()));
+    assert_eq!(ys.iter().cmp([0].iter()), Ordering::Less);
+
+    let mut cs = "a\u{e9}\u{10000}".chars();
+    assert!(cs.clone().eq("a\u{e9}\u{10000}".chars()));
+    assert!(!cs.clone().eq("a\u{e9}".chars()));
+    assert_eq!("\u{ff}".chars().cmp("\u{100}".chars()), Ordering::Less);
+    assert_eq!("\u{10000}".chars().cmp("\u{ffff}".chars()), Ordering::Greater);
+    cs.next();
+    assert!(cs.clone().eq("\u{e9}\u{10000}".chars()));
+    assert_eq!(cs.cmp("a".chars()), Ordering::Greater);
+}
+
 #[test]
 fn test_iterator_nth() {
     let v: &[_] = &[0, 1, 2, 3, 4];
@@ -358,6 +385,20 @@ fn test_min_by() {
     assert_eq!(*xs.iter().min_by(|x, y| x.abs().cmp(&y.abs())).unwrap(), 0);
 }
 
+#[test]
+fn test_position_extremum() {
+    let xs: &[isize] = &[-3, 0, 3, 5, -5, 0];
+    assert_eq!(xs.iter().position_max(), Some(3));
+    assert_eq!(xs.iter().position_min(), Some(4));
+    // Ties resolve like `max_by_key` (last) and `min_by_key` (first).
+    assert_eq!(xs.iter().position_max_by_key(|x| x.abs()), Some(4));
+    assert_eq!(xs.iter().position_min_by_key(|x| x.abs()), Some(1));
+    assert_eq!(xs.iter().position_max_by(|x, y| x.abs().cmp(&y.abs())), Some(4));
+    assert_eq!(xs.iter().position_min_by(|x, y| x.abs().cmp(&y.abs())), Some(1));
+    assert_eq!((0..0).position_max(), None);
+    assert_eq!((0..0).position_min_by_key(|&x: &i32| -x), None);
+}
+
 #[test]
 fn test_by_ref() {
     let mut xs = 0..10;
@@ -380,6 +421,25 @@ fn test_is_sorted() {
     assert!(["c", "bb", "aaa"].iter().is_sorted_by_key(|s| s.len()));
 }
 
+#[test]
+fn test_group_map_into() {
+    use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+    let groups: HashMap<i32, Vec<i32>> = (0..10).group_map_into(|x| x % 3);
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[&0], [0, 3, 6, 9]);
+    assert_eq!(groups[&1], [1, 4, 7]);
+    assert_eq!(groups[&2], [2, 5, 8]);
+
+    let groups: BTreeMap<usize, BTreeSet<&str>> =
+        ["b", "aa", "a", "bb", "a"].iter().copied().group_map_into(|s| s.len());
+    let expected = [(1, BTreeSet::from(["a", "b"])), (2, BTreeSet::from(["aa", "bb"]))];
+    assert!(groups.into_iter().eq(expected));
+
+    let empty: HashMap<bool, Vec<u8>> = std::iter::empty::<u8>().group_map_into(|_| true);
+    assert!(empty.is_empty());
+}
+
 #[test]
 fn test_partition() {
     fn check(xs: &mut [i32], ref p: impl Fn(&i32) -> bool, expected: usize) {
@@ -496,3 +556,28 @@ fn test_collect() {
     let b: Vec<isize> = a.iter().cloned().collect();
     assert!(a == b);
 }
+
+#[test]
+fn test_next_chunk() {
+    let mut it = 0..12;
+    assert_eq!(it.next_chunk().unwrap(), [0, 1, 2, 3]);
+    assert_eq!(it.next_chunk().unwrap(), []);
+    assert_eq!(it.next_chunk().unwrap(), [4, 5, 6, 7, 8, 9]);
+    assert_eq!(it.next_chunk::<4>().unwrap_err().as_slice(), &[10, 11]);
+    assert_eq!(it.next_chunk::<1>().unwrap_err().as_slice(), &[]);
+
+    let mut it = ["a", "b", "c"].iter().map(|s| s.to_string());
+    let rest = it.next_chunk::<4>().unwrap_err();
+    assert_eq!(rest.collect::<Vec<_>>(), ["a", "b", "c"]);
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_collect_into_tuples() {
+    let a = || [(1, 2, 3), (4, 5, 6), (7, 8, 9)].iter().map(|&(a, b, c)| (a, (b, c)));
+    let b: (Vec<_>, (Vec<_>, Vec<_>)) = a().collect();
+    assert_eq!(b, (vec![1, 4, 7], (vec![2, 5, 8], vec![3, 6, 9])));
+
+    let c: (Vec<_>, (Vec<_>, Vec<_>)) = a().unzip();
+    assert_eq!(b, c);
+}
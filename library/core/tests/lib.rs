@@ -2,7 +2,9 @@
 #![feature(array_chunks)]
 #![feature(array_methods)]
 #![feature(array_windows)]
-#![feature(bench_black_box)]
+#![feature(async_iterator)]
+#![feature(async_iter_from_iter)]
+#![feature(atomic_mut_ptr)]
 #![feature(bool_to_option)]
 #![feature(box_syntax)]
 #![feature(cell_update)]
@@ -26,6 +28,8 @@
 #![feature(core_private_diy_float)]
 #![feature(dec2flt)]
 #![feature(div_duration)]
+#![feature(duration_abs_diff)]
+#![feature(duration_checked_float)]
 #![feature(duration_consts_float)]
 #![feature(duration_constants)]
 #![feature(exact_size_is_empty)]
@@ -35,12 +39,40 @@
 #![feature(float_minimum_maximum)]
 #![feature(future_join)]
 #![feature(future_poll_fn)]
+#![feature(ready_into_inner)]
+#![feature(ready_macro)]
 #![feature(array_from_fn)]
 #![feature(hashmap_internals)]
 #![feature(try_find)]
 #![feature(inline_const)]
 #![feature(is_sorted)]
+#![feature(get_disjoint_mut)]
 #![feature(pattern)]
+#![feature(iter_next_chunk)]
+#![feature(iter_unfold)]
+#![feature(ascii_byte_set)]
+#![feature(char_general_category)]
+#![feature(iter_fsum)]
+#![feature(const_int_from_str)]
+#![feature(offset_of)]
+#![feature(ascii_char)]
+#![feature(char_max_len)]
+#![feature(overflow_ops)]
+#![feature(iter_position_extremum)]
+#![feature(int_from_str_prefixed)]
+#![feature(const_ordering_then_with)]
+#![feature(slice_find_bytes)]
+#![feature(iter_group_map_into)]
+#![feature(iter_result_adapters)]
+#![feature(atomic_ptr_arith)]
+#![feature(uint_bit_ops)]
+#![feature(ptr_volatile)]
+#![feature(bound_as_ref)]
+#![feature(bound_copied)]
+#![feature(bound_map)]
+#![feature(new_range_api)]
+#![feature(range_bounds_is_empty)]
+#![feature(range_into_bounds)]
 #![feature(sort_internals)]
 #![feature(slice_partition_at_index)]
 #![feature(slice_take)]
@@ -73,6 +105,9 @@
 #![feature(result_into_ok_or_err)]
 #![feature(portable_simd)]
 #![feature(ptr_metadata)]
+#![feature(provide_any)]
+#![feature(context_ext)]
+#![feature(local_waker)]
 #![feature(once_cell)]
 #![feature(option_result_contains)]
 #![feature(unsized_tuple_coercion)]
@@ -83,9 +118,11 @@
 #![feature(int_roundings)]
 #![feature(slice_group_by)]
 #![feature(split_array)]
+#![feature(array_chunks_exact)]
 #![feature(trusted_random_access)]
 #![feature(unsize)]
 #![feature(unzip_option)]
+#![feature(utf8_chunks)]
 #![feature(const_array_from_ref)]
 #![feature(const_slice_from_ref)]
 #![deny(unsafe_op_in_unsafe_fn)]
@@ -96,6 +133,7 @@
 mod any;
 mod array;
 mod ascii;
+mod async_iter;
 mod atomic;
 mod bool;
 mod cell;
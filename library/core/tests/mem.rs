@@ -301,3 +301,46 @@ const fn init_y(foo: &mut MaybeUninit<Foo>) {
 
     assert_eq!(FIELD_BY_FIELD, Foo { x: 1, y: 2 });
 }
+
+#[test]
+fn offset_of() {
+    #[repr(C)]
+    struct Inner {
+        a: u8,
+        b: u16,
+    }
+
+    #[repr(C)]
+    struct Outer {
+        tag: u8,
+        len: u32,
+        inner: Inner,
+    }
+
+    #[repr(C, packed)]
+    struct Packed {
+        a: u8,
+        b: u32,
+    }
+
+    #[allow(dead_code)]
+    union Union {
+        a: u8,
+        b: u64,
+    }
+
+    assert_eq!(offset_of!(Outer, tag), 0);
+    assert_eq!(offset_of!(Outer, len), 4);
+    assert_eq!(offset_of!(Outer, inner), 8);
+    assert_eq!(offset_of!(Outer, inner.b), 10);
+    assert_eq!(offset_of!(Packed, b), 1);
+    assert_eq!(offset_of!(Union, b), 0);
+
+    // Tuples have no defined layout, so compare against the actual address.
+    let tuple = (1u8, (2u16, 3u32));
+    let base = &tuple as *const _ as usize;
+    assert_eq!(offset_of!((u8, (u16, u32)), 1.1), &(tuple.1).1 as *const _ as usize - base);
+
+    const OFFSET: usize = offset_of!(Outer, inner.a);
+    assert_eq!(OFFSET, 8);
+}
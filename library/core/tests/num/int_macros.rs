@@ -248,6 +248,14 @@ fn test_from_str_radix() {
                 assert_eq!($T::from_str_radix("-9", 2).ok(), None::<$T>);
             }
 
+            #[test]
+            fn test_from_str_radix_const() {
+                const PARSED: Result<$T, core::num::ParseIntError> = $T::from_str_radix("-11", 16);
+                const INVALID: Result<$T, core::num::ParseIntError> = $T::from_str_radix("2", 2);
+                assert_eq!(PARSED, Ok(-17));
+                assert!(INVALID.is_err());
+            }
+
             #[test]
             fn test_pow() {
                 let mut r = 2 as $T;
@@ -143,6 +143,31 @@ fn test_empty() {
     test_parse::<u8>("", Err(IntErrorKind::Empty));
 }
 
+#[test]
+fn test_from_str_with_radix_prefix() {
+    fn parse_i32(src: &str) -> Result<(i32, usize), IntErrorKind> {
+        i32::from_str_with_radix_prefix(src).map_err(|e| e.kind().clone())
+    }
+
+    assert_eq!(parse_i32("0x1F"), Ok((31, 4)));
+    assert_eq!(parse_i32("-0o777"), Ok((-511, 6)));
+    assert_eq!(parse_i32("+0b1010_1010 rest"), Ok((170, 12)));
+    assert_eq!(parse_i32("0x_ff_"), Ok((255, 6)));
+    assert_eq!(parse_i32("1_000_000;"), Ok((1_000_000, 9)));
+    assert_eq!(parse_i32("0b102"), Ok((2, 4)));
+    assert_eq!(parse_i32("0X1F"), Ok((0, 1)));
+    assert_eq!(parse_i32("-0x80000000"), Ok((i32::MIN, 11)));
+    assert_eq!(parse_i32("0x80000000"), Err(IntErrorKind::PosOverflow));
+    assert_eq!(parse_i32("-0x80000001"), Err(IntErrorKind::NegOverflow));
+    assert_eq!(parse_i32(""), Err(IntErrorKind::Empty));
+    assert_eq!(parse_i32("-"), Err(IntErrorKind::InvalidDigit));
+    assert_eq!(parse_i32("0o"), Err(IntErrorKind::InvalidDigit));
+    assert_eq!(parse_i32("_1"), Err(IntErrorKind::InvalidDigit));
+
+    assert_eq!(u8::from_str_with_radix_prefix("0xff").unwrap(), (255, 4));
+    assert!(u8::from_str_with_radix_prefix("-1").is_err());
+}
+
 #[test]
 fn test_infallible_try_from_int_error() {
     let func = |x: i8| -> Result<i32, TryFromIntError> { Ok(x.try_into()?) };
@@ -101,6 +101,44 @@ fn test_rotate() {
                 assert_eq!(C.rotate_left(128), C);
             }
 
+            #[test]
+            fn test_funnel_shift() {
+                for n in 0..$T::BITS {
+                    assert_eq!(A.funnel_shl(A, n), A.rotate_left(n));
+                    assert_eq!(B.funnel_shr(B, n), B.rotate_right(n));
+                    assert_eq!(_1.funnel_shl(_0, n), _1 << n);
+                    assert_eq!(_0.funnel_shr(_1, n), _1 >> n);
+                }
+                assert_eq!(A.funnel_shl(B, 0), A);
+                assert_eq!(A.funnel_shr(B, 0), B);
+                assert_eq!(_0.funnel_shl(_1, $T::BITS - 1), _1 >> 1);
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_funnel_shl_overflow() {
+                let _ = A.funnel_shl(B, $T::BITS);
+            }
+
+            #[test]
+            fn test_carryless_mul() {
+                assert_eq!(A.carryless_mul(_0), _0);
+                assert_eq!(A.carryless_mul(1), A);
+                assert_eq!(A.carryless_mul(B), B.carryless_mul(A));
+                assert_eq!(A.carryless_mul(2), A << 1);
+                // (x^2 + x) * (x^2 + 1)
+                assert_eq!((0b110 as $T).carryless_mul(0b101), 0b11110);
+                // Distributes over xor.
+                assert_eq!(A.carryless_mul(B ^ C), A.carryless_mul(B) ^ A.carryless_mul(C));
+            }
+
+            #[test]
+            fn test_bit_select() {
+                assert_eq!(_1.bit_select(A, B), A);
+                assert_eq!(_0.bit_select(A, B), B);
+                assert_eq!(C.bit_select(A, B), (A & C) | (B & !C));
+            }
+
             #[test]
             fn test_swap_bytes() {
                 assert_eq!(A.swap_bytes().swap_bytes(), A);
@@ -181,6 +219,14 @@ pub fn test_parse_bytes() {
                 assert_eq!($T::from_str_radix("_", 2).ok(), None::<$T>);
             }
 
+            #[test]
+            fn test_from_str_radix_const() {
+                const PARSED: Result<$T, core::num::ParseIntError> = $T::from_str_radix("11", 16);
+                const INVALID: Result<$T, core::num::ParseIntError> = $T::from_str_radix("2", 2);
+                assert_eq!(PARSED, Ok(17));
+                assert!(INVALID.is_err());
+            }
+
             #[test]
             fn test_pow() {
                 let mut r = 2 as $T;
@@ -89,6 +89,116 @@ fn test_range_is_empty() {
     assert!((f32::NAN..=f32::NAN).is_empty());
 }
 
+#[test]
+fn test_range_bounds_is_empty() {
+    use core::ops::RangeBounds;
+
+    assert!(!RangeBounds::is_empty(&(0..10)));
+    assert!(RangeBounds::is_empty(&(5..5)));
+    assert!(!RangeBounds::is_empty(&(5..=5)));
+    assert!(!(5..).is_empty());
+    assert!(!(..5).is_empty());
+    assert!(!(..=5).is_empty());
+    assert!(!RangeBounds::<i32>::is_empty(&(..)));
+
+    assert!((Bound::Excluded(5), Bound::Excluded(5)).is_empty());
+    assert!((Bound::Excluded(5), Bound::Included(5)).is_empty());
+    assert!(!(Bound::Included(5), Bound::Included(5)).is_empty());
+    assert!(!(Bound::Excluded(4), Bound::Excluded(6)).is_empty());
+
+    let mut r = 0..=0;
+    assert_eq!(r.next(), Some(0));
+    assert!(RangeBounds::is_empty(&r));
+}
+
+#[test]
+fn test_range_intersect() {
+    use core::ops::{Bound::*, IntoBounds, RangeBounds};
+
+    assert_eq!((0..10).intersect(5..15), (Included(5), Excluded(10)));
+    assert_eq!((0..10).intersect(5..=15), (Included(5), Excluded(10)));
+    assert_eq!((0..=10).intersect(..10), (Included(0), Excluded(10)));
+    assert_eq!((0..=10).intersect(..=10), (Included(0), Included(10)));
+    assert_eq!((..).intersect(3..), (Included(3), Unbounded));
+    assert_eq!((Excluded(3), Included(8)).intersect(3..=8), (Excluded(3), Included(8)));
+
+    // Touching and disjoint ranges.
+    assert!((0..5).intersect(5..10).is_empty());
+    assert!(!(0..=5).intersect(5..10).is_empty());
+    assert!((..3).intersect(7..).is_empty());
+
+    // Clamping a request to a buffer.
+    let len = 16;
+    assert_eq!((4..100).intersect(..len), (Included(4), Excluded(16)));
+
+    let mut r = 0..=0;
+    assert_eq!(r.next(), Some(0));
+    assert!(r.intersect(..).is_empty());
+}
+
+#[test]
+fn test_new_range_copy_iter() {
+    use core::range;
+
+    let r = range::Range::from(1..4);
+    assert_eq!(r.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    // Iterating copies the range instead of consuming it.
+    assert_eq!(r.iter().rev().collect::<Vec<_>>(), [3, 2, 1]);
+    assert_eq!(r, range::Range { start: 1, end: 4 });
+    assert_eq!(r.iter().len(), 3);
+
+    let r = range::RangeInclusive::from(1..=4);
+    assert_eq!(r.into_iter().sum::<i32>(), 10);
+    assert_eq!(r.iter().rev().sum::<i32>(), 10);
+    assert_eq!(range::RangeInclusive::from(1u16..=4).iter().len(), 4);
+
+    let r = range::RangeFrom::from(5u8..);
+    assert_eq!(r.iter().take(3).collect::<Vec<_>>(), [5, 6, 7]);
+    assert_eq!(r.start, 5);
+}
+
+#[test]
+fn test_new_range_legacy_conversions() {
+    use core::range::{self, legacy};
+
+    assert_eq!(legacy::Range::from(range::Range::from(2..5)), 2..5);
+    assert_eq!(legacy::RangeFrom::from(range::RangeFrom::from(2..)), 2..);
+    assert_eq!(legacy::RangeInclusive::from(range::RangeInclusive::from(2..=5)), 2..=5);
+
+    let mut iter = range::RangeInclusive::from(2..=3).into_iter();
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.clone().remainder(), Some(range::RangeInclusive { start: 3, end: 3 }));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.remainder(), None);
+
+    let mut iter = range::Range::from(2..5).into_iter();
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.remainder(), range::Range { start: 2, end: 4 });
+}
+
+#[test]
+#[should_panic(expected = "exhausted")]
+fn test_new_range_from_exhausted_legacy() {
+    let mut r = 0..=0;
+    assert_eq!(r.next(), Some(0));
+    let _ = core::range::RangeInclusive::from(r);
+}
+
+#[test]
+fn test_new_range_slice_index() {
+    use core::range::{Range, RangeFrom, RangeInclusive};
+
+    let mut v = [0, 1, 2, 3, 4, 5];
+    assert_eq!(v[Range::from(1..3)], [1, 2]);
+    assert_eq!(v[RangeFrom::from(4..)], [4, 5]);
+    assert_eq!(v[RangeInclusive::from(1..=3)], [1, 2, 3]);
+    assert_eq!(v.get(Range::from(4..7)), None);
+    assert_eq!(v.get(RangeInclusive::from(0..=usize::MAX)), None);
+
+    v[RangeInclusive::from(0..=1)].copy_from_slice(&[9, 9]);
+    assert_eq!(v, [9, 9, 2, 3, 4, 5]);
+}
+
 #[test]
 fn test_bound_cloned_unbounded() {
     assert_eq!(Bound::<&u32>::Unbounded.cloned(), Bound::Unbounded);
@@ -104,6 +214,23 @@ fn test_bound_cloned_excluded() {
     assert_eq!(Bound::Excluded(&3).cloned(), Bound::Excluded(3));
 }
 
+#[test]
+fn test_bound_copied() {
+    assert_eq!(Bound::<&u32>::Unbounded.copied(), Bound::Unbounded);
+    assert_eq!(Bound::Included(&3).copied(), Bound::Included(3));
+    assert_eq!(Bound::Excluded(&3).copied(), Bound::Excluded(3));
+}
+
+#[test]
+fn test_bound_as_ref_map() {
+    let mut b = Bound::Excluded(String::from("key"));
+    assert_eq!(b.as_ref().map(|s| s.len()), Bound::Excluded(3));
+    if let Bound::Excluded(s) = b.as_mut() {
+        s.push('!');
+    }
+    assert_eq!(b.map(|s| s.len()), Bound::Excluded(4));
+}
+
 #[test]
 #[allow(unused_comparisons)]
 #[allow(unused_mut)]
@@ -232,3 +359,25 @@ fn deref<U: Copy, T: Deref<Target = U>>(t: T) -> U {
     let y = deref(&mut x);
     assert_eq!(y, 4);
 }
+
+#[test]
+fn overflow_ops() {
+    use core::ops::{
+        CheckedAdd, CheckedDiv, SaturatingMul, SaturatingSub, WrappingAdd, WrappingMul,
+    };
+
+    fn generic<T>(a: T, b: T) -> (T, T, Option<T>)
+    where
+        T: Copy + WrappingAdd<Output = T> + SaturatingSub<Output = T> + CheckedAdd<Output = T>,
+    {
+        (a.wrapping_add(b), a.saturating_sub(b), a.checked_add(b))
+    }
+
+    assert_eq!(generic(250u8, 10), (4, 240, None));
+    assert_eq!(generic(1u8, 2), (3, 0, Some(3)));
+    assert_eq!(generic(i32::MIN, 1), (i32::MIN + 1, i32::MIN, Some(i32::MIN + 1)));
+    assert_eq!(WrappingMul::wrapping_mul(0x80u128, 1 << 121), 0);
+    assert_eq!(SaturatingMul::saturating_mul(-100i8, 2), i8::MIN);
+    assert_eq!(CheckedDiv::checked_div(i16::MIN, -1), None);
+    assert_eq!(CheckedDiv::checked_div(7usize, 0), None);
+}
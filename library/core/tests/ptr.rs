@@ -675,3 +675,19 @@ fn drop(&mut self) {
         }
     }
 }
+
+#[test]
+fn test_volatile() {
+    let mut value = [1u16, 2];
+    let ptr = NonNull::from(&mut value[1]);
+    let reg = unsafe { Volatile::new(ptr) };
+    let copy = reg;
+
+    assert_eq!(reg.read(), 2);
+    reg.write(7);
+    copy.update(|v| v * 3);
+    assert_eq!(reg.read(), 21);
+    assert_eq!(reg.as_ptr(), ptr.as_ptr());
+    assert_eq!(format!("{:?}", reg), format!("Volatile({:?})", ptr));
+    assert_eq!(value, [1, 21]);
+}
@@ -1,6 +1,7 @@
 #![cfg(not(miri))] // Miri does not support all SIMD intrinsics
 
-use core::simd::f32x4;
+use core::convert::TryFrom;
+use core::simd::{f32x4, i32x4, mask32x4, Simd};
 
 #[test]
 fn testing() {
@@ -13,3 +14,19 @@ fn testing() {
     assert_eq!(x, r);
     assert_eq!(h, f32x4::splat(0.5));
 }
+
+#[test]
+fn array_and_slice_conversions() {
+    let v = i32x4::from([1, 2, 3, 4]);
+    assert_eq!(<[i32; 4]>::from(v), [1, 2, 3, 4]);
+
+    let slice = &[1, 2, 3, 4, 5][..];
+    assert_eq!(i32x4::try_from(&slice[..4]).unwrap(), v);
+    assert!(i32x4::try_from(slice).is_err());
+    assert!(i32x4::try_from(&slice[..3]).is_err());
+    assert_eq!(Simd::<i32, 4>::try_from(&mut [1, 2, 3, 4][..]).unwrap(), v);
+
+    let m = v.lanes_gt(i32x4::splat(2));
+    assert_eq!(<[bool; 4]>::from(m), [false, false, true, true]);
+    assert_eq!(m, mask32x4::from([false, false, true, true]));
+}
@@ -2,6 +2,7 @@
 use core::cmp::Ordering;
 use core::mem::MaybeUninit;
 use core::result::Result::{Err, Ok};
+use core::slice::GetDisjointMutError;
 
 #[test]
 fn test_position() {
@@ -1632,6 +1633,25 @@ fn brute_force_rotate_test_1() {
     }
 }
 
+#[test]
+fn brute_force_rotate_test_2() {
+    // Elements larger than 4 `usize`s, over enough lengths to reach every algorithm
+    let n = if cfg!(miri) { 30 } else { 100 };
+    for len in 0..n {
+        for s in 0..len {
+            let mut v: Vec<[usize; 5]> = Vec::with_capacity(len);
+            for i in 0..len {
+                v.push([i, 0, 0, 0, i]);
+            }
+            v[..].rotate_right(s);
+            for i in 0..v.len() {
+                let expected = v.len().wrapping_add(i.wrapping_sub(s)) % v.len();
+                assert_eq!(v[i], [expected, 0, 0, 0, expected]);
+            }
+        }
+    }
+}
+
 #[test]
 #[cfg(not(target_arch = "wasm32"))]
 fn sort_unstable() {
@@ -1706,6 +1726,64 @@ fn sort_unstable() {
     assert!(v == [0xDEADBEEF]);
 }
 
+#[test]
+fn sort_unstable_small_exhaustive() {
+    // By the 0-1 principle, a comparison-based sort that sorts every sequence of zeros and ones
+    // sorts every sequence of that length.
+    let max_len = if cfg!(miri) { 9 } else { 16 };
+    for len in 0..=max_len {
+        for bits in 0u32..(1 << len) {
+            let mut v: Vec<u8> = (0..len).map(|i| (bits >> i) as u8 & 1).collect();
+            v.sort_unstable();
+            assert!(v.windows(2).all(|w| w[0] <= w[1]), "len {} bits {:b}", len, bits);
+            assert_eq!(v.iter().filter(|&&x| x == 1).count(), bits.count_ones() as usize);
+        }
+    }
+}
+
+// This test does not work on targets without panic=unwind support.
+// To work around this problem, test is marked is should_panic, so it will
+// be automagically skipped on unsuitable targets, such as
+// wasm32-unknown-unknown.
+//
+// It means that we use panic for indicating success.
+#[test]
+#[should_panic(expected = "test succeeded")]
+fn sort_unstable_small_panic_safety() {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let prev_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    for len in 4..=20 {
+        for panic_at in 0..len {
+            let token = Rc::new(());
+            let mut v: Vec<(u32, Rc<()>)> =
+                (0..len as u32).rev().map(|i| (i, token.clone())).collect();
+            let mut comparisons = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                v.sort_unstable_by(|a, b| {
+                    comparisons += 1;
+                    if comparisons > panic_at {
+                        panic!("comparison limit reached");
+                    }
+                    a.0.cmp(&b.0)
+                });
+            }));
+            assert!(result.is_err());
+
+            // Every element must still be present exactly once.
+            let mut keys: Vec<u32> = v.iter().map(|x| x.0).collect();
+            keys.sort();
+            assert_eq!(keys, (0..len as u32).collect::<Vec<_>>());
+            drop(v);
+            assert_eq!(Rc::strong_count(&token), 1);
+        }
+    }
+    panic::set_hook(prev_hook);
+    panic!("test succeeded")
+}
+
 #[test]
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg_attr(miri, ignore)] // Miri is too slow
@@ -2378,3 +2456,150 @@ macro_rules! empty_max_mut {
     (take_mut_oob_max_range_to_inclusive, (..=usize::MAX), None, empty_max_mut!()),
     (take_mut_in_bounds_max_range_from, (usize::MAX..), Some(&mut [] as _), empty_max_mut!()),
 }
+
+#[test]
+fn test_get_disjoint_mut_normal_2() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    let [a, b] = v.get_disjoint_mut([3, 0]).unwrap();
+    *a += 10;
+    *b += 100;
+    assert_eq!(v, vec![101, 2, 3, 14, 5]);
+
+    let [a, b] = v.get_disjoint_mut([0..=1, 2..=2]).unwrap();
+    assert_eq!(a, &mut [101, 2][..]);
+    assert_eq!(b, &mut [3][..]);
+    a[0] = 999;
+    a[1] = 222;
+    b[0] = 33;
+    assert_eq!(v, vec![999, 222, 33, 14, 5]);
+}
+
+#[test]
+fn test_get_disjoint_mut_normal_3() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    let [a, b, c] = v.get_disjoint_mut([0, 4, 2]).unwrap();
+    *a += 10;
+    *b += 100;
+    *c += 1000;
+    assert_eq!(v, vec![11, 2, 1003, 4, 105]);
+
+    let [a, b, c] = v.get_disjoint_mut([0..1, 4..5, 1..4]).unwrap();
+    a[0] = 999;
+    b[0] = 888;
+    c[0] = 777;
+    assert_eq!(v, vec![999, 777, 1003, 4, 888]);
+}
+
+#[test]
+fn test_get_disjoint_mut_empty() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    let [] = v.get_disjoint_mut::<usize, 0>([]).unwrap();
+    let [] = v.get_disjoint_mut::<core::ops::Range<usize>, 0>([]).unwrap();
+    assert_eq!(v, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_get_disjoint_mut_single_first() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    let [a] = v.get_disjoint_mut([0]).unwrap();
+    *a += 10;
+    assert_eq!(v, vec![11, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_get_disjoint_mut_oob() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    assert!(matches!(v.get_disjoint_mut([5]), Err(GetDisjointMutError::IndexOutOfBounds)));
+    assert!(matches!(v.get_disjoint_mut([2, 6]), Err(GetDisjointMutError::IndexOutOfBounds)));
+    assert!(matches!(v.get_disjoint_mut([0..6]), Err(GetDisjointMutError::IndexOutOfBounds)));
+    assert!(matches!(v.get_disjoint_mut([3..2]), Err(GetDisjointMutError::IndexOutOfBounds)));
+    assert!(matches!(v.get_disjoint_mut([0..=5]), Err(GetDisjointMutError::IndexOutOfBounds)));
+    assert!(matches!(
+        v.get_disjoint_mut([0..=usize::MAX]),
+        Err(GetDisjointMutError::IndexOutOfBounds)
+    ));
+}
+
+#[test]
+fn test_get_disjoint_mut_overlapping() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    assert!(matches!(v.get_disjoint_mut([0, 0]), Err(GetDisjointMutError::OverlappingIndices)));
+    assert!(matches!(v.get_disjoint_mut([0, 1, 0]), Err(GetDisjointMutError::OverlappingIndices)));
+    assert!(matches!(
+        v.get_disjoint_mut([0..2, 1..4]),
+        Err(GetDisjointMutError::OverlappingIndices)
+    ));
+    assert!(matches!(
+        v.get_disjoint_mut([0..=2, 2..=4]),
+        Err(GetDisjointMutError::OverlappingIndices)
+    ));
+    // An empty range in the middle of another range overlaps with it.
+    assert!(matches!(
+        v.get_disjoint_mut([0..4, 2..2]),
+        Err(GetDisjointMutError::OverlappingIndices)
+    ));
+}
+
+#[test]
+fn test_get_disjoint_mut_empty_range_at_edges() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    let [a, b, c] = v.get_disjoint_mut([0..0, 0..5, 5..5]).unwrap();
+    assert!(a.is_empty());
+    assert_eq!(b, &mut [1, 2, 3, 4, 5][..]);
+    assert!(c.is_empty());
+}
+
+#[test]
+fn test_get_disjoint_mut_new_range() {
+    use core::range;
+
+    let mut v = [1, 2, 3, 4, 5];
+    let [a, b] = v.get_disjoint_mut([range::Range::from(0..2), range::Range::from(2..5)]).unwrap();
+    a.swap(0, 1);
+    b.reverse();
+    assert_eq!(v, [2, 1, 5, 4, 3]);
+
+    let overlapping = [range::RangeInclusive::from(0..=1), range::RangeInclusive::from(1..=2)];
+    assert!(matches!(
+        v.get_disjoint_mut(overlapping),
+        Err(GetDisjointMutError::OverlappingIndices)
+    ));
+}
+
+#[test]
+fn test_find_bytes() {
+    let haystack = b"abracadabra";
+    assert_eq!(haystack.find(b""), Some(0));
+    assert_eq!(haystack.rfind(b""), Some(11));
+    assert_eq!(haystack.find(b"a"), Some(0));
+    assert_eq!(haystack.rfind(b"a"), Some(10));
+    assert_eq!(haystack.find(b"abra"), Some(0));
+    assert_eq!(haystack.rfind(b"abra"), Some(7));
+    assert_eq!(haystack.find(b"cad"), Some(4));
+    assert_eq!(haystack.find(b"z"), None);
+    assert_eq!(haystack.find(b"abracadabra!"), None);
+    assert_eq!(b"".find(b"a"), None);
+    assert_eq!(b"".find(b""), Some(0));
+
+    // Not valid UTF-8, which `str` could not search.
+    let binary = [0xff, 0x00, 0xfe, 0xff, 0x00, 0xfe, 0x80];
+    assert_eq!(binary.find(&[0xff, 0x00, 0xfe]), Some(0));
+    assert_eq!(binary.rfind(&[0xff, 0x00, 0xfe]), Some(3));
+    assert_eq!(binary.find(&[0xfe, 0x80]), Some(5));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn brute_force_find_bytes() {
+    // Needles with short and long periods, over a small alphabet so that they occur often.
+    let haystack: Vec<u8> = (0..300u32).map(|i| (i * i % 7 % 3) as u8).collect();
+    for start in 0..20 {
+        for len in 1..12 {
+            let needle = &haystack[start..start + len];
+            let naive_find = haystack.windows(len).position(|w| w == needle);
+            let naive_rfind = haystack.windows(len).rposition(|w| w == needle);
+            assert_eq!(haystack.find(needle), naive_find);
+            assert_eq!(haystack.rfind(needle), naive_rfind);
+        }
+    }
+}
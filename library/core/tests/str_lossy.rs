@@ -1,4 +1,5 @@
 use core::str::lossy::*;
+use core::str::Utf8Chunks;
 
 #[test]
 fn chunks() {
@@ -65,6 +66,27 @@ fn chunks() {
     assert_eq!(None, iter.next());
 }
 
+#[test]
+fn utf8_chunks() {
+    fn chunks(bytes: &[u8]) -> Vec<(&str, &[u8])> {
+        Utf8Chunks::new(bytes).map(|c| (c.valid(), c.invalid())).collect()
+    }
+
+    assert_eq!(chunks(b""), []);
+    assert_eq!(chunks(b"hello"), [("hello", &b""[..])]);
+    assert_eq!(
+        chunks(b"Hello\xC0\x80 There\xE6\x83 Goodbye"),
+        [("Hello", &b"\xC0"[..]), ("", b"\x80"), (" There", b"\xE6\x83"), (" Goodbye", b"")]
+    );
+
+    let mut iter = Utf8Chunks::new(b"\xF1foo");
+    assert_eq!(iter.clone().count(), 2);
+    assert_eq!(iter.next().map(|c| c.invalid()), Some(&b"\xF1"[..]));
+    assert_eq!(iter.next().map(|c| c.valid()), Some("foo"));
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+
 #[test]
 fn display() {
     assert_eq!(
@@ -73,6 +95,19 @@ fn display() {
     );
 }
 
+#[test]
+fn display_format_flags() {
+    let lossy = Utf8Lossy::from_bytes(b"a\xC0b");
+    assert_eq!("a\u{FFFD}b", format!("{}", lossy));
+    assert_eq!("[a\u{FFFD}b  ]", format!("[{:5}]", lossy));
+    assert_eq!("[  a\u{FFFD}b]", format!("[{:>5}]", lossy));
+    assert_eq!("[*a\u{FFFD}b*]", format!("[{:*^5}]", lossy));
+    assert_eq!("[a\u{FFFD}]", format!("[{:.2}]", lossy));
+    assert_eq!("[a\u{FFFD}  ]", format!("[{:4.2}]", lossy));
+    assert_eq!("[a]", format!("[{:.1}]", lossy));
+    assert_eq!("[]", format!("[{:.0}]", lossy));
+}
+
 #[test]
 fn debug() {
     assert_eq!(
@@ -1,4 +1,7 @@
-use core::task::Poll;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, ContextBuilder, LocalWake, LocalWaker, Poll, Wake, Waker};
 
 #[test]
 fn poll_const() {
@@ -12,3 +15,56 @@ fn poll_const() {
     const IS_PENDING: bool = POLL.is_pending();
     assert!(IS_PENDING);
 }
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+struct LocalCounter(Cell<usize>);
+
+impl LocalWake for LocalCounter {
+    fn wake(self: Rc<Self>) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn context_local_waker() {
+    let waker = Waker::from(Arc::new(NoopWaker));
+
+    // Without a builder, the local waker wakes through the `Waker`.
+    let cx = Context::from_waker(&waker);
+    assert!(cx.local_waker().will_wake(waker.as_ref()));
+
+    let counter = Rc::new(LocalCounter(Cell::new(0)));
+    let local_waker = LocalWaker::from(counter.clone());
+    let cx = ContextBuilder::from_waker(&waker).local_waker(&local_waker).build();
+    assert!(cx.waker().will_wake(&waker));
+    cx.local_waker().wake_by_ref();
+    cx.local_waker().clone().wake();
+    assert_eq!(counter.0.get(), 2);
+
+    drop(local_waker);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn context_ext() {
+    let waker = Waker::from(Arc::new(NoopWaker));
+
+    let mut cx = Context::from_waker(&waker);
+    assert!(cx.ext().is::<()>());
+
+    let mut data = 5_u32;
+    let mut cx = ContextBuilder::from_waker(&waker).ext(&mut data).build();
+    *cx.ext().downcast_mut::<u32>().unwrap() += 1;
+
+    // A context derived from another one keeps its extension data.
+    let mut inner = ContextBuilder::from(&mut cx).build();
+    assert_eq!(inner.ext().downcast_ref::<u32>(), Some(&6));
+    drop(inner);
+    drop(cx);
+    assert_eq!(data, 6);
+}
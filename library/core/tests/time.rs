@@ -180,6 +180,29 @@ fn checked_div() {
     assert_eq!(Duration::new(2, 0).checked_div(0), None);
 }
 
+#[test]
+fn abs_diff() {
+    let (a, b) = (Duration::new(2, 0), Duration::new(1, 500_000_000));
+    assert_eq!(a.abs_diff(b), Duration::new(0, 500_000_000));
+    assert_eq!(b.abs_diff(a), Duration::new(0, 500_000_000));
+    assert_eq!(Duration::MAX.abs_diff(Duration::ZERO), Duration::MAX);
+    assert_eq!(Duration::ZERO.abs_diff(Duration::MAX), Duration::MAX);
+}
+
+#[test]
+fn checked_float_ops() {
+    let dur = Duration::new(2, 500_000_000);
+    assert_eq!(dur.checked_mul_f64(2.0), Some(Duration::new(5, 0)));
+    assert_eq!(dur.checked_mul_f64(-2.0), None);
+    assert_eq!(dur.checked_mul_f64(f64::INFINITY), None);
+    assert_eq!(Duration::MAX.checked_mul_f64(2.0), None);
+    assert_eq!(dur.checked_div_f64(2.0), Some(Duration::new(1, 250_000_000)));
+    assert_eq!(dur.checked_div_f64(0.0), None);
+    assert_eq!(dur.checked_div_f64(f64::NAN), None);
+    assert_eq!(dur.checked_mul_f32(-1.0), None);
+    assert_eq!(dur.checked_div_f32(0.0), None);
+}
+
 #[test]
 fn correct_sum() {
     let durations = [
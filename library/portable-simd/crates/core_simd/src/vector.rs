@@ -451,6 +451,33 @@ fn from(vector: Simd<T, LANES>) -> Self {
     }
 }
 
+// slice/vector conversion
+impl<T, const LANES: usize> core::convert::TryFrom<&[T]> for Simd<T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+    T: SimdElement,
+{
+    type Error = core::array::TryFromSliceError;
+
+    /// Converts a slice whose length is exactly `LANES` to a SIMD vector.
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        <[T; LANES] as core::convert::TryFrom<&[T]>>::try_from(slice).map(Self::from_array)
+    }
+}
+
+impl<T, const LANES: usize> core::convert::TryFrom<&mut [T]> for Simd<T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+    T: SimdElement,
+{
+    type Error = core::array::TryFromSliceError;
+
+    /// Converts a slice whose length is exactly `LANES` to a SIMD vector.
+    fn try_from(slice: &mut [T]) -> Result<Self, Self::Error> {
+        <Self as core::convert::TryFrom<&[T]>>::try_from(&*slice)
+    }
+}
+
 mod sealed {
     pub trait Sealed {}
 }
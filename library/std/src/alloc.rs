@@ -285,6 +285,299 @@ unsafe fn shrink(
     }
 }
 
+/// A global allocator adapter that counts the allocations made by each thread.
+///
+/// `Counting` forwards every request to the wrapped allocator (the [`System`]
+/// allocator by default) and records the number of allocations,
+/// deallocations and reallocations, along with the number of bytes they
+/// moved, in counters owned by the calling thread. The counters are read with
+/// [`thread_alloc_stats`], which makes it cheap to assert on the allocation
+/// behavior of a piece of code without writing a counting allocator first.
+///
+/// Only requests that succeed are counted. Zero-sized allocations never reach
+/// a global allocator, so they are not counted either.
+///
+/// # Platform-specific behavior
+///
+/// On platforms without native thread-local storage the counters are shared
+/// by the whole process, so allocations made by other threads are included.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(alloc_stats)]
+///
+/// use std::alloc::{thread_alloc_stats, Counting, System};
+///
+/// #[global_allocator]
+/// static GLOBAL: Counting = Counting::new(System);
+///
+/// fn main() {
+///     let before = thread_alloc_stats();
+///     let v: Vec<u64> = Vec::with_capacity(4);
+///     let stats = thread_alloc_stats().since(&before);
+///
+///     assert_eq!(stats.allocations(), 1);
+///     assert_eq!(stats.bytes_allocated(), 32);
+///     drop(v);
+/// }
+/// ```
+#[unstable(feature = "alloc_stats", issue = "none")]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Counting<A = System> {
+    inner: A,
+}
+
+impl<A> Counting<A> {
+    /// Creates a counting adapter around `inner`.
+    #[unstable(feature = "alloc_stats", issue = "none")]
+    #[inline]
+    pub const fn new(inner: A) -> Self {
+        Counting { inner }
+    }
+
+    /// Returns a reference to the wrapped allocator.
+    #[unstable(feature = "alloc_stats", issue = "none")]
+    #[inline]
+    pub const fn inner(&self) -> &A {
+        &self.inner
+    }
+}
+
+#[unstable(feature = "alloc_stats", issue = "none")]
+unsafe impl<A: GlobalAlloc> GlobalAlloc for Counting<A> {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc`
+        let ptr = unsafe { self.inner.alloc(layout) };
+        if !ptr.is_null() {
+            stats::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    #[inline]
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc_zeroed`
+        let ptr = unsafe { self.inner.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            stats::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::dealloc`
+        unsafe { self.inner.dealloc(ptr, layout) };
+        stats::record_dealloc(layout.size());
+    }
+
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::realloc`
+        let new_ptr = unsafe { self.inner.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            stats::record_realloc(layout.size(), new_size);
+        }
+        new_ptr
+    }
+}
+
+/// A snapshot of the allocation counters of the current thread.
+///
+/// Returned by [`thread_alloc_stats`]. The counters only advance while a
+/// [`Counting`] allocator is installed as the global allocator. All counts
+/// wrap around on overflow.
+#[unstable(feature = "alloc_stats", issue = "none")]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct AllocStats {
+    allocations: usize,
+    deallocations: usize,
+    reallocations: usize,
+    bytes_allocated: usize,
+    bytes_deallocated: usize,
+}
+
+impl AllocStats {
+    /// Returns the number of allocations made.
+    #[unstable(feature = "alloc_stats", issue = "none")]
+    #[inline]
+    pub fn allocations(&self) -> usize {
+        self.allocations
+    }
+
+    /// Returns the number of deallocations made.
+    #[unstable(feature = "alloc_stats", issue = "none")]
+    #[inline]
+    pub fn deallocations(&self) -> usize {
+        self.deallocations
+    }
+
+    /// Returns the number of reallocations made.
+    ///
+    /// A reallocation is not counted as an allocation or a deallocation, but
+    /// the bytes it releases and acquires are included in
+    /// [`bytes_deallocated`](AllocStats::bytes_deallocated) and
+    /// [`bytes_allocated`](AllocStats::bytes_allocated).
+    #[unstable(feature = "alloc_stats", issue = "none")]
+    #[inline]
+    pub fn reallocations(&self) -> usize {
+        self.reallocations
+    }
+
+    /// Returns the number of bytes requested by allocations and reallocations.
+    #[unstable(feature = "alloc_stats", issue = "none")]
+    #[inline]
+    pub fn bytes_allocated(&self) -> usize {
+        self.bytes_allocated
+    }
+
+    /// Returns the number of bytes released by deallocations and reallocations.
+    #[unstable(feature = "alloc_stats", issue = "none")]
+    #[inline]
+    pub fn bytes_deallocated(&self) -> usize {
+        self.bytes_deallocated
+    }
+
+    /// Returns the activity recorded between `earlier` and `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(alloc_stats)]
+    ///
+    /// use std::alloc::thread_alloc_stats;
+    ///
+    /// let before = thread_alloc_stats();
+    /// let delta = thread_alloc_stats().since(&before);
+    /// // Without a `Counting` global allocator nothing is recorded.
+    /// assert_eq!(delta.allocations(), 0);
+    /// ```
+    #[unstable(feature = "alloc_stats", issue = "none")]
+    #[must_use]
+    pub fn since(&self, earlier: &AllocStats) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations.wrapping_sub(earlier.allocations),
+            deallocations: self.deallocations.wrapping_sub(earlier.deallocations),
+            reallocations: self.reallocations.wrapping_sub(earlier.reallocations),
+            bytes_allocated: self.bytes_allocated.wrapping_sub(earlier.bytes_allocated),
+            bytes_deallocated: self.bytes_deallocated.wrapping_sub(earlier.bytes_deallocated),
+        }
+    }
+}
+
+/// Returns the allocation counters recorded by [`Counting`] for the current thread.
+///
+/// Taking a snapshot does not allocate and does not reset the counters; use
+/// [`AllocStats::since`] to measure a region of code.
+#[unstable(feature = "alloc_stats", issue = "none")]
+pub fn thread_alloc_stats() -> AllocStats {
+    stats::snapshot()
+}
+
+mod stats {
+    use super::AllocStats;
+
+    // The counters are read and written from inside the global allocator, so
+    // they must not allocate themselves. `thread_local!` may allocate on first
+    // use on some platforms, so the raw attribute is used instead, with a
+    // process-wide fallback where it is unavailable.
+    #[cfg(target_thread_local)]
+    mod imp {
+        use crate::cell::Cell;
+
+        pub struct Counter(Cell<usize>);
+
+        impl Counter {
+            pub const fn new() -> Counter {
+                Counter(Cell::new(0))
+            }
+
+            #[inline]
+            pub fn add(&self, n: usize) {
+                self.0.set(self.0.get().wrapping_add(n));
+            }
+
+            #[inline]
+            pub fn get(&self) -> usize {
+                self.0.get()
+            }
+        }
+    }
+
+    #[cfg(not(target_thread_local))]
+    mod imp {
+        use crate::sync::atomic::{AtomicUsize, Ordering};
+
+        pub struct Counter(AtomicUsize);
+
+        impl Counter {
+            pub const fn new() -> Counter {
+                Counter(AtomicUsize::new(0))
+            }
+
+            #[inline]
+            pub fn add(&self, n: usize) {
+                self.0.fetch_add(n, Ordering::Relaxed);
+            }
+
+            #[inline]
+            pub fn get(&self) -> usize {
+                self.0.load(Ordering::Relaxed)
+            }
+        }
+    }
+
+    use imp::Counter;
+
+    struct Counters {
+        allocations: Counter,
+        deallocations: Counter,
+        reallocations: Counter,
+        bytes_allocated: Counter,
+        bytes_deallocated: Counter,
+    }
+
+    #[cfg_attr(target_thread_local, thread_local)]
+    static COUNTERS: Counters = Counters {
+        allocations: Counter::new(),
+        deallocations: Counter::new(),
+        reallocations: Counter::new(),
+        bytes_allocated: Counter::new(),
+        bytes_deallocated: Counter::new(),
+    };
+
+    #[inline]
+    pub fn record_alloc(size: usize) {
+        COUNTERS.allocations.add(1);
+        COUNTERS.bytes_allocated.add(size);
+    }
+
+    #[inline]
+    pub fn record_dealloc(size: usize) {
+        COUNTERS.deallocations.add(1);
+        COUNTERS.bytes_deallocated.add(size);
+    }
+
+    #[inline]
+    pub fn record_realloc(old_size: usize, new_size: usize) {
+        COUNTERS.reallocations.add(1);
+        COUNTERS.bytes_deallocated.add(old_size);
+        COUNTERS.bytes_allocated.add(new_size);
+    }
+
+    pub fn snapshot() -> AllocStats {
+        AllocStats {
+            allocations: COUNTERS.allocations.get(),
+            deallocations: COUNTERS.deallocations.get(),
+            reallocations: COUNTERS.reallocations.get(),
+            bytes_allocated: COUNTERS.bytes_allocated.get(),
+            bytes_deallocated: COUNTERS.bytes_deallocated.get(),
+        }
+    }
+}
+
 static HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
 
 /// Registers a custom allocation error hook, replacing any that was previously registered.
@@ -297,7 +590,29 @@ unsafe fn shrink(
 /// The hook is provided with a `Layout` struct which contains information
 /// about the allocation that failed.
 ///
+/// If the hook returns, the process is terminated with
+/// [`process::abort`](crate::process::abort), which also runs the
+/// [abort hook](crate::process::set_abort_hook). A hook that wants a
+/// different exit status can instead end the process itself, for example with
+/// [`process::exit`](crate::process::exit).
+///
 /// The allocation error hook is a global resource.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(alloc_error_hook)]
+///
+/// use std::alloc::{set_alloc_error_hook, Layout};
+/// use std::process;
+///
+/// fn out_of_memory(layout: Layout) {
+///     eprintln!("fatal: failed to allocate {} bytes", layout.size());
+///     process::exit(75);
+/// }
+///
+/// set_alloc_error_hook(out_of_memory);
+/// ```
 #[unstable(feature = "alloc_error_hook", issue = "51245")]
 pub fn set_alloc_error_hook(hook: fn(Layout)) {
     HOOK.store(hook as *mut (), Ordering::SeqCst);
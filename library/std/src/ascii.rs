@@ -16,6 +16,12 @@
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::ascii::{escape_default, EscapeDefault};
 
+#[unstable(feature = "ascii_byte_set", issue = "none")]
+pub use core::ascii::ByteSet;
+
+#[unstable(feature = "ascii_char", issue = "none")]
+pub use core::ascii::Char;
+
 /// Extension methods for ASCII-subset only operations.
 ///
 /// Be aware that operations on seemingly non-ASCII characters can sometimes
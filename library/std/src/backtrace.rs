@@ -95,10 +95,12 @@
 // a backtrace or actually symbolizing it.
 
 use crate::backtrace_rs::{self, BytesOrWideString};
+use crate::borrow::Cow;
 use crate::cell::UnsafeCell;
 use crate::env;
 use crate::ffi::c_void;
 use crate::fmt;
+use crate::path::Path;
 use crate::sync::atomic::{AtomicUsize, Ordering::Relaxed};
 use crate::sync::Once;
 use crate::sys_common::backtrace::{lock, output_filename};
@@ -162,7 +164,12 @@ enum RawFrame {
     Fake,
 }
 
-struct BacktraceSymbol {
+/// A symbol that a backtrace frame resolved to.
+///
+/// A single frame may resolve to several symbols when functions have been
+/// inlined into one another, in which case the innermost function comes first.
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
@@ -375,6 +382,90 @@ pub fn frames(&'a self) -> &'a [BacktraceFrame] {
     }
 }
 
+impl BacktraceFrame {
+    /// Returns the instruction pointer of this frame.
+    ///
+    /// This is normally the address of the next instruction to execute in
+    /// the frame, which is the return address for every frame but the
+    /// innermost one.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn ip(&self) -> *mut c_void {
+        self.frame.ip()
+    }
+
+    /// Returns the symbols this frame resolved to.
+    ///
+    /// This is empty if no symbol information could be found for this
+    /// frame, for example because the binary was built without it.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        &self.symbols
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the demangled name of this symbol, without its trailing hash.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn name(&self) -> Option<String> {
+        self.name.as_ref().map(|b| format!("{:#}", backtrace_rs::SymbolName::new(b)))
+    }
+
+    /// Returns the raw, possibly mangled, name of this symbol.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn name_bytes(&self) -> Option<&[u8]> {
+        self.name.as_deref()
+    }
+
+    /// Returns the path of the source file this symbol was defined in.
+    ///
+    /// Returns `None` if no file information is available, or if the path
+    /// cannot be represented on this platform.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn filename(&self) -> Option<Cow<'_, Path>> {
+        match self.filename.as_ref()? {
+            #[cfg(unix)]
+            BytesOrWide::Bytes(bytes) => {
+                use crate::os::unix::ffi::OsStrExt;
+                Some(Path::new(crate::ffi::OsStr::from_bytes(bytes)).into())
+            }
+            #[cfg(not(unix))]
+            BytesOrWide::Bytes(bytes) => {
+                crate::str::from_utf8(bytes).ok().map(|s| Path::new(s).into())
+            }
+            #[cfg(windows)]
+            BytesOrWide::Wide(wide) => {
+                use crate::os::windows::ffi::OsStringExt;
+                Some(Cow::Owned(crate::ffi::OsString::from_wide(wide).into()))
+            }
+            #[cfg(not(windows))]
+            BytesOrWide::Wide(_) => None,
+        }
+    }
+
+    /// Returns the line number in [`filename`] for this symbol.
+    ///
+    /// [`filename`]: BacktraceSymbol::filename
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+
+    /// Returns the column number in [`filename`] for this symbol.
+    ///
+    /// [`filename`]: BacktraceSymbol::filename
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn colno(&self) -> Option<u32> {
+        self.colno
+    }
+}
+
 impl fmt::Display for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let capture = match &self.inner {
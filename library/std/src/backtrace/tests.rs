@@ -93,3 +93,22 @@ fn test_frames() {
 
     assert!(iter.all(|(f, e)| format!("{:#?}", f) == *e));
 }
+
+#[test]
+fn test_frame_accessors() {
+    let frames = generate_fake_frames();
+
+    assert_eq!(frames[0].ip(), 1 as *mut c_void);
+    assert_eq!(frames[2].symbols().len(), 2);
+
+    let symbol = &frames[2].symbols()[0];
+    assert_eq!(symbol.name().as_deref(), Some("std::rt::lang_start_internal"));
+    assert_eq!(symbol.name_bytes(), Some(&b"std::rt::lang_start_internal"[..]));
+    assert_eq!(symbol.filename().as_deref(), Some(Path::new("rust/rt.rs")));
+    assert_eq!(symbol.lineno(), Some(300));
+    assert_eq!(symbol.colno(), Some(5));
+
+    let symbol = &frames[1].symbols()[0];
+    assert_eq!(symbol.filename(), None);
+    assert_eq!(symbol.lineno(), None);
+}
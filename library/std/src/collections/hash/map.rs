@@ -7,13 +7,17 @@
 
 use crate::borrow::Borrow;
 use crate::cell::Cell;
+use crate::cmp;
+use crate::collections::TryExtend;
 use crate::collections::TryReserveError;
 use crate::collections::TryReserveErrorKind;
 use crate::fmt::{self, Debug};
 #[allow(deprecated)]
 use crate::hash::{BuildHasher, Hash, Hasher, SipHasher13};
-use crate::iter::{FromIterator, FusedIterator};
+use crate::iter::{FromIterator, FusedIterator, GroupInsert, TrustedLen};
+use crate::mem;
 use crate::ops::Index;
+use crate::ptr;
 use crate::sys;
 
 /// A [hash map] implemented with quadratic probing and SIMD lookup.
@@ -246,6 +250,45 @@ pub fn new() -> HashMap<K, V, RandomState> {
     pub fn with_capacity(capacity: usize) -> HashMap<K, V, RandomState> {
         HashMap::with_capacity_and_hasher(capacity, Default::default())
     }
+
+    /// Creates an empty `HashMap` whose hash keys are derived from `seed`
+    /// instead of being chosen at random.
+    ///
+    /// Two maps created with the same seed and filled with the same
+    /// operations iterate in the same order, which makes this useful for
+    /// golden-file tests and fuzzers that need reproducible output while
+    /// still using the default hasher type. The order for a given seed is
+    /// only reproducible within a single build of the standard library; it
+    /// may change between releases.
+    ///
+    /// # Security
+    ///
+    /// A map with a known seed offers no protection against HashDoS attacks:
+    /// anyone who knows the seed can craft keys that all collide. Never use
+    /// this for maps that hold untrusted input; use [`HashMap::new`] instead.
+    ///
+    /// See [`RandomState::with_seed`] for the underlying hash builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hashmap_with_seed)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut a = HashMap::with_seed(42);
+    /// let mut b = HashMap::with_seed(42);
+    /// for i in 0..100 {
+    ///     a.insert(i, i * 2);
+    ///     b.insert(i, i * 2);
+    /// }
+    /// assert!(a.iter().eq(b.iter()));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "hashmap_with_seed", issue = "none")]
+    pub fn with_seed(seed: u64) -> HashMap<K, V, RandomState> {
+        HashMap::with_hasher(RandomState::with_seed(seed))
+    }
 }
 
 impl<K, V, S> HashMap<K, V, S> {
@@ -326,6 +369,37 @@ pub fn capacity(&self) -> usize {
         self.base.capacity()
     }
 
+    /// Returns the number of bytes the map has allocated for its table.
+    ///
+    /// This covers the storage for the keys and values together with the
+    /// control bytes the table uses to track which slots are occupied, so it
+    /// reflects the memory actually reserved rather than [`capacity`]. It does
+    /// not include heap memory owned by the keys or values themselves.
+    ///
+    /// A map that has not allocated, such as one created by [`HashMap::new`],
+    /// reports zero. The table grows once it is seven-eighths full, so the
+    /// allocation always has somewhat more slots than [`capacity`] reports.
+    ///
+    /// [`capacity`]: HashMap::capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_map_allocation_size)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<u64, u64> = HashMap::new();
+    /// assert_eq!(map.allocation_size(), 0);
+    ///
+    /// map.reserve(100);
+    /// assert!(map.allocation_size() >= map.capacity() * 16);
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_map_allocation_size", issue = "none")]
+    pub fn allocation_size(&self) -> usize {
+        table_allocation_size::<(K, V)>(self.base.capacity())
+    }
+
     /// An iterator visiting all keys in arbitrary order.
     /// The iterator element type is `&'a K`.
     ///
@@ -668,6 +742,48 @@ pub fn clear(&mut self) {
     pub fn hasher(&self) -> &S {
         self.base.hasher()
     }
+
+    /// Feeds the entries of this map into the given [`Hasher`], independently
+    /// of the order in which they are stored.
+    ///
+    /// `HashMap` does not implement [`Hash`], since its iteration order
+    /// depends on its own hasher and history. This method can be used instead
+    /// to implement `Hash` for a type that contains a map: maps that are
+    /// equal feed the same data to `state`, whatever their [`BuildHasher`].
+    ///
+    /// Each entry is hashed on its own with a hasher keyed by the current
+    /// output of `state`, and the results are combined with a wrapping sum.
+    /// The entry hashes therefore depend on the keys of `state` (such as the
+    /// random keys of a [`RandomState`] hasher) just like the rest of its input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_unordered)]
+    /// use std::collections::HashMap;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// #[derive(PartialEq, Eq)]
+    /// struct Query {
+    ///     path: String,
+    ///     params: HashMap<String, String>,
+    /// }
+    ///
+    /// impl Hash for Query {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.path.hash(state);
+    ///         self.params.hash_unordered(state);
+    ///     }
+    /// }
+    /// ```
+    #[unstable(feature = "hash_unordered", issue = "none")]
+    pub fn hash_unordered<H: Hasher>(&self, state: &mut H)
+    where
+        K: Hash,
+        V: Hash,
+    {
+        hash_unordered(self.iter(), state)
+    }
 }
 
 impl<K, V, S> HashMap<K, V, S>
@@ -2403,6 +2519,37 @@ pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
         }
     }
 
+    /// Ensures a value is in the entry by inserting the result of the fallible default
+    /// function if empty, and returns a mutable reference to the value in the entry.
+    ///
+    /// If the default function fails, the entry is left empty and its error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_or_try_insert)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<&str, u32> = HashMap::new();
+    ///
+    /// assert!(map.entry("poneyland").or_try_insert_with(|| "x".parse::<u32>()).is_err());
+    /// assert!(map.is_empty());
+    ///
+    /// assert_eq!(map.entry("poneyland").or_try_insert_with(|| "3".parse::<u32>()), Ok(&mut 3));
+    /// assert_eq!(map["poneyland"], 3);
+    /// ```
+    #[inline]
+    #[unstable(feature = "entry_or_try_insert", issue = "none")]
+    pub fn or_try_insert_with<F, E>(self, default: F) -> Result<&'a mut V, E>
+    where
+        F: FnOnce() -> Result<V, E>,
+    {
+        match self {
+            Occupied(entry) => Ok(entry.into_mut()),
+            Vacant(entry) => Ok(entry.insert(default()?)),
+        }
+    }
+
     /// Returns a reference to this entry's key.
     ///
     /// # Examples
@@ -2457,6 +2604,109 @@ pub fn and_modify<F>(self, f: F) -> Self
         }
     }
 
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map, like [`and_modify`], but also passes
+    /// the entry's key to `f`.
+    ///
+    /// This is the counterpart of [`or_insert_with_key`]: the key does not
+    /// have to be cloned or looked up again to be used in the closure.
+    ///
+    /// [`and_modify`]: Entry::and_modify
+    /// [`or_insert_with_key`]: Entry::or_insert_with_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_and_modify_with_key)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<&str, usize> = HashMap::new();
+    ///
+    /// map.entry("poneyland")
+    ///    .and_modify_with_key(|key, len| *len += key.len())
+    ///    .or_insert_with_key(|key| key.len());
+    /// assert_eq!(map["poneyland"], 9);
+    ///
+    /// map.entry("poneyland")
+    ///    .and_modify_with_key(|key, len| *len += key.len())
+    ///    .or_insert_with_key(|key| key.len());
+    /// assert_eq!(map["poneyland"], 18);
+    /// ```
+    #[inline]
+    #[unstable(feature = "entry_and_modify_with_key", issue = "none")]
+    pub fn and_modify_with_key<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&K, &mut V),
+    {
+        match self {
+            Occupied(mut entry) => {
+                let value: *mut V = entry.get_mut();
+                // SAFETY: `value` points to the value of the entry, which is stored apart from
+                // its key, so the unique reference to it does not alias the shared reference to
+                // the key. Both live no longer than the borrow of `entry` in this call.
+                f(entry.key(), unsafe { &mut *value });
+                Occupied(entry)
+            }
+            Vacant(entry) => Vacant(entry),
+        }
+    }
+
+    /// Replaces the value of an occupied entry with the result of `f`, which is given the key
+    /// and takes ownership of the old value. Vacant entries are returned unchanged.
+    ///
+    /// This allows transforming a value that cannot be cheaply cloned or defaulted without
+    /// looking the key up again. If `f` panics, the entry is removed from the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_and_replace)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<&str, Vec<u32>> = HashMap::new();
+    /// map.insert("poneyland", vec![1, 2]);
+    ///
+    /// map.entry("poneyland")
+    ///    .and_replace_entry_with(|_, mut v| { v.push(3); v })
+    ///    .or_insert_with(Vec::new);
+    /// assert_eq!(map["poneyland"], [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "entry_and_replace", issue = "none")]
+    pub fn and_replace_entry_with<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&K, V) -> V,
+    {
+        // Removes the entry, without dropping its moved-out value, if `f` unwinds.
+        struct RemoveOnUnwind<'a, K, V>(Option<OccupiedEntry<'a, K, V>>);
+
+        impl<K, V> Drop for RemoveOnUnwind<'_, K, V> {
+            fn drop(&mut self) {
+                if let Some(entry) = self.0.take() {
+                    mem::forget(entry.remove_entry().1);
+                }
+            }
+        }
+
+        match self {
+            Occupied(entry) => {
+                let mut guard = RemoveOnUnwind(Some(entry));
+                let entry = guard.0.as_mut().unwrap();
+                let slot: *mut V = entry.get_mut();
+                // SAFETY: `slot` points to the initialized value of the entry. The value is
+                // moved out for the duration of `f` and the slot is refilled before the guard
+                // is disarmed; if `f` unwinds, the guard removes the entry and forgets the
+                // stale copy of the value instead of dropping it a second time.
+                unsafe {
+                    let value = f(entry.key(), ptr::read(slot));
+                    ptr::write(slot, value);
+                }
+                Occupied(guard.0.take().unwrap())
+            }
+            Vacant(entry) => Vacant(entry),
+        }
+    }
+
     /// Sets the value of the entry, and returns an `OccupiedEntry`.
     ///
     /// # Examples
@@ -2845,6 +3095,10 @@ impl<K, V, S> Extend<(K, V)> for HashMap<K, V, S>
 {
     #[inline]
     fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        if let Some(additional) = iter.exact_len() {
+            self.reserve(additional);
+        }
         self.base.extend(iter)
     }
 
@@ -2868,6 +3122,10 @@ impl<'a, K, V, S> Extend<(&'a K, &'a V)> for HashMap<K, V, S>
 {
     #[inline]
     fn extend<T: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        if let Some(additional) = iter.exact_len() {
+            self.reserve(additional);
+        }
         self.base.extend(iter)
     }
 
@@ -2882,6 +3140,72 @@ fn extend_reserve(&mut self, additional: usize) {
     }
 }
 
+/// Inserts all new key-values from the iterator and replaces values with existing
+/// keys with new values returned from the iterator, like [`Extend`], but returns an
+/// error instead of aborting if the map cannot grow.
+#[unstable(feature = "try_extend", issue = "none")]
+impl<K, V, S> TryExtend<(K, V)> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn try_extend<T: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: T,
+    ) -> Result<(), TryReserveError> {
+        let iter = iter.into_iter();
+        // Like `extend`, only reserve half of the lower bound for a non-empty map.
+        let reserve = match iter.exact_len() {
+            Some(additional) => additional,
+            None if self.is_empty() => iter.size_hint().0,
+            None => (iter.size_hint().0 + 1) / 2,
+        };
+        self.try_reserve(reserve)?;
+        for (k, v) in iter {
+            // With room for one more entry, `insert` does not need to allocate.
+            if self.len() == self.capacity() {
+                self.try_reserve(1)?;
+            }
+            self.insert(k, v);
+        }
+        Ok(())
+    }
+}
+
+// When extending a non-empty map, hashbrown only reserves room for half of the iterator's lower
+// bound, in case some of the keys are already present. The length of a `TrustedLen` iterator is
+// known exactly, so reserve all of it up front instead of rehashing part-way through.
+trait SpecExactLen {
+    fn exact_len(&self) -> Option<usize>;
+}
+
+impl<I: Iterator> SpecExactLen for I {
+    #[inline]
+    default fn exact_len(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<I: TrustedLen> SpecExactLen for I {
+    #[inline]
+    fn exact_len(&self) -> Option<usize> {
+        self.size_hint().1
+    }
+}
+
+#[unstable(feature = "iter_group_map_into", issue = "none")]
+impl<K, V, C, S> GroupInsert<K, V> for HashMap<K, C, S>
+where
+    K: Eq + Hash,
+    C: Default + Extend<V>,
+    S: BuildHasher,
+{
+    #[inline]
+    fn group_insert(&mut self, key: K, value: V) {
+        self.entry(key).or_default().extend_one(value);
+    }
+}
+
 /// `RandomState` is the default state for [`HashMap`] types.
 ///
 /// A particular instance `RandomState` will create the same instances of
@@ -2942,6 +3266,38 @@ pub fn new() -> RandomState {
             RandomState { k0, k1 }
         })
     }
+
+    /// Constructs a new `RandomState` whose keys are derived from `seed`.
+    ///
+    /// Hashers built from two `RandomState`s with the same seed produce the
+    /// same hashes, so hash maps using them have a reproducible iteration
+    /// order. How keys are derived from the seed is unspecified and may
+    /// change between releases.
+    ///
+    /// Because the keys are no longer secret, this gives up the HashDoS
+    /// resistance that [`RandomState::new`] provides. Only use it where the
+    /// hashed data is trusted, e.g. in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hashmap_with_seed)]
+    /// use std::collections::hash_map::RandomState;
+    /// use std::hash::{BuildHasher, Hash, Hasher};
+    ///
+    /// let hash = |s: &RandomState| {
+    ///     let mut hasher = s.build_hasher();
+    ///     "hello".hash(&mut hasher);
+    ///     hasher.finish()
+    /// };
+    /// assert_eq!(hash(&RandomState::with_seed(7)), hash(&RandomState::with_seed(7)));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "hashmap_with_seed", issue = "none")]
+    pub fn with_seed(seed: u64) -> RandomState {
+        RandomState { k0: seed, k1: seed.rotate_left(32) ^ 0x736f_6d65_7073_6575 }
+    }
 }
 
 #[stable(feature = "hashmap_build_hasher", since = "1.7.0")]
@@ -3025,6 +3381,73 @@ fn map_entry<'a, K: 'a, V: 'a>(raw: base::RustcEntry<'a, K, V>) -> Entry<'a, K,
     }
 }
 
+// Hashes each item on its own and feeds the count and the sum of the hashes to
+// `state`, which does not depend on the order of `items`. The item hasher is
+// keyed by what `state` has seen so far, so a keyed `state` still protects
+// against crafted collisions between items.
+#[allow(deprecated)]
+pub(super) fn hash_unordered<I, H>(items: I, state: &mut H)
+where
+    I: ExactSizeIterator,
+    I::Item: Hash,
+    H: Hasher,
+{
+    state.write_usize(items.len());
+    let key = state.finish();
+    let sum = items.fold(0u64, |sum, item| {
+        let mut hasher = SipHasher13::new_with_keys(key, !key);
+        item.hash(&mut hasher);
+        sum.wrapping_add(hasher.finish())
+    });
+    state.write_u64(sum);
+}
+
+/// Computes the size of the allocation backing a table that stores elements of type `T` and
+/// reports `capacity` as its capacity.
+///
+/// This mirrors the bucket sizing and memory layout of the `hashbrown` tables: the buckets are a
+/// power of two, at most seven-eighths of which are usable, followed by one control byte per
+/// bucket plus a trailing group of control bytes used by the probe sequence.
+pub(super) fn table_allocation_size<T>(capacity: usize) -> usize {
+    // Width of the control byte groups scanned at once when probing.
+    #[cfg(all(
+        target_feature = "sse2",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(miri)
+    ))]
+    const GROUP_WIDTH: usize = 16;
+    #[cfg(not(all(
+        target_feature = "sse2",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(miri)
+    )))]
+    const GROUP_WIDTH: usize = if cfg!(any(
+        target_pointer_width = "64",
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    )) {
+        8
+    } else {
+        4
+    };
+
+    // Tables that never allocated report no memory.
+    if capacity == 0 {
+        return 0;
+    }
+
+    let buckets = if capacity < 8 {
+        if capacity < 4 { 4 } else { 8 }
+    } else {
+        (capacity / 7 * 8 + capacity % 7 * 8 / 7).next_power_of_two()
+    };
+
+    let ctrl_align = cmp::max(mem::align_of::<T>(), GROUP_WIDTH);
+    let data = mem::size_of::<T>() * buckets;
+    let ctrl_offset = (data + ctrl_align - 1) & !(ctrl_align - 1);
+    ctrl_offset + buckets + GROUP_WIDTH
+}
+
 #[inline]
 pub(super) fn map_try_reserve_error(err: hashbrown::TryReserveError) -> TryReserveError {
     match err {
@@ -3,6 +3,8 @@
 use super::RandomState;
 use crate::assert_matches::assert_matches;
 use crate::cell::RefCell;
+use crate::collections::TryExtend;
+use crate::mem;
 use rand::{thread_rng, Rng};
 use realstd::collections::TryReserveErrorKind::*;
 
@@ -54,6 +56,24 @@ fn test_create_capacity_zero() {
     assert!(!m.contains_key(&0));
 }
 
+#[test]
+fn test_with_seed() {
+    let keys = || (0..200).map(|i| i * 7919 % 1000);
+    let mut a = HashMap::with_seed(1234);
+    let mut b = HashMap::with_capacity_and_hasher(0, RandomState::with_seed(1234));
+    for k in keys() {
+        a.insert(k, ());
+    }
+    for k in keys() {
+        b.insert(k, ());
+    }
+    assert_eq!(a.capacity(), b.capacity());
+    assert!(a.keys().eq(b.keys()));
+
+    let m: HashMap<i32, i32> = HashMap::with_seed(0);
+    assert_eq!(m.capacity(), 0);
+}
+
 #[test]
 fn test_insert() {
     let mut m = HashMap::new();
@@ -744,6 +764,20 @@ fn test_extend_ref() {
     assert_eq!(a[&3], "three");
 }
 
+#[test]
+fn test_extend_trusted_len() {
+    let mut a: HashMap<u32, u32> = (0..10).map(|i| (i, i)).collect();
+    assert_eq!(a.len(), 10);
+
+    // Room is reserved for every item, but duplicate keys still replace the old values.
+    a.extend((5..1000).map(|i| (i, i * 2)));
+    assert_eq!(a.len(), 1000);
+    assert!(a.capacity() >= a.len());
+    assert_eq!(a[&4], 4);
+    assert_eq!(a[&5], 10);
+    assert_eq!(a[&999], 1998);
+}
+
 #[test]
 fn test_capacity_not_less_than_len() {
     let mut a = HashMap::new();
@@ -769,6 +803,35 @@ fn test_capacity_not_less_than_len() {
     assert!(a.capacity() > a.len());
 }
 
+#[test]
+fn test_allocation_size() {
+    let mut m: HashMap<u64, u64> = HashMap::new();
+    assert_eq!(m.allocation_size(), 0);
+
+    let mut last = 0;
+    for i in 0..1000 {
+        m.insert(i, i);
+        let size = m.allocation_size();
+        // Every usable slot needs room for an entry and a control byte.
+        assert!(size >= m.capacity() * (mem::size_of::<(u64, u64)>() + 1));
+        assert!(size >= last);
+        last = size;
+    }
+
+    // Growing doubles the number of buckets, which roughly doubles the allocation.
+    let before = m.allocation_size();
+    let free = m.capacity() - m.len();
+    for i in 0..=free as u64 {
+        m.insert(1000 + i, i);
+    }
+    let after = m.allocation_size();
+    assert!(after > before * 3 / 2 && after < before * 3);
+
+    m.clear();
+    m.shrink_to_fit();
+    assert_eq!(m.allocation_size(), 0);
+}
+
 #[test]
 fn test_occupied_entry_key() {
     let mut a = HashMap::new();
@@ -805,6 +868,56 @@ fn test_vacant_entry_key() {
     assert_eq!(a[key], value);
 }
 
+#[test]
+fn test_entry_or_try_insert_with() {
+    let mut a: HashMap<u32, u32> = HashMap::new();
+    assert_eq!(a.entry(1).or_try_insert_with(|| Err::<u32, _>("nope")), Err("nope"));
+    assert!(a.is_empty());
+    assert_eq!(a.entry(1).or_try_insert_with(|| Ok::<_, ()>(10)), Ok(&mut 10));
+    assert_eq!(a.entry(1).or_try_insert_with(|| Err(())), Ok(&mut 10));
+    assert_eq!(a.len(), 1);
+}
+
+#[test]
+fn test_entry_and_modify_with_key() {
+    let mut a: HashMap<u32, String> = HashMap::new();
+    a.entry(1).and_modify_with_key(|_, _| panic!()).or_insert_with(String::new);
+    assert_eq!(a[&1], "");
+    a.entry(1).and_modify_with_key(|k, v| v.push_str(&k.to_string()));
+    a.entry(1).and_modify_with_key(|k, v| v.push_str(&(k + 1).to_string()));
+    assert_eq!(a[&1], "12");
+    assert_eq!(a.len(), 1);
+}
+
+#[test]
+fn test_entry_and_replace_entry_with() {
+    use crate::panic::{catch_unwind, AssertUnwindSafe};
+    use crate::rc::Rc;
+
+    let mut a: HashMap<u32, String> = HashMap::new();
+    a.insert(1, String::from("a"));
+    a.entry(1).and_replace_entry_with(|k, v| v + &k.to_string());
+    a.entry(2).and_replace_entry_with(|_, _| panic!());
+    assert_eq!(a[&1], "a1");
+    assert_eq!(a.len(), 1);
+
+    // A panic removes the entry without dropping the value twice.
+    let value = Rc::new(());
+    let mut a = HashMap::new();
+    a.insert(1, value.clone());
+    a.insert(2, value.clone());
+    catch_unwind(AssertUnwindSafe(|| {
+        a.entry(1).and_replace_entry_with(|_, v| {
+            drop(v);
+            panic!("panic in replace");
+        });
+    }))
+    .unwrap_err();
+    assert_eq!(a.len(), 1);
+    assert!(a.contains_key(&2));
+    assert_eq!(Rc::strong_count(&value), 2);
+}
+
 #[test]
 fn test_retain() {
     let mut map: HashMap<i32, i32> = (0..100).map(|x| (x, x * 10)).collect();
@@ -816,6 +929,17 @@ fn test_retain() {
     assert_eq!(map[&6], 60);
 }
 
+#[test]
+fn test_try_extend() {
+    let mut map: HashMap<u32, u32> = (0..4).map(|i| (i, i)).collect();
+    assert_eq!(map.try_extend((2..8).map(|i| (i, i * 10))), Ok(()));
+
+    assert_eq!(map.len(), 8);
+    assert_eq!(map[&1], 1);
+    assert_eq!(map[&3], 30);
+    assert_eq!(map[&7], 70);
+}
+
 #[test]
 fn test_try_reserve() {
     let mut empty_bytes: HashMap<u8, u8> = HashMap::new();
@@ -1100,3 +1224,31 @@ fn from_array() {
     // that's a problem!
     let _must_not_require_type_annotation = HashMap::from([(1, 2)]);
 }
+
+#[test]
+fn test_hash_unordered() {
+    use super::DefaultHasher;
+    use crate::hash::Hasher;
+
+    fn hash(map: &HashMap<i32, i32>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        map.hash_unordered(&mut hasher);
+        hasher.finish()
+    }
+
+    // Different hashers and insertion orders give different iteration orders.
+    let mut a = HashMap::new();
+    let mut b = HashMap::with_hasher(RandomState::new());
+    for i in 0..100 {
+        a.insert(i, i * 2);
+        b.insert(99 - i, (99 - i) * 2);
+    }
+    assert_eq!(hash(&a), hash(&b));
+
+    b.insert(0, 1);
+    assert_ne!(hash(&a), hash(&b));
+    b.insert(0, 0);
+    b.remove(&99);
+    assert_ne!(hash(&a), hash(&b));
+    assert_eq!(hash(&HashMap::new()), hash(&HashMap::new()));
+}
@@ -6,11 +6,11 @@
 use crate::borrow::Borrow;
 use crate::collections::TryReserveError;
 use crate::fmt;
-use crate::hash::{BuildHasher, Hash};
+use crate::hash::{BuildHasher, Hash, Hasher};
 use crate::iter::{Chain, FromIterator, FusedIterator};
 use crate::ops::{BitAnd, BitOr, BitXor, Sub};
 
-use super::map::{map_try_reserve_error, RandomState};
+use super::map::{hash_unordered, map_try_reserve_error, table_allocation_size, RandomState};
 
 // Future Optimization (FIXME!)
 // ============================
@@ -168,6 +168,30 @@ pub fn capacity(&self) -> usize {
         self.base.capacity()
     }
 
+    /// Returns the number of bytes the set has allocated for its table.
+    ///
+    /// This covers the storage for the values together with the control
+    /// bytes the table uses to track which slots are occupied. It does not
+    /// include heap memory owned by the values themselves. See
+    /// [`HashMap::allocation_size`] for details.
+    ///
+    /// [`HashMap::allocation_size`]: crate::collections::HashMap::allocation_size
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_map_allocation_size)]
+    /// use std::collections::HashSet;
+    ///
+    /// let set: HashSet<u32> = HashSet::with_capacity(100);
+    /// assert!(set.allocation_size() >= set.capacity() * 4);
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_map_allocation_size", issue = "none")]
+    pub fn allocation_size(&self) -> usize {
+        table_allocation_size::<T>(self.base.capacity())
+    }
+
     /// An iterator visiting all elements in arbitrary order.
     /// The iterator element type is `&'a T`.
     ///
@@ -406,6 +430,39 @@ pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> HashSet<T, S> {
     pub fn hasher(&self) -> &S {
         self.base.hasher()
     }
+
+    /// Feeds the elements of this set into the given [`Hasher`], independently
+    /// of the order in which they are stored.
+    ///
+    /// See [`HashMap::hash_unordered`] for details.
+    ///
+    /// [`HashMap::hash_unordered`]: crate::collections::HashMap::hash_unordered
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_unordered)]
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::collections::HashSet;
+    /// use std::hash::Hasher;
+    ///
+    /// let hash = |set: &HashSet<i32>| {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     set.hash_unordered(&mut hasher);
+    ///     hasher.finish()
+    /// };
+    ///
+    /// let a: HashSet<i32> = (0..100).collect();
+    /// let b: HashSet<i32> = (0..100).rev().collect();
+    /// assert_eq!(hash(&a), hash(&b));
+    /// ```
+    #[unstable(feature = "hash_unordered", issue = "none")]
+    pub fn hash_unordered<H: Hasher>(&self, state: &mut H)
+    where
+        T: Hash,
+    {
+        hash_unordered(self.iter(), state)
+    }
 }
 
 impl<T, S> HashSet<T, S>
@@ -728,6 +785,11 @@ pub fn get_or_insert_owned<Q: ?Sized>(&mut self, value: &Q) -> &T
     /// Inserts a value computed from `f` into the set if the given `value` is
     /// not present, then returns a reference to the value in the set.
     ///
+    /// The lookup and the insertion share a single hash of `value`, which
+    /// makes this a good fit for interning: `f` only runs, and only
+    /// allocates, when the value is missing. It must return a value equal to
+    /// `value`, otherwise the set may fail to find it again.
+    ///
     /// # Examples
     ///
     /// ```
@@ -745,6 +807,20 @@ pub fn get_or_insert_owned<Q: ?Sized>(&mut self, value: &Q) -> &T
     /// }
     /// assert_eq!(set.len(), 4); // a new "fish" was inserted
     /// ```
+    ///
+    /// A string interner can store boxed strings and look them up by `&str`:
+    ///
+    /// ```
+    /// #![feature(hash_set_entry)]
+    ///
+    /// use std::collections::HashSet;
+    ///
+    /// let mut interner: HashSet<Box<str>> = HashSet::new();
+    /// let a: *const str = &**interner.get_or_insert_with("name", |s| s.into());
+    /// let b: *const str = &**interner.get_or_insert_with("name", |s| s.into());
+    /// assert_eq!(a, b);
+    /// assert_eq!(interner.len(), 1);
+    /// ```
     #[inline]
     #[unstable(feature = "hash_set_entry", issue = "60896")]
     pub fn get_or_insert_with<Q: ?Sized, F>(&mut self, value: &Q, f: F) -> &T
@@ -496,3 +496,27 @@ fn from_array() {
     // that's a problem!
     let _must_not_require_type_annotation = HashSet::from([1, 2]);
 }
+
+#[test]
+fn test_get_or_insert_with_interning() {
+    let mut interner: HashSet<Box<str>> = HashSet::new();
+    let mut calls = 0;
+    let words = ["a", "b", "a", "c", "b", "a"];
+    let ptrs: Vec<*const str> = words
+        .iter()
+        .map(|&w| {
+            &**interner.get_or_insert_with(w, |w| {
+                calls += 1;
+                Box::from(w)
+            }) as *const str
+        })
+        .collect();
+
+    assert_eq!(calls, 3);
+    assert_eq!(interner.len(), 3);
+    assert_eq!(ptrs[0], ptrs[2]);
+    assert_eq!(ptrs[0], ptrs[5]);
+    assert_eq!(ptrs[1], ptrs[4]);
+
+    assert_eq!(interner.get("c").map(|s| &**s), Some("c"));
+}
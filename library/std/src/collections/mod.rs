@@ -420,6 +420,8 @@
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::hash_set::HashSet;
 
+#[unstable(feature = "try_extend", issue = "none")]
+pub use alloc_crate::collections::TryExtend;
 #[stable(feature = "try_reserve", since = "1.57.0")]
 pub use alloc_crate::collections::TryReserveError;
 #[unstable(
@@ -13,13 +13,16 @@
 #[cfg(test)]
 mod tests;
 
+use crate::cell::Cell;
 use crate::error::Error;
 use crate::ffi::{OsStr, OsString};
 use crate::fmt;
 use crate::io;
+use crate::marker::PhantomData;
 use crate::path::{Path, PathBuf};
 use crate::sys;
 use crate::sys::os as os_imp;
+use crate::sys_common::mutex::{StaticMutex, StaticMutexGuard};
 
 /// Returns the current working directory as a [`PathBuf`].
 ///
@@ -307,11 +310,25 @@ fn description(&self) -> &str {
 /// auditing calls to unsafe external FFI functions to ensure that any external
 /// environment accesses are properly synchronized with accesses in Rust.
 ///
+/// On Unix, every environment access made through this module, and the
+/// environment handling done when spawning a [`Command`], takes an internal
+/// lock, so Rust code alone can never observe a torn environment. That lock
+/// means nothing to C code: a concurrent call to `getenv` from a C library, or
+/// from libc itself inside functions such as `getaddrinfo`, `localtime` or
+/// `setlocale`, races with this function and may read freed memory. Setting
+/// variables is only fully sound while no other thread may be running such
+/// code, which in practice means before any threads have been spawned.
+///
 /// Discussion of this unsafety on Unix may be found in:
 ///
 ///  - [Austin Group Bugzilla](https://austingroupbugs.net/view.php?id=188)
 ///  - [GNU C library Bugzilla](https://sourceware.org/bugzilla/show_bug.cgi?id=15607#c2)
 ///
+/// To change a variable only temporarily, for example in a test, see
+/// [`set_var_scoped`].
+///
+/// [`Command`]: crate::process::Command
+///
 /// # Panics
 ///
 /// This function may panic if `key` is empty, contains an ASCII equals sign `'='`
@@ -378,6 +395,149 @@ fn _remove_var(key: &OsStr) {
         .unwrap_or_else(|e| panic!("failed to remove environment variable `{:?}`: {}", key, e))
 }
 
+/// Sets the environment variable `key` to `value` until the returned guard
+/// is dropped, at which point the variable's previous value (or absence) is
+/// restored.
+///
+/// This is meant for tests that need a particular environment. While any
+/// [`ScopedVar`] is alive on a thread, other threads trying to create one
+/// block until it is dropped, so tests using this function don't see each
+/// other's variables even when the test harness runs them in parallel. One
+/// thread may hold several guards at once; they should be dropped in the
+/// reverse order of their creation, which is what happens naturally for
+/// guards bound to local variables.
+///
+/// Only code that also uses scoped variables is kept out. Plain [`set_var`]
+/// and [`var`] calls on other threads still see the temporary value, and the
+/// same caveats about C code reading the environment apply as for
+/// [`set_var`].
+///
+/// # Panics
+///
+/// This function may panic if `key` is empty, contains an ASCII equals sign
+/// `'='` or the NUL character `'\0'`, or when `value` contains the NUL
+/// character.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(env_scoped_var)]
+/// use std::env;
+///
+/// let key = "SCOPED_KEY";
+/// assert!(env::var_os(key).is_none());
+/// {
+///     let _guard = env::set_var_scoped(key, "VALUE");
+///     assert_eq!(env::var(key), Ok("VALUE".to_string()));
+/// }
+/// assert!(env::var_os(key).is_none());
+/// ```
+#[unstable(feature = "env_scoped_var", issue = "none")]
+pub fn set_var_scoped<K: AsRef<OsStr>, V: AsRef<OsStr>>(key: K, value: V) -> ScopedVar {
+    ScopedVar::new(key.as_ref(), Some(value.as_ref()))
+}
+
+/// Removes the environment variable `key` until the returned guard is
+/// dropped, at which point its previous value is restored.
+///
+/// See [`set_var_scoped`] for how scoped variables are kept apart between
+/// threads.
+///
+/// # Panics
+///
+/// This function may panic if `key` is empty, contains an ASCII equals sign
+/// `'='` or the NUL character `'\0'`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(env_scoped_var)]
+/// use std::env;
+///
+/// let key = "SCOPED_REMOVED_KEY";
+/// env::set_var(key, "VALUE");
+/// {
+///     let _guard = env::remove_var_scoped(key);
+///     assert!(env::var_os(key).is_none());
+/// }
+/// assert_eq!(env::var(key), Ok("VALUE".to_string()));
+/// ```
+#[unstable(feature = "env_scoped_var", issue = "none")]
+pub fn remove_var_scoped<K: AsRef<OsStr>>(key: K) -> ScopedVar {
+    ScopedVar::new(key.as_ref(), None)
+}
+
+/// A temporary change to an environment variable, undone on drop.
+///
+/// This structure is created by [`set_var_scoped`] and [`remove_var_scoped`].
+/// See their documentation for more.
+///
+/// The guard must be dropped on the thread that created it, and leaking it
+/// leaves both the variable and the lock shared by scoped variables held.
+#[must_use = "the variable is restored as soon as the guard is dropped"]
+#[unstable(feature = "env_scoped_var", issue = "none")]
+pub struct ScopedVar {
+    key: OsString,
+    previous: Option<OsString>,
+    // Only the outermost guard on a thread holds the lock; nested ones hold
+    // `None`. The guard must be released on the thread that took it, which
+    // the raw pointer in `_not_send` enforces.
+    _lock: Option<StaticMutexGuard>,
+    _not_send: PhantomData<*const ()>,
+}
+
+static SCOPED_VAR_LOCK: StaticMutex = StaticMutex::new();
+
+thread_local! {
+    static SCOPED_VAR_DEPTH: Cell<usize> = Cell::new(0);
+}
+
+impl ScopedVar {
+    fn new(key: &OsStr, value: Option<&OsStr>) -> ScopedVar {
+        let depth = SCOPED_VAR_DEPTH.with(|depth| depth.replace(depth.get() + 1));
+        // SAFETY: the depth counter makes sure this thread only takes the lock
+        // when it doesn't hold it already.
+        let lock = if depth == 0 { Some(unsafe { SCOPED_VAR_LOCK.lock() }) } else { None };
+        // Build the guard before changing anything, so that a panic below for
+        // an invalid key or value still releases the lock.
+        let guard = ScopedVar {
+            key: key.to_os_string(),
+            previous: var_os(key),
+            _lock: lock,
+            _not_send: PhantomData,
+        };
+        match value {
+            Some(value) => _set_var(key, value),
+            None => _remove_var(key),
+        }
+        guard
+    }
+}
+
+#[unstable(feature = "env_scoped_var", issue = "none")]
+impl Drop for ScopedVar {
+    fn drop(&mut self) {
+        // Errors are ignored: they can only happen for keys or values that
+        // were rejected when the guard was created, in which case there is
+        // nothing to restore.
+        let _ = match self.previous {
+            Some(ref previous) => os_imp::setenv(&self.key, previous),
+            None => os_imp::unsetenv(&self.key),
+        };
+        SCOPED_VAR_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+#[unstable(feature = "env_scoped_var", issue = "none")]
+impl fmt::Debug for ScopedVar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScopedVar")
+            .field("key", &self.key)
+            .field("previous", &self.previous)
+            .finish_non_exhaustive()
+    }
+}
+
 /// An iterator that splits an environment variable into paths according to
 /// platform-specific conventions.
 ///
@@ -835,6 +995,38 @@ impl !Send for ArgsOs {}
 #[stable(feature = "env_unimpl_send_sync", since = "1.26.0")]
 impl !Sync for ArgsOs {}
 
+impl ArgsOs {
+    /// Returns the remaining arguments as a slice, without consuming them.
+    ///
+    /// This allows indexing and slicing the arguments without collecting them
+    /// into a new vector. Like the iterator itself, the slice includes the
+    /// program name as its first element unless it has already been consumed;
+    /// use `&args.as_slice()[1..]` (or advance the iterator once) to skip it.
+    ///
+    /// The arguments are exactly those the iterator would yield. On Windows
+    /// they have already been split according to the C runtime's quoting
+    /// rules; parsers that need different rules can use
+    /// `std::os::windows::env::command_line` to get the unsplit command line
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(args_os_as_slice)]
+    /// use std::env;
+    ///
+    /// let mut args = env::args_os();
+    /// let all = args.as_slice().len();
+    /// args.next();
+    /// assert_eq!(args.as_slice().len(), all.saturating_sub(1));
+    /// ```
+    #[unstable(feature = "args_os_as_slice", issue = "none")]
+    #[must_use]
+    pub fn as_slice(&self) -> &[OsString] {
+        self.inner.as_slice()
+    }
+}
+
 #[stable(feature = "env", since = "1.0.0")]
 impl Iterator for ArgsOs {
     type Item = OsString;
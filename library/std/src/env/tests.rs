@@ -13,6 +13,16 @@ fn test_self_exe_path() {
     assert!(path.is_absolute());
 }
 
+#[test]
+fn args_os_as_slice() {
+    let all: Vec<OsString> = args_os().collect();
+    let mut args = args_os();
+    assert_eq!(args.as_slice(), &all[..]);
+    if args.next().is_some() {
+        assert_eq!(args.as_slice(), &all[1..]);
+    }
+}
+
 #[test]
 fn test() {
     assert!((!Path::new("test-path").is_absolute()));
@@ -100,3 +110,30 @@ fn args_debug() {
         format!("{:?}", args_os())
     );
 }
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn scoped_var_restores() {
+    let key = "TEST_SCOPED_VAR_RESTORES";
+    set_var(key, "original");
+    {
+        let _outer = set_var_scoped(key, "outer");
+        {
+            let _inner = remove_var_scoped(key);
+            assert_eq!(var_os(key), None);
+        }
+        assert_eq!(var(key).as_deref(), Ok("outer"));
+    }
+    assert_eq!(var(key).as_deref(), Ok("original"));
+    remove_var(key);
+}
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn scoped_var_invalid_key_releases_lock() {
+    assert!(crate::panic::catch_unwind(|| set_var_scoped("", "value")).is_err());
+    // The failed guard released the lock, so another thread can take it.
+    crate::thread::spawn(|| drop(set_var_scoped("TEST_SCOPED_VAR_AFTER_PANIC", "value")))
+        .join()
+        .unwrap();
+}
@@ -12,6 +12,12 @@
 // moved the `Error` trait into libstd. As we evolve a sol'n to the
 // coherence challenge (e.g., specialization, neg impls, etc) we can
 // reconsider what crate these items belong in.
+//
+// The provider API used by `Error::provide` does live in libcore
+// (`core::any::Provider`), so `no_std` code can already hand out and request
+// typed context. Moving `Error` itself would additionally need a way to
+// define inherent methods such as `<dyn Error>::downcast`, which names `Box`,
+// outside of the crate that defines the trait.
 
 #[cfg(test)]
 mod tests;
@@ -20,7 +26,7 @@
 use core::convert::Infallible;
 
 use crate::alloc::{AllocError, LayoutError};
-use crate::any::TypeId;
+use crate::any::{Demand, Provider, TypeId};
 use crate::backtrace::Backtrace;
 use crate::borrow::Cow;
 use crate::cell;
@@ -28,6 +34,8 @@
 use crate::fmt::{self, Debug, Display};
 use crate::mem::transmute;
 use crate::num;
+use crate::rc::Rc;
+use crate::slice;
 use crate::str;
 use crate::string;
 use crate::sync::Arc;
@@ -136,6 +144,55 @@ fn backtrace(&self) -> Option<&Backtrace> {
         None
     }
 
+    /// Provides type based access to context intended for error reports.
+    ///
+    /// Used in conjunction with [`Demand::provide_value`] and [`Demand::provide_ref`] to extract
+    /// references to member variables from `dyn Error` trait objects, without needing to know,
+    /// or downcast to, the concrete type of the error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(provide_any)]
+    /// #![feature(error_generic_member_access)]
+    /// use core::fmt;
+    /// use std::any::Demand;
+    ///
+    /// #[derive(Debug)]
+    /// struct MyBacktrace {
+    ///     // ...
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct Error {
+    ///     backtrace: MyBacktrace,
+    ///     status: u16,
+    /// }
+    ///
+    /// impl fmt::Display for Error {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "Example Error")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for Error {
+    ///     fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+    ///         demand
+    ///             .provide_ref::<MyBacktrace>(&self.backtrace)
+    ///             .provide_value::<u16, _>(|| self.status);
+    ///     }
+    /// }
+    ///
+    /// let error = Error { backtrace: MyBacktrace {}, status: 404 };
+    /// let dyn_error = &error as &dyn std::error::Error;
+    ///
+    /// assert!(dyn_error.request_ref::<MyBacktrace>().is_some());
+    /// assert_eq!(dyn_error.request_value::<u16>(), Some(404));
+    /// ```
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    #[allow(unused_variables)]
+    fn provide<'a>(&'a self, demand: &mut Demand<'a>) {}
+
     /// ```
     /// if let Err(e) = "xc".parse::<u32>() {
     ///     // Print `e` itself, no need for description().
@@ -237,6 +294,81 @@ fn from(err: E) -> Box<dyn Error + Send + Sync + 'a> {
     }
 }
 
+#[stable(feature = "box_error_send", since = "1.59.0")]
+impl<'a, E: Error + Send + 'a> From<E> for Box<dyn Error + Send + 'a> {
+    /// Converts a type of [`Error`] + [`Send`] into a box of dyn [`Error`] +
+    /// [`Send`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// // `Cell` makes this error `Send` but not `Sync`.
+    /// #[derive(Debug)]
+    /// struct AnError(Cell<u32>);
+    ///
+    /// impl fmt::Display for AnError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "An error")
+    ///     }
+    /// }
+    ///
+    /// impl Error for AnError {}
+    ///
+    /// let a_boxed_error = Box::<dyn Error + Send>::from(AnError(Cell::new(0)));
+    /// assert_eq!(a_boxed_error.to_string(), "An error");
+    /// ```
+    fn from(err: E) -> Box<dyn Error + Send + 'a> {
+        Box::new(err)
+    }
+}
+
+#[stable(feature = "box_error_send", since = "1.59.0")]
+impl<'a> From<Box<dyn Error + Send + 'a>> for Box<dyn Error + 'a> {
+    /// Drops the [`Send`] marker from a boxed error, so that `?` can mix
+    /// functions returning the two kinds of boxed errors.
+    fn from(err: Box<dyn Error + Send + 'a>) -> Box<dyn Error + 'a> {
+        err
+    }
+}
+
+#[stable(feature = "box_error_send", since = "1.59.0")]
+impl<'a> From<Box<dyn Error + Send + Sync + 'a>> for Box<dyn Error + 'a> {
+    /// Drops the [`Send`] and [`Sync`] markers from a boxed error, so that `?`
+    /// can mix functions returning the two kinds of boxed errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    ///
+    /// fn parse(s: &str) -> Result<u32, Box<dyn Error + Send + Sync>> {
+    ///     Ok(s.parse()?)
+    /// }
+    ///
+    /// fn run() -> Result<u32, Box<dyn Error>> {
+    ///     Ok(parse("12")? + parse("30")?)
+    /// }
+    ///
+    /// assert_eq!(run().unwrap(), 42);
+    /// ```
+    fn from(err: Box<dyn Error + Send + Sync + 'a>) -> Box<dyn Error + 'a> {
+        err
+    }
+}
+
+#[stable(feature = "box_error_send", since = "1.59.0")]
+impl<'a> From<Box<dyn Error + Send + Sync + 'a>> for Box<dyn Error + Send + 'a> {
+    /// Drops the [`Sync`] marker from a boxed error, so that `?` can mix
+    /// functions returning the two kinds of boxed errors.
+    fn from(err: Box<dyn Error + Send + Sync + 'a>) -> Box<dyn Error + Send + 'a> {
+        err
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl From<String> for Box<dyn Error + Send + Sync> {
     /// Converts a [`String`] into a box of dyn [`Error`] + [`Send`] + [`Sync`].
@@ -415,6 +547,9 @@ fn description(&self) -> &str {
     }
 }
 
+#[unstable(feature = "str_try_index", issue = "none")]
+impl Error for str::StrIndexError {}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Error for num::ParseIntError {
     #[allow(deprecated)]
@@ -511,6 +646,10 @@ fn cause(&self) -> Option<&dyn Error> {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         Error::source(&**self)
     }
+
+    fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+        Error::provide(&**self, demand)
+    }
 }
 
 #[stable(feature = "error_by_ref", since = "1.51.0")]
@@ -532,6 +671,10 @@ fn source(&self) -> Option<&(dyn Error + 'static)> {
     fn backtrace(&self) -> Option<&Backtrace> {
         Error::backtrace(&**self)
     }
+
+    fn provide<'b>(&'b self, demand: &mut Demand<'b>) {
+        Error::provide(&**self, demand)
+    }
 }
 
 #[stable(feature = "arc_error", since = "1.52.0")]
@@ -553,6 +696,35 @@ fn source(&self) -> Option<&(dyn Error + 'static)> {
     fn backtrace(&self) -> Option<&Backtrace> {
         Error::backtrace(&**self)
     }
+
+    fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+        Error::provide(&**self, demand)
+    }
+}
+
+#[stable(feature = "rc_error", since = "1.59.0")]
+impl<T: Error + ?Sized> Error for Rc<T> {
+    #[allow(deprecated, deprecated_in_future)]
+    fn description(&self) -> &str {
+        Error::description(&**self)
+    }
+
+    #[allow(deprecated)]
+    fn cause(&self) -> Option<&dyn Error> {
+        Error::cause(&**self)
+    }
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Error::source(&**self)
+    }
+
+    fn backtrace(&self) -> Option<&Backtrace> {
+        Error::backtrace(&**self)
+    }
+
+    fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+        Error::provide(&**self, demand)
+    }
 }
 
 #[stable(feature = "fmt_error", since = "1.11.0")]
@@ -601,6 +773,78 @@ impl Error for alloc::collections::TryReserveError {}
 #[unstable(feature = "duration_checked_float", issue = "83400")]
 impl Error for time::FromSecsError {}
 
+#[unstable(feature = "get_disjoint_mut", issue = "none")]
+impl Error for slice::GetDisjointMutError {}
+
+#[unstable(feature = "error_generic_member_access", issue = "none")]
+impl<'b> Provider for dyn Error + 'b {
+    fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+        Error::provide(self, demand)
+    }
+}
+
+#[unstable(feature = "error_generic_member_access", issue = "none")]
+impl<'b> Provider for dyn Error + Send + 'b {
+    fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+        Error::provide(self, demand)
+    }
+}
+
+#[unstable(feature = "error_generic_member_access", issue = "none")]
+impl<'b> Provider for dyn Error + Send + Sync + 'b {
+    fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+        Error::provide(self, demand)
+    }
+}
+
+impl<'b> dyn Error + 'b {
+    /// Requests a reference of type `T` as context about this error.
+    ///
+    /// Returns `None` if the error does not provide a reference of that type
+    /// from [`Error::provide`].
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_ref<T: ?Sized + 'static>(&self) -> Option<&T> {
+        crate::any::request_ref(self)
+    }
+
+    /// Requests a value of type `T` as context about this error.
+    ///
+    /// Returns `None` if the error does not provide a value of that type
+    /// from [`Error::provide`].
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_value<T: 'static>(&self) -> Option<T> {
+        crate::any::request_value(self)
+    }
+}
+
+impl<'b> dyn Error + Send + 'b {
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_ref<T: ?Sized + 'static>(&self) -> Option<&T> {
+        <dyn Error + 'b>::request_ref(self)
+    }
+
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_value<T: 'static>(&self) -> Option<T> {
+        <dyn Error + 'b>::request_value(self)
+    }
+}
+
+impl<'b> dyn Error + Send + Sync + 'b {
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_ref<T: ?Sized + 'static>(&self) -> Option<&T> {
+        <dyn Error + 'b>::request_ref(self)
+    }
+
+    /// Forwards to the method defined on the type `dyn Error`.
+    #[unstable(feature = "error_generic_member_access", issue = "none")]
+    pub fn request_value<T: 'static>(&self) -> Option<T> {
+        <dyn Error + 'b>::request_value(self)
+    }
+}
+
 // Copied from `any.rs`.
 impl dyn Error + 'static {
     /// Returns `true` if the boxed type is the same as `T`
@@ -703,6 +947,30 @@ pub fn downcast<T: Error + 'static>(self: Box<Self>) -> Result<Box<T>, Box<dyn E
         }
     }
 
+    /// Attempts to downcast the shared [`Rc`] to a concrete type.
+    #[unstable(feature = "error_downcast_shared", issue = "none")]
+    #[inline]
+    pub fn downcast_rc<T: Error + 'static>(self: Rc<Self>) -> Result<Rc<T>, Rc<Self>> {
+        if self.is::<T>() {
+            // SAFETY: the type check above guarantees that the erased value is a `T`.
+            unsafe { Ok(Rc::from_raw(Rc::into_raw(self) as *const T)) }
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Attempts to downcast the shared [`Arc`] to a concrete type.
+    #[unstable(feature = "error_downcast_shared", issue = "none")]
+    #[inline]
+    pub fn downcast_arc<T: Error + 'static>(self: Arc<Self>) -> Result<Arc<T>, Arc<Self>> {
+        if self.is::<T>() {
+            // SAFETY: the type check above guarantees that the erased value is a `T`.
+            unsafe { Ok(Arc::from_raw(Arc::into_raw(self) as *const T)) }
+        } else {
+            Err(self)
+        }
+    }
+
     /// Returns an iterator starting with the current error and continuing with
     /// recursively calling [`Error::source`].
     ///
@@ -759,6 +1027,44 @@ pub fn downcast<T: Error + 'static>(self: Box<Self>) -> Result<Box<T>, Box<dyn E
     pub fn chain(&self) -> Chain<'_> {
         Chain { current: Some(self) }
     }
+
+    /// Returns an iterator starting with the current error and continuing with
+    /// recursively calling [`Error::source`].
+    ///
+    /// This is the same iterator as [`chain`](#method.chain), under the name
+    /// used by [`Report`], which renders it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(error_iter)]
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct ReadConfig(std::num::ParseIntError);
+    ///
+    /// impl fmt::Display for ReadConfig {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "invalid config")
+    ///     }
+    /// }
+    ///
+    /// impl Error for ReadConfig {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let err = ReadConfig("x".parse::<u8>().unwrap_err());
+    /// let messages: Vec<String> = (&err as &dyn Error).sources().map(|e| e.to_string()).collect();
+    /// assert_eq!(messages, ["invalid config", "invalid digit found in string"]);
+    /// ```
+    #[unstable(feature = "error_iter", issue = "58520")]
+    #[inline]
+    pub fn sources(&self) -> Chain<'_> {
+        self.chain()
+    }
 }
 
 /// An iterator over an [`Error`] and its sources.
@@ -793,6 +1099,30 @@ pub fn downcast<T: Error + 'static>(self: Box<Self>) -> Result<Box<T>, Box<dyn E
             transmute::<Box<dyn Error>, Box<dyn Error + Send>>(s)
         })
     }
+
+    /// Attempts to downcast the shared [`Rc`] to a concrete type.
+    #[unstable(feature = "error_downcast_shared", issue = "none")]
+    #[inline]
+    pub fn downcast_rc<T: Error + 'static>(self: Rc<Self>) -> Result<Rc<T>, Rc<Self>> {
+        if self.is::<T>() {
+            // SAFETY: the type check above guarantees that the erased value is a `T`.
+            unsafe { Ok(Rc::from_raw(Rc::into_raw(self) as *const T)) }
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Attempts to downcast the shared [`Arc`] to a concrete type.
+    #[unstable(feature = "error_downcast_shared", issue = "none")]
+    #[inline]
+    pub fn downcast_arc<T: Error + 'static>(self: Arc<Self>) -> Result<Arc<T>, Arc<Self>> {
+        if self.is::<T>() {
+            // SAFETY: the type check above guarantees that the erased value is a `T`.
+            unsafe { Ok(Arc::from_raw(Arc::into_raw(self) as *const T)) }
+        } else {
+            Err(self)
+        }
+    }
 }
 
 impl dyn Error + Send + Sync {
@@ -806,4 +1136,275 @@ pub fn downcast<T: Error + 'static>(self: Box<Self>) -> Result<Box<T>, Box<Self>
             transmute::<Box<dyn Error>, Box<dyn Error + Send + Sync>>(s)
         })
     }
+
+    /// Attempts to downcast the shared [`Rc`] to a concrete type.
+    #[unstable(feature = "error_downcast_shared", issue = "none")]
+    #[inline]
+    pub fn downcast_rc<T: Error + 'static>(self: Rc<Self>) -> Result<Rc<T>, Rc<Self>> {
+        if self.is::<T>() {
+            // SAFETY: the type check above guarantees that the erased value is a `T`.
+            unsafe { Ok(Rc::from_raw(Rc::into_raw(self) as *const T)) }
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Attempts to downcast the shared [`Arc`] to a concrete type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(error_downcast_shared)]
+    /// use std::error::Error;
+    /// use std::sync::Arc;
+    ///
+    /// let err: Box<dyn Error + Send + Sync> = "1x".parse::<u8>().unwrap_err().into();
+    /// let err: Arc<dyn Error + Send + Sync> = Arc::from(err);
+    /// let err = err.downcast_arc::<std::fmt::Error>().unwrap_err();
+    /// assert!(err.downcast_arc::<std::num::ParseIntError>().is_ok());
+    /// ```
+    #[unstable(feature = "error_downcast_shared", issue = "none")]
+    #[inline]
+    pub fn downcast_arc<T: Error + 'static>(self: Arc<Self>) -> Result<Arc<T>, Arc<Self>> {
+        if self.is::<T>() {
+            // SAFETY: the type check above guarantees that the erased value is a `T`.
+            unsafe { Ok(Arc::from_raw(Arc::into_raw(self) as *const T)) }
+        } else {
+            Err(self)
+        }
+    }
+}
+
+/// An error reporter that prints an error and its sources.
+///
+/// `Report` wraps an error and formats it, along with the chain of errors
+/// returned by [`Error::source`], either on a single line or, with
+/// [`pretty`](Report::pretty), over several lines with the sources numbered.
+/// It can also include the first available [`Backtrace`] in the chain.
+///
+/// The `Debug` implementation of `Report` is the same as its `Display`
+/// implementation, so a `Report` can be returned from `main` to print the
+/// whole error chain when the program fails.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(error_reporter)]
+/// use std::error::{Error, Report};
+/// use std::fmt;
+///
+/// #[derive(Debug)]
+/// struct SuperError {
+///     source: SuperErrorSideKick,
+/// }
+///
+/// impl fmt::Display for SuperError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "SuperError is here!")
+///     }
+/// }
+///
+/// impl Error for SuperError {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         Some(&self.source)
+///     }
+/// }
+///
+/// #[derive(Debug)]
+/// struct SuperErrorSideKick;
+///
+/// impl fmt::Display for SuperErrorSideKick {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "SuperErrorSideKick is here!")
+///     }
+/// }
+///
+/// impl Error for SuperErrorSideKick {}
+///
+/// let error = SuperError { source: SuperErrorSideKick };
+///
+/// let report = Report::new(&error);
+/// assert_eq!(report.to_string(), "SuperError is here!: SuperErrorSideKick is here!");
+///
+/// let report = Report::new(&error).pretty(true);
+/// assert_eq!(
+///     report.to_string(),
+///     "SuperError is here!\n\nCaused by:\n      SuperErrorSideKick is here!",
+/// );
+/// ```
+///
+/// Returning a `Report` from `main` shows the full chain on failure:
+///
+/// ```no_run
+/// #![feature(error_reporter)]
+/// use std::error::Report;
+/// use std::fs::File;
+///
+/// fn main() -> Result<(), Report> {
+///     File::open("this/file/does/not/exist")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "error_reporter", issue = "none")]
+pub struct Report<E = Box<dyn Error>> {
+    /// The error being reported.
+    error: E,
+    /// Whether a backtrace should be included as part of the report.
+    show_backtrace: bool,
+    /// Whether the report should be pretty-printed.
+    pretty: bool,
+}
+
+impl<E> Report<E>
+where
+    Report<E>: From<E>,
+{
+    /// Create a new `Report` from an input error.
+    #[unstable(feature = "error_reporter", issue = "none")]
+    pub fn new(error: E) -> Report<E> {
+        Self::from(error)
+    }
+}
+
+impl<E> Report<E> {
+    /// Enable pretty-printing the report across multiple lines.
+    ///
+    /// Each source error is printed on its own line after a `Caused by:`
+    /// heading, numbered if there is more than one.
+    #[unstable(feature = "error_reporter", issue = "none")]
+    #[must_use]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Display the first backtrace in the error chain, if any, after the
+    /// errors.
+    ///
+    /// Backtraces are only printed in pretty mode, since they always span
+    /// multiple lines.
+    #[unstable(feature = "error_reporter", issue = "none")]
+    #[must_use]
+    pub fn show_backtrace(mut self, show_backtrace: bool) -> Self {
+        self.show_backtrace = show_backtrace;
+        self
+    }
+
+    fn fmt_report(&self, error: &dyn Error, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::fmt::Write;
+
+        if !self.pretty {
+            write!(f, "{}", error)?;
+            let mut source = error.source();
+            while let Some(cause) = source {
+                write!(f, ": {}", cause)?;
+                source = cause.source();
+            }
+            return Ok(());
+        }
+
+        write!(f, "{}", error)?;
+
+        if let Some(cause) = error.source() {
+            write!(f, "\n\nCaused by:")?;
+
+            let multiple = cause.source().is_some();
+            for (ind, error) in cause.chain().enumerate() {
+                writeln!(f)?;
+                let mut indented = Indented { inner: f };
+                if multiple {
+                    write!(indented, "{: >4}: {}", ind, error)?;
+                } else {
+                    write!(indented, "      {}", error)?;
+                }
+            }
+        }
+
+        if self.show_backtrace {
+            let backtrace = error.backtrace().or_else(|| {
+                error.source().and_then(|source| source.chain().find_map(|e| e.backtrace()))
+            });
+
+            if let Some(backtrace) = backtrace {
+                let backtrace = backtrace.to_string();
+                f.write_str("\n\nStack backtrace:\n")?;
+                f.write_str(backtrace.trim_end())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[unstable(feature = "error_reporter", issue = "none")]
+impl<E> From<E> for Report<E>
+where
+    E: Error,
+{
+    fn from(error: E) -> Self {
+        Report { error, show_backtrace: false, pretty: false }
+    }
+}
+
+#[unstable(feature = "error_reporter", issue = "none")]
+impl<'a, E> From<E> for Report<Box<dyn Error + 'a>>
+where
+    E: Error + 'a,
+{
+    fn from(error: E) -> Self {
+        let error = Box::new(error);
+        Report { error, show_backtrace: false, pretty: false }
+    }
+}
+
+#[unstable(feature = "error_reporter", issue = "none")]
+impl<E> fmt::Display for Report<E>
+where
+    E: Error,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_report(&self.error, f)
+    }
+}
+
+#[unstable(feature = "error_reporter", issue = "none")]
+impl<'a> fmt::Display for Report<Box<dyn Error + 'a>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_report(&*self.error, f)
+    }
+}
+
+// This type intentionally outputs the same format for `Display` and `Debug` for
+// situations where you unwrap a `Report` or return it from main.
+#[unstable(feature = "error_reporter", issue = "none")]
+impl<E> fmt::Debug for Report<E>
+where
+    Report<E>: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Wrapper type for indenting the inner source.
+struct Indented<'a, D> {
+    inner: &'a mut D,
+}
+
+impl<T> fmt::Write for Indented<'_, T>
+where
+    T: fmt::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.inner.write_char('\n')?;
+                self.inner.write_str("      ")?;
+            }
+
+            self.inner.write_str(line)?;
+        }
+
+        Ok(())
+    }
 }
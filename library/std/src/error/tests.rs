@@ -1,5 +1,7 @@
 use super::Error;
 use crate::fmt;
+use crate::rc::Rc;
+use crate::sync::Arc;
 
 #[derive(Debug, PartialEq)]
 struct A;
@@ -35,3 +37,116 @@ fn downcasting() {
         Err(e) => assert_eq!(*e.downcast::<A>().unwrap(), A),
     }
 }
+
+#[test]
+fn downcasting_shared() {
+    let a: Rc<dyn Error> = Rc::new(A);
+    let a = a.downcast_rc::<B>().unwrap_err();
+    assert_eq!(*a.downcast_rc::<A>().unwrap(), A);
+
+    let a: Arc<dyn Error + Send + Sync> = Arc::new(A);
+    let a = a.downcast_arc::<B>().unwrap_err();
+    assert_eq!(*a.downcast_arc::<A>().unwrap(), A);
+
+    let a: Rc<dyn Error> = Rc::new(A);
+    assert_eq!(a.to_string(), "A");
+}
+
+#[test]
+fn boxed_error_conversions() {
+    fn send_sync() -> Result<(), Box<dyn Error + Send + Sync>> {
+        Err(A)?
+    }
+    fn send() -> Result<(), Box<dyn Error + Send>> {
+        send_sync()?;
+        Ok(())
+    }
+    fn local() -> Result<(), Box<dyn Error>> {
+        send()?;
+        Ok(())
+    }
+
+    assert_eq!(*local().unwrap_err().downcast::<A>().unwrap(), A);
+    let b: Box<dyn Error + Send> = B.into();
+    assert_eq!(*b.downcast::<B>().unwrap(), B);
+}
+
+#[test]
+fn request_context() {
+    use crate::any::Demand;
+
+    #[derive(Debug)]
+    struct WithContext {
+        path: String,
+    }
+
+    impl fmt::Display for WithContext {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "failed on {}", self.path)
+        }
+    }
+
+    impl Error for WithContext {
+        fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+            demand.provide_ref::<str>(&self.path).provide_value::<i32, _>(|| 2);
+        }
+    }
+
+    let err: Box<dyn Error + Send + Sync> = Box::new(WithContext { path: "a.txt".to_owned() });
+    assert_eq!(err.request_ref::<str>(), Some("a.txt"));
+    assert_eq!(err.request_value::<i32>(), Some(2));
+    assert_eq!(err.request_value::<u8>(), None);
+
+    // Wrappers forward to the error they contain.
+    let boxed = Box::new(A);
+    assert_eq!((&boxed as &dyn Error).request_value::<i32>(), None);
+    let wrapped = Box::new(WithContext { path: "b.txt".to_owned() });
+    assert_eq!((&wrapped as &dyn Error).request_ref::<str>(), Some("b.txt"));
+}
+
+#[derive(Debug)]
+struct Wrapper(&'static str, Option<Box<dyn Error + 'static>>);
+
+impl fmt::Display for Wrapper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl Error for Wrapper {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.1.as_deref()
+    }
+}
+
+#[test]
+fn sources_iterate_chain() {
+    let err = Wrapper("outer", Some(Box::new(Wrapper("inner", None))));
+    let messages: Vec<String> = (&err as &dyn Error).sources().map(|e| e.to_string()).collect();
+    assert_eq!(messages, ["outer", "inner"]);
+}
+
+#[test]
+fn report_single_line() {
+    use super::Report;
+
+    let err = Wrapper("outer", Some(Box::new(Wrapper("middle", Some(Box::new(A))))));
+    assert_eq!(Report::new(&err).to_string(), "outer: middle: A");
+    assert_eq!(format!("{:?}", Report::new(&err)), "outer: middle: A");
+
+    let report: Report = Report::from(B);
+    assert_eq!(report.to_string(), "B");
+}
+
+#[test]
+fn report_pretty() {
+    use super::Report;
+
+    let err = Wrapper("outer", Some(Box::new(A)));
+    let expected = "outer\n\nCaused by:\n      A";
+    assert_eq!(Report::new(&err).pretty(true).to_string(), expected);
+
+    let err = Wrapper("outer", Some(Box::new(Wrapper("two\nlines", Some(Box::new(A))))));
+    let expected = "outer\n\nCaused by:\n   0: two\n      lines\n   1: A";
+    assert_eq!(Report::new(&err).pretty(true).to_string(), expected);
+}
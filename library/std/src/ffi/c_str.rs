@@ -8,6 +8,7 @@
 use crate::cmp::Ordering;
 use crate::error::Error;
 use crate::fmt::{self, Write};
+use crate::intrinsics;
 use crate::io;
 use crate::mem;
 use crate::num::NonZeroU8;
@@ -262,6 +263,26 @@ pub struct FromVecWithNulError {
     bytes: Vec<u8>,
 }
 
+/// An error indicating that no nul byte was present.
+///
+/// A slice used to create a [`CStr`] must contain a nul byte somewhere
+/// within the slice.
+///
+/// This error is created by the [`CStr::from_bytes_until_nul`] method.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cstr_from_bytes_until_nul)]
+///
+/// use std::ffi::{CStr, FromBytesUntilNulError};
+///
+/// let _: FromBytesUntilNulError = CStr::from_bytes_until_nul(b"foo").unwrap_err();
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[unstable(feature = "cstr_from_bytes_until_nul", issue = "none")]
+pub struct FromBytesUntilNulError(());
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 enum FromBytesWithNulErrorKind {
     InteriorNul(usize),
@@ -269,10 +290,10 @@ enum FromBytesWithNulErrorKind {
 }
 
 impl FromBytesWithNulError {
-    fn interior_nul(pos: usize) -> FromBytesWithNulError {
+    const fn interior_nul(pos: usize) -> FromBytesWithNulError {
         FromBytesWithNulError { kind: FromBytesWithNulErrorKind::InteriorNul(pos) }
     }
-    fn not_nul_terminated() -> FromBytesWithNulError {
+    const fn not_nul_terminated() -> FromBytesWithNulError {
         FromBytesWithNulError { kind: FromBytesWithNulErrorKind::NotNulTerminated }
     }
 }
@@ -1084,6 +1105,16 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 #[stable(feature = "cstring_from_vec_with_nul", since = "1.58.0")]
 impl Error for FromVecWithNulError {}
 
+#[unstable(feature = "cstr_from_bytes_until_nul", issue = "none")]
+impl Error for FromBytesUntilNulError {}
+
+#[unstable(feature = "cstr_from_bytes_until_nul", issue = "none")]
+impl fmt::Display for FromBytesUntilNulError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("data provided does not contain a nul")
+    }
+}
+
 #[stable(feature = "cstring_from_vec_with_nul", since = "1.58.0")]
 impl fmt::Display for FromVecWithNulError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1135,6 +1166,29 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// Returns the index of the first nul byte in `bytes`, using `memchr` when
+/// called at runtime.
+#[rustc_allow_const_fn_unstable(const_eval_select)]
+const fn find_nul(bytes: &[u8]) -> Option<usize> {
+    const fn const_impl(bytes: &[u8]) -> Option<usize> {
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == 0 {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    fn rt_impl(bytes: &[u8]) -> Option<usize> {
+        memchr::memchr(0, bytes)
+    }
+
+    // SAFETY: both functions return the position of the first nul byte.
+    unsafe { intrinsics::const_eval_select((bytes,), const_impl, rt_impl) }
+}
+
 impl CStr {
     /// Wraps a raw C string with a safe C string wrapper.
     ///
@@ -1226,16 +1280,73 @@ pub unsafe fn from_ptr<'a>(ptr: *const c_char) -> &'a CStr {
     /// let cstr = CStr::from_bytes_with_nul(b"he\0llo\0");
     /// assert!(cstr.is_err());
     /// ```
+    ///
+    /// This function can be used in constants, which checks the literal at compile time:
+    ///
+    /// ```
+    /// #![feature(const_cstr_methods)]
+    ///
+    /// use std::ffi::CStr;
+    ///
+    /// const HELLO: &CStr = match CStr::from_bytes_with_nul(b"hello\0") {
+    ///     Ok(cstr) => cstr,
+    ///     Err(_) => panic!("not a valid C string"),
+    /// };
+    /// assert_eq!(HELLO.to_bytes(), b"hello");
+    /// ```
     #[stable(feature = "cstr_from_bytes", since = "1.10.0")]
-    pub fn from_bytes_with_nul(bytes: &[u8]) -> Result<&CStr, FromBytesWithNulError> {
-        let nul_pos = memchr::memchr(0, bytes);
-        if let Some(nul_pos) = nul_pos {
-            if nul_pos + 1 != bytes.len() {
-                return Err(FromBytesWithNulError::interior_nul(nul_pos));
+    #[rustc_const_unstable(feature = "const_cstr_methods", issue = "none")]
+    pub const fn from_bytes_with_nul(bytes: &[u8]) -> Result<&CStr, FromBytesWithNulError> {
+        match find_nul(bytes) {
+            Some(nul_pos) if nul_pos + 1 == bytes.len() => {
+                // SAFETY: The only nul byte is at the end of `bytes`.
+                Ok(unsafe { CStr::from_bytes_with_nul_unchecked(bytes) })
             }
-            Ok(unsafe { CStr::from_bytes_with_nul_unchecked(bytes) })
-        } else {
-            Err(FromBytesWithNulError::not_nul_terminated())
+            Some(nul_pos) => Err(FromBytesWithNulError::interior_nul(nul_pos)),
+            None => Err(FromBytesWithNulError::not_nul_terminated()),
+        }
+    }
+
+    /// Creates a C string wrapper from a byte slice with any number of nuls.
+    ///
+    /// This method will create a `CStr` from any byte slice that contains at
+    /// least one nul byte. Unlike with [`CStr::from_bytes_with_nul`], the
+    /// caller does not need to know or specify where the nul byte is located.
+    ///
+    /// If the first byte is a nul character, this method will return an
+    /// empty `CStr`. If multiple nul characters are present, the `CStr` will
+    /// end at the first one.
+    ///
+    /// If the slice only has a single nul byte at the end, this method is
+    /// equivalent to [`CStr::from_bytes_with_nul`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(cstr_from_bytes_until_nul)]
+    ///
+    /// use std::ffi::CStr;
+    ///
+    /// // A fixed-size buffer filled in by C code, padded with nuls.
+    /// let buffer = *b"AAAAAAAA\0\0\0\0\0\0\0\0";
+    /// let c_str = CStr::from_bytes_until_nul(&buffer[..]).unwrap();
+    /// assert_eq!(c_str.to_str().unwrap(), "AAAAAAAA");
+    /// ```
+    #[unstable(feature = "cstr_from_bytes_until_nul", issue = "none")]
+    #[rustc_const_unstable(feature = "const_cstr_methods", issue = "none")]
+    pub const fn from_bytes_until_nul(bytes: &[u8]) -> Result<&CStr, FromBytesUntilNulError> {
+        match find_nul(bytes) {
+            Some(nul_pos) => {
+                // SAFETY: `nul_pos + 1 <= bytes.len()`, and the subslice ends
+                // with its first nul byte.
+                Ok(unsafe {
+                    CStr::from_bytes_with_nul_unchecked(slice::from_raw_parts(
+                        bytes.as_ptr(),
+                        nul_pos + 1,
+                    ))
+                })
+            }
+            None => Err(FromBytesUntilNulError(())),
         }
     }
 
@@ -1323,6 +1434,32 @@ pub const fn as_ptr(&self) -> *const c_char {
         self.inner.as_ptr()
     }
 
+    /// Returns the length of `self`, not counting the nul terminator.
+    ///
+    /// Like [`CStr::to_bytes`], this does not scan the string for its nul
+    /// terminator, since the length of a `CStr` is already known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(cstr_count_bytes)]
+    ///
+    /// use std::ffi::CStr;
+    ///
+    /// let cstr = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+    /// assert_eq!(cstr.count_bytes(), 3);
+    ///
+    /// let cstr = CStr::from_bytes_with_nul(b"\0").unwrap();
+    /// assert_eq!(cstr.count_bytes(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "cstr_count_bytes", issue = "none")]
+    #[rustc_const_unstable(feature = "const_cstr_methods", issue = "none")]
+    pub const fn count_bytes(&self) -> usize {
+        self.inner.len() - 1
+    }
+
     /// Converts this C string to a byte slice.
     ///
     /// The returned slice will **not** contain the trailing nul terminator that this C
@@ -125,6 +125,63 @@ fn from_bytes_with_nul_interior() {
     assert!(cstr.is_err());
 }
 
+#[test]
+fn from_bytes_until_nul() {
+    // Test an empty slice. This should fail because it
+    // does not contain a nul byte.
+    let b = b"";
+    assert_eq!(CStr::from_bytes_until_nul(&b[..]), Err(FromBytesUntilNulError(())));
+
+    // Test a non-empty slice, that does not contain a nul byte.
+    let b = b"hello";
+    assert_eq!(CStr::from_bytes_until_nul(&b[..]), Err(FromBytesUntilNulError(())));
+
+    // Test an empty nul-terminated string
+    let b = b"\0";
+    let r = CStr::from_bytes_until_nul(&b[..]).unwrap();
+    assert_eq!(r.to_bytes(), b"");
+
+    // Test a slice with the nul byte in the middle
+    let b = b"hello\0world!";
+    let r = CStr::from_bytes_until_nul(&b[..]).unwrap();
+    assert_eq!(r.to_bytes(), b"hello");
+
+    // Test a slice with the nul byte at the end
+    let b = b"hello\0";
+    let r = CStr::from_bytes_until_nul(&b[..]).unwrap();
+    assert_eq!(r.to_bytes(), b"hello");
+
+    // Test a slice with two nul bytes at the end
+    let b = b"hello\0\0";
+    let r = CStr::from_bytes_until_nul(&b[..]).unwrap();
+    assert_eq!(r.to_bytes(), b"hello");
+
+    // Test a slice containing lots of nul bytes
+    let b = b"\0\0\0\0";
+    let r = CStr::from_bytes_until_nul(&b[..]).unwrap();
+    assert_eq!(r.to_bytes(), b"");
+}
+
+#[test]
+fn cstr_const_methods() {
+    const HELLO: &CStr = match CStr::from_bytes_with_nul(b"hello\0") {
+        Ok(cstr) => cstr,
+        Err(_) => panic!(),
+    };
+    const INTERIOR: bool = CStr::from_bytes_with_nul(b"he\0llo\0").is_err();
+    const UNTIL_NUL: &CStr = match CStr::from_bytes_until_nul(b"hi\0there\0") {
+        Ok(cstr) => cstr,
+        Err(_) => panic!(),
+    };
+    const LEN: usize = HELLO.count_bytes();
+
+    assert_eq!(HELLO.to_bytes(), b"hello");
+    assert!(INTERIOR);
+    assert_eq!(UNTIL_NUL.to_bytes(), b"hi");
+    assert_eq!(LEN, 5);
+    assert_eq!(CString::new("hello").unwrap().count_bytes(), 5);
+}
+
 #[test]
 fn into_boxed() {
     let orig: &[u8] = b"Hello, world!\0";
@@ -146,6 +146,8 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+#[unstable(feature = "cstr_from_bytes_until_nul", issue = "none")]
+pub use self::c_str::FromBytesUntilNulError;
 #[stable(feature = "cstr_from_bytes", since = "1.10.0")]
 pub use self::c_str::FromBytesWithNulError;
 #[stable(feature = "cstring_from_vec_with_nul", since = "1.58.0")]
@@ -168,4 +170,6 @@
 pub use core::ffi::{VaList, VaListImpl};
 
 mod c_str;
-mod os_str;
+
+#[unstable(feature = "os_str_display", issue = "none")]
+pub mod os_str;
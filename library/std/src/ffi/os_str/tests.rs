@@ -163,3 +163,51 @@ fn into_rc() {
     assert_eq!(&*rc2, os_str);
     assert_eq!(&*arc2, os_str);
 }
+
+#[test]
+fn encoded_bytes_round_trip() {
+    let os_str = OsStr::new("foo.tar.gz");
+    let bytes = os_str.as_encoded_bytes();
+    assert_eq!(bytes, b"foo.tar.gz");
+
+    let (stem, ext) = bytes.split_at(bytes.iter().position(|b| *b == b'.').unwrap());
+    assert_eq!(unsafe { OsStr::from_encoded_bytes_unchecked(stem) }, "foo");
+    assert_eq!(unsafe { OsStr::from_encoded_bytes_unchecked(ext) }, ".tar.gz");
+
+    let os_string = OsString::from("héllo");
+    let vec = os_string.clone().into_encoded_bytes();
+    assert_eq!(vec, "héllo".as_bytes());
+    assert_eq!(unsafe { OsString::from_encoded_bytes_unchecked(vec) }, os_string);
+}
+
+#[test]
+fn display_format_flags() {
+    let s = OsStr::new("abc");
+    assert_eq!(format!("{}", s.display()), "abc");
+    assert_eq!(format!("[{:>5}]", s.display()), "[  abc]");
+    assert_eq!(format!("[{:-^7}]", s.display()), "[--abc--]");
+    assert_eq!(format!("[{:.2}]", s.display()), "[ab]");
+}
+
+#[test]
+fn display_lossy_format_flags() {
+    #[cfg(unix)]
+    let os_string = {
+        use crate::os::unix::ffi::OsStrExt;
+        OsStr::from_bytes(b"a\xFFb").to_owned()
+    };
+    #[cfg(windows)]
+    let os_string = {
+        use crate::os::windows::ffi::OsStringExt;
+        OsString::from_wide(&[0x61, 0xD800, 0x62])
+    };
+    #[cfg(any(unix, windows))]
+    {
+        let s = os_string.as_os_str();
+        assert_eq!(format!("{}", s.display()), "a\u{FFFD}b");
+        assert_eq!(format!("[{:<5}]", s.display()), "[a\u{FFFD}b  ]");
+        assert_eq!(format!("[{:>5}]", s.display()), "[  a\u{FFFD}b]");
+        assert_eq!(format!("[{:^6.2}]", s.display()), "[  a\u{FFFD}  ]");
+        assert_eq!(format!("[{:.1}]", s.display()), "[a]");
+    }
+}
@@ -14,8 +14,10 @@
 use crate::ffi::OsString;
 use crate::fmt;
 use crate::io::{self, IoSlice, IoSliceMut, Read, ReadBuf, Seek, SeekFrom, Write};
+use crate::ops::Deref;
 use crate::path::{Path, PathBuf};
 use crate::sys::fs as fs_imp;
+use crate::sys::mmap as mmap_imp;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 use crate::time::SystemTime;
 
@@ -187,6 +189,59 @@ pub struct File {
 #[cfg_attr(not(test), rustc_diagnostic_item = "FileType")]
 pub struct FileType(fs_imp::FileType);
 
+/// Representation of the various timestamps on a file.
+///
+/// This is used with [`File::set_times`] to change any subset of a file's
+/// timestamps in a single call. Timestamps which are not set are left
+/// unchanged.
+#[derive(Copy, Clone, Debug, Default)]
+#[unstable(feature = "file_set_times", issue = "none")]
+pub struct FileTimes(fs_imp::FileTimes);
+
+/// The kind of target a symbolic link created by [`symlink`] points to.
+///
+/// Windows distinguishes between file and directory symlinks, and a link of
+/// the wrong kind cannot be followed. Other platforms ignore this value.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[unstable(feature = "fs_symlink", issue = "none")]
+pub enum SymlinkType {
+    /// The link points to a file, or to anything other than a directory.
+    File,
+    /// The link points to a directory.
+    Dir,
+}
+
+/// A read-only memory mapping of the contents of a file.
+///
+/// An `Mmap` is created by [`Mmap::map_readonly`] and dereferences to the
+/// mapped bytes. Pages are read from the file by the operating system as they
+/// are accessed, which makes mapping a large file cheaper than reading it into
+/// memory when only parts of it are used.
+///
+/// The mapping is independent of the [`File`] it was created from: the file
+/// may be closed while the mapping is in use. The mapping is released when the
+/// `Mmap` is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_mmap)]
+/// use std::fs::{File, Mmap};
+///
+/// fn main() -> std::io::Result<()> {
+///     let file = File::open("data.bin")?;
+///     // SAFETY: no other process or thread modifies `data.bin` while it is mapped.
+///     let map = unsafe { Mmap::map_readonly(&file)? };
+///     drop(file);
+///
+///     let newlines = map.iter().filter(|&&b| b == b'\n').count();
+///     println!("{} lines", newlines);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_mmap", issue = "none")]
+pub struct Mmap(mmap_imp::Mmap);
+
 /// A builder used to create directories in various manners.
 ///
 /// This builder also supports platform-specific options.
@@ -573,6 +628,226 @@ pub fn try_clone(&self) -> io::Result<File> {
     pub fn set_permissions(&self, perm: Permissions) -> io::Result<()> {
         self.inner.set_permissions(perm.0)
     }
+
+    /// Changes the timestamps of the underlying file.
+    ///
+    /// Only the timestamps set on `times` are changed; the others are left
+    /// as they are.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `futimens` function on Unix (falling back to
+    /// `futimes` on macOS before 10.13) and the `SetFileTime` function on Windows. Note that this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the user lacks permission to change timestamps on the
+    /// underlying file. It may also return an error in other os-specific unspecified cases.
+    ///
+    /// This function may return an error if the operating system lacks support to change one or
+    /// more of the timestamps set in the `FileTimes` structure, or if a timestamp cannot be
+    /// represented by the platform.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_set_times)]
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     use std::fs::{self, File, FileTimes};
+    ///
+    ///     let src = fs::metadata("src")?;
+    ///     let dest = File::options().write(true).open("dest")?;
+    ///     let times = FileTimes::new()
+    ///         .set_accessed(src.accessed()?)
+    ///         .set_modified(src.modified()?);
+    ///     dest.set_times(times)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_set_times", issue = "none")]
+    #[doc(alias = "futimens")]
+    #[doc(alias = "futimes")]
+    #[doc(alias = "SetFileTime")]
+    pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
+        self.inner.set_times(times.0)
+    }
+
+    /// Changes the modification time of the underlying file.
+    ///
+    /// This is an alias for `set_times(FileTimes::new().set_modified(time))`.
+    #[unstable(feature = "file_set_times", issue = "none")]
+    #[inline]
+    pub fn set_modified(&self, time: SystemTime) -> io::Result<()> {
+        self.set_times(FileTimes::new().set_modified(time))
+    }
+
+    /// Acquires an exclusive lock on the file, blocking until it can be acquired.
+    ///
+    /// At most one handle can hold an exclusive lock on a file at a time, and
+    /// no shared locks can be held alongside it. The lock is released by
+    /// [`unlock`], or when every handle that [`try_clone`] obtained for the
+    /// same open file has been dropped.
+    ///
+    /// The lock is *advisory*: it only keeps out other processes and handles
+    /// that lock the file as well, and does nothing to stop anyone from
+    /// reading or writing it. On Windows the lock is *mandatory* for the
+    /// locked range instead, so other handles cannot read or write the file
+    /// while it is held.
+    ///
+    /// Locks belong to the open file, not to the process: opening the same
+    /// file twice within one process and locking both handles deadlocks.
+    /// Whether locking a file already locked through the same handle
+    /// succeeds or deadlocks is also platform-dependent, as is whether the
+    /// lock works for files on network file systems.
+    ///
+    /// [`unlock`]: File::unlock
+    /// [`try_clone`]: File::try_clone
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_EX`
+    /// flag, and the `LockFileEx` function on Windows with the `LOCKFILE_EXCLUSIVE_LOCK` flag.
+    /// Other platforms return an [`Unsupported`] error. Note that this
+    /// [may change in the future][changes].
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.lock")?;
+    ///     f.lock()?;
+    ///     // Only one process at a time gets here.
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "LockFileEx")]
+    pub fn lock(&self) -> io::Result<()> {
+        self.inner.lock()
+    }
+
+    /// Acquires a shared lock on the file, blocking until it can be acquired.
+    ///
+    /// Any number of handles can hold a shared lock on a file at the same
+    /// time, but not while an exclusive lock is held. See [`lock`] for how
+    /// locks are released and what they guarantee.
+    ///
+    /// [`lock`]: File::lock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_SH`
+    /// flag, and the `LockFileEx` function on Windows. Other platforms return an
+    /// [`Unsupported`] error. Note that this [may change in the future][changes].
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("cache.db")?;
+    ///     f.lock_shared()?;
+    ///     // Readers may be here at the same time, but no writer.
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Tries to acquire an exclusive lock on the file without blocking.
+    ///
+    /// Returns `Ok(false)` if the lock is currently held by another handle,
+    /// and `Ok(true)` once it has been acquired. See [`lock`] for what the
+    /// lock guarantees.
+    ///
+    /// [`lock`]: File::lock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_EX`
+    /// and `LOCK_NB` flags, and the `LockFileEx` function on Windows with the
+    /// `LOCKFILE_EXCLUSIVE_LOCK` and `LOCKFILE_FAIL_IMMEDIATELY` flags. Other platforms return an
+    /// [`Unsupported`] error. Note that this [may change in the future][changes].
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("app.pid")?;
+    ///     if !f.try_lock()? {
+    ///         eprintln!("another instance is already running");
+    ///         return Ok(());
+    ///     }
+    ///     // Keep `f` alive for as long as this instance runs.
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.inner.try_lock()
+    }
+
+    /// Tries to acquire a shared lock on the file without blocking.
+    ///
+    /// Returns `Ok(false)` if an exclusive lock is currently held by another
+    /// handle, and `Ok(true)` once the lock has been acquired. See [`lock`]
+    /// for what the lock guarantees.
+    ///
+    /// [`lock`]: File::lock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_SH`
+    /// and `LOCK_NB` flags, and the `LockFileEx` function on Windows with the
+    /// `LOCKFILE_FAIL_IMMEDIATELY` flag. Other platforms return an [`Unsupported`] error. Note
+    /// that this [may change in the future][changes].
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    /// [changes]: io#platform-specific-behavior
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Releases the lock held on the file, if any.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_UN`
+    /// flag, and the `UnlockFile` function on Windows. Other platforms return an
+    /// [`Unsupported`] error. Note that this [may change in the future][changes].
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    /// [changes]: io#platform-specific-behavior
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
 }
 
 // In addition to the `impl`s here, `File` also has `impl`s for
@@ -726,6 +1001,80 @@ fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
     }
 }
 
+impl Mmap {
+    /// Maps the whole contents of `file` into memory for reading.
+    ///
+    /// The file must have been opened for reading. The length of the mapping
+    /// is the length of the file at the time of the call; an empty file gives
+    /// an empty mapping.
+    ///
+    /// # Safety
+    ///
+    /// The mapped bytes are shared with the file, not copied out of it, so a
+    /// change to the file shows up in the mapping. The caller must ensure that
+    /// the file is not modified or truncated, by this or any other process,
+    /// for as long as the returned `Mmap` exists:
+    ///
+    /// * writing to the file breaks the guarantee that the bytes behind a
+    ///   `&[u8]` do not change, which is undefined behavior;
+    /// * truncating the file makes accesses past its new end fault, which
+    ///   usually kills the process (with `SIGBUS` on Unix).
+    ///
+    /// Advisory locking with [`File::lock_shared`] only protects against
+    /// cooperating writers.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the file cannot be mapped, for
+    /// example because it is not a regular file, was not opened for reading,
+    /// or is larger than the address space, and on platforms that do not
+    /// support memory-mapped files.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_mmap)]
+    /// use std::fs::{File, Mmap};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let file = File::open("foo.txt")?;
+    ///     // SAFETY: `foo.txt` is not modified while it is mapped.
+    ///     let map = unsafe { Mmap::map_readonly(&file)? };
+    ///     assert_eq!(map.len() as u64, file.metadata()?.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_mmap", issue = "none")]
+    pub unsafe fn map_readonly(file: &File) -> io::Result<Mmap> {
+        mmap_imp::map_readonly(&file.inner).map(Mmap)
+    }
+}
+
+#[unstable(feature = "fs_mmap", issue = "none")]
+impl Deref for Mmap {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+#[unstable(feature = "fs_mmap", issue = "none")]
+impl AsRef<[u8]> for Mmap {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+#[unstable(feature = "fs_mmap", issue = "none")]
+impl fmt::Debug for Mmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mmap").field("len", &self.len()).finish_non_exhaustive()
+    }
+}
+
 impl OpenOptions {
     /// Creates a blank new set of options ready for configuration.
     ///
@@ -835,6 +1184,12 @@ pub fn append(&mut self, append: bool) -> &mut Self {
     /// the file to 0 length if it already exists.
     ///
     /// The file must be opened with write access for truncate to work.
+    /// Combining it with [`append`] is rejected when the file is opened, with
+    /// an [`InvalidInput`] error, unless [`create_new`] is also set.
+    ///
+    /// [`append`]: OpenOptions::append
+    /// [`create_new`]: OpenOptions::create_new
+    /// [`InvalidInput`]: io::ErrorKind::InvalidInput
     ///
     /// # Examples
     ///
@@ -964,6 +1319,10 @@ fn as_inner_mut(&mut self) -> &mut fs_imp::OpenOptions {
     }
 }
 
+/// Allows extension traits within `std`.
+#[unstable(feature = "sealed", issue = "none")]
+impl crate::sealed::Sealed for OpenOptions {}
+
 impl Metadata {
     /// Returns the file type for this metadata.
     ///
@@ -1108,6 +1467,16 @@ pub fn permissions(&self) -> Permissions {
     /// The returned value corresponds to the `mtime` field of `stat` on Unix
     /// platforms and the `ftLastWriteTime` field on Windows platforms.
     ///
+    /// # Precision
+    ///
+    /// The timestamp keeps the full resolution recorded by the platform:
+    /// nanoseconds on most Unix platforms and 100-nanosecond intervals on
+    /// Windows. Platforms and filesystems that only record whole seconds
+    /// (or coarser units) are truncated to that resolution; the value is
+    /// never rounded up. Timestamps from the same filesystem can therefore
+    /// be compared directly, such as when checking whether a file is newer
+    /// than one it was generated from.
+    ///
     /// # Errors
     ///
     /// This field might not be available on all platforms, and will return an
@@ -1143,6 +1512,8 @@ pub fn modified(&self) -> io::Result<SystemTime> {
     /// metadata, for example Windows has an option to disable updating this
     /// time when files are accessed and Linux similarly has `noatime`.
     ///
+    /// The precision is the same as for [`Metadata::modified`].
+    ///
     /// # Errors
     ///
     /// This field might not be available on all platforms, and will return an
@@ -1175,11 +1546,19 @@ pub fn accessed(&self) -> io::Result<SystemTime> {
     /// Linux kernel starting from to 4.11, the `birthtime` field of `stat` on other
     /// Unix platforms, and the `ftCreationTime` field on Windows platforms.
     ///
+    /// The precision is the same as for [`Metadata::modified`].
+    ///
     /// # Errors
     ///
     /// This field might not be available on all platforms, and will return an
     /// `Err` on platforms or filesystems where it is not available.
     ///
+    /// No other timestamp is substituted when the creation time is missing:
+    /// an `Err` is returned rather than, for example, the change or
+    /// modification time. Callers that want a fallback should request it
+    /// explicitly, for instance with
+    /// `metadata.created().or_else(|_| metadata.modified())`.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -1229,6 +1608,40 @@ fn from_inner(attr: fs_imp::FileAttr) -> Metadata {
     }
 }
 
+impl FileTimes {
+    /// Create a new `FileTimes` with no times set.
+    ///
+    /// Using the resulting `FileTimes` in [`File::set_times`] will not modify any timestamps.
+    #[unstable(feature = "file_set_times", issue = "none")]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the last access time of a file.
+    #[unstable(feature = "file_set_times", issue = "none")]
+    pub fn set_accessed(mut self, t: SystemTime) -> Self {
+        self.0.set_accessed(t.into_inner());
+        self
+    }
+
+    /// Set the last modified time of a file.
+    #[unstable(feature = "file_set_times", issue = "none")]
+    pub fn set_modified(mut self, t: SystemTime) -> Self {
+        self.0.set_modified(t.into_inner());
+        self
+    }
+}
+
+impl AsInnerMut<fs_imp::FileTimes> for FileTimes {
+    fn as_inner_mut(&mut self) -> &mut fs_imp::FileTimes {
+        &mut self.0
+    }
+}
+
+/// Allows extension traits within `std`.
+#[unstable(feature = "sealed", issue = "none")]
+impl crate::sealed::Sealed for FileTimes {}
+
 impl Permissions {
     /// Returns `true` if these permissions describe a readonly (unwritable) file.
     ///
@@ -1382,6 +1795,111 @@ pub fn is_file(&self) -> bool {
     pub fn is_symlink(&self) -> bool {
         self.0.is_symlink()
     }
+
+    /// Tests whether this file type represents a block device.
+    ///
+    /// This is always `false` on Windows, where devices are not part of the
+    /// filesystem namespace that [`fs::metadata`] can query.
+    ///
+    /// [`fs::metadata`]: metadata
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_type_special)]
+    /// use std::fs;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let metadata = fs::symlink_metadata("foo.txt")?;
+    ///     let file_type = metadata.file_type();
+    ///
+    ///     assert_eq!(file_type.is_block_device(), false);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    #[unstable(feature = "file_type_special", issue = "none")]
+    pub fn is_block_device(&self) -> bool {
+        self.0.is_block_device()
+    }
+
+    /// Tests whether this file type represents a character device.
+    ///
+    /// This is always `false` on Windows, where devices are not part of the
+    /// filesystem namespace that [`fs::metadata`] can query.
+    ///
+    /// [`fs::metadata`]: metadata
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_type_special)]
+    /// use std::fs;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let metadata = fs::symlink_metadata("foo.txt")?;
+    ///     let file_type = metadata.file_type();
+    ///
+    ///     assert_eq!(file_type.is_char_device(), false);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    #[unstable(feature = "file_type_special", issue = "none")]
+    pub fn is_char_device(&self) -> bool {
+        self.0.is_char_device()
+    }
+
+    /// Tests whether this file type represents a FIFO (named pipe).
+    ///
+    /// This is always `false` on Windows and WASI. Windows named pipes live in
+    /// their own namespace rather than in directories.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_type_special)]
+    /// use std::fs;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let metadata = fs::symlink_metadata("foo.txt")?;
+    ///     let file_type = metadata.file_type();
+    ///
+    ///     assert_eq!(file_type.is_fifo(), false);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    #[unstable(feature = "file_type_special", issue = "none")]
+    pub fn is_fifo(&self) -> bool {
+        self.0.is_fifo()
+    }
+
+    /// Tests whether this file type represents a socket.
+    ///
+    /// On Unix this is a Unix domain socket bound to a path, and on WASI it
+    /// is either a stream or a datagram socket. It is always `false` on
+    /// Windows, where Unix domain sockets are reported as reparse points.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_type_special)]
+    /// use std::fs;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let metadata = fs::symlink_metadata("foo.txt")?;
+    ///     let file_type = metadata.file_type();
+    ///
+    ///     assert_eq!(file_type.is_socket(), false);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    #[unstable(feature = "file_type_special", issue = "none")]
+    pub fn is_socket(&self) -> bool {
+        self.0.is_socket()
+    }
 }
 
 impl AsInner<fs_imp::FileType> for FileType {
@@ -1837,6 +2355,49 @@ pub fn soft_link<P: AsRef<Path>, Q: AsRef<Path>>(original: P, link: Q) -> io::Re
     fs_imp::symlink(original.as_ref(), link.as_ref())
 }
 
+/// Creates a new symbolic link on the filesystem.
+///
+/// The `link` path will be a symbolic link pointing to the `original` path.
+/// `ty` says whether `original` is a directory; it is needed because Windows
+/// creates different kinds of symlinks for files and directories, and is
+/// ignored on other platforms.
+///
+/// # Platform-specific behavior
+///
+/// This function currently corresponds to the `symlink` function on Unix
+/// and the `CreateSymbolicLinkW` function on Windows, which is used the same
+/// way as [`std::os::windows::fs::symlink_file`] and [`symlink_dir`].
+/// Note that, this [may change in the future][changes].
+///
+/// [`std::os::windows::fs::symlink_file`]: crate::os::windows::fs::symlink_file
+/// [`symlink_dir`]: crate::os::windows::fs::symlink_dir
+/// [changes]: io#platform-specific-behavior
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_symlink)]
+/// use std::fs::{self, SymlinkType};
+///
+/// fn main() -> std::io::Result<()> {
+///     fs::symlink("a.txt", "b.txt", SymlinkType::File)?;
+///     fs::symlink("dir", "dir-link", SymlinkType::Dir)?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_symlink", issue = "none")]
+pub fn symlink<P: AsRef<Path>, Q: AsRef<Path>>(
+    original: P,
+    link: Q,
+    ty: SymlinkType,
+) -> io::Result<()> {
+    let (original, link) = (original.as_ref(), link.as_ref());
+    match ty {
+        SymlinkType::File => fs_imp::symlink(original, link),
+        SymlinkType::Dir => fs_imp::symlink_dir(original, link),
+    }
+}
+
 /// Reads a symbolic link, returning the file that the link points to.
 ///
 /// # Platform-specific behavior
@@ -2041,11 +2602,17 @@ pub fn remove_dir<P: AsRef<Path>>(path: P) -> io::Result<()> {
 ///
 /// # Platform-specific behavior
 ///
-/// This function currently corresponds to `opendir`, `lstat`, `rm` and `rmdir` functions on Unix
-/// and the `FindFirstFile`, `GetFileAttributesEx`, `DeleteFile`, and `RemoveDirectory` functions
-/// on Windows.
+/// This function currently corresponds to `openat`, `fdopendir`, `unlinkat` and `lstat` functions
+/// on Unix (except for macOS on x86_64 and Redox, which use the path-based `opendir`, `lstat`,
+/// `rm` and `rmdir`) and the `FindFirstFile`, `GetFileAttributesEx`, `DeleteFile`, and
+/// `RemoveDirectory` functions on Windows.
 /// Note that, this [may change in the future][changes].
 ///
+/// On Unix, directories are opened relative to their parent's file descriptor with
+/// `O_NOFOLLOW`, so replacing a directory with a symlink while it is being removed
+/// cannot make this function delete anything outside of `path`. The path-based
+/// implementations used on the other platforms are not protected against such races.
+///
 /// [changes]: io#platform-specific-behavior
 ///
 /// # Errors
@@ -2296,8 +2863,6 @@ fn as_inner_mut(&mut self) -> &mut fs_imp::DirBuilder {
 /// assert!(!fs::try_exists("does_not_exist.txt").expect("Can't check existence of file does_not_exist.txt"));
 /// assert!(fs::try_exists("/root/secret_file.txt").is_err());
 /// ```
-// FIXME: stabilization should modify documentation of `exists()` to recommend this method
-// instead.
 #[unstable(feature = "path_try_exists", issue = "83186")]
 #[inline]
 pub fn try_exists<P: AsRef<Path>>(path: P) -> io::Result<bool> {
@@ -1,6 +1,6 @@
 use crate::io::prelude::*;
 
-use crate::fs::{self, File, OpenOptions};
+use crate::fs::{self, File, Mmap, OpenOptions};
 use crate::io::{ErrorKind, SeekFrom};
 use crate::path::Path;
 use crate::str;
@@ -620,6 +620,29 @@ fn recursive_rmdir_of_file_symlink() {
     }
 }
 
+#[test]
+fn recursive_rmdir_nested_files() {
+    let tmpdir = tmpdir();
+    let root = tmpdir.join("root");
+    let mut dir = root.clone();
+    for i in 0..8 {
+        dir.push(format!("d{}", i));
+        check!(fs::create_dir_all(&dir));
+        check!(check!(File::create(dir.join("file"))).write(b"foo"));
+    }
+    check!(fs::remove_dir_all(&root));
+
+    assert!(!root.exists());
+    assert!(tmpdir.path().is_dir());
+}
+
+#[test]
+fn recursive_rmdir_of_missing_path() {
+    let tmpdir = tmpdir();
+    let err = fs::remove_dir_all(tmpdir.join("missing")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+}
+
 #[test]
 fn unicode_path_is_dir() {
     assert!(Path::new(".").is_dir());
@@ -828,6 +851,194 @@ fn symlink_noexist() {
     assert_eq!(check!(fs::read_link(&tmpdir.join("bar"))).to_str().unwrap(), "foo");
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn open_tmpfile() {
+    use crate::os::linux::fs::OpenOptionsExt;
+
+    let tmpdir = tmpdir();
+    let mut file = match OpenOptions::new().read(true).write(true).tmpfile(true).open(tmpdir.path())
+    {
+        Ok(file) => file,
+        // Not every filesystem supports `O_TMPFILE`.
+        Err(e) if e.raw_os_error() == Some(libc::EOPNOTSUPP) => return,
+        Err(e) => panic!("failed to open a temporary file: {}", e),
+    };
+    check!(file.write_all(b"foo"));
+    check!(file.seek(SeekFrom::Start(0)));
+    let mut s = String::new();
+    check!(file.read_to_string(&mut s));
+    assert_eq!(s, "foo");
+    assert_eq!(check!(fs::read_dir(tmpdir.path())).count(), 0);
+}
+
+#[test]
+fn file_type_special() {
+    let tmpdir = tmpdir();
+    let file = tmpdir.join("file");
+    check!(File::create(&file));
+
+    let ft = check!(fs::metadata(&file)).file_type();
+    assert!(ft.is_file());
+    assert!(!ft.is_block_device());
+    assert!(!ft.is_char_device());
+    assert!(!ft.is_fifo());
+    assert!(!ft.is_socket());
+
+    #[cfg(unix)]
+    {
+        use crate::ffi::CString;
+        use crate::os::unix::ffi::OsStrExt;
+
+        let ft = check!(fs::metadata("/dev/null")).file_type();
+        assert!(ft.is_char_device());
+        assert!(!ft.is_file() && !ft.is_fifo());
+
+        let fifo = tmpdir.join("fifo");
+        let c_fifo = CString::new(fifo.as_os_str().as_bytes()).unwrap();
+        if unsafe { libc::mkfifo(c_fifo.as_ptr(), 0o644) } == 0 {
+            let ft = check!(fs::symlink_metadata(&fifo)).file_type();
+            assert!(ft.is_fifo());
+            assert!(!ft.is_file() && !ft.is_char_device() && !ft.is_socket());
+        }
+    }
+}
+
+#[test]
+fn portable_symlink() {
+    use crate::fs::SymlinkType;
+
+    let tmpdir = tmpdir();
+    if !got_symlink_permission(&tmpdir) {
+        return;
+    };
+
+    let file = tmpdir.join("file");
+    let dir = tmpdir.join("dir");
+    check!(check!(File::create(&file)).write(b"foo"));
+    check!(fs::create_dir(&dir));
+
+    let file_link = tmpdir.join("file_link");
+    let dir_link = tmpdir.join("dir_link");
+    check!(fs::symlink(&file, &file_link, SymlinkType::File));
+    check!(fs::symlink(&dir, &dir_link, SymlinkType::Dir));
+
+    assert!(check!(file_link.symlink_metadata()).file_type().is_symlink());
+    assert!(check!(dir_link.symlink_metadata()).file_type().is_symlink());
+    assert_eq!(check!(fs::read(&file_link)), b"foo");
+    assert!(dir_link.is_dir());
+}
+
+#[test]
+fn set_get_file_times() {
+    use crate::time::{Duration, SystemTime};
+
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("foo");
+    let file = check!(File::create(&path));
+
+    let accessed = SystemTime::UNIX_EPOCH + Duration::from_secs(12345);
+    let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(54321);
+    check!(file.set_times(fs::FileTimes::new().set_accessed(accessed).set_modified(modified)));
+
+    let metadata = check!(file.metadata());
+    assert_eq!(check!(metadata.accessed()), accessed);
+    assert_eq!(check!(metadata.modified()), modified);
+
+    // Times which are not set are left alone.
+    let later = modified + Duration::from_secs(1);
+    check!(file.set_modified(later));
+    let metadata = check!(file.metadata());
+    assert_eq!(check!(metadata.accessed()), accessed);
+    assert_eq!(check!(metadata.modified()), later);
+
+    #[cfg(windows)]
+    {
+        use crate::os::windows::fs::FileTimesExt;
+
+        let created = SystemTime::UNIX_EPOCH + Duration::from_secs(32123);
+        check!(file.set_times(fs::FileTimes::new().set_created(created)));
+        let metadata = check!(file.metadata());
+        assert_eq!(check!(metadata.created()), created);
+        assert_eq!(check!(metadata.modified()), later);
+    }
+}
+
+#[test]
+#[cfg(any(windows, target_os = "linux", target_os = "macos", target_os = "freebsd"))]
+fn file_lock() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("lock");
+    let f1 = check!(File::create(&path));
+    let f2 = check!(File::open(&path));
+    let f3 = check!(File::open(&path));
+
+    check!(f1.lock());
+    assert!(!check!(f2.try_lock()));
+    assert!(!check!(f2.try_lock_shared()));
+    check!(f1.unlock());
+    assert!(check!(f2.try_lock()));
+    check!(f2.unlock());
+
+    // Shared locks coexist, but keep out exclusive ones.
+    check!(f1.lock_shared());
+    assert!(check!(f2.try_lock_shared()));
+    assert!(!check!(f3.try_lock()));
+    check!(f1.unlock());
+    check!(f2.unlock());
+    assert!(check!(f3.try_lock()));
+
+    // Closing the file releases the lock.
+    drop(f3);
+    assert!(check!(f1.try_lock()));
+}
+
+#[test]
+fn mmap_readonly() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("mmap");
+    let contents: Vec<u8> = (0..20000u32).map(|i| i as u8).collect();
+    check!(fs::write(&path, &contents));
+
+    let file = check!(File::open(&path));
+    let map = check!(unsafe { Mmap::map_readonly(&file) });
+    // The mapping outlives the file it was created from.
+    drop(file);
+    assert_eq!(&map[..], &contents[..]);
+
+    let empty = tmpdir.join("empty");
+    check!(File::create(&empty));
+    let map = check!(unsafe { Mmap::map_readonly(&check!(File::open(&empty))) });
+    assert!(map.is_empty());
+}
+
+#[test]
+fn try_exists() {
+    let tmpdir = tmpdir();
+    let file = tmpdir.join("try_exists_file");
+    check!(File::create(&file));
+
+    assert_eq!(check!(fs::try_exists(&file)), true);
+    assert_eq!(check!(file.try_exists()), true);
+    assert_eq!(check!(tmpdir.join("missing").try_exists()), false);
+
+    // A broken symlink is reported as not existing rather than as an error.
+    if got_symlink_permission(&tmpdir) {
+        let link = tmpdir.join("try_exists_broken_link");
+        check!(symlink_file(&tmpdir.join("missing"), &link));
+        assert_eq!(check!(link.try_exists()), false);
+        assert!(!link.exists());
+    }
+
+    // Errors other than "not found" are propagated, unlike with `exists`.
+    #[cfg(unix)]
+    {
+        let nested = file.join("child");
+        assert!(!nested.exists());
+        assert_eq!(nested.try_exists().unwrap_err().kind(), ErrorKind::NotADirectory);
+    }
+}
+
 #[test]
 fn read_link() {
     if cfg!(windows) {
@@ -996,6 +1207,7 @@ fn c<T: Clone>(t: &T) -> T {
     let invalid_options = "Invalid argument";
     #[cfg(target_os = "vxworks")]
     let invalid_options = "invalid argument";
+    let truncate_append = "cannot truncate a file opened in append mode";
 
     // Test various combinations of creation modes and access modes.
     //
@@ -1033,15 +1245,15 @@ fn c<T: Clone>(t: &T) -> T {
 
     // append
     check!(c(&a).create_new(true).open(&tmpdir.join("d")));
-    error!(c(&a).create(true).truncate(true).open(&tmpdir.join("d")), invalid_options);
-    error!(c(&a).truncate(true).open(&tmpdir.join("d")), invalid_options);
+    error_contains!(c(&a).create(true).truncate(true).open(&tmpdir.join("d")), truncate_append);
+    error_contains!(c(&a).truncate(true).open(&tmpdir.join("d")), truncate_append);
     check!(c(&a).create(true).open(&tmpdir.join("d")));
     check!(c(&a).open(&tmpdir.join("d")));
 
     // read-append
     check!(c(&ra).create_new(true).open(&tmpdir.join("e")));
-    error!(c(&ra).create(true).truncate(true).open(&tmpdir.join("e")), invalid_options);
-    error!(c(&ra).truncate(true).open(&tmpdir.join("e")), invalid_options);
+    error_contains!(c(&ra).create(true).truncate(true).open(&tmpdir.join("e")), truncate_append);
+    error_contains!(c(&ra).truncate(true).open(&tmpdir.join("e")), truncate_append);
     check!(c(&ra).create(true).open(&tmpdir.join("e")));
     check!(c(&ra).open(&tmpdir.join("e")));
 
@@ -1264,6 +1476,30 @@ fn dir_entry_methods() {
     }
 }
 
+#[test]
+fn dir_entry_file_type_does_not_follow_symlinks() {
+    let tmpdir = tmpdir();
+    if !got_symlink_permission(&tmpdir) {
+        return;
+    };
+
+    check!(fs::create_dir(&tmpdir.join("dir")));
+    check!(symlink_dir(&tmpdir.join("dir"), &tmpdir.join("link")));
+
+    for entry in check!(tmpdir.path().read_dir()).map(|e| check!(e)) {
+        let file_type = check!(entry.file_type());
+        let metadata_type = check!(entry.metadata()).file_type();
+        assert_eq!(file_type.is_dir(), metadata_type.is_dir());
+        assert_eq!(file_type.is_file(), metadata_type.is_file());
+        assert_eq!(file_type.is_symlink(), metadata_type.is_symlink());
+        match entry.file_name().to_str() {
+            Some("dir") => assert!(file_type.is_dir()),
+            Some("link") => assert!(file_type.is_symlink()),
+            f => panic!("unknown file name: {:?}", f),
+        }
+    }
+}
+
 #[test]
 fn dir_entry_debug() {
     let tmpdir = tmpdir();
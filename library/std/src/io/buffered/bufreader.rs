@@ -52,8 +52,16 @@ pub struct BufReader<R> {
     pos: usize,
     cap: usize,
     init: usize,
+    // The capacity the buffer may grow to on its own; see `set_max_capacity`.
+    max_capacity: usize,
+    // How many refills in a row were consumed in full by a single `consume`.
+    full_consumes: u8,
 }
 
+// Number of back-to-back fully consumed refills after which an adaptive
+// `BufReader` grows its buffer.
+const GROW_AFTER_FULL_CONSUMES: u8 = 2;
+
 impl<R: Read> BufReader<R> {
     /// Creates a new `BufReader<R>` with a default buffer capacity. The default is currently 8 KB,
     /// but may change in the future.
@@ -94,7 +102,7 @@ pub fn new(inner: R) -> BufReader<R> {
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn with_capacity(capacity: usize, inner: R) -> BufReader<R> {
         let buf = Box::new_uninit_slice(capacity);
-        BufReader { inner, buf, pos: 0, cap: 0, init: 0 }
+        BufReader { inner, buf, pos: 0, cap: 0, init: 0, max_capacity: capacity, full_consumes: 0 }
     }
 }
 
@@ -221,6 +229,122 @@ pub fn into_inner(self) -> R {
         self.inner
     }
 
+    /// Grows the internal buffer so that it can hold at least `additional`
+    /// more bytes than it currently can.
+    ///
+    /// Any data currently in the buffer is preserved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufreader_grow)]
+    /// use std::io::{self, BufRead, BufReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = BufReader::with_capacity(4, &b"abcdefgh"[..]);
+    ///     assert_eq!(reader.fill_buf()?, b"abcd");
+    ///     reader.consume(1);
+    ///
+    ///     reader.grow(4);
+    ///     assert_eq!(reader.capacity(), 8);
+    ///     assert_eq!(reader.buffer(), b"bcd");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "bufreader_grow", issue = "none")]
+    pub fn grow(&mut self, additional: usize) {
+        let capacity = self.buf.len().checked_add(additional).expect("capacity overflow");
+        self.reallocate(capacity);
+    }
+
+    /// Shrinks the internal buffer to a capacity of at least `min_capacity`.
+    ///
+    /// The buffer never shrinks below the amount of data it currently holds,
+    /// and this method does nothing if the capacity is already at most
+    /// `min_capacity`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufreader_grow)]
+    /// use std::io::{self, BufRead, BufReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = BufReader::with_capacity(8, &b"abcdefgh"[..]);
+    ///     reader.fill_buf()?;
+    ///     reader.consume(5);
+    ///
+    ///     reader.shrink_to(2);
+    ///     assert_eq!(reader.capacity(), 3);
+    ///     assert_eq!(reader.buffer(), b"fgh");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "bufreader_grow", issue = "none")]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let capacity = cmp::max(min_capacity, self.cap - self.pos);
+        if capacity < self.buf.len() {
+            self.reallocate(capacity);
+        }
+    }
+
+    /// Lets the internal buffer grow on its own, up to `max_capacity` bytes.
+    ///
+    /// When consumers repeatedly take everything the buffer holds in one go,
+    /// as [`read_line`] does with lines longer than the buffer, the buffer
+    /// is doubled in size before the next refill, until it reaches
+    /// `max_capacity`. This cuts down on the number of small reads from the
+    /// underlying reader when dealing with large records.
+    ///
+    /// Passing a `max_capacity` at most [`capacity`] turns adaptive growth
+    /// off. The buffer is never shrunk automatically; see
+    /// [`shrink_to`] for that.
+    ///
+    /// [`read_line`]: BufRead::read_line
+    /// [`capacity`]: BufReader::capacity
+    /// [`shrink_to`]: BufReader::shrink_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufreader_grow)]
+    /// use std::io::{self, BufRead, BufReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let data = [b'x'; 200];
+    ///     let mut reader = BufReader::with_capacity(4, &data[..]);
+    ///     reader.set_max_capacity(64);
+    ///
+    ///     let mut record = Vec::new();
+    ///     reader.read_until(b'\n', &mut record)?;
+    ///     assert_eq!(record.len(), 200);
+    ///     assert_eq!(reader.capacity(), 64);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "bufreader_grow", issue = "none")]
+    pub fn set_max_capacity(&mut self, max_capacity: usize) {
+        self.max_capacity = max_capacity;
+        self.full_consumes = 0;
+    }
+
+    /// Moves the buffered data into a new buffer of exactly `capacity` bytes,
+    /// which must be able to hold it.
+    fn reallocate(&mut self, capacity: usize) {
+        let len = self.cap - self.pos;
+        debug_assert!(len <= capacity);
+        let mut buf = Box::new_uninit_slice(capacity);
+        buf[..len].copy_from_slice(&self.buf[self.pos..self.cap]);
+        self.buf = buf;
+        self.pos = 0;
+        self.cap = len;
+        self.init = len;
+    }
+
     /// Invalidates all data in the internal buffer.
     #[inline]
     fn discard_buffer(&mut self) {
@@ -234,6 +358,29 @@ impl<R: Seek> BufReader<R> {
     /// the buffer will not be flushed, allowing for more efficient seeks.
     /// This method does not return the location of the underlying reader, so the caller
     /// must track this information themselves if it is required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, BufRead, BufReader, Cursor};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = BufReader::with_capacity(4, Cursor::new(b"abcdefgh"));
+    ///     assert_eq!(reader.fill_buf()?, b"abcd");
+    ///
+    ///     // Moving around within the buffer keeps the buffered data.
+    ///     reader.seek_relative(2)?;
+    ///     assert_eq!(reader.buffer(), b"cd");
+    ///     reader.seek_relative(-1)?;
+    ///     assert_eq!(reader.buffer(), b"bcd");
+    ///
+    ///     // Seeking past the end of the buffer discards it.
+    ///     reader.seek_relative(4)?;
+    ///     assert_eq!(reader.buffer(), b"");
+    ///     assert_eq!(reader.fill_buf()?, b"fgh");
+    ///     Ok(())
+    /// }
+    /// ```
     #[stable(feature = "bufreader_seek_relative", since = "1.53.0")]
     pub fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
         let pos = self.pos as u64;
@@ -378,6 +525,13 @@ fn fill_buf(&mut self) -> io::Result<&[u8]> {
         if self.pos >= self.cap {
             debug_assert!(self.pos == self.cap);
 
+            if self.full_consumes >= GROW_AFTER_FULL_CONSUMES && self.buf.len() < self.max_capacity
+            {
+                let capacity = cmp::min(self.buf.len().saturating_mul(2), self.max_capacity);
+                self.reallocate(capacity);
+                self.full_consumes = 0;
+            }
+
             let mut readbuf = ReadBuf::uninit(&mut self.buf);
 
             // SAFETY: `self.init` is either 0 or set to `readbuf.initialized_len()`
@@ -397,6 +551,15 @@ fn fill_buf(&mut self) -> io::Result<&[u8]> {
     }
 
     fn consume(&mut self, amt: usize) {
+        if self.max_capacity > self.buf.len() {
+            // Only a full buffer handed out and taken back whole suggests that
+            // the consumer wanted more than the buffer could give it.
+            if self.pos == 0 && self.cap == self.buf.len() && amt >= self.cap {
+                self.full_consumes = self.full_consumes.saturating_add(1);
+            } else {
+                self.full_consumes = 0;
+            }
+        }
         self.pos = cmp::min(self.pos + amt, self.cap);
     }
 }
@@ -506,6 +669,14 @@ fn stream_position(&mut self) -> io::Result<u64> {
             )
         })
     }
+
+    /// Seeks relative to the current position.
+    ///
+    /// Unlike [`BufReader::seek`], this keeps the internal buffer if the new
+    /// position lies within it. See [`BufReader::seek_relative`] for details.
+    fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
+        BufReader::seek_relative(self, offset)
+    }
 }
 
 impl<T> SizeHint for BufReader<T> {
@@ -4,6 +4,10 @@
 /// Wraps a writer and buffers output to it, flushing whenever a newline
 /// (`0x0a`, `'\n'`) is detected.
 ///
+/// The byte that ends a line can be changed with [`set_delimiter`].
+///
+/// [`set_delimiter`]: LineWriter::set_delimiter
+///
 /// The [`BufWriter`] struct wraps a writer and buffers its output.
 /// But it only does this batched write when it goes out of scope, or when the
 /// internal buffer is full. Sometimes, you'd prefer to write each line as it's
@@ -66,9 +70,16 @@
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct LineWriter<W: Write> {
     inner: BufWriter<W>,
+    delimiter: u8,
 }
 
 impl<W: Write> LineWriter<W> {
+    /// Gets a mutable reference to the wrapped `BufWriter`, which writes
+    /// without looking for newlines.
+    pub(in crate::io) fn buf_writer_mut(&mut self) -> &mut BufWriter<W> {
+        &mut self.inner
+    }
+
     /// Creates a new `LineWriter`.
     ///
     /// # Examples
@@ -106,7 +117,7 @@ pub fn new(inner: W) -> LineWriter<W> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn with_capacity(capacity: usize, inner: W) -> LineWriter<W> {
-        LineWriter { inner: BufWriter::with_capacity(capacity, inner) }
+        LineWriter { inner: BufWriter::with_capacity(capacity, inner), delimiter: b'\n' }
     }
 
     /// Gets a reference to the underlying writer.
@@ -180,14 +191,57 @@ pub fn get_mut(&mut self) -> &mut W {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn into_inner(self) -> Result<W, IntoInnerError<LineWriter<W>>> {
-        self.inner.into_inner().map_err(|err| err.new_wrapped(|inner| LineWriter { inner }))
+        let delimiter = self.delimiter;
+        self.inner
+            .into_inner()
+            .map_err(|err| err.new_wrapped(|inner| LineWriter { inner, delimiter }))
+    }
+
+    /// Returns the byte that ends a line, `b'\n'` unless changed with
+    /// [`set_delimiter`].
+    ///
+    /// [`set_delimiter`]: LineWriter::set_delimiter
+    #[must_use]
+    #[unstable(feature = "line_writer_delimiter", issue = "none")]
+    pub fn delimiter(&self) -> u8 {
+        self.delimiter
+    }
+
+    /// Sets the byte that ends a line.
+    ///
+    /// The `LineWriter` then flushes whenever a line ending with `delimiter`
+    /// is complete. This is useful for streams of records separated by a byte
+    /// other than a newline, such as NUL. Lines ending in `\r\n` need no
+    /// special handling, since they also end with a newline.
+    ///
+    /// Data that is already buffered is not flushed by this call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(line_writer_delimiter)]
+    /// use std::io::{LineWriter, Write};
+    ///
+    /// let mut writer = LineWriter::new(Vec::new());
+    /// writer.set_delimiter(b'\0');
+    ///
+    /// writer.write_all(b"first\0second\nstill second")?;
+    /// assert_eq!(writer.get_ref(), b"first\0");
+    ///
+    /// writer.write_all(b"\0")?;
+    /// assert_eq!(writer.get_ref(), b"first\0second\nstill second\0");
+    /// # std::io::Result::Ok(())
+    /// ```
+    #[unstable(feature = "line_writer_delimiter", issue = "none")]
+    pub fn set_delimiter(&mut self, delimiter: u8) {
+        self.delimiter = delimiter;
     }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<W: Write> Write for LineWriter<W> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        LineWriterShim::new(&mut self.inner).write(buf)
+        LineWriterShim::new(&mut self.inner, self.delimiter).write(buf)
     }
 
     fn flush(&mut self) -> io::Result<()> {
@@ -195,7 +249,7 @@ fn flush(&mut self) -> io::Result<()> {
     }
 
     fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
-        LineWriterShim::new(&mut self.inner).write_vectored(bufs)
+        LineWriterShim::new(&mut self.inner, self.delimiter).write_vectored(bufs)
     }
 
     fn is_write_vectored(&self) -> bool {
@@ -203,15 +257,15 @@ fn is_write_vectored(&self) -> bool {
     }
 
     fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
-        LineWriterShim::new(&mut self.inner).write_all(buf)
+        LineWriterShim::new(&mut self.inner, self.delimiter).write_all(buf)
     }
 
     fn write_all_vectored(&mut self, bufs: &mut [IoSlice<'_>]) -> io::Result<()> {
-        LineWriterShim::new(&mut self.inner).write_all_vectored(bufs)
+        LineWriterShim::new(&mut self.inner, self.delimiter).write_all_vectored(bufs)
     }
 
     fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> io::Result<()> {
-        LineWriterShim::new(&mut self.inner).write_fmt(fmt)
+        LineWriterShim::new(&mut self.inner, self.delimiter).write_fmt(fmt)
     }
 }
 
@@ -227,6 +281,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
                 "buffer",
                 &format_args!("{}/{}", self.inner.buffer().len(), self.inner.capacity()),
             )
+            .field("delimiter", &self.delimiter)
             .finish_non_exhaustive()
     }
 }
@@ -1,4 +1,5 @@
 use crate::io::{self, BufWriter, IoSlice, Write};
+use crate::iter;
 use crate::sys_common::memchr;
 
 /// Private helper struct for implementing the line-buffered writing logic.
@@ -10,14 +11,18 @@
 /// implementation details of BufWriter. This also allows existing
 /// `BufWriters` to be temporarily given line-buffering logic; this is what
 /// enables Stdout to be alternately in line-buffered or block-buffered mode.
+///
+/// A "line" ends with `delimiter`, which is a newline unless the
+/// `LineWriter` was configured otherwise.
 #[derive(Debug)]
 pub struct LineWriterShim<'a, W: Write> {
     buffer: &'a mut BufWriter<W>,
+    delimiter: u8,
 }
 
 impl<'a, W: Write> LineWriterShim<'a, W> {
-    pub fn new(buffer: &'a mut BufWriter<W>) -> Self {
-        Self { buffer }
+    pub fn new(buffer: &'a mut BufWriter<W>, delimiter: u8) -> Self {
+        Self { buffer, delimiter }
     }
 
     /// Get a reference to the inner writer (that is, the writer
@@ -42,8 +47,8 @@ fn buffered(&self) -> &[u8] {
     /// earlier write only succeeded partially, and we want to retry flushing
     /// the buffered line before continuing with a subsequent write)
     fn flush_if_completed_line(&mut self) -> io::Result<()> {
-        match self.buffered().last().copied() {
-            Some(b'\n') => self.buffer.flush_buf(),
+        match self.buffered().last() {
+            Some(&last) if last == self.delimiter => self.buffer.flush_buf(),
             _ => Ok(()),
         }
     }
@@ -64,7 +69,7 @@ impl<'a, W: Write> Write for LineWriterShim<'a, W> {
     /// writer, it will also flush the existing buffer if it ends with a
     /// newline, even if the incoming data does not contain any newlines.
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let newline_idx = match memchr::memrchr(b'\n', buf) {
+        let newline_idx = match memchr::memrchr(self.delimiter, buf) {
             // If there are no new newlines (that is, if this write is less than
             // one line), just do a regular buffered write (which may flush if
             // we exceed the inner buffer's size)
@@ -122,7 +127,7 @@ fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         } else {
             let scan_area = &buf[flushed..];
             let scan_area = &scan_area[..self.buffer.capacity()];
-            match memchr::memrchr(b'\n', scan_area) {
+            match memchr::memrchr(self.delimiter, scan_area) {
                 Some(newline_idx) => &scan_area[..newline_idx + 1],
                 None => scan_area,
             }
@@ -138,9 +143,8 @@ fn flush(&mut self) -> io::Result<()> {
 
     /// Write some vectored data into this BufReader with line buffering. This
     /// means that, if any newlines are present in the data, the data up to
-    /// and including the buffer containing the last newline is sent directly
-    /// to the inner writer, and the data after it is buffered. Returns the
-    /// number of bytes written.
+    /// and including the last newline is sent directly to the inner writer,
+    /// and the data after it is buffered. Returns the number of bytes written.
     ///
     /// This function operates on a "best effort basis"; in keeping with the
     /// convention of `Write::write`, it makes at most one attempt to write
@@ -151,14 +155,9 @@ fn flush(&mut self) -> io::Result<()> {
     /// newlines.
     ///
     /// Because sorting through an array of `IoSlice` can be a bit convoluted,
-    /// This method differs from write in the following ways:
-    ///
-    /// - It attempts to write the full content of all the buffers up to and
-    ///   including the one containing the last newline. This means that it
-    ///   may attempt to write a partial line, that buffer has data past the
-    ///   newline.
-    /// - If the write only reports partial success, it does not attempt to
-    ///   find the precise location of the written bytes and buffer the rest.
+    /// this method differs from write in that, if the write only reports
+    /// partial success, it does not attempt to find the precise location of
+    /// the written bytes and buffer the rest.
     ///
     /// If the underlying vector doesn't support vectored writing, we instead
     /// simply write the first non-empty buffer with `write`. This way, we
@@ -174,30 +173,44 @@ fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
             };
         }
 
-        // Find the buffer containing the last newline
-        let last_newline_buf_idx = bufs
+        // Find the buffer containing the last newline, and where it is in
+        // that buffer
+        let delimiter = self.delimiter;
+        let last_newline = bufs
             .iter()
             .enumerate()
             .rev()
-            .find_map(|(i, buf)| memchr::memchr(b'\n', buf).map(|_| i));
+            .find_map(|(i, buf)| memchr::memrchr(delimiter, buf).map(|j| (i, j)));
 
         // If there are no new newlines (that is, if this write is less than
         // one line), just do a regular buffered write
-        let last_newline_buf_idx = match last_newline_buf_idx {
+        let (last_newline_buf_idx, newline_idx) = match last_newline {
             // No newlines; just do a normal buffered write
             None => {
                 self.flush_if_completed_line()?;
                 return self.buffer.write_vectored(bufs);
             }
-            Some(i) => i,
+            Some(found) => found,
         };
 
         // Flush existing content to prepare for our write
         self.buffer.flush_buf()?;
 
         // This is what we're going to try to write directly to the inner
-        // writer. The rest will be buffered, if nothing goes wrong.
-        let (lines, tail) = bufs.split_at(last_newline_buf_idx + 1);
+        // writer: every buffer up to the one containing the last newline, with
+        // that one cut just after the newline. The rest will be buffered, if
+        // nothing goes wrong.
+        let (last_line, partial) = bufs[last_newline_buf_idx].split_at(newline_idx + 1);
+        let lines_with_partial = &bufs[..=last_newline_buf_idx];
+        let truncated: Vec<IoSlice<'_>>;
+        let lines = if partial.is_empty() {
+            lines_with_partial
+        } else {
+            let mut lines = lines_with_partial.to_vec();
+            lines[last_newline_buf_idx] = IoSlice::new(last_line);
+            truncated = lines;
+            &truncated
+        };
 
         // Write `lines` directly to the inner writer. In keeping with the
         // `write` convention, make at most one attempt to add new (unbuffered)
@@ -222,8 +235,9 @@ fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
 
         // Now that the write has succeeded, buffer the rest (or as much of the
         // rest as possible)
-        let buffered: usize = tail
-            .iter()
+        let tail = bufs[last_newline_buf_idx + 1..].iter().map(|buf| &**buf);
+        let buffered: usize = iter::once(partial)
+            .chain(tail)
             .filter(|buf| !buf.is_empty())
             .map(|buf| self.buffer.write_to_buf(buf))
             .take_while(|&n| n > 0)
@@ -245,7 +259,7 @@ fn is_write_vectored(&self) -> bool {
     /// writer, it will also flush the existing buffer if it contains any
     /// newlines, even if the incoming data does not contain any newlines.
     fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
-        match memchr::memrchr(b'\n', buf) {
+        match memchr::memrchr(self.delimiter, buf) {
             // If there are no new newlines (that is, if this write is less than
             // one line), just do a regular buffered write (which may flush if
             // we exceed the inner buffer's size)
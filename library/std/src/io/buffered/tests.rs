@@ -137,6 +137,28 @@ fn test_buffered_reader_seek_relative() {
     assert_eq!(reader.fill_buf().ok(), Some(&[2, 3][..]));
 }
 
+#[test]
+fn test_buffered_reader_seek_relative_through_trait() {
+    fn seek_relative<S: Seek>(s: &mut S, offset: i64) -> io::Result<()> {
+        s.seek_relative(offset)
+    }
+
+    let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+    let mut reader = BufReader::with_capacity(4, io::Cursor::new(inner));
+    assert_eq!(reader.fill_buf().ok(), Some(&[5, 6, 7, 0][..]));
+    reader.consume(3);
+
+    // Staying within the buffer keeps it.
+    assert!(seek_relative(&mut reader, -2).is_ok());
+    assert_eq!(reader.buffer(), &[6, 7, 0]);
+    assert_eq!(reader.get_ref().position(), 4);
+
+    // Leaving it seeks the inner reader.
+    assert!(seek_relative(&mut reader, 4).is_ok());
+    assert_eq!(reader.buffer(), &[]);
+    assert_eq!(reader.fill_buf().ok(), Some(&[2, 3, 4][..]));
+}
+
 #[test]
 fn test_buffered_reader_stream_position() {
     let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
@@ -315,6 +337,54 @@ fn test_buffered_reader_read_to_string_consumes_buffer() {
     assert!(reader.buffer().is_empty());
 }
 
+#[test]
+fn test_buffered_reader_grow_shrink() {
+    let data: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7];
+    let mut reader = BufReader::with_capacity(3, data);
+    assert_eq!(reader.fill_buf().ok(), Some(&[0, 1, 2][..]));
+    reader.consume(1);
+
+    reader.grow(3);
+    assert_eq!(reader.capacity(), 6);
+    assert_eq!(reader.buffer(), &[1, 2]);
+    reader.consume(2);
+    assert_eq!(reader.fill_buf().ok(), Some(&[3, 4, 5, 6, 7][..]));
+    reader.consume(1);
+
+    // Never drops buffered data.
+    reader.shrink_to(0);
+    assert_eq!(reader.capacity(), 4);
+    assert_eq!(reader.buffer(), &[4, 5, 6, 7]);
+    reader.shrink_to(10);
+    assert_eq!(reader.capacity(), 4);
+}
+
+#[test]
+fn test_buffered_reader_adaptive() {
+    let mut data = vec![b'a'; 100];
+    data.extend_from_slice(b"\nbb\ncc\n");
+    let mut reader = BufReader::with_capacity(4, &data[..]);
+    reader.set_max_capacity(32);
+
+    let mut line = String::new();
+    assert_eq!(reader.read_line(&mut line).ok(), Some(101));
+    assert_eq!(reader.capacity(), 32);
+
+    // Short lines don't grow the buffer any further.
+    reader.set_max_capacity(64);
+    for _ in 0..2 {
+        line.clear();
+        assert_eq!(reader.read_line(&mut line).ok(), Some(3));
+    }
+    assert_eq!(reader.capacity(), 32);
+
+    // Without a maximum the capacity stays fixed.
+    let mut reader = BufReader::with_capacity(4, &data[..]);
+    line.clear();
+    assert_eq!(reader.read_line(&mut line).ok(), Some(101));
+    assert_eq!(reader.capacity(), 4);
+}
+
 #[test]
 fn test_buffered_writer() {
     let inner = Vec::new();
@@ -670,6 +740,9 @@ fn line_vectored() {
         0,
     );
     assert_eq!(a.write_vectored(&[IoSlice::new(b"a\nb"),]).unwrap(), 3);
+    // The partial line after the last newline is buffered.
+    assert_eq!(a.get_ref(), b"\nabaca\n");
+    a.flush().unwrap();
     assert_eq!(a.get_ref(), b"\nabaca\nb");
 }
 
@@ -736,7 +809,7 @@ fn drop(&mut self) {
     a.flush().unwrap();
 
     // erroneous writes stop and don't write more
-    a.get_mut().calls.push_back(Call::Write { inputs: vec![b"x", b"\na"], output: Err(err()) });
+    a.get_mut().calls.push_back(Call::Write { inputs: vec![b"x", b"\n"], output: Err(err()) });
     a.get_mut().calls.push_back(Call::Flush { output: Ok(()) });
     assert!(a.write_vectored(&[IoSlice::new(b"x"), IoSlice::new(b"\na")]).is_err());
     a.flush().unwrap();
@@ -746,6 +819,41 @@ fn err() -> io::Error {
     }
 }
 
+/// Test that a vectored write buffers the data after the last newline, even
+/// when it is in the same buffer as the newline.
+#[test]
+fn line_vectored_partial_line() {
+    let mut writer = LineWriter::new(Vec::new());
+    let content = [IoSlice::new(b"Line 1\nLi"), IoSlice::new(b"ne 2\nLine"), IoSlice::new(b" 3")];
+
+    assert_eq!(writer.write_vectored(&content).unwrap(), 20);
+    assert_eq!(writer.get_ref(), b"Line 1\nLine 2\n");
+
+    assert_eq!(writer.write_vectored(&[IoSlice::new(b"\n")]).unwrap(), 1);
+    assert_eq!(writer.get_ref(), b"Line 1\nLine 2\nLine 3\n");
+}
+
+#[test]
+fn line_writer_delimiter() {
+    let mut writer = LineWriter::new(Vec::new());
+    assert_eq!(writer.delimiter(), b'\n');
+    writer.set_delimiter(b'\0');
+    assert_eq!(writer.delimiter(), b'\0');
+
+    assert_eq!(writer.write(b"abc\ndef").unwrap(), 7);
+    assert_eq!(writer.get_ref(), b"");
+
+    assert_eq!(writer.write(b"\0gh").unwrap(), 3);
+    assert_eq!(writer.get_ref(), b"abc\ndef\0");
+
+    let content = [IoSlice::new(b"i\0j"), IoSlice::new(b"\nk")];
+    assert_eq!(writer.write_vectored(&content).unwrap(), 5);
+    assert_eq!(writer.get_ref(), b"abc\ndef\0ghi\0");
+
+    writer.write_all(b"l\0").unwrap();
+    assert_eq!(writer.get_ref(), b"abc\ndef\0ghi\0j\nkl\0");
+}
+
 /// Test that, in cases where vectored writing is not enabled, the
 /// LineWriter uses the normal `write` call, which more-correctly handles
 /// partial lines
@@ -255,6 +255,12 @@ pub enum ErrorKind {
     /// arguments would have been exceeded.
     #[unstable(feature = "io_error_more", issue = "86442")]
     ArgumentListTooLong,
+    /// The operation was started but could not be completed immediately.
+    ///
+    /// This typically comes from a non-blocking operation, such as connecting a socket, which will
+    /// finish asynchronously and has to be checked on later.
+    #[unstable(feature = "io_error_more", issue = "86442")]
+    InProgress,
     /// This operation was interrupted.
     ///
     /// Interrupted operations can typically be retried.
@@ -333,6 +339,7 @@ pub(crate) fn as_str(&self) -> &'static str {
             FilesystemLoop => "filesystem loop or indirection limit (e.g. symlink loop)",
             FilesystemQuotaExceeded => "filesystem quota exceeded",
             HostUnreachable => "host unreachable",
+            InProgress => "operation in progress",
             Interrupted => "operation interrupted",
             InvalidData => "invalid data",
             InvalidInput => "invalid input parameter",
@@ -451,16 +458,80 @@ fn _new(kind: ErrorKind, error: Box<dyn error::Error + Send + Sync>) -> Error {
     /// Creates a new I/O error from a known kind of error as well as a
     /// constant message.
     ///
-    /// This function does not allocate.
+    /// Unlike [`Error::new`], this function does not allocate, and can be
+    /// used in constants. The message is taken by double reference so that
+    /// `Error` can stay the size of a pointer and a tag; string literals can
+    /// simply be passed as `&"message"`.
     ///
     /// This function should maybe change to
     /// `new_const<const MSG: &'static str>(kind: ErrorKind)`
     /// in the future, when const generics allow that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(io_error_new_const)]
+    ///
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// const NOT_READY: Error = Error::new_const(ErrorKind::WouldBlock, &"device not ready");
+    ///
+    /// assert_eq!(NOT_READY.kind(), ErrorKind::WouldBlock);
+    /// assert_eq!(NOT_READY.to_string(), "device not ready");
+    /// ```
+    #[unstable(feature = "io_error_new_const", issue = "none")]
     #[inline]
-    pub(crate) const fn new_const(kind: ErrorKind, message: &'static &'static str) -> Error {
+    pub const fn new_const(kind: ErrorKind, message: &'static &'static str) -> Error {
         Self { repr: Repr::SimpleMessage(kind, message) }
     }
 
+    /// Attempts to downcast the custom boxed error to `E`.
+    ///
+    /// If this [`Error`] contains a custom boxed error of type `E`, it is
+    /// returned by value. Otherwise the original [`Error`] is returned
+    /// unchanged, so no information is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(io_error_downcast)]
+    ///
+    /// use std::fmt;
+    /// use std::io;
+    ///
+    /// #[derive(Debug)]
+    /// struct ParseFailure(u32);
+    ///
+    /// impl fmt::Display for ParseFailure {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "parse failure at line {}", self.0)
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for ParseFailure {}
+    ///
+    /// let err = io::Error::new(io::ErrorKind::InvalidData, ParseFailure(12));
+    /// let original = err.downcast::<ParseFailure>().unwrap();
+    /// assert_eq!(original.0, 12);
+    ///
+    /// let err = io::Error::from(io::ErrorKind::NotFound);
+    /// let err = err.downcast::<ParseFailure>().unwrap_err();
+    /// assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    /// ```
+    #[unstable(feature = "io_error_downcast", issue = "none")]
+    pub fn downcast<E>(self) -> result::Result<E, Self>
+    where
+        E: error::Error + Send + Sync + 'static,
+    {
+        match self.repr {
+            Repr::Custom(b) if b.error.is::<E>() => {
+                // The check above guarantees that this downcast succeeds.
+                Ok(*b.error.downcast::<E>().unwrap())
+            }
+            repr => Err(Error { repr }),
+        }
+    }
+
     /// Returns an error representing the last OS error which occurred.
     ///
     /// This function reads the value of `errno` for the target platform (e.g.
@@ -58,6 +58,37 @@ impl error::Error for TestError {}
     extracted.downcast::<TestError>().unwrap();
 }
 
+#[test]
+fn test_downcast() {
+    #[derive(Debug, PartialEq)]
+    struct TestError(i32);
+
+    impl fmt::Display for TestError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "test error {}", self.0)
+        }
+    }
+
+    impl error::Error for TestError {}
+
+    let err = Error::new(ErrorKind::Other, TestError(1));
+    assert_eq!(err.downcast::<TestError>().unwrap(), TestError(1));
+
+    let err = Error::other(TestError(2));
+    let err = err.downcast::<fmt::Error>().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert!(err.get_ref().unwrap().is::<TestError>());
+
+    for err in [
+        Error::from(ErrorKind::NotFound),
+        Error::from_raw_os_error(1),
+        Error::new_const(ErrorKind::NotFound, &"hello"),
+    ] {
+        let kind = err.kind();
+        assert_eq!(err.downcast::<TestError>().unwrap_err().kind(), kind);
+    }
+}
+
 #[test]
 fn test_const() {
     const E: Error = Error::new_const(ErrorKind::NotFound, &"hello");
@@ -67,3 +98,12 @@ fn test_const() {
     assert!(format!("{:?}", E).contains("\"hello\""));
     assert!(format!("{:?}", E).contains("NotFound"));
 }
+
+#[test]
+#[cfg(unix)]
+fn test_decode_in_progress() {
+    let err = Error::from_raw_os_error(libc::EINPROGRESS);
+    assert_eq!(err.kind(), ErrorKind::InProgress);
+    assert_eq!(format!("{:?}", ErrorKind::InProgress), "InProgress");
+    assert_eq!(Error::from(ErrorKind::InProgress).to_string(), "operation in progress");
+}
@@ -0,0 +1,254 @@
+#[cfg(test)]
+mod tests;
+
+use crate::fmt;
+use crate::io::{self, Error, ErrorKind, Write};
+use crate::str;
+
+/// An adapter which implements [`fmt::Write`] on top of an [`io::Write`].
+///
+/// This struct is created by the [`as_fmt`] method on [`Write`]. See its
+/// documentation for more.
+///
+/// [`fmt::Write`] can only report that an error happened, not which one, so the
+/// first I/O error returned by the underlying writer is kept and can be
+/// retrieved with [`take_error`].
+///
+/// [`as_fmt`]: Write::as_fmt
+/// [`take_error`]: FmtWriter::take_error
+#[unstable(feature = "io_fmt_bridge", issue = "none")]
+pub struct FmtWriter<'a, W: ?Sized + 'a> {
+    inner: &'a mut W,
+    error: Option<Error>,
+}
+
+impl<'a, W: ?Sized + Write> FmtWriter<'a, W> {
+    pub(super) fn new(inner: &'a mut W) -> FmtWriter<'a, W> {
+        FmtWriter { inner, error: None }
+    }
+
+    /// Returns the I/O error that caused a write to fail, if any, leaving
+    /// `None` in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(io_fmt_bridge)]
+    /// use std::fmt::Write as _;
+    /// use std::io::{self, Write as _};
+    ///
+    /// let mut buf = [0u8; 4];
+    /// let mut cursor = io::Cursor::new(&mut buf[..]);
+    /// let mut writer = cursor.as_fmt();
+    ///
+    /// assert!(write!(writer, "too long").is_err());
+    /// assert_eq!(writer.take_error().unwrap().kind(), io::ErrorKind::WriteZero);
+    /// assert!(writer.take_error().is_none());
+    /// ```
+    #[unstable(feature = "io_fmt_bridge", issue = "none")]
+    pub fn take_error(&mut self) -> Option<Error> {
+        self.error.take()
+    }
+}
+
+#[unstable(feature = "io_fmt_bridge", issue = "none")]
+impl<W: ?Sized + Write> fmt::Write for FmtWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self.inner.write_all(s.as_bytes()) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                if self.error.is_none() {
+                    self.error = Some(e);
+                }
+                Err(fmt::Error)
+            }
+        }
+    }
+}
+
+#[unstable(feature = "io_fmt_bridge", issue = "none")]
+impl<W: ?Sized> fmt::Debug for FmtWriter<'_, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FmtWriter").field("error", &self.error).finish_non_exhaustive()
+    }
+}
+
+/// An adapter which implements [`io::Write`] on top of a [`fmt::Write`].
+///
+/// The bytes written must form valid UTF-8. A multi-byte character may be
+/// split across several calls to [`write`]: the bytes of an incomplete
+/// character at the end of a write are buffered until the rest of it arrives.
+/// Bytes that can never form valid UTF-8 result in an error of kind
+/// [`ErrorKind::InvalidData`].
+///
+/// Since [`fmt::Write`] has no way of reporting what went wrong, an error from
+/// the underlying writer becomes a generic "formatter error".
+///
+/// [`write`]: Write::write
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_fmt_bridge)]
+/// use std::io::{self, Write};
+///
+/// let mut writer = io::Utf8Writer::new(String::new());
+///
+/// // "é" is encoded as [0xC3, 0xA9], here split over two writes.
+/// writer.write_all(b"caf\xC3")?;
+/// writer.write_all(b"\xA9!")?;
+///
+/// assert_eq!(writer.into_inner(), "café!");
+/// # Ok::<(), io::Error>(())
+/// ```
+#[unstable(feature = "io_fmt_bridge", issue = "none")]
+pub struct Utf8Writer<W> {
+    inner: W,
+    // The bytes of an incomplete character at the end of the last write.
+    partial: [u8; 4],
+    partial_len: usize,
+}
+
+impl<W: fmt::Write> Utf8Writer<W> {
+    /// Creates a new `Utf8Writer` which writes to `inner`.
+    #[unstable(feature = "io_fmt_bridge", issue = "none")]
+    pub fn new(inner: W) -> Utf8Writer<W> {
+        Utf8Writer { inner, partial: [0; 4], partial_len: 0 }
+    }
+}
+
+impl<W> Utf8Writer<W> {
+    /// Gets a reference to the underlying writer.
+    #[unstable(feature = "io_fmt_bridge", issue = "none")]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    #[unstable(feature = "io_fmt_bridge", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the bytes of an incomplete character that are waiting for the
+    /// rest of it to be written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(io_fmt_bridge)]
+    /// use std::io::{self, Write};
+    ///
+    /// let mut writer = io::Utf8Writer::new(String::new());
+    /// writer.write_all(b"\xE2\x82")?;
+    /// assert_eq!(writer.pending(), b"\xE2\x82");
+    ///
+    /// writer.write_all(b"\xAC")?;
+    /// assert_eq!(writer.pending(), b"");
+    /// assert_eq!(writer.get_ref(), "€");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    #[unstable(feature = "io_fmt_bridge", issue = "none")]
+    pub fn pending(&self) -> &[u8] {
+        &self.partial[..self.partial_len]
+    }
+
+    /// Unwraps this `Utf8Writer`, returning the underlying writer.
+    ///
+    /// The bytes of an incomplete character, as returned by [`pending`], are
+    /// discarded.
+    ///
+    /// [`pending`]: Utf8Writer::pending
+    #[unstable(feature = "io_fmt_bridge", issue = "none")]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: fmt::Write> Utf8Writer<W> {
+    fn write_str(&mut self, s: &str) -> io::Result<()> {
+        self.inner
+            .write_str(s)
+            .map_err(|_| Error::new_const(ErrorKind::Uncategorized, &"formatter error"))
+    }
+
+    // Completes the buffered character with bytes from the start of `buf`,
+    // returning how many bytes were used.
+    fn complete_partial(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut used = 0;
+        while self.partial_len > 0 && used < buf.len() {
+            self.partial[self.partial_len] = buf[used];
+            self.partial_len += 1;
+            used += 1;
+            match str::from_utf8(&self.partial[..self.partial_len]) {
+                Ok(_) => {
+                    // Copy out of `self.partial`, since `write_str` borrows `self`.
+                    let (bytes, len) = (self.partial, self.partial_len);
+                    self.partial_len = 0;
+                    // SAFETY: `bytes[..len]` was just validated as UTF-8.
+                    self.write_str(unsafe { str::from_utf8_unchecked(&bytes[..len]) })?;
+                }
+                Err(e) if e.error_len().is_some() => {
+                    self.partial_len = 0;
+                    return Err(invalid_utf8());
+                }
+                Err(_) => {}
+            }
+        }
+        Ok(used)
+    }
+}
+
+fn invalid_utf8() -> Error {
+    Error::new_const(ErrorKind::InvalidData, &"stream did not contain valid UTF-8")
+}
+
+#[unstable(feature = "io_fmt_bridge", issue = "none")]
+impl<W: fmt::Write> Write for Utf8Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let used = self.complete_partial(buf)?;
+        if self.partial_len > 0 {
+            // All of `buf` went into the incomplete character.
+            return Ok(used);
+        }
+
+        let rest = &buf[used..];
+        match str::from_utf8(rest) {
+            Ok(s) => {
+                self.write_str(s)?;
+                Ok(buf.len())
+            }
+            Err(e) => {
+                let valid = e.valid_up_to();
+                // SAFETY: `from_utf8` checked the bytes up to `valid`.
+                self.write_str(unsafe { str::from_utf8_unchecked(&rest[..valid]) })?;
+                match e.error_len() {
+                    None => {
+                        // The tail is the start of a character, at most 3 bytes long.
+                        let tail = &rest[valid..];
+                        self.partial[..tail.len()].copy_from_slice(tail);
+                        self.partial_len = tail.len();
+                        Ok(buf.len())
+                    }
+                    // Report the valid prefix as written, and the error on the next call.
+                    Some(_) if used + valid > 0 => Ok(used + valid),
+                    Some(_) => Err(invalid_utf8()),
+                }
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "io_fmt_bridge", issue = "none")]
+impl<W: fmt::Debug> fmt::Debug for Utf8Writer<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf8Writer")
+            .field("inner", &self.inner)
+            .field("pending", &self.pending())
+            .finish()
+    }
+}
@@ -0,0 +1,63 @@
+use super::Utf8Writer;
+use crate::fmt::{self, Write as _};
+use crate::io::{self, Write};
+
+#[test]
+fn fmt_writer_error() {
+    let mut buf = [0u8; 3];
+    let mut cursor = io::Cursor::new(&mut buf[..]);
+    let mut writer = cursor.as_fmt();
+
+    assert!(write!(writer, "ab").is_ok());
+    assert!(write!(writer, "cd").is_err());
+    assert_eq!(writer.take_error().unwrap().kind(), io::ErrorKind::WriteZero);
+    assert!(writer.take_error().is_none());
+    assert_eq!(&buf, b"abc");
+}
+
+#[test]
+fn utf8_writer_split_chars() {
+    let text = "a\u{e9}\u{20ac}\u{1f600}z";
+    // Feed the bytes in every chunk size, so that each character is split at
+    // every possible position.
+    for chunk in 1..=text.len() {
+        let mut writer = Utf8Writer::new(String::new());
+        for bytes in text.as_bytes().chunks(chunk) {
+            writer.write_all(bytes).unwrap();
+        }
+        assert_eq!(writer.pending(), b"");
+        assert_eq!(writer.into_inner(), text);
+    }
+}
+
+#[test]
+fn utf8_writer_invalid() {
+    let mut writer = Utf8Writer::new(String::new());
+    // The valid prefix is accepted, and the invalid byte is reported by the
+    // next call.
+    assert_eq!(writer.write(b"ab\xFFcd").unwrap(), 2);
+    assert_eq!(writer.write(b"\xFFcd").unwrap_err().kind(), io::ErrorKind::InvalidData);
+    assert_eq!(writer.write_all(b"ab\xFF").unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+    // A buffered character that turns out to be invalid is discarded.
+    assert_eq!(writer.write(b"\xE2\x82").unwrap(), 2);
+    assert_eq!(writer.write(b"x").unwrap_err().kind(), io::ErrorKind::InvalidData);
+    assert_eq!(writer.pending(), b"");
+    writer.write_all(b"!").unwrap();
+
+    assert_eq!(writer.get_ref(), "abab!");
+}
+
+#[test]
+fn utf8_writer_inner_error() {
+    struct Fail;
+
+    impl fmt::Write for Fail {
+        fn write_str(&mut self, _: &str) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+
+    let mut writer = Utf8Writer::new(Fail);
+    assert!(writer.write(b"a").is_err());
+}
@@ -276,6 +276,8 @@
 #[unstable(feature = "internal_output_capture", issue = "none")]
 #[doc(no_inline, hidden)]
 pub use self::stdio::set_output_capture;
+#[unstable(feature = "stdout_buffer_mode", issue = "none")]
+pub use self::stdio::BufferMode;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::stdio::{stderr, stdin, stdout, Stderr, Stdin, Stdout};
 #[unstable(feature = "stdio_locked", issue = "86845")]
@@ -287,17 +289,26 @@
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::util::{empty, repeat, sink, Empty, Repeat, Sink};
 
+#[unstable(feature = "io_fmt_bridge", issue = "none")]
+pub use self::fmt_writer::{FmtWriter, Utf8Writer};
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub use self::pipe::{pipe, PipeReader, PipeWriter};
 #[unstable(feature = "read_buf", issue = "78485")]
 pub use self::readbuf::ReadBuf;
+#[unstable(feature = "io_transcode", issue = "none")]
+pub use self::transcode::{Latin1Reader, Utf16Reader};
 
 mod buffered;
 pub(crate) mod copy;
 mod cursor;
 mod error;
+mod fmt_writer;
 mod impls;
+mod pipe;
 pub mod prelude;
 mod readbuf;
 mod stdio;
+mod transcode;
 mod util;
 
 const DEFAULT_BUF_SIZE: usize = crate::sys_common::io::DEFAULT_BUF_SIZE;
@@ -1639,32 +1650,14 @@ fn write_all_vectored(&mut self, mut bufs: &mut [IoSlice<'_>]) -> Result<()> {
     fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> Result<()> {
         // Create a shim which translates a Write to a fmt::Write and saves
         // off I/O errors. instead of discarding them
-        struct Adapter<'a, T: ?Sized + 'a> {
-            inner: &'a mut T,
-            error: Result<()>,
-        }
-
-        impl<T: Write + ?Sized> fmt::Write for Adapter<'_, T> {
-            fn write_str(&mut self, s: &str) -> fmt::Result {
-                match self.inner.write_all(s.as_bytes()) {
-                    Ok(()) => Ok(()),
-                    Err(e) => {
-                        self.error = Err(e);
-                        Err(fmt::Error)
-                    }
-                }
-            }
-        }
-
-        let mut output = Adapter { inner: self, error: Ok(()) };
+        let mut output = FmtWriter::new(self);
         match fmt::write(&mut output, fmt) {
             Ok(()) => Ok(()),
             Err(..) => {
                 // check if the error came from the underlying `Write` or not
-                if output.error.is_err() {
-                    output.error
-                } else {
-                    Err(Error::new_const(ErrorKind::Uncategorized, &"formatter error"))
+                match output.take_error() {
+                    Some(e) => Err(e),
+                    None => Err(Error::new_const(ErrorKind::Uncategorized, &"formatter error")),
                 }
             }
         }
@@ -1698,6 +1691,38 @@ fn by_ref(&mut self) -> &mut Self
     {
         self
     }
+
+    /// Creates an adapter which implements [`fmt::Write`] by writing to this
+    /// instance of `Write`.
+    ///
+    /// This lets code written against [`fmt::Write`] output to files, sockets
+    /// and other byte streams. The I/O error that made a write fail is lost by
+    /// [`fmt::Write`], but can be recovered with [`FmtWriter::take_error`].
+    ///
+    /// For the opposite direction, see [`Utf8Writer`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(io_fmt_bridge)]
+    /// use std::fmt;
+    /// use std::io::Write;
+    ///
+    /// fn greet(out: &mut impl fmt::Write) -> fmt::Result {
+    ///     write!(out, "Hello, {}!", "world")
+    /// }
+    ///
+    /// let mut buf = Vec::new();
+    /// greet(&mut buf.as_fmt()).unwrap();
+    /// assert_eq!(buf, b"Hello, world!");
+    /// ```
+    #[unstable(feature = "io_fmt_bridge", issue = "none")]
+    fn as_fmt(&mut self) -> FmtWriter<'_, Self>
+    where
+        Self: Sized,
+    {
+        FmtWriter::new(self)
+    }
 }
 
 /// The `Seek` trait provides a cursor which can be moved within a stream of
@@ -1854,6 +1879,36 @@ fn stream_len(&mut self) -> Result<u64> {
     fn stream_position(&mut self) -> Result<u64> {
         self.seek(SeekFrom::Current(0))
     }
+
+    /// Seeks relative to the current position.
+    ///
+    /// This is equivalent to `self.seek(SeekFrom::Current(offset))` but
+    /// doesn't return the new position, which lets implementations skip
+    /// work that finding it out would take. For example, [`BufReader`] keeps
+    /// its buffer when the new position is still inside of it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(seek_seek_relative)]
+    /// use std::io::{self, Cursor, Read, Seek};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut cursor = Cursor::new(b"abcdef");
+    ///     cursor.seek_relative(4)?;
+    ///     cursor.seek_relative(-2)?;
+    ///
+    ///     let mut rest = String::new();
+    ///     cursor.read_to_string(&mut rest)?;
+    ///     assert_eq!(rest, "cdef");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "seek_seek_relative", issue = "none")]
+    fn seek_relative(&mut self, offset: i64) -> Result<()> {
+        self.seek(SeekFrom::Current(offset))?;
+        Ok(())
+    }
 }
 
 /// Enumeration of possible methods to seek within an I/O object.
@@ -1911,6 +1966,64 @@ fn read_until<R: BufRead + ?Sized>(r: &mut R, delim: u8, buf: &mut Vec<u8>) -> R
     }
 }
 
+fn read_until_slice<R: BufRead + ?Sized>(
+    r: &mut R,
+    delim: &[u8],
+    buf: &mut Vec<u8>,
+) -> Result<usize> {
+    assert!(!delim.is_empty(), "delimiter must not be empty");
+    let start = buf.len();
+    loop {
+        let (done, used) = {
+            let available = match r.fill_buf() {
+                Ok(n) => n,
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            // A delimiter which has not been found yet can only begin in the
+            // last `delim.len() - 1` bytes appended so far.
+            let carry = cmp::min(buf.len() - start, delim.len() - 1);
+            match find_delim(&buf[buf.len() - carry..], available, delim) {
+                Some(end) => {
+                    buf.extend_from_slice(&available[..end]);
+                    (true, end)
+                }
+                None => {
+                    buf.extend_from_slice(available);
+                    (false, available.len())
+                }
+            }
+        };
+        r.consume(used);
+        if done || used == 0 {
+            return Ok(buf.len() - start);
+        }
+    }
+}
+
+/// Finds the first occurrence of `delim` in `tail` followed by `available`
+/// which ends in `available`, returning the index just past its end.
+fn find_delim(tail: &[u8], available: &[u8], delim: &[u8]) -> Option<usize> {
+    // Occurrences straddling the two slices, earliest first.
+    for k in (1..=tail.len()).rev() {
+        let (head, rest) = delim.split_at(k);
+        if tail.ends_with(head) && available.starts_with(rest) {
+            return Some(rest.len());
+        }
+    }
+
+    // Occurrences entirely within `available`.
+    let mut pos = 0;
+    while let Some(i) = memchr::memchr(delim[0], &available[pos..]) {
+        let candidate = pos + i;
+        if available[candidate..].starts_with(delim) {
+            return Some(candidate + delim.len());
+        }
+        pos = candidate + 1;
+    }
+    None
+}
+
 /// A `BufRead` is a type of `Read`er which has an internal buffer, allowing it
 /// to perform extra ways of reading.
 ///
@@ -2114,6 +2227,51 @@ fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> Result<usize> {
         read_until(self, byte, buf)
     }
 
+    /// Read all bytes into `buf` until the multi-byte delimiter `delim` or EOF
+    /// is reached.
+    ///
+    /// This behaves like [`read_until`], except that the delimiter may be
+    /// longer than a single byte, such as the `\r\n` line terminator used by
+    /// many network protocols. The delimiter is recognized even when it is
+    /// split across several calls to [`fill_buf`], and no bytes past its end
+    /// are consumed from the reader.
+    ///
+    /// If successful, this function will return the total number of bytes
+    /// read, including the delimiter if one was found.
+    ///
+    /// # Errors
+    ///
+    /// This function has the same error semantics as [`read_until`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delim` is empty.
+    ///
+    /// [`read_until`]: BufRead::read_until
+    /// [`fill_buf`]: BufRead::fill_buf
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufread_read_until_slice)]
+    /// use std::io::{self, BufRead};
+    ///
+    /// let mut cursor = io::Cursor::new(b"HELO a\r\nQUIT\r\n");
+    /// let mut buf = vec![];
+    ///
+    /// cursor.read_until_slice(b"\r\n", &mut buf)?;
+    /// assert_eq!(buf, b"HELO a\r\n");
+    /// buf.clear();
+    ///
+    /// cursor.read_until_slice(b"\r\n", &mut buf)?;
+    /// assert_eq!(buf, b"QUIT\r\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    #[unstable(feature = "bufread_read_until_slice", issue = "none")]
+    fn read_until_slice(&mut self, delim: &[u8], buf: &mut Vec<u8>) -> Result<usize> {
+        read_until_slice(self, delim, buf)
+    }
+
     /// Read all bytes until a newline (the `0xA` byte) is reached, and append
     /// them to the provided buffer.
     ///
@@ -2254,6 +2412,49 @@ fn lines(self) -> Lines<Self>
     {
         Lines { buf: self }
     }
+
+    /// Returns a reader of the lines of this reader that reuses a single
+    /// buffer for all of them.
+    ///
+    /// [`lines`] allocates a new [`String`] for every line. The returned
+    /// [`LinesReuse`] instead reads each line into the same buffer and hands
+    /// out a <code>&[str]</code> borrowing it from [`LinesReuse::next_line`],
+    /// which is valid until the next call. Since each line borrows the
+    /// reader, `LinesReuse` is not an [`Iterator`]; it is used in a
+    /// `while let` loop instead.
+    ///
+    /// Like with [`lines`], the lines do *not* have a newline byte (the `0xA`
+    /// byte) or `CRLF` (`0xD`, `0xA` bytes) at the end.
+    ///
+    /// [`lines`]: BufRead::lines
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufread_lines_reuse)]
+    /// use std::io::{self, BufRead};
+    ///
+    /// let cursor = io::Cursor::new(b"lorem\nipsum\r\ndolor");
+    ///
+    /// let mut total = 0;
+    /// let mut lines = cursor.lines_reuse();
+    /// while let Some(line) = lines.next_line() {
+    ///     total += line?.len();
+    /// }
+    /// assert_eq!(total, 15);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Each line has the same error semantics as [`BufRead::read_line`].
+    #[unstable(feature = "bufread_lines_reuse", issue = "none")]
+    fn lines_reuse(self) -> LinesReuse<Self>
+    where
+        Self: Sized,
+    {
+        LinesReuse { buf: self, line: String::new() }
+    }
 }
 
 /// Adapter to chain together two readers.
@@ -2451,6 +2652,11 @@ pub fn limit(&self) -> u64 {
     /// the amount of bytes read and the previous limit value don't matter when
     /// calling this method.
     ///
+    /// The wrapped reader is left untouched, so if it is a [`BufRead`] such as
+    /// [`BufReader`], the data it has buffered beyond the old limit is still
+    /// available after a new limit is set. This makes it possible to read a
+    /// sequence of length-prefixed frames through a single `Take`.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -2811,3 +3017,61 @@ fn next(&mut self) -> Option<Result<String>> {
         }
     }
 }
+
+/// A reader of the lines of an instance of `BufRead` that reuses one buffer
+/// for every line.
+///
+/// This struct is generally created by calling [`lines_reuse`] on a `BufRead`.
+/// Please see the documentation of [`lines_reuse`] for more details.
+///
+/// [`lines_reuse`]: BufRead::lines_reuse
+#[unstable(feature = "bufread_lines_reuse", issue = "none")]
+#[derive(Debug)]
+pub struct LinesReuse<B> {
+    buf: B,
+    line: String,
+}
+
+impl<B: BufRead> LinesReuse<B> {
+    /// Reads the next line, returning `None` at the end of the input.
+    ///
+    /// The returned line borrows the internal buffer and is overwritten by
+    /// the next call.
+    #[unstable(feature = "bufread_lines_reuse", issue = "none")]
+    pub fn next_line(&mut self) -> Option<Result<&str>> {
+        self.line.clear();
+        match self.buf.read_line(&mut self.line) {
+            Ok(0) => None,
+            Ok(_n) => {
+                if self.line.ends_with('\n') {
+                    self.line.pop();
+                    if self.line.ends_with('\r') {
+                        self.line.pop();
+                    }
+                }
+                Some(Ok(&self.line))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+impl<B> LinesReuse<B> {
+    /// Gets a reference to the underlying reader.
+    #[unstable(feature = "bufread_lines_reuse", issue = "none")]
+    pub fn get_ref(&self) -> &B {
+        &self.buf
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    #[unstable(feature = "bufread_lines_reuse", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.buf
+    }
+
+    /// Unwraps this `LinesReuse`, returning the underlying reader.
+    #[unstable(feature = "bufread_lines_reuse", issue = "none")]
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+}
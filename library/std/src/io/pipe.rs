@@ -0,0 +1,239 @@
+#[cfg(all(test, any(unix, windows), not(target_os = "emscripten")))]
+mod tests;
+
+use crate::fmt;
+use crate::io::{self, IoSlice, IoSliceMut, Read, Write};
+use crate::sys::pipe as imp;
+use crate::sys_common::{AsInner, IntoInner};
+
+/// Create an anonymous pipe.
+///
+/// # Behavior
+///
+/// A pipe is a one-way data channel provided by the OS, which works across processes. A pipe is
+/// typically used to communicate between two or more separate processes, as there are better,
+/// faster ways to communicate within a single process.
+///
+/// In particular:
+///
+/// * A read on a [`PipeReader`] blocks until the pipe is non-empty.
+/// * A write on a [`PipeWriter`] blocks when the pipe is full.
+/// * When all copies of a [`PipeWriter`] are closed, a read on the corresponding [`PipeReader`]
+///   returns EOF.
+/// * [`PipeWriter`] can be shared, and multiple processes or threads can write to it at once, but
+///   writes (above a target-specific threshold) may have their data interleaved.
+/// * [`PipeReader`] can be shared, and multiple processes or threads can read it at once. Any
+///   given byte will only get consumed by one reader. There are no guarantees about data
+///   interleaving.
+/// * Portable applications cannot assume any atomicity of messages larger than a single byte.
+///
+/// Neither end of the pipe is inherited by child processes unless it is explicitly passed to one,
+/// for example by converting it into a [`Stdio`](crate::process::Stdio).
+///
+/// # Platform-specific behavior
+///
+/// This function currently corresponds to the `pipe2` function on Linux, falling back to `pipe`
+/// and setting the close-on-exec flag on other Unix platforms, and to the `CreatePipe` function on
+/// Windows. Other platforms return an [`Unsupported`](io::ErrorKind::Unsupported) error.
+///
+/// Note that this [may change in the future][changes].
+///
+/// # Capacity
+///
+/// A pipe has finite capacity. The exact capacity is platform dependent and may change in the
+/// future. A writer that fills the pipe blocks until a reader drains some of it, so a process
+/// that writes to and reads from the same pipe can deadlock.
+///
+/// [changes]: io#platform-specific-behavior
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(anonymous_pipe)]
+/// # #[cfg(unix)] {
+/// use std::io::{self, Read};
+/// use std::process::Command;
+///
+/// let (mut reader, writer) = io::pipe()?;
+///
+/// // Hand the write end to the child as its stdout. The `Command` is dropped at the end of
+/// // the statement, so afterwards the child holds the only copy of the writer.
+/// let mut child = Command::new("echo").arg("hello").stdout(writer).spawn()?;
+///
+/// // Reading reaches EOF once the child exits and its copy of the writer is closed.
+/// let mut s = String::new();
+/// reader.read_to_string(&mut s)?;
+/// child.wait()?;
+/// assert_eq!(s, "hello\n");
+/// # }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+#[inline]
+pub fn pipe() -> io::Result<(PipeReader, PipeWriter)> {
+    imp::pipe().map(|(reader, writer)| (PipeReader(reader), PipeWriter(writer)))
+}
+
+/// Read end of an anonymous pipe.
+///
+/// This is created by the [`pipe`] function.
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeReader(imp::AnonPipe);
+
+/// Write end of an anonymous pipe.
+///
+/// This is created by the [`pipe`] function.
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeWriter(imp::AnonPipe);
+
+impl PipeReader {
+    /// Create a new [`PipeReader`] instance that shares the same underlying file description.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(anonymous_pipe)]
+    /// use std::io;
+    ///
+    /// let (reader, _writer) = io::pipe()?;
+    /// let reader2 = reader.try_clone()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.0.try_clone().map(Self)
+    }
+}
+
+impl PipeWriter {
+    /// Create a new [`PipeWriter`] instance that shares the same underlying file description.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(anonymous_pipe)]
+    /// use std::io;
+    ///
+    /// let (_reader, writer) = io::pipe()?;
+    /// let writer2 = writer.try_clone()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.0.try_clone().map(Self)
+    }
+}
+
+impl AsInner<imp::AnonPipe> for PipeReader {
+    #[inline]
+    fn as_inner(&self) -> &imp::AnonPipe {
+        &self.0
+    }
+}
+
+impl IntoInner<imp::AnonPipe> for PipeReader {
+    fn into_inner(self) -> imp::AnonPipe {
+        self.0
+    }
+}
+
+impl AsInner<imp::AnonPipe> for PipeWriter {
+    #[inline]
+    fn as_inner(&self) -> &imp::AnonPipe {
+        &self.0
+    }
+}
+
+impl IntoInner<imp::AnonPipe> for PipeWriter {
+    fn into_inner(self) -> imp::AnonPipe {
+        self.0
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeReader").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeWriter").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for &PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for &PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
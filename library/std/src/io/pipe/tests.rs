@@ -0,0 +1,59 @@
+use crate::io::{pipe, Read, Write};
+use crate::process::Command;
+use crate::thread;
+
+#[test]
+fn pipe_creation_clone_and_rw() {
+    let (rx, tx) = pipe().unwrap();
+
+    tx.try_clone().unwrap().write_all(b"12345").unwrap();
+    drop(tx);
+
+    let mut rx2 = rx.try_clone().unwrap();
+    drop(rx);
+
+    let mut s = String::new();
+    rx2.read_to_string(&mut s).unwrap();
+    drop(rx2);
+    assert_eq!(s, "12345");
+}
+
+#[test]
+fn pipe_between_threads() {
+    let (mut rx, mut tx) = pipe().unwrap();
+
+    let writer = thread::spawn(move || {
+        for _ in 0..100 {
+            tx.write_all(&[7; 1024]).unwrap();
+        }
+    });
+
+    let mut buf = Vec::new();
+    rx.read_to_end(&mut buf).unwrap();
+    writer.join().unwrap();
+    assert_eq!(buf.len(), 100 * 1024);
+    assert!(buf.iter().all(|&b| b == 7));
+}
+
+#[test]
+fn pipe_as_child_stdout() {
+    let (mut rx, tx) = pipe().unwrap();
+
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(&["/C", "echo hello"]);
+        cmd
+    } else {
+        let mut cmd = Command::new("echo");
+        cmd.arg("hello");
+        cmd
+    };
+    let mut child = cmd.stdout(tx).spawn().unwrap();
+    // The `Command` still owns the write end until it is dropped.
+    drop(cmd);
+
+    let mut s = String::new();
+    rx.read_to_string(&mut s).unwrap();
+    assert!(child.wait().unwrap().success());
+    assert_eq!(s.trim_end(), "hello");
+}
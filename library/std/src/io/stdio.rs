@@ -202,6 +202,11 @@ fn handle_ebadf<T>(r: io::Result<T>, default: T) -> io::Result<T> {
 ///
 /// [`io::stdin`]: stdin
 ///
+/// The stream is buffered, but each method called on `Stdin` itself locks the
+/// buffer for the duration of the call. When reading a lot of input, such as
+/// line by line, use [`Stdin::lines`] or [`Stdin::lock`] to take the lock only
+/// once.
+///
 /// ### Note: Windows Portability Consideration
 ///
 /// When operating in a console, the Windows implementation of this stream does not support
@@ -357,6 +362,10 @@ impl Stdin {
     /// returned guard also implements the [`Read`] and [`BufRead`] traits for
     /// accessing the underlying data.
     ///
+    /// The guard refers to the global standard input stream rather than to
+    /// this handle, so it can outlive the handle and be stored in data
+    /// structures.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -372,7 +381,7 @@ impl Stdin {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn lock(&self) -> StdinLock<'_> {
+    pub fn lock(&self) -> StdinLock<'static> {
         self.lock_any()
     }
 
@@ -449,6 +458,12 @@ pub fn into_locked(self) -> StdinLock<'static> {
     /// For detailed semantics of this method, see the documentation on
     /// [`BufRead::lines`].
     ///
+    /// The standard input stream is locked once, and stays locked until the
+    /// iterator is dropped, so reading each line neither takes the lock again
+    /// nor goes through a second buffer: this is as fast as calling
+    /// [`BufRead::lines`] on the result of [`Stdin::lock`]. Other handles to
+    /// the standard input stream block while the iterator is alive.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -599,10 +614,10 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 /// [`io::stdout`]: stdout
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct Stdout {
-    // FIXME: this should be LineWriter or BufWriter depending on the state of
-    //        stdout (tty or not). Note that if this is not line buffered it
+    // FIXME: the default buffer mode should depend on the state of stdout
+    //        (tty or not). Note that if this is not line buffered it
     //        should also flush-on-panic or some form of flush-on-abort.
-    inner: Pin<&'static ReentrantMutex<RefCell<LineWriter<StdoutRaw>>>>,
+    inner: Pin<&'static ReentrantMutex<RefCell<StdoutBuffer>>>,
 }
 
 /// A locked reference to the [`Stdout`] handle.
@@ -617,10 +632,71 @@ pub struct Stdout {
 #[must_use = "if unused stdout will immediately unlock"]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct StdoutLock<'a> {
-    inner: ReentrantMutexGuard<'a, RefCell<LineWriter<StdoutRaw>>>,
+    inner: ReentrantMutexGuard<'a, RefCell<StdoutBuffer>>,
+}
+
+static STDOUT: SyncOnceCell<ReentrantMutex<RefCell<StdoutBuffer>>> = SyncOnceCell::new();
+
+/// The buffering strategy used by [`Stdout`].
+///
+/// See [`Stdout::set_buffer_mode`] for more.
+#[unstable(feature = "stdout_buffer_mode", issue = "none")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BufferMode {
+    /// Output is flushed whenever a newline is written, and whenever the
+    /// buffer is full. This is the default.
+    Line,
+    /// Output is only flushed when the buffer is full, or when it is flushed
+    /// explicitly.
+    Block,
+}
+
+/// The shared buffer behind all [`Stdout`] handles.
+struct StdoutBuffer {
+    writer: LineWriter<StdoutRaw>,
+    mode: BufferMode,
 }
 
-static STDOUT: SyncOnceCell<ReentrantMutex<RefCell<LineWriter<StdoutRaw>>>> = SyncOnceCell::new();
+impl StdoutBuffer {
+    fn new(writer: LineWriter<StdoutRaw>) -> StdoutBuffer {
+        StdoutBuffer { writer, mode: BufferMode::Line }
+    }
+}
+
+impl Write for StdoutBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.mode {
+            BufferMode::Line => self.writer.write(buf),
+            BufferMode::Block => self.writer.buf_writer_mut().write(buf),
+        }
+    }
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        match self.mode {
+            BufferMode::Line => self.writer.write_vectored(bufs),
+            BufferMode::Block => self.writer.buf_writer_mut().write_vectored(bufs),
+        }
+    }
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.writer.is_write_vectored()
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        match self.mode {
+            BufferMode::Line => self.writer.write_all(buf),
+            BufferMode::Block => self.writer.buf_writer_mut().write_all(buf),
+        }
+    }
+    fn write_all_vectored(&mut self, bufs: &mut [IoSlice<'_>]) -> io::Result<()> {
+        match self.mode {
+            BufferMode::Line => self.writer.write_all_vectored(bufs),
+            BufferMode::Block => self.writer.buf_writer_mut().write_all_vectored(bufs),
+        }
+    }
+}
 
 /// Constructs a new handle to the standard output of the current process.
 ///
@@ -666,7 +742,9 @@ pub struct StdoutLock<'a> {
 pub fn stdout() -> Stdout {
     Stdout {
         inner: Pin::static_ref(&STDOUT).get_or_init_pin(
-            || unsafe { ReentrantMutex::new(RefCell::new(LineWriter::new(stdout_raw()))) },
+            || unsafe {
+                ReentrantMutex::new(RefCell::new(StdoutBuffer::new(LineWriter::new(stdout_raw()))))
+            },
             |mutex| unsafe { mutex.init() },
         ),
     }
@@ -717,7 +795,7 @@ pub fn cleanup() {
         // might have leaked a StdoutLock, which would
         // otherwise cause a deadlock here.
         if let Some(lock) = Pin::static_ref(instance).try_lock() {
-            *lock.borrow_mut() = LineWriter::with_capacity(0, stdout_raw());
+            lock.borrow_mut().writer = LineWriter::with_capacity(0, stdout_raw());
         }
     }
 }
@@ -729,14 +807,17 @@ impl Stdout {
     /// The lock is released when the returned lock goes out of scope. The
     /// returned guard also implements the `Write` trait for writing data.
     ///
+    /// The guard refers to the global standard output stream rather than to
+    /// this handle, so it can outlive the handle and be stored in data
+    /// structures.
+    ///
     /// # Examples
     ///
     /// ```no_run
     /// use std::io::{self, Write};
     ///
     /// fn main() -> io::Result<()> {
-    ///     let stdout = io::stdout();
-    ///     let mut handle = stdout.lock();
+    ///     let mut handle = io::stdout().lock();
     ///
     ///     handle.write_all(b"hello world")?;
     ///
@@ -744,10 +825,69 @@ impl Stdout {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn lock(&self) -> StdoutLock<'_> {
+    pub fn lock(&self) -> StdoutLock<'static> {
         self.lock_any()
     }
 
+    /// Changes how output written to the standard output stream is buffered.
+    ///
+    /// By default, standard output is line-buffered: every newline causes the
+    /// buffered output to be written out. When large amounts of output are
+    /// written, for example into a pipe or a file, switching to
+    /// [`BufferMode::Block`] avoids a write system call per line.
+    ///
+    /// Any output that is currently buffered is flushed first. The mode
+    /// applies to the whole process, not just to this handle.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error that occurs while flushing the buffered output. The
+    /// mode is left unchanged in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(stdout_buffer_mode)]
+    /// use std::io::{self, BufferMode, Write};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let stdout = io::stdout();
+    ///     stdout.set_buffer_mode(BufferMode::Block)?;
+    ///
+    ///     let mut handle = stdout.lock();
+    ///     for i in 0..10_000 {
+    ///         writeln!(handle, "{}", i)?;
+    ///     }
+    ///     handle.flush()?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "stdout_buffer_mode", issue = "none")]
+    pub fn set_buffer_mode(&self, mode: BufferMode) -> io::Result<()> {
+        let lock = self.lock();
+        let mut buffer = lock.inner.borrow_mut();
+        buffer.flush()?;
+        buffer.mode = mode;
+        Ok(())
+    }
+
+    /// Returns the current buffering strategy of the standard output stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(stdout_buffer_mode)]
+    /// use std::io::{self, BufferMode};
+    ///
+    /// assert_eq!(io::stdout().buffer_mode(), BufferMode::Line);
+    /// ```
+    #[unstable(feature = "stdout_buffer_mode", issue = "none")]
+    #[must_use]
+    pub fn buffer_mode(&self) -> BufferMode {
+        self.lock().inner.borrow().mode
+    }
+
     // Locks this handle with any lifetime. This depends on the
     // implementation detail that the underlying `ReentrantMutex` is
     // static.
@@ -994,6 +1134,10 @@ impl Stderr {
     /// The lock is released when the returned lock goes out of scope. The
     /// returned guard also implements the [`Write`] trait for writing data.
     ///
+    /// The guard refers to the global standard error stream rather than to
+    /// this handle, so it can outlive the handle and be stored in data
+    /// structures.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1009,7 +1153,7 @@ impl Stderr {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn lock(&self) -> StderrLock<'_> {
+    pub fn lock(&self) -> StderrLock<'static> {
         self.lock_any()
     }
 
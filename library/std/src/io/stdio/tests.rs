@@ -164,3 +164,21 @@ enum State {
         [Start1, Acquire1, Start2, Release1, Acquire2, Release2, Acquire1, Release1]
     );
 }
+
+#[test]
+fn stdout_lock_is_static() {
+    struct Holder {
+        _lock: StdoutLock<'static>,
+    }
+    let _holder = Holder { _lock: stdout().lock() };
+}
+
+#[test]
+fn stdout_buffer_mode() {
+    let out = stdout();
+    assert_eq!(out.buffer_mode(), BufferMode::Line);
+    out.set_buffer_mode(BufferMode::Block).unwrap();
+    assert_eq!(stdout().buffer_mode(), BufferMode::Block);
+    out.set_buffer_mode(BufferMode::Line).unwrap();
+    assert_eq!(out.buffer_mode(), BufferMode::Line);
+}
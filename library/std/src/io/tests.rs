@@ -1,5 +1,6 @@
 use super::{repeat, Cursor, ReadBuf, SeekFrom};
 use crate::cmp::{self, min};
+use crate::collections::VecDeque;
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::io::{BufRead, BufReader, Read, Seek, Write};
 use crate::mem::MaybeUninit;
@@ -58,6 +59,44 @@ fn read_line() {
     assert_eq!(v, "");
 }
 
+#[test]
+fn read_until_slice() {
+    let mut buf = Cursor::new(&b"a\r\nb\rc\r\n\r\nd"[..]);
+    let mut v = Vec::new();
+    assert_eq!(buf.read_until_slice(b"\r\n", &mut v).unwrap(), 3);
+    assert_eq!(v, b"a\r\n");
+    v.truncate(0);
+    assert_eq!(buf.read_until_slice(b"\r\n", &mut v).unwrap(), 5);
+    assert_eq!(v, b"b\rc\r\n");
+    v.truncate(0);
+    assert_eq!(buf.read_until_slice(b"\r\n", &mut v).unwrap(), 2);
+    assert_eq!(v, b"\r\n");
+    v.truncate(0);
+    assert_eq!(buf.read_until_slice(b"\r\n", &mut v).unwrap(), 1);
+    assert_eq!(v, b"d");
+    v.truncate(0);
+    assert_eq!(buf.read_until_slice(b"\r\n", &mut v).unwrap(), 0);
+    assert_eq!(v, []);
+}
+
+#[test]
+fn read_until_slice_across_fills() {
+    // A tiny buffer forces the delimiter to be split between `fill_buf` calls.
+    let data = &b"xabaabcabcy"[..];
+    for cap in 1..=4 {
+        let mut reader = BufReader::with_capacity(cap, data);
+        let mut v = Vec::new();
+        assert_eq!(reader.read_until_slice(b"abc", &mut v).unwrap(), 7);
+        assert_eq!(v, b"xabaabc");
+        v.truncate(0);
+        assert_eq!(reader.read_until_slice(b"abc", &mut v).unwrap(), 3);
+        assert_eq!(v, b"abc");
+        v.truncate(0);
+        assert_eq!(reader.read_until_slice(b"abc", &mut v).unwrap(), 1);
+        assert_eq!(v, b"y");
+    }
+}
+
 #[test]
 fn lines() {
     let buf = Cursor::new(&b"12\r"[..]);
@@ -72,6 +111,18 @@ fn lines() {
     assert!(s.next().is_none());
 }
 
+#[test]
+fn lines_reuse() {
+    let buf = Cursor::new(&b"12\r\n\nabc\xff\nlast"[..]);
+    let mut s = buf.lines_reuse();
+    assert_eq!(s.next_line().unwrap().unwrap(), "12");
+    assert_eq!(s.next_line().unwrap().unwrap(), "");
+    assert_eq!(s.next_line().unwrap().unwrap_err().kind(), io::ErrorKind::InvalidData);
+    assert_eq!(s.next_line().unwrap().unwrap(), "last");
+    assert!(s.next_line().is_none());
+    assert_eq!(s.into_inner().position(), 14);
+}
+
 #[test]
 fn buf_read_has_data_left() {
     let mut buf = Cursor::new(&b"abcd"[..]);
@@ -231,6 +282,41 @@ fn chain_bufread() {
     cmp_bufread(chain1, chain2, &testdata[..]);
 }
 
+#[test]
+fn chain_bufread_read_line() {
+    // Put back bytes that were already taken off the stream in front of it.
+    let mut stream = BufReader::with_capacity(4, &b"hello\nworld\n"[..]);
+    let mut header = [0; 2];
+    stream.read_exact(&mut header).unwrap();
+    let mut chain = (&header[..]).chain(stream);
+    let mut line = String::new();
+    chain.read_line(&mut line).unwrap();
+    assert_eq!(line, "hello\n");
+    line.clear();
+    chain.read_line(&mut line).unwrap();
+    assert_eq!(line, "world\n");
+}
+
+#[test]
+fn take_set_limit_frames() {
+    // A stream of frames, each made of a length byte and that many bytes.
+    let data = b"\x03abc\x00\x05hello";
+    let mut frames = BufReader::with_capacity(16, &data[..]).take(0);
+    let mut out = Vec::new();
+    loop {
+        // Read the length through the underlying reader; its buffer stays intact.
+        let mut len = [0];
+        if frames.get_mut().read(&mut len).unwrap() == 0 {
+            break;
+        }
+        frames.set_limit(len[0] as u64);
+        let mut frame = Vec::new();
+        frames.read_to_end(&mut frame).unwrap();
+        out.push(frame);
+    }
+    assert_eq!(out, [&b"abc"[..], b"", b"hello"]);
+}
+
 #[test]
 fn bufreader_size_hint() {
     let testdata = b"ABCDEFGHIJKL";
@@ -336,6 +422,67 @@ fn seek_len() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn seek_len_forwards_through_pointers() -> io::Result<()> {
+    // A seekable stream whose length can be computed without seeking. The
+    // pointer impls must forward to the override rather than fall back to the
+    // three-seek default.
+    struct Fixed(u64, u64);
+
+    impl Seek for Fixed {
+        fn seek(&mut self, _: SeekFrom) -> io::Result<u64> {
+            panic!("stream_len should not seek")
+        }
+
+        fn stream_len(&mut self) -> io::Result<u64> {
+            Ok(self.1)
+        }
+
+        fn stream_position(&mut self) -> io::Result<u64> {
+            Ok(self.0)
+        }
+    }
+
+    let mut s = Fixed(3, 15);
+    assert_eq!((&mut s).stream_len()?, 15);
+    assert_eq!((&mut s).stream_position()?, 3);
+
+    let mut b: Box<dyn Seek> = Box::new(s);
+    assert_eq!(b.stream_len()?, 15);
+    assert_eq!(b.stream_position()?, 3);
+
+    Ok(())
+}
+
+#[test]
+fn vec_deque_read_write() -> io::Result<()> {
+    let mut fifo = VecDeque::with_capacity(4);
+    fifo.write_all(b"abc")?;
+
+    let mut buf = [0; 2];
+    assert_eq!(fifo.read(&mut buf)?, 2);
+    assert_eq!(&buf, b"ab");
+
+    // Make the contents wrap around the end of the ring buffer.
+    fifo.write_all(b"defgh")?;
+    let mut line = Vec::new();
+    while !fifo.fill_buf()?.is_empty() {
+        let front = fifo.fill_buf()?;
+        let n = front.len();
+        line.extend_from_slice(front);
+        fifo.consume(n);
+    }
+    assert_eq!(line, b"cdefgh");
+
+    fifo.write_vectored(&[IoSlice::new(b"ij"), IoSlice::new(b"kl")])?;
+    let mut rest = Vec::new();
+    assert_eq!(fifo.read_to_end(&mut rest)?, 4);
+    assert_eq!(rest, b"ijkl");
+    assert!(fifo.is_empty());
+
+    Ok(())
+}
+
 #[test]
 fn seek_position() -> io::Result<()> {
     // All `asserts` are duplicated here to make sure the method does not
@@ -583,6 +730,29 @@ fn test_write_all_vectored() {
     }
 }
 
+#[test]
+fn test_write_all_vectored_write_zero() {
+    // A writer that accepts a few bytes and then stops making progress.
+    let mut writer = test_writer(3, 3);
+    writer.per_call = 2;
+    let mut bufs = [IoSlice::new(&[1]), IoSlice::new(&[2, 2])];
+    assert!(writer.write_all_vectored(&mut bufs).is_ok());
+
+    writer.per_call = 0;
+    let mut bufs = [IoSlice::new(&[3]), IoSlice::new(&[4])];
+    let err = writer.write_all_vectored(&mut bufs).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    assert_eq!(writer.written, &[1, 2, 2]);
+}
+
+#[test]
+fn test_write_all_vectored_only_empty_bufs() {
+    // Empty buffers must not cause a call to `write_vectored` at all.
+    let mut writer = test_writer(3, 0);
+    let mut bufs = [IoSlice::new(&[]), IoSlice::new(&[])];
+    assert!(writer.write_all_vectored(&mut bufs).is_ok());
+}
+
 #[bench]
 fn bench_take_read(b: &mut test::Bencher) {
     b.iter(|| {
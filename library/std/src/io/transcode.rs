@@ -0,0 +1,345 @@
+#[cfg(test)]
+mod tests;
+
+use crate::cmp;
+use crate::fmt;
+use crate::io::{self, Error, ErrorKind, Read, DEFAULT_BUF_SIZE};
+
+/// A reader which decodes UTF-16 text from an underlying reader and yields
+/// it as UTF-8.
+///
+/// [`Utf16Reader::new`] detects the byte order from a leading byte order mark
+/// (BOM), which is removed from the output, and assumes little-endian if there
+/// is none, as written by most Windows programs. [`new_le`] and [`new_be`]
+/// use a fixed byte order instead and keep a leading U+FEFF as part of the
+/// text.
+///
+/// Unpaired surrogates, and an odd number of bytes at the end of the input,
+/// result in an error of kind [`ErrorKind::InvalidData`]. Text decoded before
+/// the invalid data is returned first.
+///
+/// [`new_le`]: Utf16Reader::new_le
+/// [`new_be`]: Utf16Reader::new_be
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_transcode)]
+/// use std::io::{self, Read};
+///
+/// // "hé" in UTF-16LE, with a byte order mark.
+/// let bytes: &[u8] = &[0xFF, 0xFE, b'h', 0x00, 0xE9, 0x00];
+///
+/// let mut text = String::new();
+/// io::Utf16Reader::new(bytes).read_to_string(&mut text)?;
+/// assert_eq!(text, "hé");
+/// # Ok::<(), io::Error>(())
+/// ```
+#[unstable(feature = "io_transcode", issue = "none")]
+pub struct Utf16Reader<R> {
+    transcoder: Transcoder<R>,
+    // `None` until the byte order mark has been looked for.
+    endian: Option<Endian>,
+}
+
+#[derive(Clone, Copy)]
+enum Endian {
+    Little,
+    Big,
+}
+
+impl<R: Read> Utf16Reader<R> {
+    /// Creates a new `Utf16Reader` which detects the byte order from a
+    /// leading byte order mark, and otherwise assumes little-endian.
+    #[unstable(feature = "io_transcode", issue = "none")]
+    pub fn new(inner: R) -> Utf16Reader<R> {
+        Utf16Reader { transcoder: Transcoder::new(inner), endian: None }
+    }
+
+    /// Creates a new `Utf16Reader` which decodes little-endian UTF-16.
+    #[unstable(feature = "io_transcode", issue = "none")]
+    pub fn new_le(inner: R) -> Utf16Reader<R> {
+        Utf16Reader { transcoder: Transcoder::new(inner), endian: Some(Endian::Little) }
+    }
+
+    /// Creates a new `Utf16Reader` which decodes big-endian UTF-16.
+    #[unstable(feature = "io_transcode", issue = "none")]
+    pub fn new_be(inner: R) -> Utf16Reader<R> {
+        Utf16Reader { transcoder: Transcoder::new(inner), endian: Some(Endian::Big) }
+    }
+
+    fn detect_endian(&mut self) -> io::Result<Endian> {
+        let t = &mut self.transcoder;
+        while t.filled - t.pos < 2 && t.fill()? > 0 {}
+        let endian = match t.buf[t.pos..t.filled] {
+            [0xFF, 0xFE, ..] => Endian::Little,
+            [0xFE, 0xFF, ..] => Endian::Big,
+            _ => return Ok(Endian::Little),
+        };
+        t.pos += 2;
+        Ok(endian)
+    }
+}
+
+impl<R> Utf16Reader<R> {
+    /// Gets a reference to the underlying reader.
+    #[unstable(feature = "io_transcode", issue = "none")]
+    pub fn get_ref(&self) -> &R {
+        &self.transcoder.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Reading directly from the underlying reader will skip over text and
+    /// may leave the decoder in the middle of a character.
+    #[unstable(feature = "io_transcode", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.transcoder.inner
+    }
+
+    /// Unwraps this `Utf16Reader`, returning the underlying reader.
+    ///
+    /// Any bytes read from the underlying reader but not yet decoded are lost.
+    #[unstable(feature = "io_transcode", issue = "none")]
+    pub fn into_inner(self) -> R {
+        self.transcoder.inner
+    }
+}
+
+#[unstable(feature = "io_transcode", issue = "none")]
+impl<R: Read> Read for Utf16Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let endian = match self.endian {
+            Some(endian) => endian,
+            None => {
+                let endian = self.detect_endian()?;
+                self.endian = Some(endian);
+                endian
+            }
+        };
+        self.transcoder.read_with(buf, |bytes| decode_utf16(endian, bytes), &"invalid UTF-16")
+    }
+}
+
+#[unstable(feature = "io_transcode", issue = "none")]
+impl<R: fmt::Debug> fmt::Debug for Utf16Reader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf16Reader").field("inner", &self.transcoder.inner).finish_non_exhaustive()
+    }
+}
+
+fn decode_utf16(endian: Endian, bytes: &[u8]) -> Decoded {
+    let unit = |i: usize| {
+        let pair = [bytes[i], bytes[i + 1]];
+        u32::from(match endian {
+            Endian::Little => u16::from_le_bytes(pair),
+            Endian::Big => u16::from_be_bytes(pair),
+        })
+    };
+
+    if bytes.len() < 2 {
+        return Decoded::NeedMore;
+    }
+    let high = unit(0);
+    let (code_point, len) = if (0xD800..0xDC00).contains(&high) {
+        if bytes.len() < 4 {
+            return Decoded::NeedMore;
+        }
+        let low = unit(2);
+        if !(0xDC00..0xE000).contains(&low) {
+            return Decoded::Invalid;
+        }
+        (0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00), 4)
+    } else {
+        (high, 2)
+    };
+    // A lone low surrogate is rejected here.
+    match char::from_u32(code_point) {
+        Some(c) => Decoded::Char(c, len),
+        None => Decoded::Invalid,
+    }
+}
+
+/// A reader which decodes ISO-8859-1 (Latin-1) text from an underlying
+/// reader and yields it as UTF-8.
+///
+/// Every byte is a valid Latin-1 character, mapped to the Unicode code point
+/// with the same value, so this reader never fails because of its input.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_transcode)]
+/// use std::io::{self, Read};
+///
+/// let bytes: &[u8] = b"caf\xE9 \xA9";
+///
+/// let mut text = String::new();
+/// io::Latin1Reader::new(bytes).read_to_string(&mut text)?;
+/// assert_eq!(text, "café ©");
+/// # Ok::<(), io::Error>(())
+/// ```
+#[unstable(feature = "io_transcode", issue = "none")]
+pub struct Latin1Reader<R> {
+    transcoder: Transcoder<R>,
+}
+
+impl<R: Read> Latin1Reader<R> {
+    /// Creates a new `Latin1Reader` which reads from `inner`.
+    #[unstable(feature = "io_transcode", issue = "none")]
+    pub fn new(inner: R) -> Latin1Reader<R> {
+        Latin1Reader { transcoder: Transcoder::new(inner) }
+    }
+}
+
+impl<R> Latin1Reader<R> {
+    /// Gets a reference to the underlying reader.
+    #[unstable(feature = "io_transcode", issue = "none")]
+    pub fn get_ref(&self) -> &R {
+        &self.transcoder.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Reading directly from the underlying reader will skip over text.
+    #[unstable(feature = "io_transcode", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.transcoder.inner
+    }
+
+    /// Unwraps this `Latin1Reader`, returning the underlying reader.
+    ///
+    /// Any bytes read from the underlying reader but not yet decoded are lost.
+    #[unstable(feature = "io_transcode", issue = "none")]
+    pub fn into_inner(self) -> R {
+        self.transcoder.inner
+    }
+}
+
+#[unstable(feature = "io_transcode", issue = "none")]
+impl<R: Read> Read for Latin1Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.transcoder.read_with(
+            buf,
+            |bytes| match bytes.first() {
+                Some(&b) => Decoded::Char(char::from(b), 1),
+                None => Decoded::NeedMore,
+            },
+            &"invalid Latin-1",
+        )
+    }
+}
+
+#[unstable(feature = "io_transcode", issue = "none")]
+impl<R: fmt::Debug> fmt::Debug for Latin1Reader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Latin1Reader")
+            .field("inner", &self.transcoder.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The result of decoding one character from the start of a byte slice.
+enum Decoded {
+    /// A character, and how many bytes it took up.
+    Char(char, usize),
+    /// The bytes end in the middle of a character.
+    NeedMore,
+    Invalid,
+}
+
+/// The buffering shared by the transcoding readers: raw input waiting to be
+/// decoded, and the rest of a UTF-8 encoded character that did not fit into
+/// the caller's buffer.
+struct Transcoder<R> {
+    inner: R,
+    buf: Box<[u8]>,
+    pos: usize,
+    filled: usize,
+    partial: [u8; 4],
+    partial_pos: usize,
+    partial_len: usize,
+}
+
+impl<R: Read> Transcoder<R> {
+    fn new(inner: R) -> Transcoder<R> {
+        Transcoder {
+            inner,
+            buf: vec![0; DEFAULT_BUF_SIZE].into_boxed_slice(),
+            pos: 0,
+            filled: 0,
+            partial: [0; 4],
+            partial_pos: 0,
+            partial_len: 0,
+        }
+    }
+
+    // Moves the undecoded bytes to the front of the buffer and reads more
+    // after them, returning how many bytes were read.
+    fn fill(&mut self) -> io::Result<usize> {
+        if self.pos > 0 {
+            self.buf.copy_within(self.pos..self.filled, 0);
+            self.filled -= self.pos;
+            self.pos = 0;
+        }
+        let n = self.inner.read(&mut self.buf[self.filled..])?;
+        self.filled += n;
+        Ok(n)
+    }
+
+    fn read_with(
+        &mut self,
+        out: &mut [u8],
+        mut decode: impl FnMut(&[u8]) -> Decoded,
+        invalid: &'static &'static str,
+    ) -> io::Result<usize> {
+        let mut written = self.take_partial(out);
+        loop {
+            while written < out.len() {
+                match decode(&self.buf[self.pos..self.filled]) {
+                    Decoded::Char(c, len) => {
+                        self.pos += len;
+                        written += self.put_char(c, &mut out[written..]);
+                    }
+                    Decoded::NeedMore => break,
+                    // Hand out what was decoded so far; the error is reported
+                    // by the next call.
+                    Decoded::Invalid if written > 0 => return Ok(written),
+                    Decoded::Invalid => {
+                        return Err(Error::new_const(ErrorKind::InvalidData, invalid));
+                    }
+                }
+            }
+            if written > 0 || out.is_empty() {
+                return Ok(written);
+            }
+            if self.fill()? == 0 {
+                // The input ended in the middle of a character.
+                if self.pos < self.filled {
+                    return Err(Error::new_const(ErrorKind::InvalidData, invalid));
+                }
+                return Ok(0);
+            }
+        }
+    }
+
+    // Copies out as much as fits of the character left over from the last
+    // call.
+    fn take_partial(&mut self, out: &mut [u8]) -> usize {
+        let n = cmp::min(self.partial_len - self.partial_pos, out.len());
+        out[..n].copy_from_slice(&self.partial[self.partial_pos..self.partial_pos + n]);
+        self.partial_pos += n;
+        n
+    }
+
+    // Writes `c` as UTF-8 into `out`, keeping whatever does not fit for the
+    // next call. Returns how many bytes were written.
+    fn put_char(&mut self, c: char, out: &mut [u8]) -> usize {
+        let len = c.encode_utf8(&mut self.partial).len();
+        let n = cmp::min(len, out.len());
+        out[..n].copy_from_slice(&self.partial[..n]);
+        self.partial_pos = n;
+        self.partial_len = len;
+        n
+    }
+}
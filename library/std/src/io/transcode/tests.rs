@@ -0,0 +1,104 @@
+use super::{Latin1Reader, Utf16Reader};
+use crate::io::{self, Read};
+
+fn utf16le(s: &str) -> Vec<u8> {
+    s.encode_utf16().flat_map(u16::to_le_bytes).collect()
+}
+
+fn utf16be(s: &str) -> Vec<u8> {
+    s.encode_utf16().flat_map(u16::to_be_bytes).collect()
+}
+
+// A reader that hands out at most `chunk` bytes per call.
+struct Chunked<'a> {
+    bytes: &'a [u8],
+    chunk: usize,
+}
+
+impl Read for Chunked<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.chunk.min(buf.len()).min(self.bytes.len());
+        buf[..n].copy_from_slice(&self.bytes[..n]);
+        self.bytes = &self.bytes[n..];
+        Ok(n)
+    }
+}
+
+// Reads everything from `reader` with an output buffer of `out` bytes.
+fn read_all<R: Read>(mut reader: R, out: usize) -> io::Result<String> {
+    let mut text = Vec::new();
+    let mut buf = vec![0; out];
+    loop {
+        match reader.read(&mut buf)? {
+            0 => return Ok(String::from_utf8(text).unwrap()),
+            n => text.extend_from_slice(&buf[..n]),
+        }
+    }
+}
+
+#[test]
+fn utf16_bom_detection() {
+    let text = "a\u{e9}\u{20ac}\u{1f600}z";
+
+    let mut le = vec![0xFF, 0xFE];
+    le.extend(utf16le(text));
+    assert_eq!(read_all(Utf16Reader::new(&le[..]), 64).unwrap(), text);
+
+    let mut be = vec![0xFE, 0xFF];
+    be.extend(utf16be(text));
+    assert_eq!(read_all(Utf16Reader::new(&be[..]), 64).unwrap(), text);
+
+    // Without a BOM, little-endian is assumed.
+    assert_eq!(read_all(Utf16Reader::new(&utf16le(text)[..]), 64).unwrap(), text);
+    assert_eq!(read_all(Utf16Reader::new(&[][..]), 64).unwrap(), "");
+
+    // An explicit byte order keeps the BOM as text.
+    assert_eq!(read_all(Utf16Reader::new_be(&be[..]), 64).unwrap(), format!("\u{feff}{}", text));
+}
+
+#[test]
+fn utf16_split_input_and_output() {
+    let text = "a\u{e9}\u{20ac}\u{1f600}z\u{10ffff}";
+    let bytes = utf16be(text);
+    // Every input chunk size and output buffer size, so that code units,
+    // surrogate pairs and UTF-8 sequences are all split at every position.
+    for chunk in 1..=bytes.len() {
+        for out in 1..=8 {
+            let reader = Utf16Reader::new_be(Chunked { bytes: &bytes, chunk });
+            assert_eq!(read_all(reader, out).unwrap(), text, "chunk {}, out {}", chunk, out);
+        }
+    }
+}
+
+#[test]
+fn utf16_invalid() {
+    // A lone high surrogate followed by 'x'; the text before it is returned first.
+    let bytes = [b'o', 0, b'k', 0, 0x00, 0xD8, b'x', 0];
+    let mut reader = Utf16Reader::new_le(&bytes[..]);
+    let mut buf = [0; 16];
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(&buf[..2], b"ok");
+    assert_eq!(reader.read(&mut buf).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+    // A lone low surrogate.
+    let err = read_all(Utf16Reader::new_le(&[0x00, 0xDC][..]), 16).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    // An odd trailing byte, and a truncated surrogate pair.
+    let err = read_all(Utf16Reader::new_le(&[b'a', 0, b'b'][..]), 16).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let err = read_all(Utf16Reader::new_le(&[0x3D, 0xD8, 0x00][..]), 16).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn latin1() {
+    let bytes: Vec<u8> = (0..=255).collect();
+    let expected: String = (0..=255u8).map(char::from).collect();
+    for chunk in [1, 7, 256] {
+        for out in [1, 2, 3, 1024] {
+            let reader = Latin1Reader::new(Chunked { bytes: &bytes, chunk });
+            assert_eq!(read_all(reader, out).unwrap(), expected);
+        }
+    }
+}
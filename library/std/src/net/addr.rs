@@ -8,12 +8,15 @@
 use crate::io::{self, Write};
 use crate::iter;
 use crate::mem;
-use crate::net::{htons, ntohs, IpAddr, Ipv4Addr, Ipv6Addr};
+use crate::net::{htons, ntohs, IpAddr, Ipv4Addr, Ipv6Addr, Ipv6MulticastScope};
 use crate::option;
 use crate::slice;
+use crate::sync::mpsc;
 use crate::sys::net::netc as c;
 use crate::sys_common::net::LookupHost;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::thread;
+use crate::time::Duration;
 use crate::vec;
 
 /// An internet socket address, either IPv4 or IPv6.
@@ -501,11 +504,108 @@ pub fn set_flowinfo(&mut self, new_flowinfo: u32) {
         self.inner.sin6_flowinfo = new_flowinfo;
     }
 
+    /// Returns the flow label part of the [flow information] of this address.
+    ///
+    /// The flow label is the low 20 bits of the value returned by
+    /// [`SocketAddrV6::flowinfo`], as specified in [IETF RFC 2460, Section 6].
+    ///
+    /// [flow information]: SocketAddrV6::flowinfo
+    /// [IETF RFC 2460, Section 6]: https://tools.ietf.org/html/rfc2460#section-6
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ipv6_flowinfo_parts)]
+    /// use std::net::{SocketAddrV6, Ipv6Addr};
+    ///
+    /// let socket = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0x0ab1_2345, 0);
+    /// assert_eq!(socket.flow_label(), 0x1_2345);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "ipv6_flowinfo_parts", issue = "none")]
+    pub const fn flow_label(&self) -> u32 {
+        self.inner.sin6_flowinfo & 0x000f_ffff
+    }
+
+    /// Changes the flow label part of the [flow information] of this address,
+    /// leaving the traffic class untouched.
+    ///
+    /// Only the low 20 bits of `new_flow_label` are used.
+    ///
+    /// [flow information]: SocketAddrV6::flowinfo
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ipv6_flowinfo_parts)]
+    /// use std::net::{SocketAddrV6, Ipv6Addr};
+    ///
+    /// let mut socket = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0x0ab0_0000, 0);
+    /// socket.set_flow_label(0x1_2345);
+    /// assert_eq!(socket.flowinfo(), 0x0ab1_2345);
+    /// ```
+    #[unstable(feature = "ipv6_flowinfo_parts", issue = "none")]
+    pub fn set_flow_label(&mut self, new_flow_label: u32) {
+        self.inner.sin6_flowinfo =
+            (self.inner.sin6_flowinfo & !0x000f_ffff) | (new_flow_label & 0x000f_ffff);
+    }
+
+    /// Returns the traffic class part of the [flow information] of this address.
+    ///
+    /// The traffic class is the 8 bits of the value returned by
+    /// [`SocketAddrV6::flowinfo`] above the flow label, as specified in
+    /// [IETF RFC 2460, Section 7].
+    ///
+    /// [flow information]: SocketAddrV6::flowinfo
+    /// [IETF RFC 2460, Section 7]: https://tools.ietf.org/html/rfc2460#section-7
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ipv6_flowinfo_parts)]
+    /// use std::net::{SocketAddrV6, Ipv6Addr};
+    ///
+    /// let socket = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0x0ab1_2345, 0);
+    /// assert_eq!(socket.traffic_class(), 0xab);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "ipv6_flowinfo_parts", issue = "none")]
+    pub const fn traffic_class(&self) -> u8 {
+        (self.inner.sin6_flowinfo >> 20) as u8
+    }
+
+    /// Changes the traffic class part of the [flow information] of this
+    /// address, leaving the flow label untouched.
+    ///
+    /// [flow information]: SocketAddrV6::flowinfo
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ipv6_flowinfo_parts)]
+    /// use std::net::{SocketAddrV6, Ipv6Addr};
+    ///
+    /// let mut socket = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0x1_2345, 0);
+    /// socket.set_traffic_class(0xab);
+    /// assert_eq!(socket.flowinfo(), 0x0ab1_2345);
+    /// ```
+    #[unstable(feature = "ipv6_flowinfo_parts", issue = "none")]
+    pub fn set_traffic_class(&mut self, new_traffic_class: u8) {
+        self.inner.sin6_flowinfo =
+            (self.inner.sin6_flowinfo & !0x0ff0_0000) | ((new_traffic_class as u32) << 20);
+    }
+
     /// Returns the scope ID associated with this address.
     ///
     /// This information corresponds to the `sin6_scope_id` field in C's `netinet/in.h`,
     /// as specified in [IETF RFC 2553, Section 3.3].
     ///
+    /// In the textual form of a socket address the scope ID is written as a
+    /// `%` suffix of the IP address, as in `[fe80::1%2]:8080`. Parsing with
+    /// [`str::parse`] only accepts a numeric scope ID, while [`ToSocketAddrs`]
+    /// additionally accepts an interface name (such as `[fe80::1%eth0]:8080`)
+    /// on platforms whose resolver supports it.
+    ///
     /// [IETF RFC 2553, Section 3.3]: https://tools.ietf.org/html/rfc2553#section-3.3
     ///
     /// # Examples
@@ -540,6 +640,40 @@ pub const fn scope_id(&self) -> u32 {
     pub fn set_scope_id(&mut self, new_scope_id: u32) {
         self.inner.sin6_scope_id = new_scope_id;
     }
+
+    /// Returns [`true`] if the IP address of this socket address is only
+    /// meaningful on a single link.
+    ///
+    /// This is the case for unicast link-local addresses (`fe80::/10`) and for
+    /// multicast addresses with interface-local or link-local scope. Such an
+    /// address generally needs a nonzero [scope ID] identifying the interface
+    /// it belongs to before it can be connected or sent to.
+    ///
+    /// [scope ID]: SocketAddrV6::scope_id
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ipv6_scope)]
+    /// use std::net::{SocketAddrV6, Ipv6Addr};
+    ///
+    /// let socket: SocketAddrV6 = "[fe80::1%2]:8080".parse().unwrap();
+    /// assert!(socket.is_link_local());
+    /// assert_eq!(socket.scope_id(), 2);
+    ///
+    /// let socket = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0, 0);
+    /// assert!(!socket.is_link_local());
+    /// ```
+    #[must_use]
+    #[unstable(feature = "ipv6_scope", issue = "none")]
+    pub fn is_link_local(&self) -> bool {
+        let ip = self.ip();
+        ip.is_unicast_link_local()
+            || matches!(
+                ip.multicast_scope(),
+                Some(Ipv6MulticastScope::InterfaceLocal | Ipv6MulticastScope::LinkLocal)
+            )
+    }
 }
 
 impl FromInner<c::sockaddr_in> for SocketAddrV4 {
@@ -890,7 +1024,10 @@ pub trait ToSocketAddrs {
     /// outcome of any resolution performed.
     ///
     /// Note that this function may block the current thread while resolution is
-    /// performed.
+    /// performed. Use [`resolve_timeout`] to put an upper bound on how long
+    /// that takes.
+    ///
+    /// [`resolve_timeout`]: crate::net::resolve_timeout
     #[stable(feature = "rust1", since = "1.0.0")]
     fn to_socket_addrs(&self) -> io::Result<Self::Iter>;
 }
@@ -1026,3 +1163,58 @@ fn to_socket_addrs(&self) -> io::Result<vec::IntoIter<SocketAddr>> {
         (&**self).to_socket_addrs()
     }
 }
+
+/// Resolves `addr` to socket addresses, giving up after `timeout`.
+///
+/// Name resolution through [`ToSocketAddrs`] blocks inside the system
+/// resolver, which can take much longer than any read or connect timeout set
+/// on a socket. This function performs the resolution on a helper thread and
+/// returns an error of kind [`TimedOut`] if it has not finished in time.
+///
+/// The helper thread is not cancelled on timeout; it runs until the system
+/// resolver returns, and its result is discarded. A custom resolver can be
+/// plugged in the same way by implementing [`ToSocketAddrs`] for a type of
+/// one's own.
+///
+/// [`TimedOut`]: io::ErrorKind::TimedOut
+///
+/// # Errors
+///
+/// Besides timing out, this returns any error reported by
+/// [`to_socket_addrs`], as well as an error if the helper thread could not be
+/// spawned.
+///
+/// [`to_socket_addrs`]: ToSocketAddrs::to_socket_addrs
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(resolve_timeout)]
+/// use std::net::{self, TcpStream};
+/// use std::time::Duration;
+///
+/// fn main() -> std::io::Result<()> {
+///     let timeout = Duration::from_secs(2);
+///     let addrs: Vec<_> = net::resolve_timeout("example.com:80", timeout)?.collect();
+///     let stream = TcpStream::connect(&addrs[..])?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "resolve_timeout", issue = "none")]
+pub fn resolve_timeout<A>(addr: A, timeout: Duration) -> io::Result<vec::IntoIter<SocketAddr>>
+where
+    A: ToSocketAddrs + Send + 'static,
+{
+    let (tx, rx) = mpsc::sync_channel(1);
+    thread::Builder::new().name("address resolver".to_owned()).spawn(move || {
+        // The receiver may have given up waiting already, in which case
+        // nobody is interested in the result anymore.
+        let _ = tx.send(addr.to_socket_addrs().map(|addrs| addrs.collect::<Vec<_>>()));
+    })?;
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result.map(Vec::into_iter),
+        Err(_) => {
+            Err(io::Error::new_const(io::ErrorKind::TimedOut, &"address resolution timed out"))
+        }
+    }
+}
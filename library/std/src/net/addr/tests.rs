@@ -1,5 +1,9 @@
+use crate::io;
 use crate::net::test::{sa4, sa6, tsa};
 use crate::net::*;
+use crate::thread;
+use crate::time::Duration;
+use crate::vec;
 
 #[test]
 fn to_socket_addr_ipaddr_u16() {
@@ -138,6 +142,47 @@ fn set_flowinfo() {
     assert_eq!(v6.flowinfo(), 20);
 }
 
+#[test]
+fn flowinfo_parts() {
+    let mut v6 = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 80, 0x0ab1_2345, 0);
+    assert_eq!(v6.flow_label(), 0x1_2345);
+    assert_eq!(v6.traffic_class(), 0xab);
+
+    v6.set_flow_label(0xfff_ffff);
+    assert_eq!(v6.flowinfo(), 0x0abf_ffff);
+    v6.set_traffic_class(0x12);
+    assert_eq!(v6.flowinfo(), 0x012f_ffff);
+    v6.set_flow_label(0);
+    assert_eq!(v6.flowinfo(), 0x0120_0000);
+}
+
+#[test]
+fn scoped_link_local() {
+    let v6: SocketAddrV6 = "[fe80::1%3]:80".parse().unwrap();
+    assert_eq!(v6.scope_id(), 3);
+    assert!(v6.is_link_local());
+    assert_eq!(v6.to_string(), "[fe80::1%3]:80");
+
+    let link_local_multicast = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1);
+    assert!(SocketAddrV6::new(link_local_multicast, 80, 0, 0).is_link_local());
+    let global_multicast = Ipv6Addr::new(0xff0e, 0, 0, 0, 0, 0, 0, 1);
+    assert!(!SocketAddrV6::new(global_multicast, 80, 0, 0).is_link_local());
+    assert!(!SocketAddrV6::new(Ipv6Addr::LOCALHOST, 80, 0, 0).is_link_local());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn to_socket_addr_zone_name() {
+    match tsa("[fe80::1%lo]:80").unwrap()[..] {
+        [SocketAddr::V6(v6)] => {
+            assert_eq!(v6.ip(), &Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1));
+            assert_eq!(v6.port(), 80);
+            assert_ne!(v6.scope_id(), 0);
+        }
+        ref addrs => panic!("unexpected addresses {:?}", addrs),
+    }
+}
+
 #[test]
 fn set_scope_id() {
     let mut v6 = SocketAddrV6::new(Ipv6Addr::new(0x2a02, 0x6b8, 0, 1, 0, 0, 0, 1), 80, 0, 10);
@@ -165,6 +210,29 @@ fn is_v6() {
     assert!(v6.is_ipv6());
 }
 
+#[test]
+fn resolve_timeout_gives_up() {
+    let a = sa4(Ipv4Addr::new(127, 0, 0, 1), 8080);
+    let addrs = resolve_timeout("127.0.0.1:8080", Duration::from_secs(10)).unwrap();
+    assert_eq!(addrs.collect::<Vec<_>>(), vec![a]);
+
+    let err = resolve_timeout("127.0.0.1", Duration::from_secs(10)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+    struct Slow;
+
+    impl ToSocketAddrs for Slow {
+        type Iter = vec::IntoIter<SocketAddr>;
+        fn to_socket_addrs(&self) -> io::Result<Self::Iter> {
+            thread::sleep(Duration::from_secs(5));
+            Ok(Vec::new().into_iter())
+        }
+    }
+
+    let err = resolve_timeout(Slow, Duration::from_millis(10)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+}
+
 #[test]
 fn socket_v4_to_str() {
     let socket = SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 8080);
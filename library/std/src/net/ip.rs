@@ -7,6 +7,7 @@
 use crate::hash;
 use crate::io::Write as IoWrite;
 use crate::mem::transmute;
+use crate::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
 use crate::sys::net::netc as c;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 
@@ -525,6 +526,69 @@ pub const fn new(a: u8, b: u8, c: u8, d: u8) -> Ipv4Addr {
         self.inner.s_addr.to_ne_bytes()
     }
 
+    /// Creates an `Ipv4Addr` from a four element byte array.
+    ///
+    /// This is the `const` equivalent of `Ipv4Addr::from([a, b, c, d])`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_bits, const_ipv4)]
+    /// use std::net::Ipv4Addr;
+    ///
+    /// const ADDR: Ipv4Addr = Ipv4Addr::from_octets([13, 12, 11, 10]);
+    /// assert_eq!(ADDR, Ipv4Addr::new(13, 12, 11, 10));
+    /// ```
+    #[rustc_const_unstable(feature = "const_ipv4", issue = "76205")]
+    #[unstable(feature = "ip_bits", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_octets(octets: [u8; 4]) -> Ipv4Addr {
+        Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3])
+    }
+
+    /// Converts a host byte order `u32` into an `Ipv4Addr`.
+    ///
+    /// This is the `const` equivalent of `Ipv4Addr::from(bits)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_bits, const_ipv4)]
+    /// use std::net::Ipv4Addr;
+    ///
+    /// const ADDR: Ipv4Addr = Ipv4Addr::from_bits(0x12345678);
+    /// assert_eq!(ADDR, Ipv4Addr::new(0x12, 0x34, 0x56, 0x78));
+    /// ```
+    #[rustc_const_unstable(feature = "const_ipv4", issue = "76205")]
+    #[unstable(feature = "ip_bits", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_bits(bits: u32) -> Ipv4Addr {
+        Ipv4Addr::from_octets(bits.to_be_bytes())
+    }
+
+    /// Converts this address into a host byte order `u32`.
+    ///
+    /// This is the `const` equivalent of `u32::from(addr)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_bits)]
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let addr = Ipv4Addr::new(0x12, 0x34, 0x56, 0x78);
+    /// assert_eq!(addr.to_bits(), 0x12345678);
+    /// ```
+    #[rustc_const_unstable(feature = "const_ipv4", issue = "76205")]
+    #[unstable(feature = "ip_bits", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn to_bits(&self) -> u32 {
+        u32::from_be_bytes(self.octets())
+    }
+
     /// Returns [`true`] for the special 'unspecified' address (`0.0.0.0`).
     ///
     /// This property is defined in _UNIX Network Programming, Second Edition_,
@@ -1131,6 +1195,12 @@ fn into_inner(self) -> c::in_addr {
     }
 }
 
+impl FromInner<c::in_addr> for Ipv4Addr {
+    fn from_inner(addr: c::in_addr) -> Ipv4Addr {
+        Ipv4Addr { inner: addr }
+    }
+}
+
 #[stable(feature = "ip_u32", since = "1.1.0")]
 impl From<Ipv4Addr> for u32 {
     /// Converts an `Ipv4Addr` into a host byte order `u32`.
@@ -1747,6 +1817,93 @@ pub const fn to_canonical(&self) -> IpAddr {
     pub const fn octets(&self) -> [u8; 16] {
         self.inner.s6_addr
     }
+
+    /// Creates an `Ipv6Addr` from a sixteen element byte array.
+    ///
+    /// This is the `const` equivalent of `Ipv6Addr::from(octets)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_bits, const_ipv6)]
+    /// use std::net::Ipv6Addr;
+    ///
+    /// const ADDR: Ipv6Addr = Ipv6Addr::from_octets([
+    ///     0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    /// ]);
+    /// assert_eq!(ADDR, Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    /// ```
+    #[rustc_const_unstable(feature = "const_ipv6", issue = "76205")]
+    #[unstable(feature = "ip_bits", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_octets(octets: [u8; 16]) -> Ipv6Addr {
+        Ipv6Addr { inner: c::in6_addr { s6_addr: octets } }
+    }
+
+    /// Creates an `Ipv6Addr` from an eight element 16-bit array.
+    ///
+    /// This is the `const` equivalent of `Ipv6Addr::from(segments)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_bits, const_ipv6)]
+    /// use std::net::Ipv6Addr;
+    ///
+    /// const ADDR: Ipv6Addr = Ipv6Addr::from_segments([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]);
+    /// assert_eq!(ADDR, Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    /// ```
+    #[rustc_const_unstable(feature = "const_ipv6", issue = "76205")]
+    #[unstable(feature = "ip_bits", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_segments(segments: [u16; 8]) -> Ipv6Addr {
+        let [a, b, c, d, e, f, g, h] = segments;
+        Ipv6Addr::new(a, b, c, d, e, f, g, h)
+    }
+
+    /// Converts a host byte order `u128` into an `Ipv6Addr`.
+    ///
+    /// This is the `const` equivalent of `Ipv6Addr::from(bits)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_bits, const_ipv6)]
+    /// use std::net::Ipv6Addr;
+    ///
+    /// const ADDR: Ipv6Addr = Ipv6Addr::from_bits(0x20010db8_00000000_00000000_00000001);
+    /// assert_eq!(ADDR, Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    /// ```
+    #[rustc_const_unstable(feature = "const_ipv6", issue = "76205")]
+    #[unstable(feature = "ip_bits", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_bits(bits: u128) -> Ipv6Addr {
+        Ipv6Addr::from_octets(bits.to_be_bytes())
+    }
+
+    /// Converts this address into a host byte order `u128`.
+    ///
+    /// This is the `const` equivalent of `u128::from(addr)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_bits)]
+    /// use std::net::Ipv6Addr;
+    ///
+    /// let addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+    /// assert_eq!(addr.to_bits(), 0x20010db8_00000000_00000000_00000001);
+    /// ```
+    #[rustc_const_unstable(feature = "const_ipv6", issue = "76205")]
+    #[unstable(feature = "ip_bits", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn to_bits(&self) -> u128 {
+        u128::from_be_bytes(self.octets())
+    }
 }
 
 /// Write an Ipv6Addr, conforming to the canonical style described by
@@ -2114,3 +2271,124 @@ fn from(ip: u128) -> Ipv6Addr {
         IpAddr::V6(Ipv6Addr::from(segments))
     }
 }
+
+macro_rules! bitop_impls {
+    ($(
+        $(#[$attr:meta])*
+        impl ($BitOp:ident, $BitOpAssign:ident) for $ty:ty = ($bitop:ident, $bitop_assign:ident);
+    )*) => {
+        $(
+            $(#[$attr])*
+            impl $BitOpAssign for $ty {
+                fn $bitop_assign(&mut self, rhs: $ty) {
+                    *self = Self::from_bits(self.to_bits().$bitop(rhs.to_bits()));
+                }
+            }
+
+            $(#[$attr])*
+            impl $BitOpAssign<&'_ $ty> for $ty {
+                fn $bitop_assign(&mut self, rhs: &'_ $ty) {
+                    self.$bitop_assign(*rhs);
+                }
+            }
+
+            $(#[$attr])*
+            impl $BitOp for $ty {
+                type Output = $ty;
+
+                #[inline]
+                fn $bitop(mut self, rhs: $ty) -> $ty {
+                    self.$bitop_assign(rhs);
+                    self
+                }
+            }
+
+            $(#[$attr])*
+            impl $BitOp<&'_ $ty> for $ty {
+                type Output = $ty;
+
+                #[inline]
+                fn $bitop(mut self, rhs: &'_ $ty) -> $ty {
+                    self.$bitop_assign(*rhs);
+                    self
+                }
+            }
+
+            $(#[$attr])*
+            impl $BitOp<$ty> for &'_ $ty {
+                type Output = $ty;
+
+                #[inline]
+                fn $bitop(self, rhs: $ty) -> $ty {
+                    let mut lhs = *self;
+                    lhs.$bitop_assign(rhs);
+                    lhs
+                }
+            }
+
+            $(#[$attr])*
+            impl $BitOp<&'_ $ty> for &'_ $ty {
+                type Output = $ty;
+
+                #[inline]
+                fn $bitop(self, rhs: &'_ $ty) -> $ty {
+                    let mut lhs = *self;
+                    lhs.$bitop_assign(*rhs);
+                    lhs
+                }
+            }
+        )*
+    };
+}
+
+bitop_impls! {
+    #[stable(feature = "ip_bitops", since = "1.59.0")]
+    impl (BitAnd, BitAndAssign) for Ipv4Addr = (bitand, bitand_assign);
+    #[stable(feature = "ip_bitops", since = "1.59.0")]
+    impl (BitOr, BitOrAssign) for Ipv4Addr = (bitor, bitor_assign);
+
+    #[stable(feature = "ip_bitops", since = "1.59.0")]
+    impl (BitAnd, BitAndAssign) for Ipv6Addr = (bitand, bitand_assign);
+    #[stable(feature = "ip_bitops", since = "1.59.0")]
+    impl (BitOr, BitOrAssign) for Ipv6Addr = (bitor, bitor_assign);
+}
+
+#[stable(feature = "ip_bitops", since = "1.59.0")]
+impl Not for Ipv4Addr {
+    type Output = Ipv4Addr;
+
+    #[inline]
+    fn not(self) -> Ipv4Addr {
+        Ipv4Addr::from_bits(!self.to_bits())
+    }
+}
+
+#[stable(feature = "ip_bitops", since = "1.59.0")]
+impl Not for &'_ Ipv4Addr {
+    type Output = Ipv4Addr;
+
+    #[inline]
+    fn not(self) -> Ipv4Addr {
+        !*self
+    }
+}
+
+#[stable(feature = "ip_bitops", since = "1.59.0")]
+impl Not for Ipv6Addr {
+    type Output = Ipv6Addr;
+
+    #[inline]
+    fn not(self) -> Ipv6Addr {
+        Ipv6Addr::from_bits(!self.to_bits())
+    }
+}
+
+#[stable(feature = "ip_bitops", since = "1.59.0")]
+impl Not for &'_ Ipv6Addr {
+    type Output = Ipv6Addr;
+
+    #[inline]
+    fn not(self) -> Ipv6Addr {
+        !*self
+    }
+}
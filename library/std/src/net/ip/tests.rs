@@ -871,6 +871,15 @@ fn ipv4_const() {
         IP_V6_MAPPED,
         Ipv6Addr::from([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 127, 0, 0, 1])
     );
+
+    const FROM_OCTETS: Ipv4Addr = Ipv4Addr::from_octets([127, 0, 0, 1]);
+    assert_eq!(FROM_OCTETS, IP_ADDRESS);
+
+    const BITS: u32 = IP_ADDRESS.to_bits();
+    assert_eq!(BITS, 0x7f000001);
+
+    const FROM_BITS: Ipv4Addr = Ipv4Addr::from_bits(BITS);
+    assert_eq!(FROM_BITS, IP_ADDRESS);
 }
 
 #[test]
@@ -918,6 +927,47 @@ fn ipv6_const() {
 
     const IP_V4: Option<Ipv4Addr> = IP_ADDRESS.to_ipv4();
     assert_eq!(IP_V4.unwrap(), Ipv4Addr::new(0, 0, 0, 1));
+
+    const FROM_OCTETS: Ipv6Addr = Ipv6Addr::from_octets(OCTETS);
+    assert_eq!(FROM_OCTETS, IP_ADDRESS);
+
+    const FROM_SEGMENTS: Ipv6Addr = Ipv6Addr::from_segments(SEGMENTS);
+    assert_eq!(FROM_SEGMENTS, IP_ADDRESS);
+
+    const BITS: u128 = IP_ADDRESS.to_bits();
+    assert_eq!(BITS, 1);
+
+    const FROM_BITS: Ipv6Addr = Ipv6Addr::from_bits(BITS);
+    assert_eq!(FROM_BITS, IP_ADDRESS);
+}
+
+#[test]
+fn ip_bitops() {
+    let v4 = Ipv4Addr::new(192, 168, 17, 42);
+    let mask = Ipv4Addr::new(255, 255, 255, 0);
+    assert_eq!(v4 & mask, Ipv4Addr::new(192, 168, 17, 0));
+    assert_eq!(&v4 & &mask, Ipv4Addr::new(192, 168, 17, 0));
+    assert_eq!(v4 | !mask, Ipv4Addr::new(192, 168, 17, 255));
+    assert_eq!(!&mask, Ipv4Addr::new(0, 0, 0, 255));
+
+    let mut net = v4;
+    net &= mask;
+    assert_eq!(net, Ipv4Addr::new(192, 168, 17, 0));
+    net |= &Ipv4Addr::new(0, 0, 0, 1);
+    assert_eq!(net, Ipv4Addr::new(192, 168, 17, 1));
+
+    let v6 = Ipv6Addr::new(0x2001, 0xdb8, 0x1234, 0x5678, 0, 0, 0, 1);
+    let mask = Ipv6Addr::new(0xffff, 0xffff, 0xffff, 0, 0, 0, 0, 0);
+    assert_eq!(v6 & mask, Ipv6Addr::new(0x2001, 0xdb8, 0x1234, 0, 0, 0, 0, 0));
+    assert_eq!(
+        v6 | !mask,
+        Ipv6Addr::new(0x2001, 0xdb8, 0x1234, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff)
+    );
+
+    let mut net = v6;
+    net &= &mask;
+    net |= Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1);
+    assert_eq!(net, Ipv6Addr::new(0x2001, 0xdb8, 0x1234, 0, 0, 0, 0, 1));
 }
 
 #[test]
@@ -21,6 +21,8 @@
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::addr::{SocketAddr, SocketAddrV4, SocketAddrV6, ToSocketAddrs};
+#[unstable(feature = "resolve_timeout", issue = "none")]
+pub use self::addr::resolve_timeout;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::ip::{IpAddr, Ipv4Addr, Ipv6Addr, Ipv6MulticastScope};
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -63,6 +65,45 @@ pub enum Shutdown {
     Both,
 }
 
+/// The kinds of readiness that can be waited for with [`TcpStream::ready`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[unstable(feature = "tcp_ready", issue = "none")]
+pub enum Interest {
+    /// Wait until the socket can be read from without blocking.
+    Readable,
+    /// Wait until the socket can be written to without blocking.
+    Writable,
+    /// Wait until the socket can be read from or written to without blocking.
+    Both,
+}
+
+/// The readiness of a socket, as returned by [`TcpStream::ready`].
+///
+/// An error or hang-up on the socket is reported as readiness for every
+/// requested [`Interest`], so that the next read or write reports it.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[unstable(feature = "tcp_ready", issue = "none")]
+pub struct Readiness {
+    readable: bool,
+    writable: bool,
+}
+
+impl Readiness {
+    /// Returns `true` if the socket can be read from without blocking.
+    #[must_use]
+    #[unstable(feature = "tcp_ready", issue = "none")]
+    pub fn is_readable(&self) -> bool {
+        self.readable
+    }
+
+    /// Returns `true` if the socket can be written to without blocking.
+    #[must_use]
+    #[unstable(feature = "tcp_ready", issue = "none")]
+    pub fn is_writable(&self) -> bool {
+        self.writable
+    }
+}
+
 #[inline]
 const fn htons(i: u16) -> u16 {
     i.to_be()
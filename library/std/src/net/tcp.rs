@@ -6,8 +6,8 @@
 use crate::io::prelude::*;
 
 use crate::fmt;
-use crate::io::{self, IoSlice, IoSliceMut};
-use crate::net::{Shutdown, SocketAddr, ToSocketAddrs};
+use crate::io::{self, IoSlice, IoSliceMut, ReadBuf};
+use crate::net::{Interest, Readiness, Shutdown, SocketAddr, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 use crate::time::Duration;
@@ -95,6 +95,7 @@
 #[derive(Debug)]
 pub struct Incoming<'a> {
     listener: &'a TcpListener,
+    retry_transient: bool,
 }
 
 /// An iterator that infinitely [`accept`]s connections on a [`TcpListener`].
@@ -107,6 +108,7 @@ pub struct Incoming<'a> {
 #[unstable(feature = "tcplistener_into_incoming", issue = "88339")]
 pub struct IntoIncoming {
     listener: TcpListener,
+    retry_transient: bool,
 }
 
 impl TcpStream {
@@ -461,6 +463,70 @@ pub fn linger(&self) -> io::Result<Option<Duration>> {
         self.0.linger()
     }
 
+    /// Sets the value of the `SO_KEEPALIVE` option on this socket, along with
+    /// the time the connection has to be idle before keepalive probes are sent.
+    ///
+    /// `None` disables keepalive probes. `Some(idle)` enables them, starting
+    /// after the connection has been idle for `idle`, rounded down to whole
+    /// seconds with a minimum of one second. The interval between probes and
+    /// the number of probes before the connection is dropped keep their
+    /// system defaults.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// The idle time is set with `TCP_KEEPIDLE`, or `TCP_KEEPALIVE` on macOS
+    /// and iOS. Windows supports it from Windows 10, version 1709. On other
+    /// platforms only `None` is accepted, and passing `Some` returns an
+    /// [`Unsupported`] error without changing the socket.
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive)]
+    ///
+    /// use std::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive(Some(Duration::from_secs(60))).expect("set_keepalive call failed");
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        self.0.set_keepalive(keepalive)
+    }
+
+    /// Gets the keepalive configuration of this socket.
+    ///
+    /// Returns `None` if `SO_KEEPALIVE` is not set, and otherwise the idle
+    /// time before the first keepalive probe. The idle time is the system
+    /// default unless it was changed with [`TcpStream::set_keepalive`].
+    ///
+    /// On platforms where the idle time cannot be queried, a socket with
+    /// keepalive enabled yields an [`Unsupported`] error.
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive)]
+    ///
+    /// use std::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive(Some(Duration::from_secs(60))).expect("set_keepalive call failed");
+    /// assert_eq!(stream.keepalive().unwrap(), Some(Duration::from_secs(60)));
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        self.0.keepalive()
+    }
+
     /// Sets the value of the `TCP_NODELAY` option on this socket.
     ///
     /// If set, this option disables the Nagle algorithm. This means that
@@ -546,6 +612,13 @@ pub fn ttl(&self) -> io::Result<u32> {
     /// the field in the process. This can be useful for checking errors between
     /// calls.
     ///
+    /// The pending error is usually the reason an asynchronous operation
+    /// failed, such as a nonblocking connect or a connection reset reported
+    /// while no call was in progress. Because it is cleared when read, a
+    /// second call returns `Ok(None)` unless a new error has occurred. Errors
+    /// that were already returned by a failed `read` or `write` are not
+    /// stored again.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -560,6 +633,58 @@ pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         self.0.take_error()
     }
 
+    /// Blocks until this stream is ready for the given [`Interest`], or until
+    /// `timeout` has elapsed.
+    ///
+    /// A `timeout` of [`None`] waits indefinitely. If the timeout elapses
+    /// before the socket becomes ready, an error of kind
+    /// [`io::ErrorKind::TimedOut`] is returned.
+    ///
+    /// This is mostly useful together with [`set_nonblocking`], to wait for a
+    /// [`WouldBlock`] operation to become possible without spinning. A socket
+    /// with a pending error or a closed connection is reported as ready, and
+    /// the error is returned by the next read or write, or by [`take_error`].
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `poll` function on Unix and
+    /// to the `select` function on Windows. Other platforms return an
+    /// [`Unsupported`](io::ErrorKind::Unsupported) error.
+    ///
+    /// [`set_nonblocking`]: TcpStream::set_nonblocking
+    /// [`take_error`]: TcpStream::take_error
+    /// [`WouldBlock`]: io::ErrorKind::WouldBlock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_ready)]
+    ///
+    /// use std::io::Read;
+    /// use std::net::{Interest, TcpStream};
+    /// use std::time::Duration;
+    ///
+    /// let mut stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_nonblocking(true).expect("set_nonblocking call failed");
+    ///
+    /// let ready = stream.ready(Interest::Readable, Some(Duration::from_secs(1)))
+    ///                   .expect("socket did not become readable");
+    /// assert!(ready.is_readable());
+    /// let mut buf = [0; 128];
+    /// stream.read(&mut buf).expect("read call failed");
+    /// ```
+    #[unstable(feature = "tcp_ready", issue = "none")]
+    pub fn ready(&self, interest: Interest, timeout: Option<Duration>) -> io::Result<Readiness> {
+        let (readable, writable) = match interest {
+            Interest::Readable => (true, false),
+            Interest::Writable => (false, true),
+            Interest::Both => (true, true),
+        };
+        let (readable, writable) = self.0.ready(readable, writable, timeout)?;
+        Ok(Readiness { readable, writable })
+    }
+
     /// Moves this TCP stream into or out of nonblocking mode.
     ///
     /// This will result in `read`, `write`, `recv` and `send` operations
@@ -618,6 +743,10 @@ fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
 
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.0.read_buf(buf)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
@@ -652,6 +781,10 @@ fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
 
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.0.read_buf(buf)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
@@ -818,6 +951,12 @@ pub fn accept(&self) -> io::Result<(TcpStream, SocketAddr)> {
     /// the peer's [`SocketAddr`] structure. Iterating over it is equivalent to
     /// calling [`TcpListener::accept`] in a loop.
     ///
+    /// Errors that only affect a single pending connection, such as a peer
+    /// that aborted or reset the connection before it was accepted, are not
+    /// yielded: the iterator moves on to the next connection instead. Any
+    /// other error is yielded, after which iteration may continue. This can
+    /// be turned off with [`Incoming::retry_transient_errors`].
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -843,7 +982,7 @@ pub fn accept(&self) -> io::Result<(TcpStream, SocketAddr)> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn incoming(&self) -> Incoming<'_> {
-        Incoming { listener: self }
+        Incoming { listener: self, retry_transient: true }
     }
 
     /// Turn this into an iterator over the connections being received on this
@@ -853,6 +992,10 @@ pub fn incoming(&self) -> Incoming<'_> {
     /// the peer's [`SocketAddr`] structure. Iterating over it is equivalent to
     /// calling [`TcpListener::accept`] in a loop.
     ///
+    /// Like [`TcpListener::incoming`], the iterator skips errors that only
+    /// affect a single pending connection. This can be turned off with
+    /// [`IntoIncoming::retry_transient_errors`].
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -875,7 +1018,7 @@ pub fn incoming(&self) -> Incoming<'_> {
     #[must_use = "`self` will be dropped if the result is not used"]
     #[unstable(feature = "tcplistener_into_incoming", issue = "88339")]
     pub fn into_incoming(self) -> IntoIncoming {
-        IntoIncoming { listener: self }
+        IntoIncoming { listener: self, retry_transient: true }
     }
 
     /// Sets the value for the `IP_TTL` option on this socket.
@@ -1007,11 +1150,85 @@ pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
 // `AsSocket`/`From<OwnedSocket>`/`Into<OwnedSocket>` and
 // `AsRawSocket`/`IntoRawSocket`/`FromRawSocket` on Windows.
 
+/// Accepts the next connection, skipping connections that failed before they
+/// could be accepted if `retry_transient` is set.
+fn accept_next(listener: &TcpListener, retry_transient: bool) -> io::Result<TcpStream> {
+    loop {
+        match listener.accept() {
+            Ok((stream, _)) => return Ok(stream),
+            Err(ref e)
+                if retry_transient
+                    && matches!(
+                        e.kind(),
+                        io::ErrorKind::ConnectionAborted
+                            | io::ErrorKind::ConnectionReset
+                            | io::ErrorKind::Interrupted
+                    ) => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+impl<'a> Incoming<'a> {
+    /// Sets whether errors that only affect a single pending connection are
+    /// skipped rather than yielded.
+    ///
+    /// This is the case for connections that were aborted or reset by the
+    /// peer before they could be accepted, and for interrupted calls. Such
+    /// errors are skipped by default; errors that affect the listener itself,
+    /// like running out of file descriptors, are always yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(incoming_retry_transient)]
+    /// use std::net::TcpListener;
+    ///
+    /// let listener = TcpListener::bind("127.0.0.1:80").unwrap();
+    /// for stream in listener.incoming().retry_transient_errors(false) {
+    ///     match stream {
+    ///         Ok(stream) => { /* handle the connection */ }
+    ///         Err(e) => eprintln!("accept failed: {}", e),
+    ///     }
+    /// }
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    #[unstable(feature = "incoming_retry_transient", issue = "none")]
+    pub fn retry_transient_errors(mut self, retry: bool) -> Incoming<'a> {
+        self.retry_transient = retry;
+        self
+    }
+}
+
+impl IntoIncoming {
+    /// Sets whether errors that only affect a single pending connection are
+    /// skipped rather than yielded.
+    ///
+    /// See [`Incoming::retry_transient_errors`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(incoming_retry_transient)]
+    /// #![feature(tcplistener_into_incoming)]
+    /// use std::net::TcpListener;
+    ///
+    /// let listener = TcpListener::bind("127.0.0.1:80").unwrap();
+    /// let incoming = listener.into_incoming().retry_transient_errors(false);
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    #[unstable(feature = "incoming_retry_transient", issue = "none")]
+    pub fn retry_transient_errors(mut self, retry: bool) -> IntoIncoming {
+        self.retry_transient = retry;
+        self
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a> Iterator for Incoming<'a> {
     type Item = io::Result<TcpStream>;
     fn next(&mut self) -> Option<io::Result<TcpStream>> {
-        Some(self.listener.accept().map(|p| p.0))
+        Some(accept_next(self.listener, self.retry_transient))
     }
 }
 
@@ -1019,7 +1236,7 @@ fn next(&mut self) -> Option<io::Result<TcpStream>> {
 impl Iterator for IntoIncoming {
     type Item = io::Result<TcpStream>;
     fn next(&mut self) -> Option<io::Result<TcpStream>> {
-        Some(self.listener.accept().map(|p| p.0))
+        Some(accept_next(&self.listener, self.retry_transient))
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::fmt;
 use crate::io::prelude::*;
-use crate::io::{ErrorKind, IoSlice, IoSliceMut};
+use crate::io::{ErrorKind, IoSlice, IoSliceMut, ReadBuf};
+use crate::mem::MaybeUninit;
 use crate::net::test::{next_test_ip4, next_test_ip6};
 use crate::net::*;
 use crate::sync::mpsc::channel;
@@ -172,6 +173,28 @@ fn multiple_connect_serial() {
     })
 }
 
+#[test]
+fn into_incoming_without_retry() {
+    each_ip(&mut |addr| {
+        let max = 3;
+        let acceptor = t!(TcpListener::bind(&addr));
+
+        let _t = thread::spawn(move || {
+            for _ in 0..max {
+                let mut stream = t!(TcpStream::connect(&addr));
+                t!(stream.write(&[99]));
+            }
+        });
+
+        for stream in acceptor.into_incoming().retry_transient_errors(false).take(max) {
+            let mut stream = t!(stream);
+            let mut buf = [0];
+            t!(stream.read(&mut buf));
+            assert_eq!(buf[0], 99);
+        }
+    })
+}
+
 #[test]
 fn multiple_connect_interleaved_greedy_schedule() {
     const MAX: usize = 10;
@@ -305,6 +328,26 @@ fn read_vectored() {
     })
 }
 
+#[test]
+fn read_buf() {
+    each_ip(&mut |addr| {
+        let srv = t!(TcpListener::bind(&addr));
+        let t = thread::spawn(move || {
+            let mut s = t!(TcpStream::connect(&addr));
+            s.write_all(&[1, 2, 3, 4]).unwrap();
+        });
+
+        let mut s = t!(srv.accept()).0;
+        let mut storage = [MaybeUninit::<u8>::uninit(); 128];
+        let mut buf = ReadBuf::uninit(&mut storage);
+        t!(s.read_buf(&mut buf));
+        assert!(buf.filled_len() >= 1);
+        assert!(buf.initialized_len() >= buf.filled_len());
+        assert_eq!(buf.filled(), &[1, 2, 3, 4][..buf.filled_len()]);
+        t.join().ok().expect("thread panicked");
+    })
+}
+
 #[test]
 fn write_vectored() {
     each_ip(&mut |addr| {
@@ -782,6 +825,24 @@ fn linger() {
     assert_eq!(None, t!(stream.linger()));
 }
 
+#[test]
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "freebsd"))]
+fn keepalive() {
+    let addr = next_test_ip4();
+    let _listener = t!(TcpListener::bind(&addr));
+
+    let stream = t!(TcpStream::connect(&("localhost", addr.port())));
+
+    assert_eq!(None, t!(stream.keepalive()));
+    t!(stream.set_keepalive(Some(Duration::from_secs(30))));
+    assert_eq!(Some(Duration::from_secs(30)), t!(stream.keepalive()));
+    // Sub-second idle times are rounded up to the one second minimum.
+    t!(stream.set_keepalive(Some(Duration::from_millis(10))));
+    assert_eq!(Some(Duration::from_secs(1)), t!(stream.keepalive()));
+    t!(stream.set_keepalive(None));
+    assert_eq!(None, t!(stream.keepalive()));
+}
+
 #[test]
 #[cfg_attr(target_env = "sgx", ignore)]
 fn nodelay() {
@@ -868,6 +929,42 @@ fn peek() {
     })
 }
 
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)] // FIXME: https://github.com/fortanix/rust-sgx/issues/31
+fn ready() {
+    each_ip(&mut |addr| {
+        let (txdone, rxdone) = channel();
+
+        let srv = t!(TcpListener::bind(&addr));
+        let _t = thread::spawn(move || {
+            let mut cl = t!(srv.accept()).0;
+            t!(rxdone.recv());
+            cl.write(&[1, 3, 3, 7]).unwrap();
+            t!(rxdone.recv());
+        });
+
+        let mut c = t!(TcpStream::connect(&addr));
+        t!(c.set_nonblocking(true));
+
+        let ready = t!(c.ready(Interest::Writable, None));
+        assert!(ready.is_writable());
+        assert!(!ready.is_readable());
+
+        match c.ready(Interest::Readable, Some(Duration::from_millis(10))) {
+            Ok(_) => panic!("expected error"),
+            Err(ref e) if e.kind() == ErrorKind::TimedOut => {}
+            Err(e) => panic!("unexpected error {}", e),
+        }
+
+        t!(txdone.send(()));
+        let ready = t!(c.ready(Interest::Readable, Some(Duration::from_secs(10))));
+        assert!(ready.is_readable());
+        let mut b = [0; 10];
+        assert_eq!(t!(c.read(&mut b)), 4);
+        t!(txdone.send(()));
+    })
+}
+
 #[test]
 #[cfg_attr(target_env = "sgx", ignore)] // FIXME: https://github.com/fortanix/rust-sgx/issues/31
 fn connect_timeout_valid() {
@@ -2,7 +2,7 @@
 mod tests;
 
 use crate::fmt;
-use crate::io::{self, Error, ErrorKind};
+use crate::io::{self, Error, ErrorKind, IoSlice, IoSliceMut};
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
@@ -516,6 +516,135 @@ pub fn multicast_loop_v6(&self) -> io::Result<bool> {
         self.0.multicast_loop_v6()
     }
 
+    /// Sets the value of the `IPV6_MULTICAST_HOPS` option for this socket.
+    ///
+    /// Indicates the hop limit of outgoing multicast packets for this socket.
+    /// The default value is 1 which means that multicast packets don't leave
+    /// the local network unless explicitly requested.
+    ///
+    /// Note that this might not have any effect on IPv4 sockets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_multicast_options)]
+    ///
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("[::1]:34254").expect("couldn't bind to address");
+    /// socket.set_multicast_hops_v6(42).expect("set_multicast_hops_v6 call failed");
+    /// ```
+    #[unstable(feature = "udp_multicast_options", issue = "none")]
+    pub fn set_multicast_hops_v6(&self, multicast_hops_v6: u32) -> io::Result<()> {
+        self.0.set_multicast_hops_v6(multicast_hops_v6)
+    }
+
+    /// Gets the value of the `IPV6_MULTICAST_HOPS` option for this socket.
+    ///
+    /// For more information about this option, see [`UdpSocket::set_multicast_hops_v6`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_multicast_options)]
+    ///
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("[::1]:34254").expect("couldn't bind to address");
+    /// socket.set_multicast_hops_v6(42).expect("set_multicast_hops_v6 call failed");
+    /// assert_eq!(socket.multicast_hops_v6().unwrap(), 42);
+    /// ```
+    #[unstable(feature = "udp_multicast_options", issue = "none")]
+    pub fn multicast_hops_v6(&self) -> io::Result<u32> {
+        self.0.multicast_hops_v6()
+    }
+
+    /// Sets the value of the `IP_MULTICAST_IF` option for this socket.
+    ///
+    /// Selects the local interface, identified by its address, that outgoing
+    /// multicast packets are sent from. If it's equal to `INADDR_ANY` then
+    /// the system chooses an appropriate interface.
+    ///
+    /// Note that this might not have any effect on IPv6 sockets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_multicast_options)]
+    ///
+    /// use std::net::{Ipv4Addr, UdpSocket};
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.set_multicast_if_v4(&Ipv4Addr::LOCALHOST).expect("set_multicast_if_v4 call failed");
+    /// ```
+    #[unstable(feature = "udp_multicast_options", issue = "none")]
+    pub fn set_multicast_if_v4(&self, interface: &Ipv4Addr) -> io::Result<()> {
+        self.0.set_multicast_if_v4(interface)
+    }
+
+    /// Gets the value of the `IP_MULTICAST_IF` option for this socket.
+    ///
+    /// For more information about this option, see [`UdpSocket::set_multicast_if_v4`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_multicast_options)]
+    ///
+    /// use std::net::{Ipv4Addr, UdpSocket};
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.set_multicast_if_v4(&Ipv4Addr::LOCALHOST).expect("set_multicast_if_v4 call failed");
+    /// assert_eq!(socket.multicast_if_v4().unwrap(), Ipv4Addr::LOCALHOST);
+    /// ```
+    #[unstable(feature = "udp_multicast_options", issue = "none")]
+    pub fn multicast_if_v4(&self) -> io::Result<Ipv4Addr> {
+        self.0.multicast_if_v4()
+    }
+
+    /// Sets the value of the `IPV6_MULTICAST_IF` option for this socket.
+    ///
+    /// Selects the local interface, identified by its index, that outgoing
+    /// multicast packets are sent from. An index of 0 lets the system choose
+    /// an appropriate interface.
+    ///
+    /// Note that this might not have any effect on IPv4 sockets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_multicast_options)]
+    ///
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("[::1]:34254").expect("couldn't bind to address");
+    /// socket.set_multicast_if_v6(0).expect("set_multicast_if_v6 call failed");
+    /// ```
+    #[unstable(feature = "udp_multicast_options", issue = "none")]
+    pub fn set_multicast_if_v6(&self, interface: u32) -> io::Result<()> {
+        self.0.set_multicast_if_v6(interface)
+    }
+
+    /// Gets the value of the `IPV6_MULTICAST_IF` option for this socket.
+    ///
+    /// For more information about this option, see [`UdpSocket::set_multicast_if_v6`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_multicast_options)]
+    ///
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("[::1]:34254").expect("couldn't bind to address");
+    /// socket.set_multicast_if_v6(0).expect("set_multicast_if_v6 call failed");
+    /// assert_eq!(socket.multicast_if_v6().unwrap(), 0);
+    /// ```
+    #[unstable(feature = "udp_multicast_options", issue = "none")]
+    pub fn multicast_if_v6(&self) -> io::Result<u32> {
+        self.0.multicast_if_v6()
+    }
+
     /// Sets the value for the `IP_TTL` option on this socket.
     ///
     /// This value sets the time-to-live field that is used in every packet sent
@@ -733,6 +862,71 @@ pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.peek(buf)
     }
 
+    /// Like [`send`], except that it sends a single datagram gathered from
+    /// a slice of buffers.
+    ///
+    /// The buffers are concatenated in order to form the datagram, so no
+    /// intermediate copy into a single buffer is needed.
+    ///
+    /// [`UdpSocket::connect`] will connect this socket to a remote address. This
+    /// method will fail if the socket is not connected.
+    ///
+    /// [`send`]: UdpSocket::send
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_vectored)]
+    ///
+    /// use std::io::IoSlice;
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.connect("127.0.0.1:8080").expect("connect function failed");
+    /// let header = [0, 1];
+    /// let payload = [2, 3, 4];
+    /// socket.send_vectored(&[IoSlice::new(&header), IoSlice::new(&payload)])
+    ///       .expect("couldn't send message");
+    /// ```
+    #[unstable(feature = "udp_vectored", issue = "none")]
+    pub fn send_vectored(&self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.send_vectored(bufs)
+    }
+
+    /// Like [`recv`], except that it scatters a single received datagram
+    /// into a slice of buffers.
+    ///
+    /// The buffers are filled in order. If the datagram is longer than the
+    /// combined length of the buffers, excess bytes may be discarded.
+    ///
+    /// [`UdpSocket::connect`] will connect this socket to a remote address. This
+    /// method will fail if the socket is not connected.
+    ///
+    /// [`recv`]: UdpSocket::recv
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_vectored)]
+    ///
+    /// use std::io::IoSliceMut;
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.connect("127.0.0.1:8080").expect("connect function failed");
+    /// let mut header = [0; 2];
+    /// let mut payload = [0; 512];
+    /// let mut bufs = [IoSliceMut::new(&mut header), IoSliceMut::new(&mut payload)];
+    /// match socket.recv_vectored(&mut bufs) {
+    ///     Ok(received) => println!("received {} bytes", received),
+    ///     Err(e) => println!("recv_vectored function failed: {:?}", e),
+    /// }
+    /// ```
+    #[unstable(feature = "udp_vectored", issue = "none")]
+    pub fn recv_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.recv_vectored(bufs)
+    }
+
     /// Moves this UDP socket into or out of nonblocking mode.
     ///
     /// This will result in `recv`, `recv_from`, `send`, and `send_to`
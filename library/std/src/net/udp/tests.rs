@@ -1,4 +1,4 @@
-use crate::io::ErrorKind;
+use crate::io::{ErrorKind, IoSlice, IoSliceMut};
 use crate::net::test::{next_test_ip4, next_test_ip6};
 use crate::net::*;
 use crate::sync::mpsc::channel;
@@ -310,6 +310,24 @@ fn connect_send_peek_recv() {
     })
 }
 
+#[test]
+fn connect_send_recv_vectored() {
+    each_ip(&mut |addr, _| {
+        let socket = t!(UdpSocket::bind(&addr));
+        t!(socket.connect(addr));
+
+        t!(socket.send_vectored(&[IoSlice::new(b"hello "), IoSlice::new(b"world")]));
+
+        let mut a = [0; 4];
+        let mut b = [0; 7];
+        let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+        let size = t!(socket.recv_vectored(&mut bufs));
+        assert_eq!(size, 11);
+        assert_eq!(b"hell", &a[..]);
+        assert_eq!(b"o world", &b[..]);
+    })
+}
+
 #[test]
 fn peek_from() {
     each_ip(&mut |addr, _| {
@@ -342,6 +360,19 @@ fn ttl() {
     assert_eq!(ttl, t!(stream.ttl()));
 }
 
+#[test]
+fn multicast_options() {
+    let socket = t!(UdpSocket::bind(&next_test_ip4()));
+    t!(socket.set_multicast_if_v4(&Ipv4Addr::UNSPECIFIED));
+    assert_eq!(Ipv4Addr::UNSPECIFIED, t!(socket.multicast_if_v4()));
+
+    let socket = t!(UdpSocket::bind(&next_test_ip6()));
+    t!(socket.set_multicast_hops_v6(42));
+    assert_eq!(42, t!(socket.multicast_hops_v6()));
+    t!(socket.set_multicast_if_v6(0));
+    assert_eq!(0, t!(socket.multicast_if_v6()));
+}
+
 #[test]
 fn set_nonblocking() {
     each_ip(&mut |addr, _| {
@@ -6,8 +6,11 @@
 use super::raw::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
 use crate::fmt;
 use crate::fs;
+use crate::io;
 use crate::marker::PhantomData;
 use crate::mem::forget;
+#[cfg(not(target_os = "wasi"))]
+use crate::sys::cvt;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 
 /// A borrowed file descriptor.
@@ -65,6 +68,62 @@ pub unsafe fn borrow_raw_fd(fd: RawFd) -> Self {
         // SAFETY: we just asserted that the value is in the valid range and isn't `-1` (the only value bigger than `0xFF_FF_FF_FE` unsigned)
         unsafe { Self { fd, _phantom: PhantomData } }
     }
+
+    /// Creates a new `OwnedFd` instance that shares the same underlying file
+    /// description as the existing `BorrowedFd` instance.
+    ///
+    /// The new file descriptor has the close-on-exec flag set and is never
+    /// one of the standard descriptors `0`, `1` or `2`.
+    #[cfg(not(target_os = "wasi"))]
+    #[unstable(feature = "io_safety", issue = "87074")]
+    pub fn try_clone_to_owned(&self) -> io::Result<OwnedFd> {
+        // We want to atomically duplicate this file descriptor and set the
+        // CLOEXEC flag, and currently that's done via F_DUPFD_CLOEXEC. ESP-IDF
+        // has no CLOEXEC semantics, so it only gets F_DUPFD.
+        #[cfg(not(target_os = "espidf"))]
+        let cmd = libc::F_DUPFD_CLOEXEC;
+        #[cfg(target_os = "espidf")]
+        let cmd = libc::F_DUPFD;
+
+        let fd = cvt(unsafe { libc::fcntl(self.as_raw_fd(), cmd, 3) })?;
+        Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+    }
+
+    /// Creates a new `OwnedFd` instance that shares the same underlying file
+    /// description as the existing `BorrowedFd` instance.
+    ///
+    /// WASI has no way to duplicate a file descriptor, so this always fails.
+    #[cfg(target_os = "wasi")]
+    #[unstable(feature = "io_safety", issue = "87074")]
+    pub fn try_clone_to_owned(&self) -> io::Result<OwnedFd> {
+        Err(io::Error::new_const(
+            io::ErrorKind::Unsupported,
+            &"operation not supported on WASI yet",
+        ))
+    }
+}
+
+impl OwnedFd {
+    /// Creates a new `OwnedFd` instance that shares the same underlying file
+    /// description as the existing `OwnedFd` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(io_safety)]
+    /// # #[cfg(unix)] {
+    /// use std::fs::File;
+    /// use std::os::unix::io::OwnedFd;
+    ///
+    /// let fd = OwnedFd::from(File::open("foo.txt")?);
+    /// let clone = fd.try_clone()?;
+    /// # }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[unstable(feature = "io_safety", issue = "87074")]
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.as_fd().try_clone_to_owned()
+    }
 }
 
 #[unstable(feature = "io_safety", issue = "87074")]
@@ -287,3 +346,43 @@ fn from(owned_fd: OwnedFd) -> Self {
         ))))
     }
 }
+
+#[unstable(feature = "io_safety", issue = "87074")]
+impl<T: AsFd + ?Sized> AsFd for &T {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        T::as_fd(self)
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "87074")]
+impl<T: AsFd + ?Sized> AsFd for &mut T {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        T::as_fd(self)
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "87074")]
+impl<T: AsFd + ?Sized> AsFd for Box<T> {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        (**self).as_fd()
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "87074")]
+impl<T: AsFd + ?Sized> AsFd for crate::rc::Rc<T> {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        (**self).as_fd()
+    }
+}
+
+#[unstable(feature = "io_safety", issue = "87074")]
+impl<T: AsFd + ?Sized> AsFd for crate::sync::Arc<T> {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        (**self).as_fd()
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(unix)]
+#[test]
+fn test_try_clone() {
+    use crate::fs::File;
+    use crate::os::unix::io::{AsFd, AsRawFd, OwnedFd};
+
+    let fd = OwnedFd::from(File::open("/dev/null").unwrap());
+    let clone = fd.try_clone().unwrap();
+    assert_ne!(fd.as_raw_fd(), clone.as_raw_fd());
+    assert!(clone.as_raw_fd() > 2);
+    assert!(clone.as_fd().try_clone_to_owned().is_ok());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_as_fd_through_pointers() {
+    use crate::fs::File;
+    use crate::os::unix::io::{AsFd, AsRawFd};
+    use crate::sync::Arc;
+
+    fn raw(fd: impl AsFd) -> i32 {
+        fd.as_fd().as_raw_fd()
+    }
+
+    let mut file = File::open("/dev/null").unwrap();
+    let expected = file.as_raw_fd();
+    assert_eq!(raw(&file), expected);
+    assert_eq!(raw(&mut file), expected);
+    let file = Box::new(file);
+    assert_eq!(raw(&file), expected);
+    let file = Arc::new(file);
+    assert_eq!(raw(file.clone()), expected);
+}
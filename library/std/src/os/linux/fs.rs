@@ -6,6 +6,8 @@
 
 use crate::fs::Metadata;
 use crate::sys_common::AsInner;
+#[cfg(any(target_os = "linux", doc))]
+use crate::{fs::OpenOptions, sealed::Sealed, sys_common::AsInnerMut};
 
 #[allow(deprecated)]
 use crate::os::linux::raw;
@@ -380,3 +382,51 @@ fn st_blocks(&self) -> u64 {
         self.as_inner().as_inner().st_blocks as u64
     }
 }
+
+/// Linux-specific extensions to [`fs::OpenOptions`].
+///
+/// [`fs::OpenOptions`]: crate::fs::OpenOptions
+#[cfg(any(target_os = "linux", doc))]
+#[unstable(feature = "open_options_tmpfile", issue = "none")]
+pub trait OpenOptionsExt: Sealed {
+    /// Sets the option to create an unnamed temporary file.
+    ///
+    /// When this is set, the path given to [`OpenOptions::open`] must name a
+    /// directory, and the file is created in that directory without a name
+    /// (`O_TMPFILE`). Write access is required, and [`create`] and [`truncate`]
+    /// have no effect. The file is deleted once its last descriptor is closed,
+    /// unless it is first given a name with `linkat`; setting [`create_new`]
+    /// as well forbids that.
+    ///
+    /// Opening fails if the filesystem does not support `O_TMPFILE`.
+    ///
+    /// [`create`]: OpenOptions::create
+    /// [`truncate`]: OpenOptions::truncate
+    /// [`create_new`]: OpenOptions::create_new
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(open_options_tmpfile)]
+    /// use std::fs::OpenOptions;
+    /// use std::io::Write;
+    /// use std::os::linux::fs::OpenOptionsExt;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut scratch = OpenOptions::new().read(true).write(true).tmpfile(true).open("/tmp")?;
+    ///     scratch.write_all(b"never visible to other processes")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "open_options_tmpfile", issue = "none")]
+    fn tmpfile(&mut self, tmpfile: bool) -> &mut Self;
+}
+
+#[cfg(any(target_os = "linux", doc))]
+#[unstable(feature = "open_options_tmpfile", issue = "none")]
+impl OpenOptionsExt for OpenOptions {
+    fn tmpfile(&mut self, tmpfile: bool) -> &mut OpenOptions {
+        self.as_inner_mut().tmpfile(tmpfile);
+        self
+    }
+}
@@ -334,6 +334,13 @@ pub trait OpenOptionsExt {
     /// Custom flags can only set flags, not remove flags set by Rusts options.
     /// This options overwrites any previously set custom flags.
     ///
+    /// The remaining bits are passed to `open` unchanged, so their meaning is
+    /// platform-specific. For example, `O_DIRECT` exists on Linux, Android and
+    /// FreeBSD but not on macOS, where `fcntl` with `F_NOCACHE` after opening is
+    /// the closest equivalent; files opened with `O_DIRECT` usually require the
+    /// buffers, offsets and lengths of reads and writes to be aligned to the
+    /// filesystem's block size. `O_CLOEXEC` is always set by Rust.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -734,7 +741,7 @@ pub trait FileTypeExt {
     /// fn main() -> io::Result<()> {
     ///     let meta = fs::metadata("block_device_file")?;
     ///     let file_type = meta.file_type();
-    ///     assert!(file_type.is_block_device());
+    ///     assert!(FileTypeExt::is_block_device(&file_type));
     ///     Ok(())
     /// }
     /// ```
@@ -752,7 +759,7 @@ pub trait FileTypeExt {
     /// fn main() -> io::Result<()> {
     ///     let meta = fs::metadata("char_device_file")?;
     ///     let file_type = meta.file_type();
-    ///     assert!(file_type.is_char_device());
+    ///     assert!(FileTypeExt::is_char_device(&file_type));
     ///     Ok(())
     /// }
     /// ```
@@ -770,7 +777,7 @@ pub trait FileTypeExt {
     /// fn main() -> io::Result<()> {
     ///     let meta = fs::metadata("fifo_file")?;
     ///     let file_type = meta.file_type();
-    ///     assert!(file_type.is_fifo());
+    ///     assert!(FileTypeExt::is_fifo(&file_type));
     ///     Ok(())
     /// }
     /// ```
@@ -788,7 +795,7 @@ pub trait FileTypeExt {
     /// fn main() -> io::Result<()> {
     ///     let meta = fs::metadata("unix.socket")?;
     ///     let file_type = meta.file_type();
-    ///     assert!(file_type.is_socket());
+    ///     assert!(FileTypeExt::is_socket(&file_type));
     ///     Ok(())
     /// }
     /// ```
@@ -127,6 +127,51 @@ pub(super) fn from_parts(
         Ok(SocketAddr { addr, len })
     }
 
+    /// Constructs a `SocketAddr` with the family `AF_UNIX` and the provided path.
+    ///
+    /// Together with [`UnixListener::bind_addr`], [`UnixStream::connect_addr`]
+    /// and the other `*_addr` methods, this allows pathname and
+    /// [abstract](SocketAddr::from_abstract_namespace) addresses to be handled
+    /// through the same code.
+    ///
+    /// [`UnixListener::bind_addr`]: crate::os::unix::net::UnixListener::bind_addr
+    /// [`UnixStream::connect_addr`]: crate::os::unix::net::UnixStream::connect_addr
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is longer than `SUN_LEN` or if it contains
+    /// NULL bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unix_socket_creation)]
+    /// use std::os::unix::net::SocketAddr;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let address = SocketAddr::from_pathname("/path/to/socket")?;
+    /// assert_eq!(address.as_pathname(), Some(Path::new("/path/to/socket")));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Creating a `SocketAddr` with a NULL byte results in an error.
+    ///
+    /// ```
+    /// #![feature(unix_socket_creation)]
+    /// use std::os::unix::net::SocketAddr;
+    ///
+    /// assert!(SocketAddr::from_pathname("/path/with/\0/bytes").is_err());
+    /// ```
+    #[unstable(feature = "unix_socket_creation", issue = "none")]
+    pub fn from_pathname<P>(path: P) -> io::Result<SocketAddr>
+    where
+        P: AsRef<Path>,
+    {
+        unsafe { sockaddr_un(path.as_ref()).map(|(addr, len)| SocketAddr { addr, len }) }
+    }
+
     /// Returns `true` if the address is unnamed.
     ///
     /// # Examples
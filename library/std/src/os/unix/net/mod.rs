@@ -25,6 +25,7 @@
 mod ancillary;
 mod datagram;
 mod listener;
+mod socket;
 mod stream;
 #[cfg(all(test, not(target_os = "emscripten")))]
 mod tests;
@@ -47,5 +48,7 @@
 pub use self::datagram::*;
 #[stable(feature = "unix_socket", since = "1.10.0")]
 pub use self::listener::*;
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+pub use self::socket::*;
 #[stable(feature = "unix_socket", since = "1.10.0")]
 pub use self::stream::*;
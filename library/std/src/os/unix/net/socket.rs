@@ -0,0 +1,350 @@
+use crate::fmt;
+use crate::io::{self, IoSlice, IoSliceMut, Read, Write};
+use crate::net::SocketAddr;
+use crate::os::raw::c_int;
+use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+use crate::sys::net::Socket as SysSocket;
+use crate::sys::{cvt, cvt_r};
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::time::Duration;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "haiku"
+))]
+use libc::MSG_NOSIGNAL;
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "haiku"
+)))]
+const MSG_NOSIGNAL: libc::c_int = 0x0;
+
+/// A socket of any address family, type and protocol.
+///
+/// The standard library only creates the sockets it has dedicated types for,
+/// such as [`TcpStream`] and [`UdpSocket`]. `Socket` gives access to the
+/// others, such as `SOCK_RAW` sockets for ICMP or `SOCK_SEQPACKET` sockets for
+/// SCTP, while reusing the standard library's handling of timeouts and I/O.
+///
+/// Like the other socket types, a `Socket` is created with the close-on-exec
+/// flag set and owns its file descriptor. It converts to and from an
+/// [`OwnedFd`], which in turn converts into the standard socket types when the
+/// socket is of an appropriate kind.
+///
+/// The address family, type and protocol are the values expected by the
+/// `socket(2)` system call, for instance as found in the `libc` crate.
+///
+/// [`TcpStream`]: crate::net::TcpStream
+/// [`UdpSocket`]: crate::net::UdpSocket
+///
+/// # Examples
+///
+/// Sending an ICMP echo request from an unprivileged "ping" socket on Linux:
+///
+/// ```no_run
+/// #![feature(unix_socket_generic)]
+/// use std::os::unix::net::Socket;
+/// use std::time::Duration;
+///
+/// // Values from `<sys/socket.h>` and `<netinet/in.h>` on Linux.
+/// const AF_INET: i32 = 2;
+/// const SOCK_DGRAM: i32 = 2;
+/// const IPPROTO_ICMP: i32 = 1;
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = Socket::new(AF_INET, SOCK_DGRAM, IPPROTO_ICMP)?;
+///     socket.set_read_timeout(Some(Duration::from_secs(1)))?;
+///
+///     // Type 8 (echo request), code 0, the kernel fills in checksum and identifier.
+///     let request = [8, 0, 0, 0, 0, 0, 0, 1];
+///     socket.send_to(&request, &"127.0.0.1:0".parse().unwrap())?;
+///
+///     let mut reply = [0; 64];
+///     let (len, from) = socket.recv_from(&mut reply)?;
+///     println!("{} bytes from {}", len, from.ip());
+///     Ok(())
+/// }
+/// ```
+///
+/// Converting a socket into a standard socket type:
+///
+/// ```no_run
+/// #![feature(io_safety, unix_socket_generic)]
+/// use std::net::UdpSocket;
+/// use std::os::unix::io::OwnedFd;
+/// use std::os::unix::net::Socket;
+///
+/// const AF_INET6: i32 = 10;
+/// const SOCK_DGRAM: i32 = 2;
+/// const IPPROTO_UDPLITE: i32 = 136;
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = Socket::new(AF_INET6, SOCK_DGRAM, IPPROTO_UDPLITE)?;
+///     socket.bind(&"[::1]:0".parse().unwrap())?;
+///     let socket = UdpSocket::from(OwnedFd::from(socket));
+///     println!("bound to {}", socket.local_addr()?);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+pub struct Socket(SysSocket);
+
+impl Socket {
+    /// Creates a new socket with the given address family, type and protocol.
+    ///
+    /// This corresponds to the `socket(2)` system call. A `protocol` of `0`
+    /// selects the default protocol for the family and type.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn new(domain: c_int, ty: c_int, protocol: c_int) -> io::Result<Socket> {
+        SysSocket::new_with_protocol(domain, ty, protocol).map(Socket)
+    }
+
+    /// Binds the socket to the given IPv4 or IPv6 address.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn bind(&self, addr: &SocketAddr) -> io::Result<()> {
+        let (addrp, len) = addr.into_inner();
+        cvt(unsafe { libc::bind(self.0.as_raw(), addrp, len) })?;
+        Ok(())
+    }
+
+    /// Connects the socket to the given IPv4 or IPv6 address.
+    ///
+    /// For connectionless sockets this sets the default destination of
+    /// [`send`] and limits [`recv`] to data coming from that address.
+    ///
+    /// [`send`]: Socket::send
+    /// [`recv`]: Socket::recv
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn connect(&self, addr: &SocketAddr) -> io::Result<()> {
+        let (addrp, len) = addr.into_inner();
+        cvt_r(|| unsafe { libc::connect(self.0.as_raw(), addrp, len) })?;
+        Ok(())
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<Socket> {
+        self.0.duplicate().map(Socket)
+    }
+
+    /// Receives data from the socket, returning the number of bytes read.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    /// Receives data from the socket, returning the number of bytes read and
+    /// the address it came from.
+    ///
+    /// An error of kind [`InvalidInput`] is returned if the sender's address
+    /// is neither an IPv4 nor an IPv6 address.
+    ///
+    /// [`InvalidInput`]: io::ErrorKind::InvalidInput
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        self.0.recv_from(buf)
+    }
+
+    /// Sends data on the connected socket, returning the number of bytes
+    /// written.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    /// Sends data to the given address, returning the number of bytes written.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn send_to(&self, buf: &[u8], addr: &SocketAddr) -> io::Result<usize> {
+        let (addrp, len) = addr.into_inner();
+        let count = cvt(unsafe {
+            libc::sendto(
+                self.0.as_raw(),
+                buf.as_ptr() as *const _,
+                buf.len(),
+                MSG_NOSIGNAL,
+                addrp,
+                len,
+            )
+        })?;
+        Ok(count as usize)
+    }
+
+    /// Sets the read timeout of the socket.
+    ///
+    /// If the provided value is [`None`], reads block indefinitely. An
+    /// [`Err`] is returned if the zero [`Duration`] is passed.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_RCVTIMEO)
+    }
+
+    /// Sets the write timeout of the socket.
+    ///
+    /// If the provided value is [`None`], writes block indefinitely. An
+    /// [`Err`] is returned if the zero [`Duration`] is passed.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_SNDTIMEO)
+    }
+
+    /// Returns the read timeout of the socket.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn read_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_RCVTIMEO)
+    }
+
+    /// Returns the write timeout of the socket.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_SNDTIMEO)
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option, clearing it.
+    #[unstable(feature = "unix_socket_generic", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+}
+
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+impl fmt::Debug for Socket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Socket").field("fd", &self.0.as_raw()).finish()
+    }
+}
+
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+impl Read for Socket {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        io::Read::read(&mut &*self, buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        io::Read::read_vectored(&mut &*self, bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+}
+
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+impl<'a> Read for &'a Socket {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+}
+
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+impl Write for Socket {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        io::Write::write(&mut &*self, buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        io::Write::write_vectored(&mut &*self, bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+impl<'a> Write for &'a Socket {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+impl AsRawFd for Socket {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_inner().as_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+impl FromRawFd for Socket {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> Socket {
+        Socket(SysSocket::from_inner(FromInner::from_inner(OwnedFd::from_raw_fd(fd))))
+    }
+}
+
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+impl IntoRawFd for Socket {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_inner().into_inner().into_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+impl AsFd for Socket {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_inner().as_fd()
+    }
+}
+
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+impl From<Socket> for OwnedFd {
+    #[inline]
+    fn from(socket: Socket) -> OwnedFd {
+        socket.0.into_inner().into_inner()
+    }
+}
+
+#[unstable(feature = "unix_socket_generic", issue = "none")]
+impl From<OwnedFd> for Socket {
+    #[inline]
+    fn from(owned: OwnedFd) -> Self {
+        Socket(SysSocket::from_inner(FromInner::from_inner(owned)))
+    }
+}
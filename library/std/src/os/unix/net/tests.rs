@@ -152,6 +152,34 @@ fn iter() {
     thread.join().unwrap();
 }
 
+#[test]
+fn pathname_addr() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("sock");
+    let addr = or_panic!(SocketAddr::from_pathname(&socket_path));
+    assert_eq!(addr.as_pathname(), Some(&*socket_path));
+
+    let listener = or_panic!(UnixListener::bind_addr(&addr));
+    let thread = thread::spawn(move || {
+        let mut stream = or_panic!(listener.accept()).0;
+        or_panic!(stream.write_all(b"hello"));
+    });
+
+    let mut stream = or_panic!(UnixStream::connect_addr(&addr));
+    assert_eq!(Some(&*socket_path), stream.peer_addr().unwrap().as_pathname());
+    let mut buf = vec![];
+    or_panic!(stream.read_to_end(&mut buf));
+    assert_eq!(b"hello", &buf[..]);
+
+    thread.join().unwrap();
+
+    match SocketAddr::from_pathname("foo\0bar") {
+        Err(ref e) if e.kind() == io::ErrorKind::InvalidInput => {}
+        Err(e) => panic!("unexpected error {}", e),
+        Ok(_) => panic!("unexpected success"),
+    }
+}
+
 #[test]
 fn long_path() {
     let dir = tmpdir();
@@ -785,3 +813,34 @@ fn test_send_vectored_with_ancillary_unix_datagram() {
         unreachable!("must be ScmRights");
     }
 }
+
+#[test]
+fn socket_udp() {
+    use crate::net::UdpSocket;
+    use crate::os::unix::io::OwnedFd;
+
+    let timeout = Some(Duration::from_millis(1000));
+    let socket = or_panic!(Socket::new(libc::AF_INET, libc::SOCK_DGRAM, libc::IPPROTO_UDP));
+    or_panic!(socket.bind(&"127.0.0.1:0".parse().unwrap()));
+    or_panic!(socket.set_read_timeout(timeout));
+    assert_eq!(or_panic!(socket.read_timeout()), timeout);
+
+    // The socket keeps its options when converted into a `UdpSocket`.
+    let socket = UdpSocket::from(OwnedFd::from(socket));
+    assert_eq!(or_panic!(socket.read_timeout()), timeout);
+    let addr = or_panic!(socket.local_addr());
+
+    let client = or_panic!(Socket::new(libc::AF_INET, libc::SOCK_DGRAM, 0));
+    or_panic!(client.set_read_timeout(timeout));
+    or_panic!(client.connect(&addr));
+    assert_eq!(or_panic!(client.send(b"hello")), 5);
+
+    let mut buf = [0; 8];
+    let (len, from) = or_panic!(socket.recv_from(&mut buf));
+    assert_eq!(&buf[..len], b"hello");
+    assert_eq!(or_panic!(socket.send_to(b"world", from)), 5);
+
+    let mut buf = [0; 8];
+    let len = or_panic!((&client).read(&mut buf));
+    assert_eq!(&buf[..len], b"world");
+}
@@ -145,10 +145,162 @@ fn before_exec<F>(&mut self, f: F) -> &mut process::Command
     ///
     /// Set the first process argument, `argv[0]`, to something other than the
     /// default executable path.
+    ///
+    /// This is what multi-call binaries, which pick their behavior based on
+    /// the name they were invoked under, look at. The program that is run is
+    /// still the one given to [`Command::new`].
+    ///
+    /// [`Command::new`]: process::Command::new
+    ///
+    /// # Examples
+    ///
+    /// Re-running the current executable under a different name:
+    ///
+    /// ```no_run
+    /// use std::env;
+    /// use std::os::unix::process::CommandExt;
+    /// use std::process::Command;
+    ///
+    /// let status = Command::new(env::current_exe()?).arg0("ls").arg("-l").status()?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
     #[stable(feature = "process_set_argv0", since = "1.45.0")]
     fn arg0<S>(&mut self, arg: S) -> &mut process::Command
     where
         S: AsRef<OsStr>;
+
+    /// Sets the process group ID (PGID) of the child process. Equivalent to a
+    /// `setpgid` call in the child process, but may be more efficient.
+    ///
+    /// Process groups determine which processes receive signals.
+    ///
+    /// A `pgroup` of 0 puts the child into a new process group whose ID is
+    /// the child's process ID. Since [`Child::kill`] only signals the child
+    /// itself, the whole group, including any processes the child spawned
+    /// that stayed in it, can then be signalled with `kill` and a negated
+    /// process group ID (the `killpg` function in C).
+    ///
+    /// [`Child::kill`]: process::Child::kill
+    ///
+    /// # Examples
+    ///
+    /// Pressing Ctrl-C in a terminal will send SIGINT to all processes in
+    /// the current foreground process group. By spawning the `sleep`
+    /// subprocess in a new process group, it will not receive SIGINT from the
+    /// terminal.
+    ///
+    /// The parent process could install a signal handler and manage the
+    /// subprocess on its own terms.
+    ///
+    /// ```no_run
+    /// #![feature(process_set_process_group)]
+    ///
+    /// use std::process::Command;
+    /// use std::os::unix::process::CommandExt;
+    ///
+    /// Command::new("sleep")
+    ///     .arg("10")
+    ///     .process_group(0)
+    ///     .spawn()?
+    ///     .wait()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[unstable(feature = "process_set_process_group", issue = "none")]
+    fn process_group(&mut self, pgroup: i32) -> &mut process::Command;
+
+    /// Makes `fd` available in the child process as descriptor number
+    /// `child_fd`.
+    ///
+    /// This is the safe replacement for calling `dup2` from a [`pre_exec`]
+    /// closure, for example to pass a listening socket to a re-executed
+    /// server. The descriptor is applied after stdin, stdout and stderr have
+    /// been set up, so mapping one of `0`, `1` or `2` overrides the
+    /// corresponding [`Stdio`](process::Stdio) configuration. Mapping the same
+    /// `child_fd` twice replaces the earlier mapping.
+    ///
+    /// The `Command` keeps `fd` open for as long as it lives, so the same
+    /// mapping is applied to every child it spawns. In the parent the
+    /// descriptor stays close-on-exec, so it does not leak into children of
+    /// other `Command`s.
+    ///
+    /// If [`exec`](CommandExt::exec) fails after the mappings were applied,
+    /// the target descriptors of the current process have already been
+    /// replaced.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// Spawning returns an [`Unsupported`](io::ErrorKind::Unsupported) error
+    /// on Fuchsia and VxWorks if any descriptors are mapped.
+    ///
+    /// [`pre_exec`]: CommandExt::pre_exec
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(command_map_fd, io_safety)]
+    /// use std::net::TcpListener;
+    /// use std::os::unix::io::OwnedFd;
+    /// use std::os::unix::process::CommandExt;
+    /// use std::process::Command;
+    ///
+    /// let listener = TcpListener::bind("127.0.0.1:8080")?;
+    ///
+    /// // The new server expects to find its listening socket at fd 3.
+    /// Command::new("/usr/local/bin/server")
+    ///     .map_fd(3, OwnedFd::from(listener))
+    ///     .spawn()?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[unstable(feature = "command_map_fd", issue = "none")]
+    fn map_fd(&mut self, child_fd: RawFd, fd: OwnedFd) -> &mut process::Command;
+
+    /// Sets whether the child process only inherits stdin, stdout, stderr and
+    /// the descriptors added with [`map_fd`].
+    ///
+    /// By default a child inherits every descriptor of the parent that does
+    /// not have close-on-exec set. The standard library opens all of its
+    /// descriptors with close-on-exec, but other code in the process may not,
+    /// and such a descriptor then silently leaks into every child. When
+    /// `close` is `true`, all other descriptors are closed when the child
+    /// executes the new program, making the set of inherited descriptors
+    /// explicit.
+    ///
+    /// Descriptors opened by [`pre_exec`] closures are not affected.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Linux 5.11 and later this uses a single `close_range` call. Elsewhere
+    /// every descriptor number below the `RLIMIT_NOFILE` limit is visited,
+    /// which can be slow when the limit is high, and spawning fails if there
+    /// is no limit.
+    ///
+    /// Spawning returns an [`Unsupported`](io::ErrorKind::Unsupported) error
+    /// on Fuchsia and VxWorks if `close` is `true`.
+    ///
+    /// [`map_fd`]: CommandExt::map_fd
+    /// [`pre_exec`]: CommandExt::pre_exec
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(command_close_fds, command_map_fd, io_safety)]
+    /// use std::net::TcpListener;
+    /// use std::os::unix::io::OwnedFd;
+    /// use std::os::unix::process::CommandExt;
+    /// use std::process::Command;
+    ///
+    /// let listener = TcpListener::bind("127.0.0.1:8080")?;
+    ///
+    /// // The worker gets its stdio and the listening socket at fd 3, nothing else.
+    /// Command::new("/usr/local/bin/worker")
+    ///     .map_fd(3, OwnedFd::from(listener))
+    ///     .close_fds(true)
+    ///     .spawn()?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[unstable(feature = "command_close_fds", issue = "none")]
+    fn close_fds(&mut self, close: bool) -> &mut process::Command;
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -201,6 +353,21 @@ fn arg0<S>(&mut self, arg: S) -> &mut process::Command
         self.as_inner_mut().set_arg_0(arg.as_ref());
         self
     }
+
+    fn process_group(&mut self, pgroup: i32) -> &mut process::Command {
+        self.as_inner_mut().pgroup(pgroup);
+        self
+    }
+
+    fn map_fd(&mut self, child_fd: RawFd, fd: OwnedFd) -> &mut process::Command {
+        self.as_inner_mut().map_fd(child_fd, fd);
+        self
+    }
+
+    fn close_fds(&mut self, close: bool) -> &mut process::Command {
+        self.as_inner_mut().close_fds(close);
+        self
+    }
 }
 
 /// Unix-specific extensions to [`process::ExitStatus`] and
@@ -435,6 +602,44 @@ fn from(child_stderr: crate::process::ChildStderr) -> OwnedFd {
     }
 }
 
+macro_rules! impl_pipe_fd_traits {
+    ($($t:ty),*) => {$(
+        #[unstable(feature = "anonymous_pipe", issue = "none")]
+        impl AsRawFd for $t {
+            #[inline]
+            fn as_raw_fd(&self) -> RawFd {
+                self.as_inner().as_raw_fd()
+            }
+        }
+
+        #[unstable(feature = "anonymous_pipe", issue = "none")]
+        impl IntoRawFd for $t {
+            #[inline]
+            fn into_raw_fd(self) -> RawFd {
+                self.into_inner().into_raw_fd()
+            }
+        }
+
+        #[unstable(feature = "anonymous_pipe", issue = "none")]
+        impl AsFd for $t {
+            #[inline]
+            fn as_fd(&self) -> BorrowedFd<'_> {
+                self.as_inner().as_fd()
+            }
+        }
+
+        #[unstable(feature = "anonymous_pipe", issue = "none")]
+        impl From<$t> for OwnedFd {
+            #[inline]
+            fn from(pipe: $t) -> OwnedFd {
+                pipe.into_inner().into_inner().into_inner()
+            }
+        }
+    )*};
+}
+
+impl_pipe_fd_traits!(io::PipeReader, io::PipeWriter);
+
 /// Returns the OS-assigned process identifier associated with this process's parent.
 #[must_use]
 #[stable(feature = "unix_ppid", since = "1.27.0")]
@@ -223,7 +223,6 @@ fn write_all_at(&self, mut buf: &[u8], mut offset: u64) -> io::Result<()> {
 
 // FIXME: bind fd_fdstat_get - need to define a custom return type
 // FIXME: bind fd_readdir - can't return `ReadDir` since we only have entry name
-// FIXME: bind fd_filestat_set_times maybe? - on crates.io for unix
 // FIXME: bind path_filestat_set_times maybe? - on crates.io for unix
 // FIXME: bind poll_oneoff maybe? - probably should wait for I/O to settle
 // FIXME: bind random_get maybe? - on crates.io for unix
@@ -0,0 +1,36 @@
+//! Windows-specific extensions to the primitives in the [`std::env`] module.
+//!
+//! [`std::env`]: crate::env
+
+#![unstable(feature = "windows_command_line", issue = "none")]
+
+use crate::ffi::OsString;
+use crate::sys;
+
+/// Returns the command line of the current process, exactly as it was passed
+/// to the process and before it is split into arguments.
+///
+/// On Windows a process receives its command line as a single string, and it
+/// is up to the program to split it. [`env::args_os`] splits it following the
+/// conventions of the Microsoft C runtime; programs that implement other
+/// conventions, such as those of `cmd.exe` built-ins or response files with
+/// their own quoting, can use this string instead. The string includes the
+/// program name as it was written by the caller, which is not necessarily the
+/// path of the executable.
+///
+/// This corresponds to the `GetCommandLineW` function.
+///
+/// [`env::args_os`]: crate::env::args_os
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(windows_command_line)]
+/// use std::os::windows::env::command_line;
+///
+/// println!("started with {:?}", command_line());
+/// ```
+#[must_use]
+pub fn command_line() -> OsString {
+    sys::args::command_line()
+}
@@ -7,8 +7,10 @@
 use crate::fs::{self, Metadata, OpenOptions};
 use crate::io;
 use crate::path::Path;
+use crate::sealed::Sealed;
 use crate::sys;
-use crate::sys_common::{AsInner, AsInnerMut};
+use crate::sys_common::{AsInner, AsInnerMut, IntoInner};
+use crate::time::SystemTime;
 
 /// Windows-specific extensions to [`fs::File`].
 #[stable(feature = "file_offset", since = "1.15.0")]
@@ -155,6 +157,12 @@ pub trait OpenOptionsExt {
     /// Custom flags can only set flags, not remove flags set by Rust's options.
     /// This option overwrites any previously set custom flags.
     ///
+    /// The flags are passed to `CreateFileW` unchanged. For example,
+    /// `FILE_FLAG_NO_BUFFERING` requires the buffers, offsets and lengths of
+    /// reads and writes to be multiples of the volume's sector size, and
+    /// `FILE_FLAG_OPEN_REPARSE_POINT` is always added when
+    /// [`create_new`](crate::fs::OpenOptions::create_new) is set.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -519,6 +527,22 @@ fn is_symlink_file(&self) -> bool {
     }
 }
 
+/// Windows-specific extensions to [`fs::FileTimes`].
+#[unstable(feature = "file_set_times", issue = "none")]
+pub trait FileTimesExt: Sealed {
+    /// Set the creation time of a file.
+    #[unstable(feature = "file_set_times", issue = "none")]
+    fn set_created(self, t: SystemTime) -> Self;
+}
+
+#[unstable(feature = "file_set_times", issue = "none")]
+impl FileTimesExt for fs::FileTimes {
+    fn set_created(mut self, t: SystemTime) -> Self {
+        self.as_inner_mut().set_created(t.into_inner());
+        self
+    }
+}
+
 /// Creates a new symlink to a non-directory file on the filesystem.
 ///
 /// The `link` path will be a file symbolic link pointing to the `original`
@@ -6,9 +6,12 @@
 use crate::convert::TryFrom;
 use crate::fmt;
 use crate::fs;
+use crate::io;
 use crate::marker::PhantomData;
 use crate::mem::forget;
+use crate::ptr;
 use crate::sys::c;
+use crate::sys::cvt;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 
 /// A borrowed handle.
@@ -132,6 +135,38 @@ impl BorrowedHandle<'_> {
     pub unsafe fn borrow_raw_handle(handle: RawHandle) -> Self {
         Self { handle, _phantom: PhantomData }
     }
+
+    /// Creates a new `OwnedHandle` instance that refers to the same kernel
+    /// object as the existing `BorrowedHandle` instance, with the same
+    /// access rights.
+    ///
+    /// The new handle is not inheritable.
+    #[unstable(feature = "io_safety", issue = "87074")]
+    pub fn try_clone_to_owned(&self) -> io::Result<OwnedHandle> {
+        let mut ret = ptr::null_mut();
+        cvt(unsafe {
+            let cur_proc = c::GetCurrentProcess();
+            c::DuplicateHandle(
+                cur_proc,
+                self.as_raw_handle(),
+                cur_proc,
+                &mut ret,
+                0,
+                c::FALSE,
+                c::DUPLICATE_SAME_ACCESS,
+            )
+        })?;
+        Ok(unsafe { OwnedHandle::from_raw_handle(ret) })
+    }
+}
+
+impl OwnedHandle {
+    /// Creates a new `OwnedHandle` instance that refers to the same kernel
+    /// object as the existing `OwnedHandle` instance.
+    #[unstable(feature = "io_safety", issue = "87074")]
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.as_handle().try_clone_to_owned()
+    }
 }
 
 impl TryFrom<HandleOrNull> for OwnedHandle {
@@ -419,3 +454,76 @@ fn from(join_handle: crate::thread::JoinHandle<T>) -> OwnedHandle {
         join_handle.into_inner().into_handle().into_inner()
     }
 }
+
+macro_rules! impl_pipe_handle_traits {
+    ($($t:ty),*) => {$(
+        #[unstable(feature = "anonymous_pipe", issue = "none")]
+        impl AsRawHandle for $t {
+            #[inline]
+            fn as_raw_handle(&self) -> RawHandle {
+                self.as_inner().handle().as_raw_handle()
+            }
+        }
+
+        #[unstable(feature = "anonymous_pipe", issue = "none")]
+        impl IntoRawHandle for $t {
+            #[inline]
+            fn into_raw_handle(self) -> RawHandle {
+                self.into_inner().into_handle().into_raw_handle()
+            }
+        }
+
+        #[unstable(feature = "anonymous_pipe", issue = "none")]
+        impl AsHandle for $t {
+            #[inline]
+            fn as_handle(&self) -> BorrowedHandle<'_> {
+                self.as_inner().handle().as_handle()
+            }
+        }
+
+        #[unstable(feature = "anonymous_pipe", issue = "none")]
+        impl From<$t> for OwnedHandle {
+            #[inline]
+            fn from(pipe: $t) -> OwnedHandle {
+                pipe.into_inner().into_handle().into_inner()
+            }
+        }
+    )*};
+}
+
+impl_pipe_handle_traits!(crate::io::PipeReader, crate::io::PipeWriter);
+
+impl<T: AsHandle + ?Sized> AsHandle for &T {
+    #[inline]
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        T::as_handle(self)
+    }
+}
+
+impl<T: AsHandle + ?Sized> AsHandle for &mut T {
+    #[inline]
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        T::as_handle(self)
+    }
+}
+
+impl<T: AsHandle + ?Sized> AsHandle for Box<T> {
+    #[inline]
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        (**self).as_handle()
+    }
+}
+
+impl<T: AsHandle + ?Sized> AsHandle for crate::rc::Rc<T> {
+    #[inline]
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        (**self).as_handle()
+    }
+}
+
+impl<T: AsHandle + ?Sized> AsHandle for crate::sync::Arc<T> {
+    #[inline]
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        (**self).as_handle()
+    }
+}
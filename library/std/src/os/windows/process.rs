@@ -125,6 +125,29 @@ fn from_raw(raw: u32) -> Self {
     }
 }
 
+/// Windows-specific extensions to [`process::ExitCode`].
+///
+/// This trait is sealed: it cannot be implemented outside the standard library.
+/// This is so that future additional methods are not breaking changes.
+#[unstable(feature = "windows_process_exit_code_from", issue = "none")]
+pub trait ExitCodeExt: Sealed {
+    /// Creates a new `ExitCode` from the raw underlying `u32` return value of
+    /// a process.
+    ///
+    /// The exit code should not be 259, as this conflicts with the `STILL_ACTIVE`
+    /// macro returned from the `GetExitCodeProcess` function to signal that the
+    /// process has yet to run to completion.
+    #[unstable(feature = "windows_process_exit_code_from", issue = "none")]
+    fn from_raw(raw: u32) -> Self;
+}
+
+#[unstable(feature = "windows_process_exit_code_from", issue = "none")]
+impl ExitCodeExt for process::ExitCode {
+    fn from_raw(raw: u32) -> Self {
+        process::ExitCode::from_inner(From::from(raw))
+    }
+}
+
 /// Windows-specific extensions to the [`process::Command`] builder.
 ///
 /// This trait is sealed: it cannot be implemented outside the standard library.
@@ -135,7 +158,15 @@ pub trait CommandExt: Sealed {
     ///
     /// These will always be ORed with `CREATE_UNICODE_ENVIRONMENT`.
     ///
+    /// Passing `CREATE_NEW_PROCESS_GROUP` (`0x00000200`) makes the child the
+    /// root of a new process group, the Windows counterpart of
+    /// `process_group(0)` on Unix. Console control events such as Ctrl-C are
+    /// then no longer delivered to it, and they can be sent to the group
+    /// alone with `GenerateConsoleCtrlEvent`. Note that [`Child::kill`] only
+    /// terminates the child itself, not the rest of its group.
+    ///
     /// [1]: https://docs.microsoft.com/en-us/windows/win32/procthread/process-creation-flags
+    /// [`Child::kill`]: process::Child::kill
     #[stable(feature = "windows_process_extensions", since = "1.16.0")]
     fn creation_flags(&mut self, flags: u32) -> &mut process::Command;
 
@@ -161,6 +192,67 @@ pub trait CommandExt: Sealed {
     /// `CommandLineToArgvW` escaping rules.
     #[unstable(feature = "windows_process_extensions_raw_arg", issue = "29494")]
     fn raw_arg<S: AsRef<OsStr>>(&mut self, text_to_append_as_is: S) -> &mut process::Command;
+
+    /// Lets the child process inherit `handle`, under the same value it has
+    /// in the parent.
+    ///
+    /// Windows children can only use handles that were inheritable when they
+    /// were created, and making a handle inheritable by hand means every
+    /// child spawned concurrently by any thread inherits it too. This method
+    /// instead keeps `handle` with the `Command` and marks it inheritable only
+    /// while the child is being created, under the same lock the standard
+    /// library uses for stdio handles. It is left non-inheritable afterwards.
+    ///
+    /// The child needs to be told the handle's value separately, for example
+    /// on its command line; `as_raw_handle` gives the value to pass.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(windows_process_inherit_handle)]
+    /// use std::fs::File;
+    /// use std::os::windows::io::{AsRawHandle, OwnedHandle};
+    /// use std::os::windows::process::CommandExt;
+    /// use std::process::Command;
+    ///
+    /// let file = File::open("state.bin")?;
+    /// let raw = file.as_raw_handle() as usize;
+    /// Command::new("worker.exe")
+    ///     .arg(format!("--state-handle={}", raw))
+    ///     .inherit_handle(OwnedHandle::from(file))
+    ///     .spawn()?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[unstable(feature = "windows_process_inherit_handle", issue = "none")]
+    fn inherit_handle(&mut self, handle: OwnedHandle) -> &mut process::Command;
+
+    /// Sets the first argument on the child's command line, its `argv[0]`,
+    /// to something other than the program path.
+    ///
+    /// Windows passes a process its command line as a single string, whose
+    /// first token is conventionally the program name. The program that is
+    /// run is still the one given to [`Command::new`]; only what the child
+    /// sees as its name changes. This is the Windows counterpart of
+    /// `arg0` on Unix.
+    ///
+    /// This has no effect when running `.bat` and `.cmd` files, because
+    /// `cmd.exe` uses the first token to locate the script.
+    ///
+    /// [`Command::new`]: process::Command::new
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(windows_process_extensions_arg0)]
+    /// use std::env;
+    /// use std::os::windows::process::CommandExt;
+    /// use std::process::Command;
+    ///
+    /// let status = Command::new(env::current_exe()?).arg0("dir").status()?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[unstable(feature = "windows_process_extensions_arg0", issue = "none")]
+    fn arg0<S: AsRef<OsStr>>(&mut self, arg: S) -> &mut process::Command;
 }
 
 #[stable(feature = "windows_process_extensions", since = "1.16.0")]
@@ -179,4 +271,14 @@ fn raw_arg<S: AsRef<OsStr>>(&mut self, raw_text: S) -> &mut process::Command {
         self.as_inner_mut().raw_arg(raw_text.as_ref());
         self
     }
+
+    fn inherit_handle(&mut self, handle: OwnedHandle) -> &mut process::Command {
+        self.as_inner_mut().inherit_handle(sys::handle::Handle::from_inner(handle));
+        self
+    }
+
+    fn arg0<S: AsRef<OsStr>>(&mut self, arg: S) -> &mut process::Command {
+        self.as_inner_mut().set_arg_0(arg.as_ref());
+        self
+    }
 }
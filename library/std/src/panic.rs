@@ -4,7 +4,9 @@
 
 use crate::any::Any;
 use crate::collections;
+use crate::fmt;
 use crate::panicking;
+use crate::result;
 use crate::sync::{Mutex, RwLock};
 use crate::thread::Result;
 
@@ -36,6 +38,9 @@
 #[stable(feature = "panic_hooks", since = "1.10.0")]
 pub use crate::panicking::{set_hook, take_hook};
 
+#[unstable(feature = "panic_update_hook", issue = "none")]
+pub use crate::panicking::update_hook;
+
 #[stable(feature = "panic_hooks", since = "1.10.0")]
 pub use core::panic::{Location, PanicInfo};
 
@@ -163,6 +168,136 @@ pub fn resume_unwind(payload: Box<dyn Any + Send>) -> ! {
     panicking::rust_panic_without_hook(payload)
 }
 
+/// Invokes a closure, capturing the cause of an unwinding panic if one occurs,
+/// without requiring the closure to be [`UnwindSafe`].
+///
+/// This is [`catch_unwind`] for the cases where the [`UnwindSafe`] bound would
+/// only be satisfied by wrapping the closure in [`AssertUnwindSafe`]: test
+/// harnesses that report a panic and move on, or functions called from
+/// foreign code that must not unwind into it. Like [`AssertUnwindSafe`], this
+/// assumes that the caller does not go on to observe state that a panic left
+/// half-updated, or copes with it if it does.
+///
+/// The cause of the panic is returned as a [`CaughtPanic`], which gives access
+/// to the panic message without downcasting the payload by hand.
+///
+/// This function is subject to the same limitations as [`catch_unwind`]: it
+/// only catches unwinding panics, and unwinding into it with a foreign
+/// exception is undefined behavior.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(catch_panic)]
+/// use std::panic;
+///
+/// let mut log = Vec::new();
+///
+/// // Borrowing `log` mutably would not be `UnwindSafe`.
+/// let result = panic::catch_panic(|| {
+///     log.push("starting");
+///     panic!("failed after {} step", log.len());
+/// });
+///
+/// assert_eq!(result.unwrap_err().message(), Some("failed after 1 step"));
+/// assert_eq!(log, ["starting"]);
+/// ```
+#[unstable(feature = "catch_panic", issue = "none")]
+pub fn catch_panic<F: FnOnce() -> R, R>(f: F) -> result::Result<R, CaughtPanic> {
+    catch_unwind(AssertUnwindSafe(f)).map_err(|payload| CaughtPanic { payload })
+}
+
+/// The cause of a panic caught by [`catch_panic`].
+///
+/// This wraps the payload the panic was started with. Panics started with the
+/// [`panic!`] macro carry a message, which [`message`] returns; other payloads,
+/// for example from [`panic_any`], are available through [`payload`].
+///
+/// [`message`]: CaughtPanic::message
+/// [`payload`]: CaughtPanic::payload
+#[unstable(feature = "catch_panic", issue = "none")]
+pub struct CaughtPanic {
+    payload: Box<dyn Any + Send>,
+}
+
+impl CaughtPanic {
+    /// Returns the panic message, if the payload is a string.
+    ///
+    /// This is the case for panics started with [`panic!`], with or without
+    /// format arguments, whose payloads are a `&'static str` or a [`String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(catch_panic)]
+    /// use std::panic;
+    ///
+    /// let caught = panic::catch_panic(|| panic!("oh no!")).unwrap_err();
+    /// assert_eq!(caught.message(), Some("oh no!"));
+    ///
+    /// let caught = panic::catch_panic(|| panic::panic_any(42)).unwrap_err();
+    /// assert_eq!(caught.message(), None);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "catch_panic", issue = "none")]
+    pub fn message(&self) -> Option<&str> {
+        if let Some(s) = self.payload.downcast_ref::<&'static str>() {
+            Some(s)
+        } else {
+            self.payload.downcast_ref::<String>().map(String::as_str)
+        }
+    }
+
+    /// Returns the payload the panic was started with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(catch_panic)]
+    /// use std::panic;
+    ///
+    /// let caught = panic::catch_panic(|| panic::panic_any(42)).unwrap_err();
+    /// assert_eq!(caught.payload().downcast_ref::<i32>(), Some(&42));
+    /// ```
+    #[must_use]
+    #[unstable(feature = "catch_panic", issue = "none")]
+    pub fn payload(&self) -> &(dyn Any + Send) {
+        &*self.payload
+    }
+
+    /// Unwraps the payload the panic was started with.
+    #[must_use]
+    #[unstable(feature = "catch_panic", issue = "none")]
+    pub fn into_payload(self) -> Box<dyn Any + Send> {
+        self.payload
+    }
+
+    /// Continues the panic with the same payload, without invoking the panic
+    /// hook again.
+    ///
+    /// This is the same as calling [`resume_unwind`] with the payload.
+    #[unstable(feature = "catch_panic", issue = "none")]
+    pub fn resume(self) -> ! {
+        resume_unwind(self.payload)
+    }
+}
+
+#[unstable(feature = "catch_panic", issue = "none")]
+impl fmt::Debug for CaughtPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CaughtPanic").field("message", &self.message()).finish_non_exhaustive()
+    }
+}
+
+/// Formats the panic message, or `Box<dyn Any>` for other payloads, as the
+/// default panic hook does.
+#[unstable(feature = "catch_panic", issue = "none")]
+impl fmt::Display for CaughtPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message().unwrap_or("Box<dyn Any>"))
+    }
+}
+
 /// Make all future panics abort directly without running the panic hook or unwinding.
 ///
 /// There is no way to undo this; the effect lasts until the process exits or
@@ -1,7 +1,7 @@
 #![allow(dead_code)]
 
 use crate::cell::RefCell;
-use crate::panic::{AssertUnwindSafe, UnwindSafe};
+use crate::panic::{self, AssertUnwindSafe, UnwindSafe};
 use crate::rc::Rc;
 use crate::sync::{Arc, Mutex, RwLock};
 
@@ -54,3 +54,32 @@ fn baz<T: UnwindSafe>() {
         assert::<Arc<AssertUnwindSafe<T>>>();
     }
 }
+
+#[test]
+fn catch_panic_message() {
+    let mut count = 0;
+    let caught = panic::catch_panic(|| {
+        count += 1;
+        panic!("count is {}", count)
+    })
+    .unwrap_err();
+    assert_eq!(caught.message(), Some("count is 1"));
+    assert_eq!(caught.to_string(), "count is 1");
+
+    let caught = panic::catch_panic(|| panic!("static")).unwrap_err();
+    assert_eq!(caught.message(), Some("static"));
+
+    let caught = panic::catch_panic(|| panic::panic_any(7u8)).unwrap_err();
+    assert_eq!(caught.message(), None);
+    assert_eq!(caught.to_string(), "Box<dyn Any>");
+    assert_eq!(*caught.into_payload().downcast::<u8>().unwrap(), 7);
+
+    assert_eq!(panic::catch_panic(|| count).unwrap(), 1);
+}
+
+#[test]
+fn catch_panic_resume() {
+    let inner = panic::catch_panic(|| panic!("inner")).unwrap_err();
+    let outer = panic::catch_panic(|| inner.resume()).unwrap_err();
+    assert_eq!(outer.message(), Some("inner"));
+}
@@ -180,6 +180,70 @@ pub fn take_hook() -> Box<dyn Fn(&PanicInfo<'_>) + 'static + Sync + Send> {
     }
 }
 
+/// Atomically changes the panic hook, wrapping the one that was previously
+/// registered.
+///
+/// `hook_fn` becomes the new panic hook. It is called with the previous hook
+/// (the default hook if none was registered) and the `PanicInfo` of the
+/// panic, so it can decide whether and when to run the previous hook.
+///
+/// Unlike a [`take_hook`] followed by a [`set_hook`], no panic can observe
+/// the default hook in between, and two threads updating the hook at the
+/// same time cannot lose each other's changes.
+///
+/// # Panics
+///
+/// Panics if called from a panicking thread.
+///
+/// # Examples
+///
+/// The following will print the custom message, and then the normal output of panic.
+///
+/// ```should_panic
+/// #![feature(panic_update_hook)]
+/// use std::panic;
+///
+/// // Equivalent to
+/// // let prev = panic::take_hook();
+/// // panic::set_hook(Box::new(move |info| {
+/// //     println!("...");
+/// //     prev(info);
+/// // }));
+/// panic::update_hook(move |prev, info| {
+///     println!("Print custom message and execute panic handler as usual");
+///     prev(info);
+/// });
+///
+/// panic!("Custom and then normal");
+/// ```
+#[unstable(feature = "panic_update_hook", issue = "none")]
+pub fn update_hook<F>(hook_fn: F)
+where
+    F: Fn(&(dyn Fn(&PanicInfo<'_>) + Send + Sync + 'static), &PanicInfo<'_>)
+        + Sync
+        + Send
+        + 'static,
+{
+    if thread::panicking() {
+        panic!("cannot modify the panic hook from a panicking thread");
+    }
+
+    unsafe {
+        let guard = HOOK_LOCK.write();
+        let old_hook = HOOK;
+        HOOK = Hook::Default;
+
+        let prev: Box<dyn Fn(&PanicInfo<'_>) + 'static + Sync + Send> = match old_hook {
+            Hook::Default => Box::new(default_hook),
+            Hook::Custom(ptr) => Box::from_raw(ptr),
+        };
+
+        let hook = Box::new(move |info: &PanicInfo<'_>| hook_fn(&*prev, info));
+        HOOK = Hook::Custom(Box::into_raw(hook));
+        drop(guard);
+    }
+}
+
 fn default_hook(info: &PanicInfo<'_>) {
     // If this is a double panic, make sure that we print a backtrace
     // for this panic. Otherwise only print it if logging is enabled.
@@ -192,13 +256,7 @@ fn default_hook(info: &PanicInfo<'_>) {
     // The current implementation always returns `Some`.
     let location = info.location().unwrap();
 
-    let msg = match info.payload().downcast_ref::<&'static str>() {
-        Some(s) => *s,
-        None => match info.payload().downcast_ref::<String>() {
-            Some(s) => &s[..],
-            None => "Box<dyn Any>",
-        },
-    };
+    let msg = info.payload_as_str().unwrap_or("Box<dyn Any>");
     let thread = thread_info::current_thread();
     let name = thread.as_ref().and_then(|t| t.name()).unwrap_or("<unnamed>");
 
@@ -602,11 +660,11 @@ fn rust_panic_with_hook(
             // formatting.)
             Hook::Default if panic_output().is_none() => {}
             Hook::Default => {
-                info.set_payload(payload.get());
+                set_payload(&mut info, payload.get());
                 default_hook(&info);
             }
             Hook::Custom(ptr) => {
-                info.set_payload(payload.get());
+                set_payload(&mut info, payload.get());
                 (*ptr)(&info);
             }
         };
@@ -624,6 +682,15 @@ fn rust_panic_with_hook(
     rust_panic(payload)
 }
 
+/// Points `info` at `payload`, recording the contents of a `String` payload so
+/// that `PanicInfo::payload_as_str` can see it from `core`.
+fn set_payload<'a>(info: &mut PanicInfo<'a>, payload: &'a (dyn Any + Send)) {
+    info.set_payload(payload);
+    if let Some(s) = payload.downcast_ref::<String>() {
+        info.set_payload_str(s);
+    }
+}
+
 /// This is the entry point for `resume_unwind`.
 /// It just forwards the payload to the panic runtime.
 pub fn rust_panic_without_hook(payload: Box<dyn Any + Send>) -> ! {
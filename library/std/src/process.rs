@@ -111,14 +111,22 @@
 use crate::fmt;
 use crate::fs;
 use crate::io::{self, IoSlice, IoSliceMut};
+use crate::lazy::SyncOnceCell;
+use crate::mem;
 use crate::num::NonZeroI32;
+use crate::panic;
 use crate::path::Path;
+use crate::ptr;
 use crate::str;
-use crate::sys::pipe::{read2, AnonPipe};
+use crate::sync::atomic::{AtomicPtr, Ordering};
+use crate::sync::{Mutex, MutexGuard};
+use crate::sys::pipe::{read2, read2_limited, AnonPipe};
 use crate::sys::process as imp;
 #[stable(feature = "command_access", since = "1.57.0")]
 pub use crate::sys_common::process::CommandEnvs;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+use crate::thread;
+use crate::time::{Duration, Instant};
 
 /// Representation of a running or exited child process.
 ///
@@ -1391,6 +1399,24 @@ fn from(file: fs::File) -> Stdio {
     }
 }
 
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeReader> for Stdio {
+    /// Converts the read end of an anonymous pipe into a `Stdio`, typically
+    /// for use as a child's standard input.
+    fn from(pipe: io::PipeReader) -> Stdio {
+        Stdio::from_inner(pipe.into_inner().into())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeWriter> for Stdio {
+    /// Converts the write end of an anonymous pipe into a `Stdio`, typically
+    /// for use as a child's standard output or standard error.
+    fn from(pipe: io::PipeWriter) -> Stdio {
+        Stdio::from_inner(pipe.into_inner().into())
+    }
+}
+
 /// Describes the result of a process after it has terminated.
 ///
 /// This `struct` is used to represent the exit status or other termination of a child process.
@@ -1499,6 +1525,31 @@ pub fn success(&self) -> bool {
     pub fn code(&self) -> Option<i32> {
         self.0.code()
     }
+
+    /// Converts this status into an [`ExitCode`] that makes the current
+    /// process exit the same way, for programs that run a child and want to
+    /// pass its result on as their own.
+    ///
+    /// Returns `None` if the process didn't exit normally, in which case
+    /// [`code`](ExitStatus::code) is also `None`. On Unix that means it was
+    /// terminated by a signal, which can't be reproduced with an exit code.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_exitcode_placeholder, exit_status_to_exit_code)]
+    /// use std::process::{Command, ExitCode};
+    ///
+    /// fn main() -> ExitCode {
+    ///     let status = Command::new("ls").status().expect("failed to execute ls");
+    ///     status.to_exit_code().unwrap_or(ExitCode::FAILURE)
+    /// }
+    /// ```
+    #[must_use]
+    #[unstable(feature = "exit_status_to_exit_code", issue = "none")]
+    pub fn to_exit_code(&self) -> Option<ExitCode> {
+        self.code().map(|code| ExitCode(imp::ExitCode::from_status_code(code)))
+    }
 }
 
 impl AsInner<imp::ExitStatus> for ExitStatus {
@@ -1674,8 +1725,56 @@ impl ExitCode {
     /// return the same codes (but will also `eprintln!` the error).
     #[unstable(feature = "process_exitcode_placeholder", issue = "48711")]
     pub const FAILURE: ExitCode = ExitCode(imp::ExitCode::FAILURE);
+
+    /// Exits the current process with this exit code, exactly as if
+    /// [`exit`] had been called with it.
+    ///
+    /// Like [`exit`], this doesn't run destructors on the current stack or
+    /// any other thread's stack, so it's usually better to return the code
+    /// from `main` instead. It is useful where that isn't possible, such as
+    /// deep inside a helper that has decided the program can't continue.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_exitcode_placeholder, exitcode_exit_method)]
+    /// use std::process::ExitCode;
+    ///
+    /// fn check_config(ok: bool) {
+    ///     if !ok {
+    ///         eprintln!("invalid configuration");
+    ///         ExitCode::from(2).exit_process()
+    ///     }
+    /// }
+    /// # check_config(true);
+    /// ```
+    #[unstable(feature = "exitcode_exit_method", issue = "none")]
+    pub fn exit_process(self) -> ! {
+        exit(self.0.as_i32())
+    }
 }
 
+#[unstable(feature = "process_exitcode_placeholder", issue = "48711")]
+impl From<u8> for ExitCode {
+    /// Constructs an `ExitCode` from an arbitrary `u8` value.
+    ///
+    /// Every platform can report at least eight bits of exit status, so the
+    /// value is passed through unchanged.
+    fn from(code: u8) -> Self {
+        ExitCode(imp::ExitCode::from(code))
+    }
+}
+
+impl FromInner<imp::ExitCode> for ExitCode {
+    fn from_inner(s: imp::ExitCode) -> ExitCode {
+        ExitCode(s)
+    }
+}
+
+/// Allows extension traits within `std`.
+#[unstable(feature = "sealed", issue = "none")]
+impl crate::sealed::Sealed for ExitCode {}
+
 impl Child {
     /// Forces the child process to exit. If the child has already exited, an [`InvalidInput`]
     /// error is returned.
@@ -1797,6 +1896,66 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         Ok(self.handle.try_wait()?.map(ExitStatus))
     }
 
+    /// Waits for the child to exit, giving up after `timeout` has elapsed.
+    ///
+    /// If the child exits before the timeout then `Ok(Some(status))` is
+    /// returned, and on Unix the process ID is reaped. If the timeout elapses
+    /// first then `Ok(None)` is returned and the child keeps running; it can
+    /// then be [`kill`]ed or waited on again.
+    ///
+    /// Like [`try_wait`], and unlike [`wait`], this function will not attempt
+    /// to drop stdin. A child that is blocked reading from a piped stdin will
+    /// therefore not exit on its own before the timeout.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This is currently implemented by repeatedly calling [`try_wait`] with
+    /// an increasing sleep in between, so the status may be observed up to a
+    /// few tens of milliseconds after the child actually exits. This
+    /// [may change in the future](crate::io#platform-specific-behavior).
+    ///
+    /// [`kill`]: Child::kill
+    /// [`try_wait`]: Child::try_wait
+    /// [`wait`]: Child::wait
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(child_wait_timeout)]
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+    ///
+    /// match child.wait_timeout(Duration::from_secs(1)) {
+    ///     Ok(Some(status)) => println!("exited with: {}", status),
+    ///     Ok(None) => {
+    ///         println!("still running after a second, killing it");
+    ///         child.kill().unwrap();
+    ///         child.wait().unwrap();
+    ///     }
+    ///     Err(e) => println!("error attempting to wait: {}", e),
+    /// }
+    /// ```
+    #[unstable(feature = "child_wait_timeout", issue = "none")]
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        const MAX_SLEEP: Duration = Duration::from_millis(50);
+
+        let start = Instant::now();
+        let mut sleep = Duration::from_millis(1);
+        loop {
+            if let Some(status) = self.try_wait()? {
+                return Ok(Some(status));
+            }
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return Ok(None);
+            }
+            thread::sleep(sleep.min(timeout - elapsed));
+            sleep = (sleep * 2).min(MAX_SLEEP);
+        }
+    }
+
     /// Simultaneously waits for the child to exit and collect all remaining
     /// output on the stdout/stderr handles, returning an `Output`
     /// instance.
@@ -1853,6 +2012,74 @@ pub fn wait_with_output(mut self) -> io::Result<Output> {
         let status = self.wait()?;
         Ok(Output { status, stdout, stderr })
     }
+
+    /// Like [`wait_with_output`], but keeps at most `stdout_limit` bytes of
+    /// stdout and `stderr_limit` bytes of stderr.
+    ///
+    /// Both pipes are drained concurrently until the child closes them, so a
+    /// child producing lots of output on one stream cannot deadlock while the
+    /// parent is waiting on the other. Bytes past a limit are read and
+    /// discarded rather than left in the pipe, which means the child is never
+    /// blocked by a full pipe and memory use stays bounded no matter how much
+    /// the child writes.
+    ///
+    /// An output whose length equals its limit may have been truncated.
+    ///
+    /// The stdin handle to the child process, if any, will be closed before
+    /// waiting, exactly as with [`wait_with_output`].
+    ///
+    /// [`wait_with_output`]: Child::wait_with_output
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(child_output_limits)]
+    /// use std::process::{Command, Stdio};
+    ///
+    /// let child = Command::new("/bin/cat")
+    ///     .arg("huge.log")
+    ///     .stdout(Stdio::piped())
+    ///     .stderr(Stdio::piped())
+    ///     .spawn()
+    ///     .expect("failed to execute child");
+    ///
+    /// let output = child
+    ///     .wait_with_output_limited(4096, 4096)
+    ///     .expect("failed to wait on child");
+    ///
+    /// assert!(output.stdout.len() <= 4096);
+    /// ```
+    #[unstable(feature = "child_output_limits", issue = "none")]
+    pub fn wait_with_output_limited(
+        mut self,
+        stdout_limit: usize,
+        stderr_limit: usize,
+    ) -> io::Result<Output> {
+        drop(self.stdin.take());
+
+        fn read_limited<R: Read>(pipe: &mut R, dst: &mut Vec<u8>, limit: usize) -> io::Result<()> {
+            pipe.by_ref().take(limit as u64).read_to_end(dst)?;
+            io::copy(pipe, &mut io::sink()).map(drop)
+        }
+
+        let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+        match (self.stdout.take(), self.stderr.take()) {
+            (None, None) => {}
+            (Some(mut out), None) => read_limited(&mut out, &mut stdout, stdout_limit)?,
+            (None, Some(mut err)) => read_limited(&mut err, &mut stderr, stderr_limit)?,
+            (Some(out), Some(err)) => read2_limited(
+                out.inner,
+                &mut stdout,
+                stdout_limit,
+                err.inner,
+                &mut stderr,
+                stderr_limit,
+            )?,
+        }
+
+        let status = self.wait()?;
+        Ok(Output { status, stdout, stderr })
+    }
 }
 
 /// Terminates the current process with the specified exit code.
@@ -1867,6 +2094,14 @@ pub fn wait_with_output(mut self) -> io::Result<Output> {
 /// this function at a known point where there are no more destructors left
 /// to run.
 ///
+/// Before the process exits, the callbacks registered with [`at_exit`] are
+/// run and buffered data in [`stdout`](crate::io::stdout) is flushed, just as
+/// when returning from `main`. Other buffers, such as those of a [`BufWriter`]
+/// that is never dropped, are not flushed, so code that needs them written can
+/// register a callback that flushes them.
+///
+/// [`BufWriter`]: crate::io::BufWriter
+///
 /// ## Platform-specific behavior
 ///
 /// **Unix**: On Unix-like platforms, it is unlikely that all 32 bits of `exit`
@@ -1912,6 +2147,91 @@ pub fn exit(code: i32) -> ! {
     crate::sys::os::exit(code)
 }
 
+type AtExitCallback = Box<dyn FnOnce() + Send>;
+
+// `None` once the callbacks have been taken to be run.
+static AT_EXIT: SyncOnceCell<Mutex<Option<Vec<AtExitCallback>>>> = SyncOnceCell::new();
+
+fn at_exit_callbacks() -> MutexGuard<'static, Option<Vec<AtExitCallback>>> {
+    let callbacks = AT_EXIT.get_or_init(|| Mutex::new(Some(Vec::new())));
+    callbacks.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Registers a callback to be run when the process exits normally.
+///
+/// Callbacks run when `main` returns and when [`exit`] is called, on the
+/// thread that does so, after `main`'s local variables have been dropped. They
+/// run in the reverse order of their registration, each of them at most once,
+/// and before standard output is flushed, so they may still print. If a
+/// callback panics, the panic is reported as usual and the remaining callbacks
+/// still run.
+///
+/// Callbacks do not run when the process is terminated abnormally: by
+/// [`abort`], by a panic with `panic = "abort"`, by a signal, or when `main`
+/// is not written in Rust. Registering a callback fails with an
+/// [`AtExitError`] once the process has started running them, including
+/// from within a callback.
+///
+/// This is meant for flushing buffers or writing a final log line; other
+/// threads may still be running while callbacks are, so callbacks should not
+/// wait for them.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(process_at_exit)]
+/// use std::process;
+///
+/// process::at_exit(|| println!("goodbye")).unwrap();
+/// process::at_exit(|| println!("first")).unwrap();
+///
+/// // Prints "first", then "goodbye".
+/// ```
+#[unstable(feature = "process_at_exit", issue = "none")]
+pub fn at_exit<F>(callback: F) -> Result<(), AtExitError>
+where
+    F: FnOnce() + Send + 'static,
+{
+    let mut callbacks = at_exit_callbacks();
+    callbacks.as_mut().ok_or(AtExitError)?.push(Box::new(callback));
+    Ok(())
+}
+
+/// Runs the callbacks registered with [`at_exit`]. Called once by the runtime
+/// cleanup.
+pub(crate) fn run_at_exit() {
+    let callbacks = at_exit_callbacks().take().unwrap_or_default();
+    for callback in callbacks.into_iter().rev() {
+        if let Err(payload) = panic::catch_unwind(panic::AssertUnwindSafe(callback)) {
+            // Dropping the payload could panic again, so leak it instead.
+            mem::forget(payload);
+        }
+    }
+}
+
+/// The error returned by [`at_exit`] when the process is already exiting.
+#[unstable(feature = "process_at_exit", issue = "none")]
+#[non_exhaustive]
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct AtExitError;
+
+#[unstable(feature = "process_at_exit", issue = "none")]
+impl fmt::Debug for AtExitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtExitError").finish()
+    }
+}
+
+#[unstable(feature = "process_at_exit", issue = "none")]
+impl fmt::Display for AtExitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt("the process is already exiting", f)
+    }
+}
+
+#[unstable(feature = "process_at_exit", issue = "none")]
+impl crate::error::Error for AtExitError {}
+
 /// Terminates the process in an abnormal fashion.
 ///
 /// The function will never return and will immediately terminate the current
@@ -1928,7 +2248,8 @@ pub fn exit(code: i32) -> ! {
 /// the current thread's stack and calls all destructors.
 /// When `panic="abort"` is set, either as an argument to `rustc` or in a
 /// crate's Cargo.toml, [`panic!`] and `abort` are similar. However,
-/// [`panic!`] will still call the [panic hook] while `abort` will not.
+/// [`panic!`] will still call the [panic hook] while `abort` will not, except
+/// for the hook registered with [`set_abort_hook`].
 ///
 /// If a clean shutdown is needed it is recommended to only call
 /// this function at a known point where there are no more destructors left
@@ -1977,9 +2298,75 @@ pub fn exit(code: i32) -> ! {
 #[stable(feature = "process_abort", since = "1.17.0")]
 #[cold]
 pub fn abort() -> ! {
+    // Abort even if the hook panics, and take the hook out so that an
+    // `abort` from within the hook terminates the process right away.
+    struct AbortOnDrop;
+    impl Drop for AbortOnDrop {
+        fn drop(&mut self) {
+            crate::sys::abort_internal();
+        }
+    }
+
+    let _guard = AbortOnDrop;
+    let hook = ABORT_HOOK.swap(ptr::null_mut(), Ordering::SeqCst);
+    if !hook.is_null() {
+        // SAFETY: only `fn()` pointers are ever stored in `ABORT_HOOK`.
+        let hook: fn() = unsafe { mem::transmute(hook) };
+        hook();
+    }
     crate::sys::abort_internal();
 }
 
+static ABORT_HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Registers a hook that is run by [`abort`] right before the process is
+/// terminated, replacing any that was previously registered.
+///
+/// The hook is intended for a last diagnostic, such as logging why the process
+/// is about to go down. It also runs when the runtime aborts after an
+/// infallible allocation failed and the [allocation error hook] returned. The
+/// hook runs at most once, and the process is aborted even if the hook panics.
+///
+/// The hook is not run for aborts that bypass [`abort`], such as a panic while
+/// panicking or a panic with `panic = "abort"`, and it is run on whichever
+/// thread called [`abort`]. Like in a signal handler, the process may be in an
+/// inconsistent state at that point, so the hook should do as little as
+/// possible, and in particular should avoid allocating.
+///
+/// The abort hook is a global resource.
+///
+/// [allocation error hook]: crate::alloc::set_alloc_error_hook
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(abort_hook)]
+/// use std::process;
+///
+/// fn last_words() {
+///     eprintln!("fatal: shutting down");
+/// }
+///
+/// process::set_abort_hook(last_words);
+/// process::abort();
+/// ```
+#[unstable(feature = "abort_hook", issue = "none")]
+pub fn set_abort_hook(hook: fn()) {
+    ABORT_HOOK.store(hook as *mut (), Ordering::SeqCst);
+}
+
+/// Unregisters the current abort hook, returning it.
+///
+/// *See also the function [`set_abort_hook`].*
+///
+/// Returns `None` if no hook is registered.
+#[unstable(feature = "abort_hook", issue = "none")]
+pub fn take_abort_hook() -> Option<fn()> {
+    let hook = ABORT_HOOK.swap(ptr::null_mut(), Ordering::SeqCst);
+    // SAFETY: only `fn()` pointers are ever stored in `ABORT_HOOK`.
+    if hook.is_null() { None } else { Some(unsafe { mem::transmute(hook) }) }
+}
+
 /// Returns the OS-assigned process identifier associated with this process.
 ///
 /// # Examples
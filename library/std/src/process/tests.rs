@@ -1,8 +1,9 @@
 use crate::io::prelude::*;
 
-use super::{Command, Output, Stdio};
+use super::{Command, ExitCode, Output, Stdio};
 use crate::io::ErrorKind;
 use crate::str;
+use crate::time::Duration;
 
 fn known_command() -> Command {
     if cfg!(windows) { Command::new("help") } else { Command::new("echo") }
@@ -160,6 +161,41 @@ fn test_process_output_output() {
     assert_eq!(stderr, Vec::new());
 }
 
+#[test]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn test_wait_timeout() {
+    let mut prog = if cfg!(target_os = "windows") {
+        Command::new("cmd").args(&["/C", "ping -n 30 127.0.0.1 > nul"]).spawn().unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("sleep 30").spawn().unwrap()
+    };
+
+    assert!(prog.wait_timeout(Duration::from_millis(20)).unwrap().is_none());
+    prog.kill().unwrap();
+    let status = prog.wait_timeout(Duration::from_secs(30)).unwrap().unwrap();
+    assert!(!status.success());
+}
+
+#[test]
+#[cfg(unix)]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn test_wait_with_output_limited() {
+    // Both streams are larger than a pipe buffer, so reading them one after
+    // the other would deadlock.
+    let prog = shell_cmd()
+        .arg("-c")
+        .arg("head -c 200000 /dev/zero; head -c 200000 /dev/zero >&2")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let Output { status, stdout, stderr } = prog.wait_with_output_limited(10, 100_000).unwrap();
+    assert!(status.success());
+    assert_eq!(stdout, [0; 10]);
+    assert_eq!(stderr.len(), 100_000);
+}
+
 #[test]
 #[cfg_attr(any(target_os = "vxworks"), ignore)]
 fn test_process_output_error() {
@@ -175,6 +211,27 @@ fn test_process_output_error() {
     assert!(!stderr.is_empty());
 }
 
+#[test]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn test_exit_status_to_exit_code() {
+    let status = if cfg!(target_os = "windows") {
+        Command::new("cmd").args(&["/C", "exit 3"]).status().unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("exit 3").status().unwrap()
+    };
+    let code = status.to_exit_code().unwrap();
+    assert_eq!(format!("{:?}", code), format!("{:?}", ExitCode::from(3)));
+}
+
+#[test]
+#[cfg(unix)]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn test_signal_status_has_no_exit_code() {
+    let status = shell_cmd().arg("-c").arg("kill -9 $$").status().unwrap();
+    assert_eq!(status.code(), None);
+    assert!(status.to_exit_code().is_none());
+}
+
 #[test]
 #[cfg_attr(any(target_os = "vxworks"), ignore)]
 fn test_finish_once() {
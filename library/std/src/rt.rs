@@ -82,7 +82,7 @@ unsafe fn init(argc: isize, argv: *const *const u8) {
         // created. Note that this isn't necessary in general for new threads,
         // but we just do this to name the main thread and to give it correct
         // info about the stack bounds.
-        let thread = Thread::new(Some(rtunwrap!(Ok, CString::new("main"))));
+        let thread = Thread::new(Some(rtunwrap!(Ok, CString::new("main"))), None);
         thread_info::set(main_guard, thread);
     }
 }
@@ -93,6 +93,7 @@ unsafe fn init(argc: isize, argv: *const *const u8) {
 pub(crate) fn cleanup() {
     static CLEANUP: Once = Once::new();
     CLEANUP.call_once(|| unsafe {
+        crate::process::run_at_exit();
         // Flush stdout and disable buffering.
         crate::io::cleanup();
         // SAFETY: Only called once during runtime cleanup.
@@ -55,7 +55,10 @@ struct BarrierState {
 /// let barrier_wait_result = barrier.wait();
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
-pub struct BarrierWaitResult(bool);
+pub struct BarrierWaitResult {
+    is_leader: bool,
+    generation: usize,
+}
 
 #[stable(feature = "std_debug", since = "1.16.0")]
 impl fmt::Debug for Barrier {
@@ -133,12 +136,12 @@ pub fn wait(&self) -> BarrierWaitResult {
             while local_gen == lock.generation_id {
                 lock = self.cvar.wait(lock).unwrap();
             }
-            BarrierWaitResult(false)
+            BarrierWaitResult { is_leader: false, generation: local_gen }
         } else {
             lock.count = 0;
             lock.generation_id = lock.generation_id.wrapping_add(1);
             self.cvar.notify_all();
-            BarrierWaitResult(true)
+            BarrierWaitResult { is_leader: true, generation: local_gen }
         }
     }
 }
@@ -146,7 +149,10 @@ pub fn wait(&self) -> BarrierWaitResult {
 #[stable(feature = "std_debug", since = "1.16.0")]
 impl fmt::Debug for BarrierWaitResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("BarrierWaitResult").field("is_leader", &self.is_leader()).finish()
+        f.debug_struct("BarrierWaitResult")
+            .field("is_leader", &self.is_leader)
+            .field("generation", &self.generation)
+            .finish()
     }
 }
 
@@ -169,6 +175,31 @@ impl BarrierWaitResult {
     #[stable(feature = "rust1", since = "1.0.0")]
     #[must_use]
     pub fn is_leader(&self) -> bool {
-        self.0
+        self.is_leader
+    }
+
+    /// Returns the generation of the [`Barrier`] this thread was released
+    /// from.
+    ///
+    /// Every time all threads have rendezvoused, the barrier moves on to the
+    /// next generation, starting at 0. All threads released together get the
+    /// same generation, which makes it possible to tell apart the rounds of a
+    /// reused barrier. The counter wraps around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(barrier_generation)]
+    ///
+    /// use std::sync::Barrier;
+    ///
+    /// let barrier = Barrier::new(1);
+    /// assert_eq!(barrier.wait().generation(), 0);
+    /// assert_eq!(barrier.wait().generation(), 1);
+    /// ```
+    #[unstable(feature = "barrier_generation", issue = "none")]
+    #[must_use]
+    pub fn generation(&self) -> usize {
+        self.generation
     }
 }
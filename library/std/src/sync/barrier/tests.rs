@@ -33,3 +33,23 @@ fn test_barrier() {
     }
     assert!(leader_found);
 }
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn test_barrier_generation() {
+    const N: usize = 4;
+
+    let barrier = Arc::new(Barrier::new(N));
+    let handles: Vec<_> = (0..N - 1)
+        .map(|_| {
+            let c = barrier.clone();
+            thread::spawn(move || (0..3).map(|_| c.wait().generation()).collect::<Vec<_>>())
+        })
+        .collect();
+
+    let mine: Vec<_> = (0..3).map(|_| barrier.wait().generation()).collect();
+    assert_eq!(mine, [0, 1, 2]);
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), mine);
+    }
+}
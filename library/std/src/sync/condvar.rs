@@ -80,6 +80,16 @@ pub fn timed_out(&self) -> bool {
 /// Note that any attempt to use multiple mutexes on the same condition
 /// variable may result in a runtime panic.
 ///
+/// Waiting threads may wake up spuriously, so the predicate has to be checked
+/// again every time [`wait`] or [`wait_timeout`] returns. The [`wait_while`]
+/// and [`wait_timeout_while`] methods perform this loop internally and are
+/// usually the easier choice.
+///
+/// [`wait`]: Condvar::wait
+/// [`wait_timeout`]: Condvar::wait_timeout
+/// [`wait_while`]: Condvar::wait_while
+/// [`wait_timeout_while`]: Condvar::wait_timeout_while
+///
 /// # Examples
 ///
 /// ```
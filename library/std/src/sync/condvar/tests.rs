@@ -191,7 +191,9 @@ fn wait_timeout_wake() {
 
 #[test]
 #[should_panic]
-#[cfg_attr(not(unix), ignore)]
+// The futex-based mutexes on Linux and Android can move, so their condvars
+// can't check which mutex they're used with.
+#[cfg_attr(any(not(unix), target_os = "linux", target_os = "android"), ignore)]
 fn two_mutexes() {
     let m = Arc::new(Mutex::new(()));
     let m2 = m.clone();
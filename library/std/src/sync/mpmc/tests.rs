@@ -0,0 +1,317 @@
+use super::*;
+use crate::env;
+use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::sync::Arc;
+use crate::thread;
+use crate::time::{Duration, Instant};
+
+pub fn stress_factor() -> usize {
+    match env::var("RUST_TEST_STRESS") {
+        Ok(val) => val.parse().unwrap(),
+        Err(..) => 1,
+    }
+}
+
+#[test]
+fn smoke() {
+    let (tx, rx) = channel::<i32>();
+    tx.send(1).unwrap();
+    assert_eq!(rx.recv().unwrap(), 1);
+}
+
+#[test]
+fn smoke_sync() {
+    let (tx, rx) = sync_channel::<i32>(1);
+    tx.send(1).unwrap();
+    assert_eq!(rx.recv().unwrap(), 1);
+}
+
+#[test]
+fn smoke_rendezvous() {
+    let (tx, rx) = sync_channel::<i32>(0);
+    let _t = thread::spawn(move || {
+        tx.send(1).unwrap();
+    });
+    assert_eq!(rx.recv().unwrap(), 1);
+}
+
+#[test]
+fn drop_full() {
+    let (tx, _rx) = channel::<Box<isize>>();
+    tx.send(Box::new(1)).unwrap();
+
+    let (tx, _rx) = sync_channel::<Box<isize>>(1);
+    tx.send(Box::new(1)).unwrap();
+}
+
+#[test]
+fn drop_pending_messages() {
+    let count = Arc::new(AtomicUsize::new(0));
+
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let (tx, rx) = channel();
+    for _ in 0..100 {
+        tx.send(DropCounter(count.clone())).unwrap();
+    }
+    drop(rx.recv().unwrap());
+    drop((tx, rx));
+    assert_eq!(count.load(Ordering::SeqCst), 100);
+
+    count.store(0, Ordering::SeqCst);
+    let (tx, rx) = sync_channel(10);
+    for _ in 0..10 {
+        tx.send(DropCounter(count.clone())).unwrap();
+    }
+    drop(rx.recv().unwrap());
+    drop((tx, rx));
+    assert_eq!(count.load(Ordering::SeqCst), 10);
+}
+
+#[test]
+fn smoke_port_gone() {
+    let (tx, rx) = channel::<i32>();
+    drop(rx);
+    assert!(tx.send(1).is_err());
+
+    let (tx, rx) = sync_channel::<i32>(1);
+    drop(rx);
+    assert!(tx.send(1).is_err());
+
+    let (tx, rx) = sync_channel::<i32>(0);
+    drop(rx);
+    assert!(tx.send(1).is_err());
+}
+
+#[test]
+fn port_gone_only_after_last_clone() {
+    let (tx, rx) = channel::<i32>();
+    let rx2 = rx.clone();
+    drop(rx);
+    tx.send(1).unwrap();
+    assert_eq!(rx2.recv().unwrap(), 1);
+    drop(rx2);
+    assert!(tx.send(1).is_err());
+}
+
+#[test]
+fn chan_gone_concurrent() {
+    let (tx, rx) = channel::<i32>();
+    let _t = thread::spawn(move || {
+        tx.send(1).unwrap();
+        tx.send(1).unwrap();
+    });
+    while rx.recv().is_ok() {}
+}
+
+#[test]
+fn recv_after_disconnect_drains_buffer() {
+    let (tx, rx) = sync_channel::<i32>(3);
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    drop(tx);
+    assert_eq!(rx.recv(), Ok(1));
+    assert_eq!(rx.recv(), Ok(2));
+    assert_eq!(rx.recv(), Err(RecvError));
+}
+
+#[test]
+fn try_send_full() {
+    let (tx, rx) = sync_channel::<i32>(1);
+    assert_eq!(tx.try_send(1), Ok(()));
+    assert_eq!(tx.try_send(2), Err(TrySendError::Full(2)));
+    assert_eq!(rx.recv(), Ok(1));
+    drop(rx);
+    assert_eq!(tx.try_send(3), Err(TrySendError::Disconnected(3)));
+}
+
+#[test]
+fn try_recv_states() {
+    let (tx, rx) = channel::<i32>();
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+    tx.send(1).unwrap();
+    assert_eq!(rx.try_recv(), Ok(1));
+    drop(tx);
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Disconnected));
+}
+
+#[test]
+fn len_and_capacity() {
+    let (tx, rx) = sync_channel::<i32>(3);
+    assert_eq!(tx.capacity(), Some(3));
+    assert!(rx.is_empty());
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    assert_eq!(rx.len(), 2);
+    tx.send(3).unwrap();
+    assert!(tx.is_full());
+    rx.recv().unwrap();
+    assert!(!tx.is_full());
+
+    let (tx, rx) = channel::<i32>();
+    assert_eq!(rx.capacity(), None);
+    for i in 0..100 {
+        tx.send(i).unwrap();
+    }
+    assert_eq!(tx.len(), 100);
+}
+
+#[test]
+fn send_timeout_rendezvous() {
+    let (tx, rx) = sync_channel::<i32>(0);
+    assert_eq!(tx.send_timeout(1, Duration::from_millis(1)), Err(SendTimeoutError::Timeout(1)));
+    drop(rx);
+    assert_eq!(
+        tx.send_timeout(1, Duration::from_millis(1)),
+        Err(SendTimeoutError::Disconnected(1))
+    );
+}
+
+#[test]
+fn recv_timeout() {
+    let (tx, rx) = channel::<i32>();
+    let timeout = Duration::from_millis(1);
+    assert_eq!(rx.recv_timeout(timeout), Err(RecvTimeoutError::Timeout));
+    tx.send(1).unwrap();
+    assert_eq!(rx.recv_timeout(timeout), Ok(1));
+    drop(tx);
+    assert_eq!(rx.recv_timeout(timeout), Err(RecvTimeoutError::Disconnected));
+}
+
+#[test]
+fn recv_deadline_elapses() {
+    let (_tx, rx) = sync_channel::<i32>(1);
+    let start = Instant::now();
+    let deadline = start + Duration::from_millis(10);
+    assert_eq!(rx.recv_deadline(deadline), Err(RecvTimeoutError::Timeout));
+    assert!(Instant::now() >= deadline);
+}
+
+#[test]
+fn recv_timeout_with_live_clone() {
+    let (tx, rx) = channel::<()>();
+    let timeout = Duration::from_millis(1);
+    let _tx_clone = tx.clone();
+
+    let start = Instant::now();
+    assert_eq!(rx.recv_timeout(timeout), Err(RecvTimeoutError::Timeout));
+    assert!(Instant::now() >= start + timeout);
+}
+
+#[test]
+fn same_channel() {
+    let (tx, rx) = channel::<i32>();
+    let (tx2, rx2) = channel::<i32>();
+    assert!(tx.same_channel(&tx.clone()));
+    assert!(rx.same_channel(&rx.clone()));
+    assert!(!tx.same_channel(&tx2));
+    assert!(!rx.same_channel(&rx2));
+}
+
+fn multi_consumer(tx: Sender<usize>, rx: Receiver<usize>) {
+    const AMT: usize = 10000;
+    const NTHREADS: usize = 8;
+
+    let total = Arc::new(AtomicUsize::new(0));
+    let received = Arc::new(AtomicUsize::new(0));
+
+    let consumers: Vec<_> = (0..NTHREADS)
+        .map(|_| {
+            let rx = rx.clone();
+            let total = total.clone();
+            let received = received.clone();
+            thread::spawn(move || {
+                for i in rx.iter() {
+                    total.fetch_add(i, Ordering::SeqCst);
+                    received.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+        })
+        .collect();
+    drop(rx);
+
+    let producers: Vec<_> = (0..NTHREADS)
+        .map(|_| {
+            let tx = tx.clone();
+            thread::spawn(move || {
+                for i in 0..AMT * stress_factor() {
+                    tx.send(i).unwrap();
+                }
+            })
+        })
+        .collect();
+    drop(tx);
+
+    for p in producers {
+        p.join().unwrap();
+    }
+    for c in consumers {
+        c.join().unwrap();
+    }
+
+    let amt = AMT * stress_factor();
+    assert_eq!(received.load(Ordering::SeqCst), amt * NTHREADS);
+    assert_eq!(total.load(Ordering::SeqCst), NTHREADS * amt * (amt - 1) / 2);
+}
+
+#[test]
+fn stress_unbounded() {
+    let (tx, rx) = channel();
+    multi_consumer(tx, rx);
+}
+
+#[test]
+fn stress_bounded() {
+    let (tx, rx) = sync_channel(16);
+    multi_consumer(tx, rx);
+}
+
+#[test]
+fn stress_rendezvous() {
+    let (tx, rx) = sync_channel(0);
+    multi_consumer(tx, rx);
+}
+
+#[test]
+fn oneshot_multi_thread_recv_close_stress() {
+    for _ in 0..stress_factor() {
+        let (tx, rx) = sync_channel::<i32>(0);
+        let _t = thread::spawn(move || {
+            let res = thread::spawn(move || {
+                rx.recv().unwrap();
+            })
+            .join();
+            assert!(res.is_err());
+        });
+        let _t = thread::spawn(move || {
+            thread::spawn(move || {
+                drop(tx);
+            });
+        });
+    }
+}
+
+#[test]
+fn try_iter_drains() {
+    let (tx, rx) = channel::<i32>();
+    for i in 0..3 {
+        tx.send(i).unwrap();
+    }
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+    assert_eq!(rx.try_iter().next(), None);
+}
+
+#[test]
+fn into_iter_ends_on_disconnect() {
+    let (tx, rx) = channel::<i32>();
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    drop(tx);
+    assert_eq!(rx.into_iter().collect::<Vec<_>>(), vec![1, 2]);
+}
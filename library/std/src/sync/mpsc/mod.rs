@@ -428,6 +428,42 @@ pub struct TryIter<'a, T: 'a> {
     rx: &'a Receiver<T>,
 }
 
+/// An iterator that blocks waiting for messages on a [`Receiver`] until a
+/// deadline, created by [`iter_until`].
+///
+/// This iterator will return [`None`] once the deadline has passed with no
+/// message pending, or once the corresponding channel has hung up.
+///
+/// [`iter_until`]: Receiver::iter_until
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(deadline_api)]
+/// use std::sync::mpsc::channel;
+/// use std::thread;
+/// use std::time::{Duration, Instant};
+///
+/// let (send, recv) = channel();
+///
+/// thread::spawn(move || {
+///     for i in 0..10 {
+///         send.send(i).unwrap();
+///     }
+/// });
+///
+/// // Collect a batch of at most four messages, waiting at most a second.
+/// let deadline = Instant::now() + Duration::from_secs(1);
+/// let batch: Vec<_> = recv.iter_until(deadline).take(4).collect();
+/// assert_eq!(batch, [0, 1, 2, 3]);
+/// ```
+#[unstable(feature = "deadline_api", issue = "46316")]
+#[derive(Debug)]
+pub struct IterUntil<'a, T: 'a> {
+    rx: &'a Receiver<T>,
+    deadline: Instant,
+}
+
 /// An owning iterator over messages on a [`Receiver`],
 /// created by **Receiver::into_iter**.
 ///
@@ -1370,6 +1406,9 @@ pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
                 unsafe {
                     mem::swap(self.inner_mut(), new_port.inner_mut());
                 }
+                // The upgraded port may already hold data, so check it
+                // before giving up on the deadline.
+                continue;
             }
 
             // If we're already passed the deadline, and we're here without
@@ -1448,6 +1487,44 @@ pub fn iter(&self) -> Iter<'_, T> {
     pub fn try_iter(&self) -> TryIter<'_, T> {
         TryIter { rx: self }
     }
+
+    /// Returns an iterator that will block waiting for messages until
+    /// `deadline`, but never [`panic!`]. It will return [`None`] when the
+    /// deadline has passed with no message pending, or when the channel has
+    /// hung up.
+    ///
+    /// Each call to [`next`] is a call to [`recv_deadline`] with the same
+    /// `deadline`, so once it has passed, the iterator still yields the
+    /// messages that are already pending, like [`try_iter`].
+    ///
+    /// [`next`]: Iterator::next
+    /// [`recv_deadline`]: Receiver::recv_deadline
+    /// [`try_iter`]: Receiver::try_iter
+    ///
+    /// # Examples
+    ///
+    /// Processing messages in batches of at most 100 messages or 50
+    /// milliseconds:
+    ///
+    /// ```no_run
+    /// #![feature(deadline_api)]
+    /// use std::sync::mpsc::Receiver;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// fn process_batches(recv: &Receiver<String>, mut process: impl FnMut(Vec<String>)) {
+    ///     loop {
+    ///         let deadline = Instant::now() + Duration::from_millis(50);
+    ///         let batch: Vec<_> = recv.iter_until(deadline).take(100).collect();
+    ///         if !batch.is_empty() {
+    ///             process(batch);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[unstable(feature = "deadline_api", issue = "46316")]
+    pub fn iter_until(&self, deadline: Instant) -> IterUntil<'_, T> {
+        IterUntil { rx: self, deadline }
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1468,6 +1545,15 @@ fn next(&mut self) -> Option<T> {
     }
 }
 
+#[unstable(feature = "deadline_api", issue = "46316")]
+impl<'a, T> Iterator for IterUntil<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.recv_deadline(self.deadline).ok()
+    }
+}
+
 #[stable(feature = "receiver_into_iter", since = "1.1.0")]
 impl<'a, T> IntoIterator for &'a Receiver<T> {
     type Item = T;
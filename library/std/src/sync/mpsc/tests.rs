@@ -463,6 +463,30 @@ fn recv_timeout_upgrade() {
     assert!(Instant::now() >= start + timeout);
 }
 
+#[test]
+fn iter_until() {
+    let (tx, rx) = channel();
+    for i in 0..3 {
+        tx.send(i).unwrap();
+    }
+
+    // Pending messages are still received once the deadline has passed.
+    let past = Instant::now();
+    assert_eq!(rx.iter_until(past).collect::<Vec<_>>(), [0, 1, 2]);
+
+    let deadline = Instant::now() + Duration::from_millis(1);
+    assert_eq!(rx.iter_until(deadline).next(), None);
+    assert!(Instant::now() >= deadline);
+
+    let tx_clone = tx.clone();
+    thread::spawn(move || {
+        tx_clone.send(3).unwrap();
+        tx.send(4).unwrap();
+    });
+    let deadline = Instant::now() + Duration::from_secs(60);
+    assert_eq!(rx.iter_until(deadline).collect::<Vec<_>>(), [3, 4]);
+}
+
 #[test]
 fn stress_recv_timeout_shared() {
     let (tx, rx) = channel();
@@ -1,6 +1,6 @@
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sync::mpsc::channel;
-use crate::sync::{Arc, Condvar, Mutex};
+use crate::sync::{Arc, Condvar, MappedMutexGuard, Mutex, MutexGuard};
 use crate::thread;
 
 struct Packet<T>(Arc<(Mutex<T>, Condvar)>);
@@ -236,3 +236,36 @@ fn test_mutex_unsized() {
     let comp: &[i32] = &[4, 2, 5];
     assert_eq!(&*mutex.lock().unwrap(), comp);
 }
+
+#[test]
+fn test_mapped_guard() {
+    let m = Mutex::new((1, vec![2, 3]));
+    {
+        let guard = m.lock().unwrap();
+        let mut v = MutexGuard::map(guard, |(_, v)| v);
+        v.push(4);
+        assert!(m.try_lock().is_err());
+        let mut last = MappedMutexGuard::try_map(v, |v| v.last_mut()).unwrap();
+        *last = 5;
+    }
+    assert_eq!(*m.lock().unwrap(), (1, vec![2, 3, 5]));
+
+    let guard = m.lock().unwrap();
+    let guard = MutexGuard::try_map(guard, |(_, v)| v.get_mut(10)).unwrap_err();
+    Mutex::unlock(guard);
+    assert!(m.try_lock().is_ok());
+}
+
+#[test]
+fn test_mapped_guard_poison() {
+    let m = Arc::new(Mutex::new((1, 2)));
+    let m2 = m.clone();
+    let _ = thread::spawn(move || {
+        let mut guard = MutexGuard::map(m2.lock().unwrap(), |(a, _)| a);
+        *guard = 10;
+        panic!("test panic in inner thread to poison mutex");
+    })
+    .join();
+    assert!(m.is_poisoned());
+    assert_eq!(*m.lock().unwrap_err().into_inner(), (10, 2));
+}
@@ -114,3 +114,36 @@ fn wait_for_force_to_finish() {
     assert!(t1.join().is_ok());
     assert!(t2.join().is_ok());
 }
+
+#[test]
+fn poison_retry_force() {
+    static O: Once = Once::new();
+
+    // poison the once
+    let t = panic::catch_unwind(|| {
+        O.call_once(|| panic!());
+    });
+    assert!(t.is_err());
+
+    // a failed recovery attempt leaves the once poisoned
+    let t = panic::catch_unwind(|| {
+        O.call_once_force(|p| {
+            assert!(p.is_poisoned());
+            panic!();
+        });
+    });
+    assert!(t.is_err());
+    assert!(!O.is_completed());
+
+    // so the next attempt still observes the poison, and can finish the job
+    let mut called = false;
+    O.call_once_force(|p| {
+        called = true;
+        assert!(p.is_poisoned());
+    });
+    assert!(called);
+    assert!(O.is_completed());
+
+    // after that, nothing runs again
+    O.call_once_force(|_| panic!());
+}
@@ -42,6 +42,7 @@ pub fn get(&self) -> bool {
     }
 }
 
+#[derive(Clone)]
 pub struct Guard {
     panicking: bool,
 }
@@ -21,9 +21,19 @@
 ///
 /// The priority policy of the lock is dependent on the underlying operating
 /// system's implementation, and this type does not guarantee that any
-/// particular policy will be used. In particular, a writer which is waiting to
-/// acquire the lock in `write` might or might not block concurrent calls to
-/// `read`, e.g.:
+/// particular policy will be used. Currently:
+///
+/// - On Linux and Android, writers are preferred. A waiting writer blocks new
+///   readers, except that a small, bounded number of readers may still join a
+///   lock that is already read-locked. Writers therefore cannot be starved by
+///   a continuous stream of readers.
+/// - On Windows, the lock is an SRW lock, which makes no fairness guarantees.
+/// - On other Unix platforms, the policy is that of the system's
+///   `pthread_rwlock_t`.
+///
+/// This list is informational and may change in the future. In particular, a
+/// writer which is waiting to acquire the lock in `write` might or might not
+/// block concurrent calls to `read`, e.g.:
 ///
 /// <details><summary>Potential deadlock example</summary>
 ///
@@ -1,4 +1,4 @@
-use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use crate::sync::mpsc::channel;
 use crate::sync::{Arc, RwLock, TryLockError};
 use crate::thread;
@@ -245,3 +245,34 @@ fn test_get_mut_poison() {
         Ok(x) => panic!("get_mut of poisoned RwLock is Ok: {:?}", x),
     }
 }
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn test_rwlock_writer_not_starved() {
+    const READERS: usize = 8;
+
+    let lock = Arc::new(RwLock::new(0));
+    let done = Arc::new(AtomicBool::new(false));
+    let readers: Vec<_> = (0..READERS)
+        .map(|_| {
+            let lock = lock.clone();
+            let done = done.clone();
+            thread::spawn(move || {
+                // Keep the lock read-locked at nearly all times.
+                while !done.load(Ordering::Relaxed) {
+                    let _r = lock.read().unwrap();
+                    thread::yield_now();
+                }
+            })
+        })
+        .collect();
+
+    for _ in 0..100 {
+        *lock.write().unwrap() += 1;
+    }
+    done.store(true, Ordering::Relaxed);
+    for reader in readers {
+        reader.join().unwrap();
+    }
+    assert_eq!(*lock.read().unwrap(), 100);
+}
@@ -0,0 +1,236 @@
+#[cfg(all(test, not(target_os = "emscripten")))]
+mod tests;
+
+use crate::fmt;
+use crate::sync::{Condvar, Mutex};
+
+/// A counting semaphore.
+///
+/// A semaphore holds a number of permits. [`acquire`] takes one of them,
+/// blocking the current thread until one becomes available, and hands out a
+/// [`SemaphoreGuard`] which returns the permit when it is dropped. This makes
+/// semaphores a natural fit for limiting how many threads may run a section of
+/// code at the same time.
+///
+/// Permits are not tied to a particular thread, and more of them can be handed
+/// out at any time with [`add_permits`].
+///
+/// [`acquire`]: Semaphore::acquire
+/// [`add_permits`]: Semaphore::add_permits
+///
+/// # Examples
+///
+/// ```
+/// #![feature(semaphore)]
+///
+/// use std::sync::{Arc, Semaphore};
+/// use std::thread;
+///
+/// // At most two threads may work at the same time.
+/// let semaphore = Arc::new(Semaphore::new(2));
+///
+/// let handles: Vec<_> = (0..8)
+///     .map(|i| {
+///         let semaphore = Arc::clone(&semaphore);
+///         thread::spawn(move || {
+///             let _permit = semaphore.acquire();
+///             println!("thread {} is working", i);
+///         })
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// assert_eq!(semaphore.available_permits(), 2);
+/// ```
+#[unstable(feature = "semaphore", issue = "none")]
+pub struct Semaphore {
+    permits: Mutex<usize>,
+    cvar: Condvar,
+}
+
+/// An RAII guard for a permit of a [`Semaphore`]. When this structure is
+/// dropped (falls out of scope), the permit is returned to the semaphore.
+///
+/// This structure is created by the [`acquire`] and [`try_acquire`] methods
+/// on [`Semaphore`].
+///
+/// [`acquire`]: Semaphore::acquire
+/// [`try_acquire`]: Semaphore::try_acquire
+#[must_use = "if unused the permit will immediately be released"]
+#[unstable(feature = "semaphore", issue = "none")]
+pub struct SemaphoreGuard<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl Semaphore {
+    /// Creates a new semaphore holding `permits` permits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    ///
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(4);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    #[must_use]
+    pub fn new(permits: usize) -> Semaphore {
+        Semaphore { permits: Mutex::new(permits), cvar: Condvar::new() }
+    }
+
+    /// Takes a permit from the semaphore, blocking the current thread until
+    /// one is available.
+    ///
+    /// The permit is returned when the returned guard is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    ///
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(1);
+    /// let permit = semaphore.acquire();
+    /// assert!(semaphore.try_acquire().is_none());
+    /// drop(permit);
+    /// assert!(semaphore.try_acquire().is_some());
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn acquire(&self) -> SemaphoreGuard<'_> {
+        let mut permits = self.cvar.wait_while(self.permits.lock().unwrap(), |p| *p == 0).unwrap();
+        *permits -= 1;
+        SemaphoreGuard { semaphore: self }
+    }
+
+    /// Attempts to take a permit from the semaphore without blocking.
+    ///
+    /// Returns `None` if no permit is currently available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    ///
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(0);
+    /// assert!(semaphore.try_acquire().is_none());
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn try_acquire(&self) -> Option<SemaphoreGuard<'_>> {
+        let mut permits = self.permits.lock().unwrap();
+        if *permits == 0 {
+            return None;
+        }
+        *permits -= 1;
+        Some(SemaphoreGuard { semaphore: self })
+    }
+
+    /// Adds `n` new permits to the semaphore, waking up blocked threads as
+    /// necessary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of available permits overflows a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    ///
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(0);
+    /// semaphore.add_permits(2);
+    /// assert_eq!(semaphore.available_permits(), 2);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn add_permits(&self, n: usize) {
+        let mut permits = self.permits.lock().unwrap();
+        match permits.checked_add(n) {
+            Some(new) => *permits = new,
+            None => {
+                // Release the lock first so that the panic doesn't poison it.
+                drop(permits);
+                panic!("overflow in Semaphore::add_permits");
+            }
+        }
+        drop(permits);
+        if n == 1 {
+            self.cvar.notify_one();
+        } else if n > 1 {
+            self.cvar.notify_all();
+        }
+    }
+
+    /// Returns the number of permits that are currently available.
+    ///
+    /// If other threads are active, this value may be stale by the time it is
+    /// used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    ///
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(3);
+    /// let _permit = semaphore.acquire();
+    /// assert_eq!(semaphore.available_permits(), 2);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    #[must_use]
+    pub fn available_permits(&self) -> usize {
+        *self.permits.lock().unwrap()
+    }
+}
+
+impl SemaphoreGuard<'_> {
+    /// Consumes the guard without returning its permit to the semaphore.
+    ///
+    /// This permanently lowers the number of permits in circulation, which
+    /// can be undone with [`Semaphore::add_permits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    ///
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(1);
+    /// semaphore.acquire().forget();
+    /// assert_eq!(semaphore.available_permits(), 0);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn forget(self) {
+        crate::mem::forget(self);
+    }
+}
+
+#[unstable(feature = "semaphore", issue = "none")]
+impl Drop for SemaphoreGuard<'_> {
+    fn drop(&mut self) {
+        self.semaphore.add_permits(1);
+    }
+}
+
+#[unstable(feature = "semaphore", issue = "none")]
+impl fmt::Debug for Semaphore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Semaphore").field("permits", &self.available_permits()).finish()
+    }
+}
+
+#[unstable(feature = "semaphore", issue = "none")]
+impl fmt::Debug for SemaphoreGuard<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemaphoreGuard").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,85 @@
+use crate::panic::{self, AssertUnwindSafe};
+use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::sync::mpsc::channel;
+use crate::sync::{Arc, Semaphore};
+use crate::thread;
+
+#[test]
+fn smoke() {
+    let s = Semaphore::new(1);
+    drop(s.acquire());
+    drop(s.acquire());
+    assert_eq!(s.available_permits(), 1);
+}
+
+#[test]
+fn try_acquire() {
+    let s = Semaphore::new(2);
+    let a = s.try_acquire().unwrap();
+    let _b = s.try_acquire().unwrap();
+    assert!(s.try_acquire().is_none());
+    drop(a);
+    assert!(s.try_acquire().is_some());
+}
+
+#[test]
+fn forget_and_add_permits() {
+    let s = Semaphore::new(1);
+    s.acquire().forget();
+    assert!(s.try_acquire().is_none());
+    s.add_permits(3);
+    assert_eq!(s.available_permits(), 3);
+}
+
+#[test]
+fn acquire_blocks_until_release() {
+    let s = Arc::new(Semaphore::new(0));
+    let s2 = s.clone();
+    let (tx, rx) = channel();
+    let t = thread::spawn(move || {
+        let _permit = s2.acquire();
+        tx.send(()).unwrap();
+    });
+    assert!(rx.try_recv().is_err());
+    s.add_permits(1);
+    rx.recv().unwrap();
+    t.join().unwrap();
+    assert_eq!(s.available_permits(), 1);
+}
+
+#[test]
+fn bounded_concurrency() {
+    const PERMITS: usize = 3;
+    const THREADS: usize = 10;
+
+    let s = Arc::new(Semaphore::new(PERMITS));
+    let active = Arc::new(AtomicUsize::new(0));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let s = s.clone();
+            let active = active.clone();
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    let _permit = s.acquire();
+                    let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                    assert!(now <= PERMITS);
+                    thread::yield_now();
+                    active.fetch_sub(1, Ordering::SeqCst);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(s.available_permits(), PERMITS);
+}
+
+#[test]
+fn add_permits_overflow_does_not_poison() {
+    let s = Semaphore::new(usize::MAX);
+    let r = panic::catch_unwind(AssertUnwindSafe(|| s.add_permits(1)));
+    assert!(r.is_err());
+    assert_eq!(s.available_permits(), usize::MAX);
+    drop(s.acquire());
+}
@@ -27,6 +27,12 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+impl Args {
+    pub fn as_slice(&self) -> &[OsString] {
+        self.iter.as_slice()
+    }
+}
+
 impl !Send for Args {}
 impl !Sync for Args {}
 
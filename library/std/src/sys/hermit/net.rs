@@ -1,6 +1,6 @@
 use crate::convert::TryFrom;
 use crate::fmt;
-use crate::io::{self, ErrorKind, IoSlice, IoSliceMut};
+use crate::io::{self, ErrorKind, IoSlice, IoSliceMut, ReadBuf};
 use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
 use crate::str;
 use crate::sync::Arc;
@@ -111,6 +111,10 @@ pub fn read(&self, buffer: &mut [u8]) -> io::Result<usize> {
         self.read_vectored(&mut [IoSliceMut::new(buffer)])
     }
 
+    pub fn read_buf(&self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        io::default_read_buf(|b| self.read(b), buf)
+    }
+
     pub fn read_vectored(&self, ioslice: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         let mut size: usize = 0;
 
@@ -190,6 +194,14 @@ pub fn linger(&self) -> io::Result<Option<Duration>> {
         unsupported()
     }
 
+    pub fn set_keepalive(&self, _keepalive: Option<Duration>) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        unsupported()
+    }
+
     pub fn set_nodelay(&self, mode: bool) -> io::Result<()> {
         abi::tcpstream::set_nodelay(*self.0.as_inner(), mode)
             .map_err(|_| io::Error::new_const(ErrorKind::Uncategorized, &"set_nodelay failed"))
@@ -214,6 +226,10 @@ pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         unsupported()
     }
 
+    pub fn ready(&self, _: bool, _: bool, _: Option<Duration>) -> io::Result<(bool, bool)> {
+        unsupported()
+    }
+
     pub fn set_nonblocking(&self, mode: bool) -> io::Result<()> {
         abi::tcpstream::set_nonblocking(*self.0.as_inner(), mode).map_err(|_| {
             io::Error::new_const(ErrorKind::Uncategorized, &"unable to set blocking mode")
@@ -369,6 +385,30 @@ pub fn multicast_loop_v6(&self) -> io::Result<bool> {
         unsupported()
     }
 
+    pub fn set_multicast_hops_v6(&self, _: u32) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn multicast_hops_v6(&self) -> io::Result<u32> {
+        unsupported()
+    }
+
+    pub fn set_multicast_if_v4(&self, _: &Ipv4Addr) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn multicast_if_v4(&self) -> io::Result<Ipv4Addr> {
+        unsupported()
+    }
+
+    pub fn set_multicast_if_v6(&self, _: u32) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn multicast_if_v6(&self) -> io::Result<u32> {
+        unsupported()
+    }
+
     pub fn join_multicast_v4(&self, _: &Ipv4Addr, _: &Ipv4Addr) -> io::Result<()> {
         unsupported()
     }
@@ -413,6 +453,14 @@ pub fn send(&self, _: &[u8]) -> io::Result<usize> {
         unsupported()
     }
 
+    pub fn recv_vectored(&self, _: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        unsupported()
+    }
+
+    pub fn send_vectored(&self, _: &[IoSlice<'_>]) -> io::Result<usize> {
+        unsupported()
+    }
+
     pub fn connect(&self, _: io::Result<&SocketAddr>) -> io::Result<()> {
         unsupported()
     }
@@ -36,6 +36,12 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+impl Args {
+    pub fn as_slice(&self) -> &[OsString] {
+        self.0.as_slice()
+    }
+}
+
 impl Iterator for Args {
     type Item = OsString;
     fn next(&mut self) -> Option<OsString> {
@@ -23,6 +23,8 @@
 #[path = "../unsupported/io.rs"]
 pub mod io;
 pub mod memchr;
+#[path = "../unsupported/mmap.rs"]
+pub mod mmap;
 pub mod mutex;
 pub mod net;
 pub mod os;
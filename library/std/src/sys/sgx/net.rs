@@ -1,7 +1,7 @@
 use crate::convert::TryFrom;
 use crate::error;
 use crate::fmt;
-use crate::io::{self, IoSlice, IoSliceMut};
+use crate::io::{self, IoSlice, IoSliceMut, ReadBuf};
 use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, ToSocketAddrs};
 use crate::sync::Arc;
 use crate::sys::fd::FileDesc;
@@ -145,6 +145,10 @@ pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.inner.inner.read(buf)
     }
 
+    pub fn read_buf(&self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        io::default_read_buf(|b| self.read(b), buf)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.inner.inner.read_vectored(bufs)
     }
@@ -191,6 +195,14 @@ pub fn linger(&self) -> io::Result<Option<Duration>> {
         sgx_ineffective(None)
     }
 
+    pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+        sgx_ineffective(())
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        sgx_ineffective(None)
+    }
+
     pub fn set_nodelay(&self, _: bool) -> io::Result<()> {
         sgx_ineffective(())
     }
@@ -211,6 +223,10 @@ pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         Ok(None)
     }
 
+    pub fn ready(&self, _: bool, _: bool, _: Option<Duration>) -> io::Result<(bool, bool)> {
+        unsupported()
+    }
+
     pub fn set_nonblocking(&self, _: bool) -> io::Result<()> {
         sgx_ineffective(())
     }
@@ -397,6 +413,30 @@ pub fn multicast_loop_v6(&self) -> io::Result<bool> {
         self.0
     }
 
+    pub fn set_multicast_hops_v6(&self, _: u32) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn multicast_hops_v6(&self) -> io::Result<u32> {
+        self.0
+    }
+
+    pub fn set_multicast_if_v4(&self, _: &Ipv4Addr) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn multicast_if_v4(&self) -> io::Result<Ipv4Addr> {
+        self.0
+    }
+
+    pub fn set_multicast_if_v6(&self, _: u32) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn multicast_if_v6(&self) -> io::Result<u32> {
+        self.0
+    }
+
     pub fn join_multicast_v4(&self, _: &Ipv4Addr, _: &Ipv4Addr) -> io::Result<()> {
         self.0
     }
@@ -441,6 +481,14 @@ pub fn send(&self, _: &[u8]) -> io::Result<usize> {
         self.0
     }
 
+    pub fn recv_vectored(&self, _: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0
+    }
+
+    pub fn send_vectored(&self, _: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0
+    }
+
     pub fn connect(&self, _: io::Result<&SocketAddr>) -> io::Result<()> {
         self.0
     }
@@ -11,10 +11,13 @@
 pub const IPPROTO_TCP: i32 = 6;
 pub const IPV6_ADD_MEMBERSHIP: i32 = 12;
 pub const IPV6_DROP_MEMBERSHIP: i32 = 13;
+pub const IPV6_MULTICAST_IF: i32 = 17;
+pub const IPV6_MULTICAST_HOPS: i32 = 18;
 pub const IPV6_MULTICAST_LOOP: i32 = 19;
 pub const IPV6_V6ONLY: i32 = 27;
 pub const IP_TTL: i32 = 2;
 pub const IP_MULTICAST_TTL: i32 = 5;
+pub const IP_MULTICAST_IF: i32 = 6;
 pub const IP_MULTICAST_LOOP: i32 = 7;
 pub const IP_ADD_MEMBERSHIP: i32 = 3;
 pub const IP_DROP_MEMBERSHIP: i32 = 4;
@@ -83,6 +83,9 @@ pub struct OpenOptions {
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct FileType(c_short);
 
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FileTimes {}
+
 #[derive(Debug)]
 pub struct DirBuilder {}
 
@@ -126,6 +129,11 @@ pub fn set_readonly(&mut self, readonly: bool) {
     }
 }
 
+impl FileTimes {
+    pub fn set_accessed(&mut self, _t: SystemTime) {}
+    pub fn set_modified(&mut self, _t: SystemTime) {}
+}
+
 impl FileType {
     pub fn is_dir(&self) -> bool {
         self.is(abi::S_IFDIR)
@@ -136,6 +144,18 @@ pub fn is_file(&self) -> bool {
     pub fn is_symlink(&self) -> bool {
         false
     }
+    pub fn is_block_device(&self) -> bool {
+        self.is(abi::S_IFBLK)
+    }
+    pub fn is_char_device(&self) -> bool {
+        self.is(abi::S_IFCHR)
+    }
+    pub fn is_fifo(&self) -> bool {
+        self.is(abi::S_IFIFO)
+    }
+    pub fn is_socket(&self) -> bool {
+        false
+    }
 
     pub fn is(&self, mode: c_short) -> bool {
         self.0 & abi::S_IFMT == mode
@@ -433,6 +453,30 @@ pub fn duplicate(&self) -> io::Result<File> {
     pub fn set_permissions(&self, _perm: FilePermissions) -> io::Result<()> {
         unsupported()
     }
+
+    pub fn set_times(&self, _times: FileTimes) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        unsupported()
+    }
 }
 
 impl Drop for File {
@@ -519,6 +563,11 @@ pub fn symlink(_original: &Path, _link: &Path) -> io::Result<()> {
     unsupported()
 }
 
+pub fn symlink_dir(_original: &Path, _link: &Path) -> io::Result<()> {
+    // This target doesn't support symlinks
+    unsupported()
+}
+
 pub fn link(_src: &Path, _dst: &Path) -> io::Result<()> {
     // This target doesn't support symlinks
     unsupported()
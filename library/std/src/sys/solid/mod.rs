@@ -28,6 +28,8 @@ mod itron {
 pub(crate) mod error;
 pub mod fs;
 pub mod io;
+#[path = "../unsupported/mmap.rs"]
+pub mod mmap;
 pub mod net;
 pub mod os;
 #[path = "../unix/os_str.rs"]
@@ -305,6 +305,10 @@ pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.recv_with_flags(buf, 0)
     }
 
+    pub fn read_buf(&self, buf: &mut io::ReadBuf<'_>) -> io::Result<()> {
+        io::default_read_buf(|b| self.read(b), buf)
+    }
+
     pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.recv_with_flags(buf, MSG_PEEK)
     }
@@ -422,6 +426,14 @@ pub fn linger(&self) -> io::Result<Option<Duration>> {
         Ok((val.l_onoff != 0).then(|| Duration::from_secs(val.l_linger as u64)))
     }
 
+    pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+        super::unsupported()
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        super::unsupported()
+    }
+
     pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
         setsockopt(self, netc::IPPROTO_TCP, netc::TCP_NODELAY, nodelay as c_int)
     }
@@ -444,6 +456,10 @@ pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         if raw == 0 { Ok(None) } else { Ok(Some(io::Error::from_raw_os_error(raw as i32))) }
     }
 
+    pub fn ready(&self, _: bool, _: bool, _: Option<Duration>) -> io::Result<(bool, bool)> {
+        super::unsupported()
+    }
+
     // This method is used by sys_common code to abstract over targets.
     pub fn as_raw(&self) -> c_int {
         *self.as_inner()
@@ -11,6 +11,7 @@
 use crate::sys::fd::FileDesc;
 use crate::sys::time::SystemTime;
 use crate::sys::{cvt, cvt_r};
+use crate::sys_common::fs::TRUNCATE_APPEND_ERROR;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 
 #[cfg(any(
@@ -19,7 +20,7 @@
     target_os = "ios",
 ))]
 use crate::sys::weak::syscall;
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "android"))]
 use crate::sys::weak::weak;
 
 use libc::{c_int, mode_t};
@@ -64,7 +65,7 @@
     dirent64, fstat64, ftruncate64, lseek64, lstat64, off64_t, open64, readdir64_r, stat64,
 };
 
-pub use crate::sys_common::fs::{remove_dir_all, try_exists};
+pub use crate::sys_common::fs::try_exists;
 
 pub struct File(FileDesc);
 
@@ -228,7 +229,7 @@ pub struct DirEntry {
         target_os = "fuchsia",
         target_os = "redox"
     ))]
-    name: Box<[u8]>,
+    name: CString,
 }
 
 #[derive(Clone, Debug)]
@@ -243,6 +244,8 @@ pub struct OpenOptions {
     // system-specific
     custom_flags: i32,
     mode: mode_t,
+    #[cfg(target_os = "linux")]
+    tmpfile: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -250,6 +253,12 @@ pub struct FilePermissions {
     mode: mode_t,
 }
 
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FileTimes {
+    accessed: Option<SystemTime>,
+    modified: Option<SystemTime>,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct FileType {
     mode: mode_t,
@@ -415,6 +424,16 @@ pub fn mode(&self) -> u32 {
     }
 }
 
+impl FileTimes {
+    pub fn set_accessed(&mut self, t: SystemTime) {
+        self.accessed = Some(t);
+    }
+
+    pub fn set_modified(&mut self, t: SystemTime) {
+        self.modified = Some(t);
+    }
+}
+
 impl FileType {
     pub fn is_dir(&self) -> bool {
         self.is(libc::S_IFDIR)
@@ -425,6 +444,18 @@ pub fn is_file(&self) -> bool {
     pub fn is_symlink(&self) -> bool {
         self.is(libc::S_IFLNK)
     }
+    pub fn is_block_device(&self) -> bool {
+        self.is(libc::S_IFBLK)
+    }
+    pub fn is_char_device(&self) -> bool {
+        self.is(libc::S_IFCHR)
+    }
+    pub fn is_fifo(&self) -> bool {
+        self.is(libc::S_IFIFO)
+    }
+    pub fn is_socket(&self) -> bool {
+        self.is(libc::S_IFSOCK)
+    }
 
     pub fn is(&self, mode: mode_t) -> bool {
         self.mode & libc::S_IFMT == mode
@@ -455,8 +486,6 @@ impl Iterator for ReadDir {
         target_os = "illumos"
     ))]
     fn next(&mut self) -> Option<io::Result<DirEntry>> {
-        use crate::slice;
-
         unsafe {
             loop {
                 // Although readdir_r(3) would be a correct function to use here because
@@ -474,14 +503,9 @@ fn next(&mut self) -> Option<io::Result<DirEntry>> {
                     };
                 }
 
-                let name = (*entry_ptr).d_name.as_ptr();
-                let namelen = libc::strlen(name) as usize;
-
                 let ret = DirEntry {
                     entry: *entry_ptr,
-                    name: slice::from_raw_parts(name as *const u8, namelen as usize)
-                        .to_owned()
-                        .into_boxed_slice(),
+                    name: CStr::from_ptr((*entry_ptr).d_name.as_ptr()).to_owned(),
                     dir: Arc::clone(&self.inner),
                 };
                 if ret.name_bytes() != b"." && ret.name_bytes() != b".." {
@@ -577,7 +601,7 @@ pub fn metadata(&self) -> io::Result<FileAttr> {
         target_os = "vxworks"
     ))]
     pub fn file_type(&self) -> io::Result<FileType> {
-        lstat(&self.path()).map(|m| m.file_type())
+        self.metadata().map(|m| m.file_type())
     }
 
     #[cfg(not(any(
@@ -595,7 +619,10 @@ pub fn file_type(&self) -> io::Result<FileType> {
             libc::DT_SOCK => Ok(FileType { mode: libc::S_IFSOCK }),
             libc::DT_DIR => Ok(FileType { mode: libc::S_IFDIR }),
             libc::DT_BLK => Ok(FileType { mode: libc::S_IFBLK }),
-            _ => lstat(&self.path()).map(|m| m.file_type()),
+            // The filesystem didn't report the type (`DT_UNKNOWN`), so stat the
+            // entry. `metadata` does this relative to the directory's fd where
+            // possible, which avoids building the full path.
+            _ => self.metadata().map(|m| m.file_type()),
         }
     }
 
@@ -664,7 +691,22 @@ fn name_bytes(&self) -> &[u8] {
         target_os = "redox"
     ))]
     fn name_bytes(&self) -> &[u8] {
-        &*self.name
+        self.name.as_bytes()
+    }
+
+    #[cfg(not(any(
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "fuchsia",
+        target_os = "redox",
+        all(target_os = "macos", target_arch = "x86_64")
+    )))]
+    fn name_cstr(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.entry.d_name.as_ptr()) }
+    }
+    #[cfg(any(target_os = "solaris", target_os = "illumos", target_os = "fuchsia"))]
+    fn name_cstr(&self) -> &CStr {
+        &self.name
     }
 
     pub fn file_name_os_str(&self) -> &OsStr {
@@ -685,6 +727,8 @@ pub fn new() -> OpenOptions {
             // system-specific
             custom_flags: 0,
             mode: 0o666,
+            #[cfg(target_os = "linux")]
+            tmpfile: false,
         }
     }
 
@@ -713,6 +757,10 @@ pub fn custom_flags(&mut self, flags: i32) {
     pub fn mode(&mut self, mode: u32) {
         self.mode = mode as mode_t;
     }
+    #[cfg(target_os = "linux")]
+    pub fn tmpfile(&mut self, tmpfile: bool) {
+        self.tmpfile = tmpfile;
+    }
 
     fn get_access_mode(&self) -> io::Result<c_int> {
         match (self.read, self.write, self.append) {
@@ -735,11 +783,18 @@ fn get_creation_mode(&self) -> io::Result<c_int> {
             }
             (_, true) => {
                 if self.truncate && !self.create_new {
-                    return Err(Error::from_raw_os_error(libc::EINVAL));
+                    return Err(TRUNCATE_APPEND_ERROR);
                 }
             }
         }
 
+        #[cfg(target_os = "linux")]
+        if self.tmpfile {
+            // An unnamed temporary file is always created. `create_new` additionally
+            // prevents it from ever being linked into the filesystem.
+            return Ok(libc::O_TMPFILE | if self.create_new { libc::O_EXCL } else { 0 });
+        }
+
         Ok(match (self.create, self.truncate, self.create_new) {
             (false, false, false) => 0,
             (true, false, false) => libc::O_CREAT,
@@ -903,6 +958,265 @@ pub fn set_permissions(&self, perm: FilePermissions) -> io::Result<()> {
         cvt_r(|| unsafe { libc::fchmod(self.as_raw_fd(), perm.mode) })?;
         Ok(())
     }
+
+    pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
+        cfg_if::cfg_if! {
+            if #[cfg(any(target_os = "redox", target_os = "espidf"))] {
+                // Neither target provides `futimens` with `UTIME_OMIT` support.
+                let _ = times;
+                Err(io::Error::new_const(
+                    io::ErrorKind::Unsupported,
+                    &"setting file times not supported",
+                ))
+            } else {
+                let to_timespec = |time: Option<SystemTime>| match time {
+                    Some(time) => time.to_timespec(),
+                    None => libc::timespec { tv_sec: 0, tv_nsec: libc::UTIME_OMIT as _ },
+                };
+                let timespecs = [to_timespec(times.accessed), to_timespec(times.modified)];
+                self.futimens(&timespecs, times)
+            }
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "redox",
+        target_os = "espidf",
+        target_os = "macos",
+        target_os = "android"
+    )))]
+    fn futimens(&self, timespecs: &[libc::timespec; 2], _times: FileTimes) -> io::Result<()> {
+        cvt(unsafe { libc::futimens(self.as_raw_fd(), timespecs.as_ptr()) })?;
+        Ok(())
+    }
+
+    // `futimens` requires macOS 10.13 and Android API level 19, so look it up
+    // at runtime on those targets.
+    #[cfg(any(target_os = "macos", target_os = "android"))]
+    fn futimens(&self, timespecs: &[libc::timespec; 2], times: FileTimes) -> io::Result<()> {
+        weak!(fn futimens(c_int, *const libc::timespec) -> c_int);
+        if let Some(futimens) = futimens.get() {
+            cvt(unsafe { futimens(self.as_raw_fd(), timespecs.as_ptr()) })?;
+            return Ok(());
+        }
+
+        if cfg!(target_os = "android") {
+            return Err(io::Error::new_const(
+                io::ErrorKind::Unsupported,
+                &"setting file times requires Android API level 19",
+            ));
+        }
+
+        // `futimes` can't leave a timestamp unchanged, so read back the
+        // current value of any that were not set.
+        let (accessed, modified) = match (times.accessed, times.modified) {
+            (Some(accessed), Some(modified)) => (accessed, modified),
+            (accessed, modified) => {
+                let attr = self.file_attr()?;
+                let accessed = match accessed {
+                    Some(t) => t,
+                    None => attr.accessed()?,
+                };
+                let modified = match modified {
+                    Some(t) => t,
+                    None => attr.modified()?,
+                };
+                (accessed, modified)
+            }
+        };
+        let to_timeval = |time: SystemTime| {
+            let ts = time.to_timespec();
+            libc::timeval { tv_sec: ts.tv_sec, tv_usec: (ts.tv_nsec / 1000) as _ }
+        };
+        let timevals = [to_timeval(accessed), to_timeval(modified)];
+        cvt(unsafe { libc::futimes(self.as_raw_fd(), timevals.as_ptr()) })?;
+        Ok(())
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    ))]
+    pub fn lock(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_EX)
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    ))]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_SH)
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    ))]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.try_flock(libc::LOCK_EX)
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    ))]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.try_flock(libc::LOCK_SH)
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    ))]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_UN)
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    ))]
+    fn try_flock(&self, operation: c_int) -> io::Result<bool> {
+        match self.flock(operation | libc::LOCK_NB) {
+            Ok(()) => Ok(true),
+            Err(e) if e.raw_os_error() == Some(libc::EWOULDBLOCK) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    ))]
+    fn flock(&self, operation: c_int) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), operation) })?;
+        Ok(())
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    )))]
+    pub fn lock(&self) -> io::Result<()> {
+        Err(LOCK_UNSUPPORTED_ERROR)
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    )))]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        Err(LOCK_UNSUPPORTED_ERROR)
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    )))]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        Err(LOCK_UNSUPPORTED_ERROR)
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    )))]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        Err(LOCK_UNSUPPORTED_ERROR)
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "fuchsia",
+    )))]
+    pub fn unlock(&self) -> io::Result<()> {
+        Err(LOCK_UNSUPPORTED_ERROR)
+    }
 }
 
 impl DirBuilder {
@@ -921,6 +1235,20 @@ pub fn set_mode(&mut self, mode: u32) {
     }
 }
 
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "fuchsia",
+)))]
+const LOCK_UNSUPPORTED_ERROR: io::Error =
+    io::Error::new_const(io::ErrorKind::Unsupported, &"file locking is not supported");
+
 fn cstr(path: &Path) -> io::Result<CString> {
     Ok(CString::new(path.as_os_str().as_bytes())?)
 }
@@ -1138,6 +1466,11 @@ pub fn symlink(original: &Path, link: &Path) -> io::Result<()> {
     Ok(())
 }
 
+pub fn symlink_dir(original: &Path, link: &Path) -> io::Result<()> {
+    // Symlinks do not record the type of their target on Unix.
+    symlink(original, link)
+}
+
 pub fn link(original: &Path, link: &Path) -> io::Result<()> {
     let original = cstr(original)?;
     let link = cstr(link)?;
@@ -1437,3 +1770,139 @@ pub fn chroot(dir: &Path) -> io::Result<()> {
     cvt(unsafe { libc::chroot(dir.as_ptr()) })?;
     Ok(())
 }
+
+pub use remove_dir_impl::remove_dir_all;
+
+// Fallback for REDOX, and for macOS on x86_64 where the minimum supported
+// release predates `openat`, `fdopendir` and `unlinkat`.
+#[cfg(any(target_os = "redox", all(target_os = "macos", target_arch = "x86_64")))]
+mod remove_dir_impl {
+    pub use crate::sys_common::fs::remove_dir_all;
+}
+
+// Modern implementation using openat(), unlinkat() and fdopendir()
+#[cfg(not(any(target_os = "redox", all(target_os = "macos", target_arch = "x86_64"))))]
+mod remove_dir_impl {
+    use super::{cstr, lstat, Dir, DirEntry, InnerReadDir, ReadDir};
+    use crate::ffi::CStr;
+    use crate::io;
+    use crate::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+    use crate::path::{Path, PathBuf};
+    use crate::sync::Arc;
+    use crate::sys::{cvt, cvt_r};
+    use libc::{fdopendir, openat, unlinkat};
+
+    pub fn openat_nofollow_dironly(parent_fd: Option<RawFd>, p: &CStr) -> io::Result<OwnedFd> {
+        let fd = cvt_r(|| unsafe {
+            openat(
+                parent_fd.unwrap_or(libc::AT_FDCWD),
+                p.as_ptr(),
+                libc::O_CLOEXEC | libc::O_RDONLY | libc::O_NOFOLLOW | libc::O_DIRECTORY,
+            )
+        })?;
+        Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+    }
+
+    fn fdreaddir(dir_fd: OwnedFd) -> io::Result<(ReadDir, RawFd)> {
+        let ptr = unsafe { fdopendir(dir_fd.as_raw_fd()) };
+        if ptr.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        let dirp = Dir(ptr);
+        // file descriptor is automatically closed by libc::closedir() now, so give up ownership
+        let new_parent_fd = dir_fd.into_raw_fd();
+        // a valid root is not needed because we do not call any functions involving the full path
+        // of the DirEntrys.
+        let dummy_root = PathBuf::new();
+        Ok((
+            ReadDir {
+                inner: Arc::new(InnerReadDir { dirp, root: dummy_root }),
+                #[cfg(not(any(
+                    target_os = "solaris",
+                    target_os = "illumos",
+                    target_os = "fuchsia",
+                    target_os = "redox",
+                )))]
+                end_of_stream: false,
+            },
+            new_parent_fd,
+        ))
+    }
+
+    #[cfg(any(
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+        target_os = "vxworks"
+    ))]
+    fn is_dir(_ent: &DirEntry) -> Option<bool> {
+        None
+    }
+
+    #[cfg(not(any(
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+        target_os = "vxworks"
+    )))]
+    fn is_dir(ent: &DirEntry) -> Option<bool> {
+        match ent.entry.d_type {
+            libc::DT_UNKNOWN => None,
+            libc::DT_DIR => Some(true),
+            _ => Some(false),
+        }
+    }
+
+    fn remove_dir_all_recursive(parent_fd: Option<RawFd>, p: &Path) -> io::Result<()> {
+        let pcstr = cstr(p)?;
+
+        // entry is expected to be a directory, open as such
+        let fd = openat_nofollow_dironly(parent_fd, &pcstr)?;
+
+        // open the directory passing ownership of the fd
+        let (dir, fd) = fdreaddir(fd)?;
+        for child in dir {
+            let child = child?;
+            match is_dir(&child) {
+                Some(true) => {
+                    remove_dir_all_recursive(Some(fd), Path::new(&child.file_name()))?;
+                }
+                Some(false) => {
+                    cvt(unsafe { unlinkat(fd, child.name_cstr().as_ptr(), 0) })?;
+                }
+                None => match cvt(unsafe { unlinkat(fd, child.name_cstr().as_ptr(), 0) }) {
+                    // type unknown - try to unlink
+                    Err(err)
+                        if err.raw_os_error() == Some(libc::EISDIR)
+                            || err.raw_os_error() == Some(libc::EPERM) =>
+                    {
+                        // if the file is a directory unlink fails with EISDIR on Linux and EPERM
+                        // everywhere else
+                        remove_dir_all_recursive(Some(fd), Path::new(&child.file_name()))?;
+                    }
+                    result => {
+                        result?;
+                    }
+                },
+            }
+        }
+
+        // unlink the directory after removing its contents
+        cvt(unsafe {
+            unlinkat(parent_fd.unwrap_or(libc::AT_FDCWD), pcstr.as_ptr(), libc::AT_REMOVEDIR)
+        })?;
+        Ok(())
+    }
+
+    pub fn remove_dir_all(p: &Path) -> io::Result<()> {
+        // We cannot just call remove_dir_all_recursive() here because that would not delete a passed
+        // symlink. No need to worry about races, because remove_dir_all_recursive() does not recurse
+        // into symlinks.
+        let attr = lstat(p)?;
+        if attr.file_type().is_symlink() {
+            crate::fs::remove_file(p)
+        } else {
+            remove_dir_all_recursive(None, p)
+        }
+    }
+}
@@ -49,12 +49,11 @@
 use crate::fs::{File, Metadata};
 use crate::io::copy::generic_copy;
 use crate::io::{
-    BufRead, BufReader, BufWriter, Error, Read, Result, StderrLock, StdinLock, StdoutLock, Take,
-    Write,
+    BufRead, BufReader, BufWriter, Error, Read, Result, Stderr, StderrLock, StdinLock, Stdout,
+    StdoutLock, Take, Write,
 };
 use crate::mem::ManuallyDrop;
 use crate::net::TcpStream;
-use crate::os::unix::fs::FileTypeExt;
 use crate::os::unix::io::{AsRawFd, FromRawFd, RawFd};
 use crate::os::unix::net::UnixStream;
 use crate::process::{ChildStderr, ChildStdin, ChildStdout};
@@ -396,6 +395,33 @@ fn properties(&self) -> CopyParams {
     }
 }
 
+// `Copier` flushes any output buffered in the unlocked handles before it starts
+// writing to the file descriptor directly. Concurrent writes from other threads
+// may interleave with the copied data, just as they can with the generic loop.
+impl CopyWrite for Stdout {
+    fn properties(&self) -> CopyParams {
+        CopyParams(FdMeta::NoneObtained, Some(self.as_raw_fd()))
+    }
+}
+
+impl CopyWrite for &Stdout {
+    fn properties(&self) -> CopyParams {
+        CopyParams(FdMeta::NoneObtained, Some(self.as_raw_fd()))
+    }
+}
+
+impl CopyWrite for Stderr {
+    fn properties(&self) -> CopyParams {
+        CopyParams(FdMeta::NoneObtained, Some(self.as_raw_fd()))
+    }
+}
+
+impl CopyWrite for &Stderr {
+    fn properties(&self) -> CopyParams {
+        CopyParams(FdMeta::NoneObtained, Some(self.as_raw_fd()))
+    }
+}
+
 impl CopyWrite for StdoutLock<'_> {
     fn properties(&self) -> CopyParams {
         CopyParams(FdMeta::NoneObtained, Some(self.as_raw_fd()))
@@ -106,6 +106,14 @@ pub fn linger(&self) -> io::Result<Option<Duration>> {
             unimpl!();
         }
 
+        pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+            unimpl!();
+        }
+
         pub fn set_nodelay(&self, _: bool) -> io::Result<()> {
             unimpl!();
         }
@@ -121,6 +129,10 @@ pub fn set_nonblocking(&self, _: bool) -> io::Result<()> {
         pub fn take_error(&self) -> io::Result<Option<io::Error>> {
             unimpl!();
         }
+
+        pub fn ready(&self, _: bool, _: bool, _: Option<Duration>) -> io::Result<(bool, bool)> {
+            unimpl!();
+        }
     }
 
     impl AsInner<libc::c_int> for Socket {
@@ -230,6 +242,14 @@ pub fn linger(&self) -> io::Result<Option<Duration>> {
             unimpl!();
         }
 
+        pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+            unimpl!();
+        }
+
         pub fn set_nodelay(&self, _: bool) -> io::Result<()> {
             unimpl!();
         }
@@ -250,6 +270,10 @@ pub fn take_error(&self) -> io::Result<Option<io::Error>> {
             unimpl!();
         }
 
+        pub fn ready(&self, _: bool, _: bool, _: Option<Duration>) -> io::Result<(bool, bool)> {
+            unimpl!();
+        }
+
         pub fn set_nonblocking(&self, _: bool) -> io::Result<()> {
             unimpl!();
         }
@@ -422,6 +446,30 @@ pub fn multicast_loop_v6(&self) -> io::Result<bool> {
             unimpl!();
         }
 
+        pub fn set_multicast_hops_v6(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn multicast_hops_v6(&self) -> io::Result<u32> {
+            unimpl!();
+        }
+
+        pub fn set_multicast_if_v4(&self, _: &Ipv4Addr) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn multicast_if_v4(&self) -> io::Result<Ipv4Addr> {
+            unimpl!();
+        }
+
+        pub fn set_multicast_if_v6(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn multicast_if_v6(&self) -> io::Result<u32> {
+            unimpl!();
+        }
+
         pub fn join_multicast_v4(&self, _: &Ipv4Addr, _: &Ipv4Addr) -> io::Result<()> {
             unimpl!();
         }
@@ -466,6 +514,14 @@ pub fn send(&self, _: &[u8]) -> io::Result<usize> {
             unimpl!();
         }
 
+        pub fn recv_vectored(&self, _: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+            unimpl!();
+        }
+
+        pub fn send_vectored(&self, _: &[IoSlice<'_>]) -> io::Result<usize> {
+            unimpl!();
+        }
+
         pub fn connect(&self, _: io::Result<&SocketAddr>) -> io::Result<()> {
             unimpl!();
         }
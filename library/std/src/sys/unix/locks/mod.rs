@@ -0,0 +1,40 @@
+//! Locking primitives for Unix platforms.
+//!
+//! On Linux and Android the locks are built directly on top of futexes. They
+//! are small, need no initialization or cleanup, and can be moved freely, so
+//! they don't have to be boxed. All other platforms use the pthread
+//! primitives, which must stay at a fixed address once they've been used.
+
+#[cfg_attr(any(target_os = "linux", target_os = "android"), allow(dead_code))]
+mod pthread_mutex;
+
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "linux", target_os = "android"))] {
+        mod futex;
+        mod futex_rwlock;
+
+        pub mod mutex {
+            pub use super::futex::{MovableMutex, Mutex};
+            pub use super::pthread_mutex::ReentrantMutex;
+        }
+        pub mod condvar {
+            pub use super::futex::{Condvar, MovableCondvar};
+        }
+        pub mod rwlock {
+            pub use super::futex_rwlock::{MovableRWLock, RWLock};
+        }
+    } else {
+        mod pthread_condvar;
+        mod pthread_rwlock;
+
+        pub mod mutex {
+            pub use super::pthread_mutex::{MovableMutex, Mutex, ReentrantMutex};
+        }
+        pub mod condvar {
+            pub use super::pthread_condvar::{Condvar, MovableCondvar};
+        }
+        pub mod rwlock {
+            pub use super::pthread_rwlock::{MovableRWLock, RWLock};
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::cell::UnsafeCell;
-use crate::sys::mutex::{self, Mutex};
+use crate::sys::locks::pthread_mutex::{self as mutex, Mutex};
 use crate::time::Duration;
 
 pub struct Condvar {
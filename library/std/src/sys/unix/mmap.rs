@@ -0,0 +1,52 @@
+use crate::convert::TryFrom;
+use crate::io;
+use crate::os::unix::io::AsRawFd;
+use crate::ptr::{self, NonNull};
+use crate::slice;
+use crate::sys::fs::File;
+
+pub struct Mmap {
+    ptr: NonNull<u8>,
+    len: usize,
+}
+
+// SAFETY: the mapping is read-only and owned by this value.
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+pub fn map_readonly(file: &File) -> io::Result<Mmap> {
+    let len = usize::try_from(file.file_attr()?.size()).map_err(|_| {
+        io::Error::new_const(io::ErrorKind::InvalidInput, &"file is too large to be mapped")
+    })?;
+    // `mmap` rejects a length of zero, so an empty file is represented
+    // without a mapping.
+    if len == 0 {
+        return Ok(Mmap { ptr: NonNull::dangling(), len: 0 });
+    }
+    let ptr = unsafe {
+        libc::mmap(ptr::null_mut(), len, libc::PROT_READ, libc::MAP_SHARED, file.as_raw_fd(), 0)
+    };
+    if ptr == libc::MAP_FAILED {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: `mmap` doesn't return null on success without `MAP_FIXED`.
+    Ok(Mmap { ptr: unsafe { NonNull::new_unchecked(ptr as *mut u8) }, len })
+}
+
+impl Mmap {
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: `ptr` points to a readable mapping of `len` bytes which
+        // lives as long as `self`.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            unsafe {
+                libc::munmap(self.ptr.as_ptr() as *mut libc::c_void, self.len);
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::io::ErrorKind;
 
+pub use self::locks::{condvar, mutex, rwlock};
 pub use self::rand::hashmap_random_keys;
 pub use libc::strlen;
 
@@ -14,7 +15,6 @@
 pub mod args;
 #[path = "../unix/cmath.rs"]
 pub mod cmath;
-pub mod condvar;
 pub mod env;
 pub mod fd;
 pub mod fs;
@@ -24,8 +24,13 @@
 pub mod kernel_copy;
 #[cfg(target_os = "l4re")]
 mod l4re;
+pub mod locks;
 pub mod memchr;
-pub mod mutex;
+#[cfg(not(target_os = "espidf"))]
+pub mod mmap;
+#[cfg(target_os = "espidf")]
+#[path = "../unsupported/mmap.rs"]
+pub mod mmap;
 #[cfg(not(target_os = "l4re"))]
 pub mod net;
 #[cfg(target_os = "l4re")]
@@ -36,7 +41,6 @@
 pub mod pipe;
 pub mod process;
 pub mod rand;
-pub mod rwlock;
 pub mod stack_overflow;
 pub mod stdio;
 pub mod thread;
@@ -150,6 +154,7 @@ pub fn decode_error_kind(errno: i32) -> ErrorKind {
         libc::EEXIST => AlreadyExists,
         libc::EFBIG => FileTooLarge,
         libc::EHOSTUNREACH => HostUnreachable,
+        libc::EINPROGRESS => InProgress,
         libc::EINTR => Interrupted,
         libc::EINVAL => InvalidInput,
         libc::EISDIR => IsADirectory,
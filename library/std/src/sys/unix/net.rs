@@ -1,6 +1,6 @@
 use crate::cmp;
 use crate::ffi::CStr;
-use crate::io::{self, IoSlice, IoSliceMut};
+use crate::io::{self, IoSlice, IoSliceMut, ReadBuf};
 use crate::mem;
 use crate::net::{Shutdown, SocketAddr};
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
@@ -12,6 +12,18 @@
 
 use libc::{c_int, c_void, size_t, sockaddr, socklen_t, MSG_PEEK};
 
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+use libc::TCP_KEEPALIVE as TCP_KEEPIDLE;
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "linux",
+    target_os = "netbsd",
+))]
+use libc::TCP_KEEPIDLE;
+
 cfg_if::cfg_if! {
     if #[cfg(target_vendor = "apple")] {
         use libc::SO_LINGER_SEC as SO_LINGER;
@@ -68,6 +80,10 @@ pub fn new(addr: &SocketAddr, ty: c_int) -> io::Result<Socket> {
     }
 
     pub fn new_raw(fam: c_int, ty: c_int) -> io::Result<Socket> {
+        Socket::new_with_protocol(fam, ty, 0)
+    }
+
+    pub fn new_with_protocol(fam: c_int, ty: c_int, protocol: c_int) -> io::Result<Socket> {
         unsafe {
             cfg_if::cfg_if! {
                 if #[cfg(any(
@@ -82,10 +98,10 @@ pub fn new_raw(fam: c_int, ty: c_int) -> io::Result<Socket> {
                     // On platforms that support it we pass the SOCK_CLOEXEC
                     // flag to atomically create the socket and set it as
                     // CLOEXEC. On Linux this was added in 2.6.27.
-                    let fd = cvt(libc::socket(fam, ty | libc::SOCK_CLOEXEC, 0))?;
+                    let fd = cvt(libc::socket(fam, ty | libc::SOCK_CLOEXEC, protocol))?;
                     Ok(Socket(FileDesc::from_raw_fd(fd)))
                 } else {
-                    let fd = cvt(libc::socket(fam, ty, 0))?;
+                    let fd = cvt(libc::socket(fam, ty, protocol))?;
                     let fd = FileDesc::from_raw_fd(fd);
                     fd.set_cloexec()?;
                     let socket = Socket(fd);
@@ -146,8 +162,7 @@ pub fn connect_timeout(&self, addr: &SocketAddr, timeout: Duration) -> io::Resul
 
         match r {
             Ok(_) => return Ok(()),
-            // there's no ErrorKind for EINPROGRESS :(
-            Err(ref e) if e.raw_os_error() == Some(libc::EINPROGRESS) => {}
+            Err(ref e) if e.kind() == io::ErrorKind::InProgress => {}
             Err(e) => return Err(e),
         }
 
@@ -251,6 +266,10 @@ pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.recv_with_flags(buf, 0)
     }
 
+    pub fn read_buf(&self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.0.read_buf(buf)
+    }
+
     pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.recv_with_flags(buf, MSG_PEEK)
     }
@@ -399,6 +418,85 @@ pub fn linger(&self) -> io::Result<Option<Duration>> {
         Ok((val.l_onoff != 0).then(|| Duration::from_secs(val.l_linger as u64)))
     }
 
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+    ))]
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        if let Some(idle) = keepalive {
+            // The idle time is in whole seconds and must be at least one.
+            let secs = cmp::min(cmp::max(idle.as_secs(), 1), c_int::MAX as u64) as c_int;
+            setsockopt(self, libc::IPPROTO_TCP, TCP_KEEPIDLE, secs)?;
+        }
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE, keepalive.is_some() as c_int)
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+    ))]
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        let enabled: c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE)?;
+        if enabled == 0 {
+            return Ok(None);
+        }
+        let secs: c_int = getsockopt(self, libc::IPPROTO_TCP, TCP_KEEPIDLE)?;
+        Ok(Some(Duration::from_secs(secs as u64)))
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+    )))]
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        if keepalive.is_some() {
+            return Err(io::Error::new_const(
+                io::ErrorKind::Unsupported,
+                &"the keepalive idle time cannot be set per socket on this platform",
+            ));
+        }
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE, 0 as c_int)
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+    )))]
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        let enabled: c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE)?;
+        if enabled == 0 {
+            return Ok(None);
+        }
+        Err(io::Error::new_const(
+            io::ErrorKind::Unsupported,
+            &"the keepalive idle time cannot be queried per socket on this platform",
+        ))
+    }
+
     pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
         setsockopt(self, libc::IPPROTO_TCP, libc::TCP_NODELAY, nodelay as c_int)
     }
@@ -437,6 +535,68 @@ pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         if raw == 0 { Ok(None) } else { Ok(Some(io::Error::from_raw_os_error(raw as i32))) }
     }
 
+    pub fn ready(
+        &self,
+        readable: bool,
+        writable: bool,
+        timeout: Option<Duration>,
+    ) -> io::Result<(bool, bool)> {
+        let mut events = 0;
+        if readable {
+            events |= libc::POLLIN;
+        }
+        if writable {
+            events |= libc::POLLOUT;
+        }
+        let mut pollfd = libc::pollfd { fd: self.as_raw_fd(), events, revents: 0 };
+
+        // A deadline too far in the future to represent is treated as no
+        // deadline at all.
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+
+        loop {
+            let timeout = match deadline {
+                None => -1,
+                Some(deadline) => {
+                    let timeout = deadline.saturating_duration_since(Instant::now());
+                    // Round up to whole milliseconds so that a nonzero timeout
+                    // never turns into a zero-length poll.
+                    let mut millis = timeout.as_millis();
+                    if timeout.subsec_nanos() % 1_000_000 != 0 {
+                        millis += 1;
+                    }
+                    cmp::min(millis, c_int::MAX as u128) as c_int
+                }
+            };
+
+            match unsafe { libc::poll(&mut pollfd, 1, timeout) } {
+                -1 => {
+                    let err = io::Error::last_os_error();
+                    if err.kind() != io::ErrorKind::Interrupted {
+                        return Err(err);
+                    }
+                }
+                0 => {
+                    return Err(io::Error::new_const(
+                        io::ErrorKind::TimedOut,
+                        &"timed out waiting for readiness",
+                    ));
+                }
+                _ => {
+                    // An error or hang-up makes every requested direction
+                    // ready, so that the caller's next operation reports it.
+                    if pollfd.revents & (libc::POLLERR | libc::POLLHUP | libc::POLLNVAL) != 0 {
+                        return Ok((readable, writable));
+                    }
+                    return Ok((
+                        pollfd.revents & libc::POLLIN != 0,
+                        pollfd.revents & libc::POLLOUT != 0,
+                    ));
+                }
+            }
+        }
+    }
+
     // This is used by sys_common code to abstract over Windows and Unix.
     pub fn as_raw(&self) -> RawFd {
         self.as_raw_fd()
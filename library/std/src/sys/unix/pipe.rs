@@ -1,3 +1,4 @@
+use crate::cmp;
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::mem;
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
@@ -44,7 +45,16 @@ pub fn anon_pipe() -> io::Result<(AnonPipe, AnonPipe)> {
     }
 }
 
+/// Creates a pipe for use by `std::io::pipe`. Both ends are close-on-exec.
+pub fn pipe() -> io::Result<(AnonPipe, AnonPipe)> {
+    anon_pipe()
+}
+
 impl AnonPipe {
+    pub fn try_clone(&self) -> io::Result<AnonPipe> {
+        self.0.duplicate().map(AnonPipe)
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
@@ -79,6 +89,20 @@ fn into_inner(self) -> FileDesc {
 }
 
 pub fn read2(p1: AnonPipe, v1: &mut Vec<u8>, p2: AnonPipe, v2: &mut Vec<u8>) -> io::Result<()> {
+    read2_limited(p1, v1, usize::MAX, p2, v2, usize::MAX)
+}
+
+/// Like `read2`, but stops appending to `v1` and `v2` once they hold `l1` and
+/// `l2` bytes respectively. Anything past a limit is still read from the pipe
+/// and dropped, so the writer never blocks on a full pipe.
+pub fn read2_limited(
+    p1: AnonPipe,
+    v1: &mut Vec<u8>,
+    l1: usize,
+    p2: AnonPipe,
+    v2: &mut Vec<u8>,
+    l2: usize,
+) -> io::Result<()> {
     // Set both pipes into nonblocking mode as we're gonna be reading from both
     // in the `select` loop below, and we wouldn't want one to block the other!
     let p1 = p1.into_inner();
@@ -95,13 +119,13 @@ pub fn read2(p1: AnonPipe, v1: &mut Vec<u8>, p2: AnonPipe, v2: &mut Vec<u8>) ->
         // wait for either pipe to become readable using `poll`
         cvt_r(|| unsafe { libc::poll(fds.as_mut_ptr(), 2, -1) })?;
 
-        if fds[0].revents != 0 && read(&p1, v1)? {
+        if fds[0].revents != 0 && read(&p1, v1, l1)? {
             p2.set_nonblocking(false)?;
-            return p2.read_to_end(v2).map(drop);
+            return read(&p2, v2, l2).map(drop);
         }
-        if fds[1].revents != 0 && read(&p2, v2)? {
+        if fds[1].revents != 0 && read(&p2, v2, l2)? {
             p1.set_nonblocking(false)?;
-            return p1.read_to_end(v1).map(drop);
+            return read(&p1, v1, l1).map(drop);
         }
     }
 
@@ -110,9 +134,14 @@ pub fn read2(p1: AnonPipe, v1: &mut Vec<u8>, p2: AnonPipe, v2: &mut Vec<u8>) ->
     // reader will return Ok(0), in which case we'll see `Ok` ourselves. In
     // this case we flip the other fd back into blocking mode and read
     // whatever's leftover on that file descriptor.
-    fn read(fd: &FileDesc, dst: &mut Vec<u8>) -> Result<bool, io::Error> {
-        match fd.read_to_end(dst) {
-            Ok(_) => Ok(true),
+    fn read(fd: &FileDesc, dst: &mut Vec<u8>, limit: usize) -> Result<bool, io::Error> {
+        let res = if limit == usize::MAX {
+            fd.read_to_end(dst).map(drop)
+        } else {
+            read_limited(fd, dst, limit)
+        };
+        match res {
+            Ok(()) => Ok(true),
             Err(e) => {
                 if e.raw_os_error() == Some(libc::EWOULDBLOCK)
                     || e.raw_os_error() == Some(libc::EAGAIN)
@@ -124,6 +153,23 @@ fn read(fd: &FileDesc, dst: &mut Vec<u8>) -> Result<bool, io::Error> {
             }
         }
     }
+
+    // Reads until EOF, keeping at most `limit` bytes in `dst` and discarding
+    // the rest.
+    fn read_limited(fd: &FileDesc, dst: &mut Vec<u8>, limit: usize) -> io::Result<()> {
+        let mut buf = [0; 8 * 1024];
+        loop {
+            match fd.read(&mut buf) {
+                Ok(0) => return Ok(()),
+                Ok(n) => {
+                    let keep = cmp::min(n, limit.saturating_sub(dst.len()));
+                    dst.extend_from_slice(&buf[..keep]);
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
 }
 
 impl AsRawFd for AnonPipe {
@@ -9,6 +9,7 @@
 use crate::io;
 use crate::path::Path;
 use crate::ptr;
+use crate::sys::cvt;
 use crate::sys::fd::FileDesc;
 use crate::sys::fs::File;
 use crate::sys::pipe::{self, AnonPipe};
@@ -18,7 +19,7 @@
 #[cfg(not(target_os = "fuchsia"))]
 use crate::sys::fs::OpenOptions;
 
-use libc::{c_char, c_int, gid_t, uid_t, EXIT_FAILURE, EXIT_SUCCESS};
+use libc::{c_char, c_int, gid_t, pid_t, uid_t, EXIT_FAILURE, EXIT_SUCCESS};
 
 cfg_if::cfg_if! {
     if #[cfg(target_os = "fuchsia")] {
@@ -77,6 +78,10 @@ pub struct Command {
     saw_nul: bool,
     closures: Vec<Box<dyn FnMut() -> io::Result<()> + Send + Sync>>,
     groups: Option<Box<[gid_t]>>,
+    pgroup: Option<pid_t>,
+    // Sorted by target descriptor.
+    fd_mappings: Vec<(RawFd, OwnedFd)>,
+    close_fds: bool,
     stdin: Option<Stdio>,
     stdout: Option<Stdio>,
     stderr: Option<Stdio>,
@@ -142,6 +147,9 @@ pub fn new(program: &OsStr) -> Command {
             saw_nul,
             closures: Vec::new(),
             groups: None,
+            pgroup: None,
+            fd_mappings: Vec::new(),
+            close_fds: false,
             stdin: None,
             stdout: None,
             stderr: None,
@@ -163,6 +171,9 @@ pub fn new(program: &OsStr) -> Command {
             saw_nul,
             closures: Vec::new(),
             groups: None,
+            pgroup: None,
+            fd_mappings: Vec::new(),
+            close_fds: false,
             stdin: None,
             stdout: None,
             stderr: None,
@@ -202,6 +213,18 @@ pub fn gid(&mut self, id: gid_t) {
     pub fn groups(&mut self, groups: &[gid_t]) {
         self.groups = Some(Box::from(groups));
     }
+    pub fn pgroup(&mut self, pgroup: pid_t) {
+        self.pgroup = Some(pgroup);
+    }
+    pub fn map_fd(&mut self, child_fd: RawFd, fd: OwnedFd) {
+        match self.fd_mappings.binary_search_by_key(&child_fd, |&(target, _)| target) {
+            Ok(i) => self.fd_mappings[i].1 = fd,
+            Err(i) => self.fd_mappings.insert(i, (child_fd, fd)),
+        }
+    }
+    pub fn close_fds(&mut self, close: bool) {
+        self.close_fds = close;
+    }
 
     #[cfg(target_os = "linux")]
     pub fn create_pidfd(&mut self, val: bool) {
@@ -265,6 +288,51 @@ pub fn get_gid(&self) -> Option<gid_t> {
     pub fn get_groups(&self) -> Option<&[gid_t]> {
         self.groups.as_deref()
     }
+    #[allow(dead_code)]
+    pub fn get_pgroup(&self) -> Option<pid_t> {
+        self.pgroup
+    }
+    #[allow(dead_code)]
+    pub fn get_fd_mappings(&self) -> &[(RawFd, OwnedFd)] {
+        &self.fd_mappings
+    }
+    #[allow(dead_code)]
+    pub fn get_close_fds(&self) -> bool {
+        self.close_fds
+    }
+
+    /// The highest descriptor the child may overwrite before exec, either
+    /// while setting up stdio or while applying `fd_mappings`.
+    fn highest_clobbered_fd(&self) -> RawFd {
+        self.fd_mappings.iter().map(|&(target, _)| target).fold(libc::STDERR_FILENO, RawFd::max)
+    }
+
+    /// Moves every mapped descriptor above `highest_clobbered_fd`, so that
+    /// the `dup2` calls in the child can be applied in any order without one
+    /// overwriting the source of another.
+    #[allow(dead_code)]
+    pub fn prepare_fd_mappings(&mut self) -> io::Result<()> {
+        let ceiling = self.highest_clobbered_fd();
+        for (_, fd) in self.fd_mappings.iter_mut() {
+            if fd.as_raw_fd() <= ceiling {
+                *fd = dup_above(fd.as_fd(), ceiling)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Makes sure `pipe` survives applying `fd_mappings` in the child, moving
+    /// it to a higher number if necessary.
+    #[allow(dead_code)]
+    pub fn avoid_fd_mappings(&self, pipe: AnonPipe) -> io::Result<AnonPipe> {
+        let ceiling = self.highest_clobbered_fd();
+        if self.fd_mappings.is_empty() || pipe.as_raw_fd() > ceiling {
+            Ok(pipe)
+        } else {
+            let fd = dup_above(pipe.as_fd(), ceiling)?;
+            Ok(unsafe { AnonPipe::from_raw_fd(fd.into_raw_fd()) })
+        }
+    }
 
     pub fn get_closures(&mut self) -> &mut Vec<Box<dyn FnMut() -> io::Result<()> + Send + Sync>> {
         &mut self.closures
@@ -457,6 +525,13 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+// Duplicates `fd` to the lowest free descriptor greater than `floor`, with
+// close-on-exec set.
+fn dup_above(fd: BorrowedFd<'_>, floor: RawFd) -> io::Result<OwnedFd> {
+    let fd = cvt(unsafe { libc::fcntl(fd.as_raw_fd(), libc::F_DUPFD_CLOEXEC, floor + 1) })?;
+    Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+}
+
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub struct ExitCode(u8);
 
@@ -474,6 +549,19 @@ impl ExitCode {
     pub fn as_i32(&self) -> i32 {
         self.0 as i32
     }
+
+    // `code` comes from `ExitStatus::code`, which only ever reports the low
+    // eight bits of the status, so this doesn't truncate.
+    #[inline]
+    pub fn from_status_code(code: i32) -> ExitCode {
+        ExitCode(code as u8)
+    }
+}
+
+impl From<u8> for ExitCode {
+    fn from(code: u8) -> Self {
+        Self(code)
+    }
 }
 
 pub struct CommandArgs<'a> {
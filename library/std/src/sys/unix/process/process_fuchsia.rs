@@ -29,6 +29,19 @@ pub fn spawn(
             ));
         }
 
+        if !self.get_fd_mappings().is_empty() {
+            return Err(io::Error::new_const(
+                io::ErrorKind::Unsupported,
+                &"mapping extra file descriptors is not supported on this platform",
+            ));
+        }
+        if self.get_close_fds() {
+            return Err(io::Error::new_const(
+                io::ErrorKind::Unsupported,
+                &"closing inherited file descriptors is not supported on this platform",
+            ));
+        }
+
         let (ours, theirs) = self.setup_io(default, needs_stdin)?;
 
         let process_handle = unsafe { self.do_exec(theirs, envp.as_ref())? };
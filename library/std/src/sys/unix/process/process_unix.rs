@@ -4,6 +4,7 @@
 use crate::mem;
 use crate::num::NonZeroI32;
 use crate::os::raw::NonZero_c_int;
+use crate::os::unix::io::AsRawFd;
 use crate::ptr;
 use crate::sys;
 use crate::sys::cvt;
@@ -51,12 +52,16 @@ pub fn spawn(
         }
 
         let (ours, theirs) = self.setup_io(default, needs_stdin)?;
+        self.prepare_fd_mappings()?;
 
         if let Some(ret) = self.posix_spawn(&theirs, envp.as_ref())? {
             return Ok((ret, ours));
         }
 
         let (input, output) = sys::pipe::anon_pipe()?;
+        // The child reports exec failures through `output`, so it must not be
+        // one of the descriptors that `fd_mappings` is about to overwrite.
+        let output = self.avoid_fd_mappings(output)?;
 
         // Whatever happens after the fork is almost for sure going to touch or
         // look at the environment in one way or another (PATH in `execvp` or
@@ -228,6 +233,10 @@ pub fn exec(&mut self, default: Stdio) -> io::Error {
             );
         }
 
+        if let Err(e) = self.prepare_fd_mappings() {
+            return e;
+        }
+
         match self.setup_io(default, true) {
             Ok((_, theirs)) => {
                 unsafe {
@@ -290,6 +299,14 @@ unsafe fn do_exec(
         if let Some(fd) = stdio.stderr.fd() {
             cvt_r(|| libc::dup2(fd, libc::STDERR_FILENO))?;
         }
+        // `prepare_fd_mappings` made sure no source is also a target, so the
+        // new descriptor never has close-on-exec set.
+        for (child_fd, fd) in self.get_fd_mappings() {
+            cvt_r(|| libc::dup2(fd.as_raw_fd(), *child_fd))?;
+        }
+        if self.get_close_fds() {
+            self.cloexec_unmapped_fds()?;
+        }
 
         #[cfg(not(target_os = "l4re"))]
         {
@@ -320,6 +337,10 @@ unsafe fn do_exec(
             cvt(libc::chdir(cwd.as_ptr()))?;
         }
 
+        if let Some(pgroup) = self.get_pgroup() {
+            cvt(libc::setpgid(0, pgroup))?;
+        }
+
         // emscripten has no signal support.
         #[cfg(not(target_os = "emscripten"))]
         {
@@ -379,6 +400,22 @@ fn drop(&mut self) {
         Err(io::Error::last_os_error())
     }
 
+    // Sets close-on-exec on every descriptor above stderr that is not the
+    // target of a mapping, so that the child only inherits its stdio and the
+    // mapped descriptors. The pipe reporting exec failures is already
+    // close-on-exec, so it keeps working. Like the rest of `do_exec`, this
+    // must not allocate.
+    unsafe fn cloexec_unmapped_fds(&self) -> io::Result<()> {
+        let mut first = libc::STDERR_FILENO + 1;
+        for &(target, _) in self.get_fd_mappings() {
+            if target >= first {
+                cloexec_fd_range(first, target - 1)?;
+                first = target + 1;
+            }
+        }
+        cloexec_fd_range(first, c_int::MAX)
+    }
+
     #[cfg(not(any(
         target_os = "macos",
         target_os = "freebsd",
@@ -415,6 +452,8 @@ fn posix_spawn(
             || !self.get_closures().is_empty()
             || self.get_groups().is_some()
             || self.get_create_pidfd()
+            || !self.get_fd_mappings().is_empty()
+            || self.get_close_fds()
         {
             return Ok(None);
         }
@@ -522,7 +561,11 @@ fn drop(&mut self) {
             cvt(sigaddset(set.as_mut_ptr(), libc::SIGPIPE))?;
             cvt_nz(libc::posix_spawnattr_setsigdefault(attrs.0.as_mut_ptr(), set.as_ptr()))?;
 
-            let flags = libc::POSIX_SPAWN_SETSIGDEF | libc::POSIX_SPAWN_SETSIGMASK;
+            let mut flags = libc::POSIX_SPAWN_SETSIGDEF | libc::POSIX_SPAWN_SETSIGMASK;
+            if let Some(pgroup) = self.get_pgroup() {
+                flags |= libc::POSIX_SPAWN_SETPGROUP;
+                cvt_nz(libc::posix_spawnattr_setpgroup(attrs.0.as_mut_ptr(), pgroup))?;
+            }
             cvt_nz(libc::posix_spawnattr_setflags(attrs.0.as_mut_ptr(), flags as _))?;
 
             // Make sure we synchronize access to the global `environ` resource
@@ -541,6 +584,53 @@ fn drop(&mut self) {
     }
 }
 
+// Sets close-on-exec on all open descriptors in `first..=last`.
+unsafe fn cloexec_fd_range(first: c_int, last: c_int) -> io::Result<()> {
+    if first > last {
+        return Ok(());
+    }
+
+    // `close_range` does this in one call since Linux 5.11.
+    #[cfg(target_os = "linux")]
+    {
+        const CLOSE_RANGE_CLOEXEC: libc::c_uint = 1 << 2;
+        raw_syscall! {
+            fn close_range(first: libc::c_uint, last: libc::c_uint, flags: libc::c_uint) -> c_int
+        }
+        if close_range(first as libc::c_uint, last as libc::c_uint, CLOSE_RANGE_CLOEXEC) == 0 {
+            return Ok(());
+        }
+    }
+
+    // Otherwise visit every descriptor below the current limit. Descriptors
+    // at or above it cannot be open, unless the limit was lowered after they
+    // were opened.
+    let mut limit = mem::MaybeUninit::<libc::rlimit>::uninit();
+    cvt(libc::getrlimit(libc::RLIMIT_NOFILE, limit.as_mut_ptr()))?;
+    let limit = limit.assume_init().rlim_cur;
+    if limit == libc::RLIM_INFINITY {
+        return Err(io::Error::new_const(
+            ErrorKind::Uncategorized,
+            &"cannot find open file descriptors without a descriptor limit",
+        ));
+    }
+    let last = if limit > last as libc::rlim_t { last } else { limit as c_int - 1 };
+    for fd in first..=last {
+        let flags = libc::fcntl(fd, libc::F_GETFD);
+        if flags == -1 {
+            // `EBADF` means there is no such descriptor.
+            if sys::os::errno() == libc::EBADF {
+                continue;
+            }
+            return Err(io::Error::last_os_error());
+        }
+        if flags & libc::FD_CLOEXEC == 0 {
+            cvt(libc::fcntl(fd, libc::F_SETFD, flags | libc::FD_CLOEXEC))?;
+        }
+    }
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Processes
 ////////////////////////////////////////////////////////////////////////////////
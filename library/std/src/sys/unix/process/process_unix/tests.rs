@@ -55,3 +55,78 @@ fn test_command_fork_no_unwind() {
     let signal = status.signal().expect("expected child process to die of signal");
     assert!(signal == libc::SIGABRT || signal == libc::SIGILL || signal == libc::SIGTRAP);
 }
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn test_process_group() {
+    use crate::process::Stdio;
+
+    // `cat` blocks on its piped stdin, so the child is still alive (and its
+    // process group still exists) until stdin is closed below.
+    let mut child = Command::new("cat").stdin(Stdio::piped()).process_group(0).spawn().unwrap();
+    let pgid = unsafe { libc::getpgid(child.id() as libc::pid_t) };
+    assert_eq!(pgid, child.id() as libc::pid_t);
+    assert_ne!(pgid, unsafe { libc::getpgrp() });
+
+    drop(child.stdin.take());
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+#[cfg_attr(any(target_os = "vxworks", target_os = "fuchsia"), ignore)]
+fn test_map_fd() {
+    use crate::io::{self, Read};
+    use crate::os::unix::io::{AsRawFd, OwnedFd};
+
+    let (mut r1, w1) = io::pipe().unwrap();
+    let (mut r2, w2) = io::pipe().unwrap();
+    let (fd1, fd2) = (w1.as_raw_fd(), w2.as_raw_fd());
+
+    // Swap the two writers, so each mapping's source is the other's target.
+    // Some shells only accept single-digit descriptors in `>&n`, so write
+    // through `/dev/fd` instead.
+    let mut cmd = Command::new("/bin/sh");
+    cmd.arg("-c")
+        .arg(format!("echo one >/dev/fd/{}; echo two >/dev/fd/{}", fd1, fd2))
+        .map_fd(fd1, OwnedFd::from(w2))
+        .map_fd(fd2, OwnedFd::from(w1));
+    assert!(cmd.status().unwrap().success());
+    drop(cmd);
+
+    let (mut s1, mut s2) = (String::new(), String::new());
+    r1.read_to_string(&mut s1).unwrap();
+    r2.read_to_string(&mut s2).unwrap();
+    assert_eq!(s1, "two\n");
+    assert_eq!(s2, "one\n");
+}
+
+#[test]
+#[cfg_attr(any(target_os = "vxworks", target_os = "fuchsia"), ignore)]
+fn test_close_fds() {
+    use crate::io::{self, Read};
+    use crate::os::unix::io::{AsRawFd, FromRawFd, OwnedFd};
+    use crate::process::Stdio;
+
+    let (mut r, w) = io::pipe().unwrap();
+    // A descriptor without close-on-exec, like one leaked by a careless library.
+    let leaked = unsafe { libc::dup(w.as_raw_fd()) };
+    assert!(leaked >= 0);
+    let leaked = unsafe { OwnedFd::from_raw_fd(leaked) };
+    let mapped = w.as_raw_fd();
+
+    let script =
+        format!("echo leaked >/dev/fd/{} || echo closed >/dev/fd/{}", leaked.as_raw_fd(), mapped);
+    let mut cmd = Command::new("/bin/sh");
+    cmd.arg("-c")
+        .arg(script)
+        .stderr(Stdio::null())
+        .map_fd(mapped, OwnedFd::from(w))
+        .close_fds(true);
+    assert!(cmd.status().unwrap().success());
+    drop(cmd);
+    drop(leaked);
+
+    let mut s = String::new();
+    r.read_to_string(&mut s).unwrap();
+    assert_eq!(s, "closed\n");
+}
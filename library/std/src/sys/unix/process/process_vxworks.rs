@@ -29,6 +29,18 @@ pub fn spawn(
                 &"nul byte found in provided data",
             ));
         }
+        if !self.get_fd_mappings().is_empty() {
+            return Err(io::Error::new_const(
+                ErrorKind::Unsupported,
+                &"mapping extra file descriptors is not supported on this platform",
+            ));
+        }
+        if self.get_close_fds() {
+            return Err(io::Error::new_const(
+                ErrorKind::Unsupported,
+                &"closing inherited file descriptors is not supported on this platform",
+            ));
+        }
         let (ours, theirs) = self.setup_io(default, needs_stdin)?;
         let mut p = Process { pid: 0, status: None };
 
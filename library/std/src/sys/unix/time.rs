@@ -215,6 +215,12 @@ fn from(t: libc::timespec) -> SystemTime {
         }
     }
 
+    impl SystemTime {
+        pub fn to_timespec(&self) -> libc::timespec {
+            self.t.t
+        }
+    }
+
     impl fmt::Debug for SystemTime {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             f.debug_struct("SystemTime")
@@ -353,6 +359,12 @@ fn from(t: libc::timespec) -> SystemTime {
         }
     }
 
+    impl SystemTime {
+        pub fn to_timespec(&self) -> libc::timespec {
+            self.t.t
+        }
+    }
+
     impl fmt::Debug for SystemTime {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             f.debug_struct("SystemTime")
@@ -21,6 +21,9 @@ pub struct OpenOptions {}
 
 pub struct FileType(!);
 
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FileTimes {}
+
 #[derive(Debug)]
 pub struct DirBuilder {}
 
@@ -86,6 +89,11 @@ fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+impl FileTimes {
+    pub fn set_accessed(&mut self, _t: SystemTime) {}
+    pub fn set_modified(&mut self, _t: SystemTime) {}
+}
+
 impl FileType {
     pub fn is_dir(&self) -> bool {
         self.0
@@ -98,6 +106,22 @@ pub fn is_file(&self) -> bool {
     pub fn is_symlink(&self) -> bool {
         self.0
     }
+
+    pub fn is_block_device(&self) -> bool {
+        self.0
+    }
+
+    pub fn is_char_device(&self) -> bool {
+        self.0
+    }
+
+    pub fn is_fifo(&self) -> bool {
+        self.0
+    }
+
+    pub fn is_socket(&self) -> bool {
+        self.0
+    }
 }
 
 impl Clone for FileType {
@@ -237,6 +261,30 @@ pub fn duplicate(&self) -> io::Result<File> {
     pub fn set_permissions(&self, _perm: FilePermissions) -> io::Result<()> {
         self.0
     }
+
+    pub fn set_times(&self, _times: FileTimes) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.0
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.0
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.0
+    }
 }
 
 impl DirBuilder {
@@ -291,6 +339,10 @@ pub fn symlink(_original: &Path, _link: &Path) -> io::Result<()> {
     unsupported()
 }
 
+pub fn symlink_dir(_original: &Path, _link: &Path) -> io::Result<()> {
+    unsupported()
+}
+
 pub fn link(_src: &Path, _dst: &Path) -> io::Result<()> {
     unsupported()
 }
@@ -8,6 +8,7 @@
 pub mod env;
 pub mod fs;
 pub mod io;
+pub mod mmap;
 pub mod mutex;
 pub mod net;
 pub mod os;
@@ -1,6 +1,6 @@
 use crate::convert::TryFrom;
 use crate::fmt;
-use crate::io::{self, IoSlice, IoSliceMut};
+use crate::io::{self, IoSlice, IoSliceMut, ReadBuf};
 use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
 use crate::sys::unsupported;
 use crate::time::Duration;
@@ -40,6 +40,10 @@ pub fn read(&self, _: &mut [u8]) -> io::Result<usize> {
         self.0
     }
 
+    pub fn read_buf(&self, _: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.0
+    }
+
     pub fn read_vectored(&self, _: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0
     }
@@ -84,6 +88,14 @@ pub fn linger(&self) -> io::Result<Option<Duration>> {
         self.0
     }
 
+    pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        self.0
+    }
+
     pub fn set_nodelay(&self, _: bool) -> io::Result<()> {
         self.0
     }
@@ -104,6 +116,10 @@ pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         self.0
     }
 
+    pub fn ready(&self, _: bool, _: bool, _: Option<Duration>) -> io::Result<(bool, bool)> {
+        self.0
+    }
+
     pub fn set_nonblocking(&self, _: bool) -> io::Result<()> {
         self.0
     }
@@ -244,6 +260,30 @@ pub fn multicast_loop_v6(&self) -> io::Result<bool> {
         self.0
     }
 
+    pub fn set_multicast_hops_v6(&self, _: u32) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn multicast_hops_v6(&self) -> io::Result<u32> {
+        self.0
+    }
+
+    pub fn set_multicast_if_v4(&self, _: &Ipv4Addr) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn multicast_if_v4(&self) -> io::Result<Ipv4Addr> {
+        self.0
+    }
+
+    pub fn set_multicast_if_v6(&self, _: u32) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn multicast_if_v6(&self) -> io::Result<u32> {
+        self.0
+    }
+
     pub fn join_multicast_v4(&self, _: &Ipv4Addr, _: &Ipv4Addr) -> io::Result<()> {
         self.0
     }
@@ -288,6 +328,14 @@ pub fn send(&self, _: &[u8]) -> io::Result<usize> {
         self.0
     }
 
+    pub fn recv_vectored(&self, _: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0
+    }
+
+    pub fn send_vectored(&self, _: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0
+    }
+
     pub fn connect(&self, _: io::Result<&SocketAddr>) -> io::Result<()> {
         self.0
     }
@@ -160,6 +160,16 @@ impl ExitCode {
     pub fn as_i32(&self) -> i32 {
         self.0 as i32
     }
+
+    pub fn from_status_code(code: i32) -> ExitCode {
+        ExitCode(code != 0)
+    }
+}
+
+impl From<u8> for ExitCode {
+    fn from(code: u8) -> Self {
+        ExitCode(code != 0)
+    }
 }
 
 pub struct Process(!);
@@ -63,6 +63,12 @@ pub struct FilePermissions {
     readonly: bool,
 }
 
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FileTimes {
+    accessed: Option<SystemTime>,
+    modified: Option<SystemTime>,
+}
+
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
 pub struct FileType {
     bits: wasi::Filetype,
@@ -112,6 +118,16 @@ pub fn set_readonly(&mut self, readonly: bool) {
     }
 }
 
+impl FileTimes {
+    pub fn set_accessed(&mut self, t: SystemTime) {
+        self.accessed = Some(t);
+    }
+
+    pub fn set_modified(&mut self, t: SystemTime) {
+        self.modified = Some(t);
+    }
+}
+
 impl FileType {
     pub fn is_dir(&self) -> bool {
         self.bits == wasi::FILETYPE_DIRECTORY
@@ -125,6 +141,22 @@ pub fn is_symlink(&self) -> bool {
         self.bits == wasi::FILETYPE_SYMBOLIC_LINK
     }
 
+    pub fn is_block_device(&self) -> bool {
+        self.bits == wasi::FILETYPE_BLOCK_DEVICE
+    }
+
+    pub fn is_char_device(&self) -> bool {
+        self.bits == wasi::FILETYPE_CHARACTER_DEVICE
+    }
+
+    pub fn is_fifo(&self) -> bool {
+        false
+    }
+
+    pub fn is_socket(&self) -> bool {
+        self.bits == wasi::FILETYPE_SOCKET_STREAM || self.bits == wasi::FILETYPE_SOCKET_DGRAM
+    }
+
     pub fn bits(&self) -> wasi::Filetype {
         self.bits
     }
@@ -447,6 +479,50 @@ pub fn set_permissions(&self, _perm: FilePermissions) -> io::Result<()> {
         unsupported()
     }
 
+    pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
+        let to_timestamp = |time: Option<SystemTime>| match time {
+            None => Ok(0),
+            Some(time) => time.to_wasi_timestamp().ok_or_else(|| {
+                io::Error::new_const(
+                    io::ErrorKind::InvalidInput,
+                    &"timestamp is out of range for a WASI timestamp",
+                )
+            }),
+        };
+        let mut flags = 0;
+        if times.accessed.is_some() {
+            flags |= wasi::FSTFLAGS_ATIM;
+        }
+        if times.modified.is_some() {
+            flags |= wasi::FSTFLAGS_MTIM;
+        }
+        self.fd.filestat_set_times(
+            to_timestamp(times.accessed)?,
+            to_timestamp(times.modified)?,
+            flags,
+        )
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn read_link(&self, file: &Path) -> io::Result<PathBuf> {
         read_link(&self.fd, file)
     }
@@ -588,6 +664,11 @@ pub fn symlink(original: &Path, link: &Path) -> io::Result<()> {
     link.symlink(osstr2str(original.as_ref())?, osstr2str(link_file.as_ref())?)
 }
 
+pub fn symlink_dir(original: &Path, link: &Path) -> io::Result<()> {
+    // Symlinks do not record the type of their target on WASI.
+    symlink(original, link)
+}
+
 pub fn link(original: &Path, link: &Path) -> io::Result<()> {
     let (original, original_file) = open_parent(original)?;
     let (link, link_file) = open_parent(link)?;
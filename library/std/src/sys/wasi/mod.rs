@@ -28,6 +28,8 @@
 pub mod fd;
 pub mod fs;
 pub mod io;
+#[path = "../unsupported/mmap.rs"]
+pub mod mmap;
 #[path = "../unsupported/mutex.rs"]
 pub mod mutex;
 pub mod net;
@@ -71,6 +73,7 @@ pub fn decode_error_kind(errno: i32) -> std_io::ErrorKind {
         wasi::ERRNO_ADDRNOTAVAIL => AddrNotAvailable,
         wasi::ERRNO_ADDRINUSE => AddrInUse,
         wasi::ERRNO_NOENT => NotFound,
+        wasi::ERRNO_INPROGRESS => InProgress,
         wasi::ERRNO_INTR => Interrupted,
         wasi::ERRNO_INVAL => InvalidInput,
         wasi::ERRNO_TIMEDOUT => TimedOut,
@@ -3,7 +3,7 @@
 use super::fd::WasiFd;
 use crate::convert::TryFrom;
 use crate::fmt;
-use crate::io::{self, IoSlice, IoSliceMut};
+use crate::io::{self, IoSlice, IoSliceMut, ReadBuf};
 use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
 use crate::os::wasi::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
 use crate::sys::unsupported;
@@ -91,6 +91,10 @@ pub fn read(&self, _: &mut [u8]) -> io::Result<usize> {
         unsupported()
     }
 
+    pub fn read_buf(&self, _: &mut ReadBuf<'_>) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn read_vectored(&self, _: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         unsupported()
     }
@@ -135,6 +139,14 @@ pub fn linger(&self) -> io::Result<Option<Duration>> {
         unsupported()
     }
 
+    pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        unsupported()
+    }
+
     pub fn set_nodelay(&self, _: bool) -> io::Result<()> {
         unsupported()
     }
@@ -155,6 +167,10 @@ pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         unsupported()
     }
 
+    pub fn ready(&self, _: bool, _: bool, _: Option<Duration>) -> io::Result<(bool, bool)> {
+        unsupported()
+    }
+
     pub fn set_nonblocking(&self, _: bool) -> io::Result<()> {
         unsupported()
     }
@@ -339,6 +355,30 @@ pub fn multicast_loop_v6(&self) -> io::Result<bool> {
         unsupported()
     }
 
+    pub fn set_multicast_hops_v6(&self, _: u32) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn multicast_hops_v6(&self) -> io::Result<u32> {
+        unsupported()
+    }
+
+    pub fn set_multicast_if_v4(&self, _: &Ipv4Addr) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn multicast_if_v4(&self) -> io::Result<Ipv4Addr> {
+        unsupported()
+    }
+
+    pub fn set_multicast_if_v6(&self, _: u32) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn multicast_if_v6(&self) -> io::Result<u32> {
+        unsupported()
+    }
+
     pub fn join_multicast_v4(&self, _: &Ipv4Addr, _: &Ipv4Addr) -> io::Result<()> {
         unsupported()
     }
@@ -383,6 +423,14 @@ pub fn send(&self, _: &[u8]) -> io::Result<usize> {
         unsupported()
     }
 
+    pub fn recv_vectored(&self, _: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        unsupported()
+    }
+
+    pub fn send_vectored(&self, _: &[IoSlice<'_>]) -> io::Result<usize> {
+        unsupported()
+    }
+
     pub fn connect(&self, _: io::Result<&SocketAddr>) -> io::Result<()> {
         unsupported()
     }
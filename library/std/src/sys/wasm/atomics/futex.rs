@@ -3,15 +3,15 @@
 use crate::sync::atomic::AtomicI32;
 use crate::time::Duration;
 
-pub fn futex_wait(futex: &AtomicI32, expected: i32, timeout: Option<Duration>) {
+/// Returns false if the wait timed out, and true otherwise.
+pub fn futex_wait(futex: &AtomicI32, expected: i32, timeout: Option<Duration>) -> bool {
     let timeout = timeout.and_then(|t| t.as_nanos().try_into().ok()).unwrap_or(-1);
     unsafe {
-        wasm32::memory_atomic_wait32(futex as *const AtomicI32 as *mut i32, expected, timeout);
+        wasm32::memory_atomic_wait32(futex as *const AtomicI32 as *mut i32, expected, timeout) < 2
     }
 }
 
-pub fn futex_wake(futex: &AtomicI32) {
-    unsafe {
-        wasm32::memory_atomic_notify(futex as *const AtomicI32 as *mut i32, 1);
-    }
+/// Returns true if this actually woke up a waiting thread.
+pub fn futex_wake(futex: &AtomicI32) -> bool {
+    unsafe { wasm32::memory_atomic_notify(futex as *const AtomicI32 as *mut i32, 1) > 0 }
 }
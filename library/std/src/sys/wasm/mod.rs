@@ -26,6 +26,8 @@
 pub mod fs;
 #[path = "../unsupported/io.rs"]
 pub mod io;
+#[path = "../unsupported/mmap.rs"]
+pub mod mmap;
 #[path = "../unsupported/net.rs"]
 pub mod net;
 #[path = "../unsupported/os.rs"]
@@ -32,6 +32,16 @@ pub fn args() -> Args {
     }
 }
 
+/// Returns the command line of the current process as a single string, before
+/// any parsing into arguments.
+pub fn command_line() -> OsString {
+    // SAFETY: `GetCommandLineW` returns a pointer to a null terminated UTF-16
+    // string so it's safe for `WStrUnits` to use.
+    let units = unsafe { WStrUnits::new(c::GetCommandLineW()) };
+    let wide: Vec<u16> = units.into_iter().flatten().map(NonZeroU16::get).collect();
+    OsString::from_wide(&wide)
+}
+
 /// Implements the Windows command-line argument parsing algorithm.
 ///
 /// Microsoft's documentation for the Windows CLI argument format can be found at
@@ -164,6 +174,12 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+impl Args {
+    pub fn as_slice(&self) -> &[OsString] {
+        self.parsed_args_list.as_slice()
+    }
+}
+
 impl Iterator for Args {
     type Item = OsString;
     fn next(&mut self) -> Option<OsString> {
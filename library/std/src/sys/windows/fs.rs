@@ -12,6 +12,7 @@
 use crate::sys::handle::Handle;
 use crate::sys::time::SystemTime;
 use crate::sys::{c, cvt};
+use crate::sys_common::fs::TRUNCATE_APPEND_ERROR;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 
 use super::path::maybe_verbatim;
@@ -34,6 +35,13 @@ pub struct FileAttr {
     file_index: Option<u64>,
 }
 
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FileTimes {
+    accessed: Option<SystemTime>,
+    modified: Option<SystemTime>,
+    created: Option<SystemTime>,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct FileType {
     attributes: c::DWORD,
@@ -258,7 +266,7 @@ fn get_creation_mode(&self) -> io::Result<c::DWORD> {
             }
             (_, true) => {
                 if self.truncate && !self.create_new {
-                    return Err(Error::from_raw_os_error(ERROR_INVALID_PARAMETER));
+                    return Err(TRUNCATE_APPEND_ERROR);
                 }
             }
         }
@@ -547,6 +555,88 @@ pub fn set_permissions(&self, perm: FilePermissions) -> io::Result<()> {
         })?;
         Ok(())
     }
+
+    pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
+        // Zero and `0xFFFF_FFFF_FFFF_FFFF` have special meanings for
+        // `SetFileTime`: they respectively leave the time unchanged and stop
+        // the following operations on the handle from updating it. Reject
+        // them so a time that happens to map to one of them isn't misread.
+        let to_filetime = |time: Option<SystemTime>| -> io::Result<Option<c::FILETIME>> {
+            match time {
+                None => Ok(None),
+                Some(time) => {
+                    let ft = time.into_inner();
+                    if (ft.dwLowDateTime, ft.dwHighDateTime) == (0, 0)
+                        || (ft.dwLowDateTime, ft.dwHighDateTime) == (!0, !0)
+                    {
+                        Err(io::Error::new_const(
+                            io::ErrorKind::InvalidInput,
+                            &"cannot set file timestamp to 0 or 0xFFFF_FFFF_FFFF_FFFF",
+                        ))
+                    } else {
+                        Ok(Some(ft))
+                    }
+                }
+            }
+        };
+        let as_ptr = |ft: &Option<c::FILETIME>| match ft {
+            Some(ft) => ft as *const c::FILETIME,
+            None => ptr::null(),
+        };
+        let created = to_filetime(times.created)?;
+        let accessed = to_filetime(times.accessed)?;
+        let modified = to_filetime(times.modified)?;
+        cvt(unsafe {
+            c::SetFileTime(
+                self.as_raw_handle(),
+                as_ptr(&created),
+                as_ptr(&accessed),
+                as_ptr(&modified),
+            )
+        })?;
+        Ok(())
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        self.lock_file_ex(c::LOCKFILE_EXCLUSIVE_LOCK)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock_file_ex(0)
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.try_lock_file_ex(c::LOCKFILE_EXCLUSIVE_LOCK)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.try_lock_file_ex(0)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        // Unlock the same range that `lock_file_ex` locks: the whole file,
+        // however large it grows.
+        cvt(unsafe { c::UnlockFile(self.handle.as_raw_handle(), 0, 0, !0, !0) })?;
+        Ok(())
+    }
+
+    fn try_lock_file_ex(&self, flags: c::DWORD) -> io::Result<bool> {
+        match self.lock_file_ex(flags | c::LOCKFILE_FAIL_IMMEDIATELY) {
+            Ok(()) => Ok(true),
+            Err(e) if e.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as i32) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn lock_file_ex(&self, flags: c::DWORD) -> io::Result<()> {
+        // The lock covers the whole range of possible offsets, starting at the
+        // offset given in the (otherwise unused) `OVERLAPPED` structure.
+        let mut overlapped: c::OVERLAPPED = unsafe { mem::zeroed() };
+        cvt(unsafe {
+            c::LockFileEx(self.handle.as_raw_handle(), flags, 0, !0, !0, &mut overlapped)
+        })?;
+        Ok(())
+    }
 }
 
 impl AsInner<Handle> for File {
@@ -675,6 +765,20 @@ pub fn set_readonly(&mut self, readonly: bool) {
     }
 }
 
+impl FileTimes {
+    pub fn set_accessed(&mut self, t: SystemTime) {
+        self.accessed = Some(t);
+    }
+
+    pub fn set_modified(&mut self, t: SystemTime) {
+        self.modified = Some(t);
+    }
+
+    pub fn set_created(&mut self, t: SystemTime) {
+        self.created = Some(t);
+    }
+}
+
 impl FileType {
     fn new(attrs: c::DWORD, reparse_tag: c::DWORD) -> FileType {
         FileType { attributes: attrs, reparse_tag }
@@ -694,6 +798,18 @@ pub fn is_symlink_dir(&self) -> bool {
     pub fn is_symlink_file(&self) -> bool {
         self.is_symlink() && !self.is_directory()
     }
+    pub fn is_block_device(&self) -> bool {
+        false
+    }
+    pub fn is_char_device(&self) -> bool {
+        false
+    }
+    pub fn is_fifo(&self) -> bool {
+        false
+    }
+    pub fn is_socket(&self) -> bool {
+        false
+    }
     fn is_directory(&self) -> bool {
         self.attributes & c::FILE_ATTRIBUTE_DIRECTORY != 0
     }
@@ -797,6 +913,10 @@ pub fn symlink(original: &Path, link: &Path) -> io::Result<()> {
     symlink_inner(original, link, false)
 }
 
+pub fn symlink_dir(original: &Path, link: &Path) -> io::Result<()> {
+    symlink_inner(original, link, true)
+}
+
 pub fn symlink_inner(original: &Path, link: &Path, dir: bool) -> io::Result<()> {
     let original = to_u16s(original)?;
     let link = maybe_verbatim(link)?;
@@ -0,0 +1,66 @@
+use crate::convert::TryFrom;
+use crate::io;
+use crate::os::windows::io::{AsRawHandle, FromRawHandle};
+use crate::ptr::{self, NonNull};
+use crate::slice;
+use crate::sys::c;
+use crate::sys::fs::File;
+use crate::sys::handle::Handle;
+
+pub struct Mmap {
+    ptr: NonNull<u8>,
+    len: usize,
+}
+
+// SAFETY: the mapping is read-only and owned by this value.
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+pub fn map_readonly(file: &File) -> io::Result<Mmap> {
+    let len = usize::try_from(file.file_attr()?.size()).map_err(|_| {
+        io::Error::new_const(io::ErrorKind::InvalidInput, &"file is too large to be mapped")
+    })?;
+    // Mapping an empty file fails, so its contents are represented without one.
+    if len == 0 {
+        return Ok(Mmap { ptr: NonNull::dangling(), len: 0 });
+    }
+    unsafe {
+        let mapping = c::CreateFileMappingW(
+            file.as_raw_handle(),
+            ptr::null_mut(),
+            c::PAGE_READONLY,
+            0,
+            0,
+            ptr::null(),
+        );
+        if mapping.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        // The view keeps the mapping object alive, so the handle can be
+        // closed once the view has been created.
+        let mapping = Handle::from_raw_handle(mapping);
+        let view = c::MapViewOfFile(mapping.as_raw_handle(), c::FILE_MAP_READ, 0, 0, len);
+        match NonNull::new(view as *mut u8) {
+            Some(ptr) => Ok(Mmap { ptr, len }),
+            None => Err(io::Error::last_os_error()),
+        }
+    }
+}
+
+impl Mmap {
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: `ptr` points to a read-only view of `len` bytes which lives
+        // as long as `self`.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            unsafe {
+                c::UnmapViewOfFile(self.ptr.as_ptr() as *const _);
+            }
+        }
+    }
+}
@@ -22,6 +22,7 @@
 pub mod handle;
 pub mod io;
 pub mod memchr;
+pub mod mmap;
 pub mod mutex;
 pub mod net;
 pub mod os;
@@ -115,6 +116,7 @@ pub fn decode_error_kind(errno: i32) -> ErrorKind {
         c::WSAECONNABORTED => ConnectionAborted,
         c::WSAECONNREFUSED => ConnectionRefused,
         c::WSAECONNRESET => ConnectionReset,
+        c::WSAEINPROGRESS => InProgress,
         c::WSAEINVAL => InvalidInput,
         c::WSAENOTCONN => NotConnected,
         c::WSAEWOULDBLOCK => WouldBlock,
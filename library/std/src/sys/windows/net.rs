@@ -1,7 +1,7 @@
 #![unstable(issue = "none", feature = "windows_net")]
 
 use crate::cmp;
-use crate::io::{self, IoSlice, IoSliceMut, Read};
+use crate::io::{self, IoSlice, IoSliceMut, Read, ReadBuf};
 use crate::lazy::SyncOnceCell;
 use crate::mem;
 use crate::net::{Shutdown, SocketAddr};
@@ -286,6 +286,35 @@ pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.recv_with_flags(buf, 0)
     }
 
+    pub fn read_buf(&self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        // On unix when a socket is shut down all further reads return 0, so we
+        // do the same on windows to map a shut down socket to returning EOF.
+        let length = cmp::min(buf.remaining(), i32::MAX as usize) as i32;
+        let result = unsafe {
+            c::recv(self.as_raw_socket(), buf.unfilled_mut().as_mut_ptr() as *mut _, length, 0)
+        };
+
+        match result {
+            c::SOCKET_ERROR => {
+                let error = unsafe { c::WSAGetLastError() };
+
+                if error == c::WSAESHUTDOWN {
+                    Ok(())
+                } else {
+                    Err(io::Error::from_raw_os_error(error))
+                }
+            }
+            _ => {
+                // Safety: `result` bytes were written to the unfilled portion of the buffer.
+                unsafe {
+                    buf.assume_init(result as usize);
+                }
+                buf.add_filled(result as usize);
+                Ok(())
+            }
+        }
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         // On unix when a socket is shut down all further reads return 0, so we
         // do the same on windows to map a shut down socket to returning EOF.
@@ -466,6 +495,24 @@ pub fn linger(&self) -> io::Result<Option<Duration>> {
         Ok((val.l_onoff != 0).then(|| Duration::from_secs(val.l_linger as u64)))
     }
 
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        if let Some(idle) = keepalive {
+            // `TCP_KEEPIDLE` is only supported from Windows 10, version 1709.
+            let secs = cmp::min(cmp::max(idle.as_secs(), 1), c::DWORD::MAX as u64) as c::DWORD;
+            net::setsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPIDLE, secs)?;
+        }
+        net::setsockopt(self, c::SOL_SOCKET, c::SO_KEEPALIVE, keepalive.is_some() as c::BOOL)
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        let enabled: c::BOOL = net::getsockopt(self, c::SOL_SOCKET, c::SO_KEEPALIVE)?;
+        if enabled == 0 {
+            return Ok(None);
+        }
+        let secs: c::DWORD = net::getsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPIDLE)?;
+        Ok(Some(Duration::from_secs(secs as u64)))
+    }
+
     pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
         net::setsockopt(self, c::IPPROTO_TCP, c::TCP_NODELAY, nodelay as c::BYTE)
     }
@@ -480,6 +527,53 @@ pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         if raw == 0 { Ok(None) } else { Ok(Some(io::Error::from_raw_os_error(raw as i32))) }
     }
 
+    pub fn ready(
+        &self,
+        readable: bool,
+        writable: bool,
+        timeout: Option<Duration>,
+    ) -> io::Result<(bool, bool)> {
+        let timeout = timeout.map(|timeout| c::timeval {
+            tv_sec: cmp::min(timeout.as_secs(), c_long::MAX as u64) as c_long,
+            tv_usec: (timeout.subsec_nanos() / 1000) as c_long,
+        });
+
+        let fds = {
+            let mut fds = unsafe { mem::zeroed::<c::fd_set>() };
+            fds.fd_count = 1;
+            fds.fd_array[0] = self.as_raw_socket();
+            fds
+        };
+
+        let mut readfds = fds;
+        let mut writefds = fds;
+        let mut errorfds = fds;
+
+        let count = {
+            let result = unsafe {
+                c::select(
+                    1,
+                    if readable { &mut readfds } else { ptr::null_mut() },
+                    if writable { &mut writefds } else { ptr::null_mut() },
+                    &mut errorfds,
+                    timeout.as_ref().map_or(ptr::null(), |timeout| timeout as *const _),
+                )
+            };
+            cvt(result)?
+        };
+
+        match count {
+            0 => Err(io::Error::new_const(
+                io::ErrorKind::TimedOut,
+                &"timed out waiting for readiness",
+            )),
+            // An error makes every requested direction ready, so that the
+            // caller's next operation reports it.
+            _ if errorfds.fd_count == 1 => Ok((readable, writable)),
+            _ => Ok((readable && readfds.fd_count == 1, writable && writefds.fd_count == 1)),
+        }
+    }
+
     // This is used by sys_common code to abstract over Windows and Unix.
     pub fn as_raw(&self) -> RawSocket {
         self.as_inner().as_raw_socket()
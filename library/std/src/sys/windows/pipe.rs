@@ -1,5 +1,6 @@
 use crate::os::windows::prelude::*;
 
+use crate::cmp;
 use crate::ffi::OsStr;
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::mem;
@@ -9,6 +10,7 @@
 use crate::sync::atomic::AtomicUsize;
 use crate::sync::atomic::Ordering::SeqCst;
 use crate::sys::c;
+use crate::sys::cvt;
 use crate::sys::fs::{File, OpenOptions};
 use crate::sys::handle::Handle;
 use crate::sys::hashmap_random_keys;
@@ -162,6 +164,23 @@ pub fn anon_pipe(ours_readable: bool, their_handle_inheritable: bool) -> io::Res
     }
 }
 
+/// Creates a plain anonymous pipe for use by `std::io::pipe`.
+///
+/// Unlike `anon_pipe` above, neither end is opened in overlapped mode and
+/// neither is inheritable, so both behave like ordinary synchronous handles
+/// until they are explicitly handed to a child process.
+pub fn pipe() -> io::Result<(AnonPipe, AnonPipe)> {
+    let mut read_pipe = c::INVALID_HANDLE_VALUE;
+    let mut write_pipe = c::INVALID_HANDLE_VALUE;
+    unsafe {
+        cvt(c::CreatePipe(&mut read_pipe, &mut write_pipe, ptr::null_mut(), 0))?;
+        Ok((
+            AnonPipe { inner: Handle::from_raw_handle(read_pipe) },
+            AnonPipe { inner: Handle::from_raw_handle(write_pipe) },
+        ))
+    }
+}
+
 fn random_number() -> usize {
     static N: AtomicUsize = AtomicUsize::new(0);
     loop {
@@ -181,6 +200,11 @@ pub fn into_handle(self) -> Handle {
         self.inner
     }
 
+    pub fn try_clone(&self) -> io::Result<AnonPipe> {
+        let inner = self.inner.duplicate(0, false, c::DUPLICATE_SAME_ACCESS)?;
+        Ok(AnonPipe { inner })
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.inner.read(buf)
     }
@@ -209,11 +233,25 @@ pub fn is_write_vectored(&self) -> bool {
 }
 
 pub fn read2(p1: AnonPipe, v1: &mut Vec<u8>, p2: AnonPipe, v2: &mut Vec<u8>) -> io::Result<()> {
+    read2_limited(p1, v1, usize::MAX, p2, v2, usize::MAX)
+}
+
+/// Like `read2`, but stops appending to `v1` and `v2` once they hold `l1` and
+/// `l2` bytes respectively. Anything past a limit is still read from the pipe
+/// and dropped, so the writer never blocks on a full pipe.
+pub fn read2_limited(
+    p1: AnonPipe,
+    v1: &mut Vec<u8>,
+    l1: usize,
+    p2: AnonPipe,
+    v2: &mut Vec<u8>,
+    l2: usize,
+) -> io::Result<()> {
     let p1 = p1.into_handle();
     let p2 = p2.into_handle();
 
-    let mut p1 = AsyncPipe::new(p1, v1)?;
-    let mut p2 = AsyncPipe::new(p2, v2)?;
+    let mut p1 = AsyncPipe::new(p1, v1, l1)?;
+    let mut p2 = AsyncPipe::new(p2, v2, l2)?;
     let objs = [p1.event.as_raw_handle(), p2.event.as_raw_handle()];
 
     // In a loop we wait for either pipe's scheduled read operation to complete.
@@ -245,6 +283,12 @@ struct AsyncPipe<'a> {
     event: Handle,
     overlapped: Box<c::OVERLAPPED>, // needs a stable address
     dst: &'a mut Vec<u8>,
+    limit: usize,
+    // Scratch space for reads past `limit`, allocated on first use. Whether
+    // the read in flight targets this buffer rather than `dst` is tracked by
+    // `discarding`.
+    discard: Vec<u8>,
+    discarding: bool,
     state: State,
 }
 
@@ -256,7 +300,7 @@ enum State {
 }
 
 impl<'a> AsyncPipe<'a> {
-    fn new(pipe: Handle, dst: &'a mut Vec<u8>) -> io::Result<AsyncPipe<'a>> {
+    fn new(pipe: Handle, dst: &'a mut Vec<u8>, limit: usize) -> io::Result<AsyncPipe<'a>> {
         // Create an event which we'll use to coordinate our overlapped
         // operations, this event will be used in WaitForMultipleObjects
         // and passed as part of the OVERLAPPED handle.
@@ -270,7 +314,16 @@ fn new(pipe: Handle, dst: &'a mut Vec<u8>) -> io::Result<AsyncPipe<'a>> {
         let event = Handle::new_event(true, true)?;
         let mut overlapped: Box<c::OVERLAPPED> = unsafe { Box::new(mem::zeroed()) };
         overlapped.hEvent = event.as_raw_handle();
-        Ok(AsyncPipe { pipe, overlapped, event, dst, state: State::NotReading })
+        Ok(AsyncPipe {
+            pipe,
+            overlapped,
+            event,
+            dst,
+            limit,
+            discard: Vec::new(),
+            discarding: false,
+            state: State::NotReading,
+        })
     }
 
     /// Executes an overlapped read operation.
@@ -281,8 +334,19 @@ fn new(pipe: Handle, dst: &'a mut Vec<u8>) -> io::Result<AsyncPipe<'a>> {
     /// then `result()` should not be called as it will just block forever.
     fn schedule_read(&mut self) -> io::Result<bool> {
         assert_eq!(self.state, State::NotReading);
+        self.discarding = self.dst.len() >= self.limit;
         let amt = unsafe {
-            let slice = slice_to_end(self.dst);
+            let slice = if self.discarding {
+                if self.discard.is_empty() {
+                    self.discard = vec![0; 4096];
+                }
+                &mut self.discard[..]
+            } else {
+                let room = self.limit - self.dst.len();
+                let slice = slice_to_end(self.dst);
+                let len = cmp::min(slice.len(), room);
+                &mut slice[..len]
+            };
             self.pipe.read_overlapped(slice, &mut *self.overlapped)?
         };
 
@@ -319,9 +383,11 @@ fn result(&mut self) -> io::Result<bool> {
             State::Read(amt) => amt,
         };
         self.state = State::NotReading;
-        unsafe {
-            let len = self.dst.len();
-            self.dst.set_len(len + amt);
+        if !self.discarding {
+            unsafe {
+                let len = self.dst.len();
+                self.dst.set_len(len + amt);
+            }
         }
         Ok(amt != 0)
     }
@@ -357,9 +423,10 @@ fn drop(&mut self) {
         // the buffer/OVERLAPPED pointers to ensure we're at least memory safe.
         if self.pipe.cancel_io().is_err() || self.result().is_err() {
             let buf = mem::take(self.dst);
+            let discard = mem::take(&mut self.discard);
             let overlapped = Box::new(unsafe { mem::zeroed() });
             let overlapped = mem::replace(&mut self.overlapped, overlapped);
-            mem::forget((buf, overlapped));
+            mem::forget((buf, discard, overlapped));
         }
     }
 }
@@ -157,6 +157,7 @@ fn ensure_no_nuls<T: AsRef<OsStr>>(str: T) -> io::Result<T> {
 
 pub struct Command {
     program: OsString,
+    arg0: Option<OsString>,
     args: Vec<Arg>,
     env: CommandEnv,
     cwd: Option<OsString>,
@@ -166,6 +167,7 @@ pub struct Command {
     stdout: Option<Stdio>,
     stderr: Option<Stdio>,
     force_quotes_enabled: bool,
+    inherited_handles: Vec<Handle>,
 }
 
 pub enum Stdio {
@@ -193,6 +195,7 @@ impl Command {
     pub fn new(program: &OsStr) -> Command {
         Command {
             program: program.to_os_string(),
+            arg0: None,
             args: Vec::new(),
             env: Default::default(),
             cwd: None,
@@ -202,12 +205,16 @@ pub fn new(program: &OsStr) -> Command {
             stdout: None,
             stderr: None,
             force_quotes_enabled: false,
+            inherited_handles: Vec::new(),
         }
     }
 
     pub fn arg(&mut self, arg: &OsStr) {
         self.args.push(Arg::Regular(arg.to_os_string()))
     }
+    pub fn set_arg_0(&mut self, arg: &OsStr) {
+        self.arg0 = Some(arg.to_os_string());
+    }
     pub fn env_mut(&mut self) -> &mut CommandEnv {
         &mut self.env
     }
@@ -235,6 +242,10 @@ pub fn raw_arg(&mut self, command_str_to_append: &OsStr) {
         self.args.push(Arg::Raw(command_str_to_append.to_os_string()))
     }
 
+    pub fn inherit_handle(&mut self, handle: Handle) {
+        self.inherited_handles.push(handle);
+    }
+
     pub fn get_program(&self) -> &OsStr {
         &self.program
     }
@@ -269,8 +280,12 @@ pub fn spawn(
             None
         };
         let program = resolve_exe(&self.program, child_paths)?;
-        let mut cmd_str =
-            make_command_line(program.as_os_str(), &self.args, self.force_quotes_enabled)?;
+        let mut cmd_str = make_command_line(
+            program.as_os_str(),
+            self.arg0.as_deref(),
+            &self.args,
+            self.force_quotes_enabled,
+        )?;
         cmd_str.push(0); // add null terminator
 
         // stolen from the libuv code.
@@ -310,7 +325,11 @@ pub fn spawn(
         si.hStdError = stderr.as_raw_handle();
 
         let program = to_u16s(&program)?;
-        unsafe {
+
+        // Extra handles are only made inheritable for the duration of the
+        // `CreateProcessW` call, which is still covered by the lock above.
+        let res = self.inherited_handles.iter().try_for_each(|h| set_inherit(h, true));
+        let res = res.and_then(|()| unsafe {
             cvt(c::CreateProcessW(
                 program.as_ptr(),
                 cmd_str.as_mut_ptr(),
@@ -323,7 +342,11 @@ pub fn spawn(
                 &mut si,
                 &mut pi,
             ))
-        }?;
+        });
+        for handle in &self.inherited_handles {
+            let _ = set_inherit(handle, false);
+        }
+        res?;
 
         // We close the thread handle because we don't care about keeping
         // the thread id valid, and we aren't keeping the thread handle
@@ -655,6 +678,35 @@ impl ExitCode {
     pub fn as_i32(&self) -> i32 {
         self.0 as i32
     }
+
+    #[inline]
+    pub fn from_status_code(code: i32) -> ExitCode {
+        ExitCode(code as c::DWORD)
+    }
+}
+
+impl From<u8> for ExitCode {
+    fn from(code: u8) -> Self {
+        ExitCode(c::DWORD::from(code))
+    }
+}
+
+impl From<c::DWORD> for ExitCode {
+    fn from(code: c::DWORD) -> Self {
+        ExitCode(code)
+    }
+}
+
+#[cfg(not(target_vendor = "uwp"))]
+fn set_inherit(handle: &Handle, inherit: bool) -> io::Result<()> {
+    let flags = if inherit { c::HANDLE_FLAG_INHERIT } else { 0 };
+    cvt(unsafe { c::SetHandleInformation(handle.as_raw_handle(), c::HANDLE_FLAG_INHERIT, flags) })
+        .map(drop)
+}
+
+#[cfg(target_vendor = "uwp")]
+fn set_inherit(_handle: &Handle, _inherit: bool) -> io::Result<()> {
+    Err(io::Error::new_const(io::ErrorKind::Unsupported, &"Unavailable on UWP"))
 }
 
 fn zeroed_startupinfo() -> c::STARTUPINFO {
@@ -700,7 +752,12 @@ enum Quote {
 
 // Produces a wide string *without terminating null*; returns an error if
 // `prog` or any of the `args` contain a nul.
-fn make_command_line(prog: &OsStr, args: &[Arg], force_quotes: bool) -> io::Result<Vec<u16>> {
+fn make_command_line(
+    prog: &OsStr,
+    arg0: Option<&OsStr>,
+    args: &[Arg],
+    force_quotes: bool,
+) -> io::Result<Vec<u16>> {
     // Encode the command and arguments in a command line string such
     // that the spawned process may recover them using CommandLineToArgvW.
     let mut cmd: Vec<u16> = Vec::new();
@@ -718,8 +775,10 @@ fn make_command_line(prog: &OsStr, args: &[Arg], force_quotes: bool) -> io::Resu
     }
 
     // Always quote the program name so CreateProcess doesn't interpret args as
-    // part of the name if the binary wasn't found first time.
-    append_arg(&mut cmd, prog, Quote::Always)?;
+    // part of the name if the binary wasn't found first time. `cmd.exe` finds
+    // batch files through the first token, so `arg0` can't replace it there.
+    let argv0 = if is_batch_file { prog } else { arg0.unwrap_or(prog) };
+    append_arg(&mut cmd, argv0, Quote::Always)?;
     for arg in args {
         cmd.push(' ' as u16);
         let (arg, quote) = match arg {
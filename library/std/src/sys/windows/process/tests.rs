@@ -8,6 +8,7 @@
 fn test_raw_args() {
     let command_line = &make_command_line(
         OsStr::new("quoted exe"),
+        None,
         &[
             Arg::Regular(OsString::from("quote me")),
             Arg::Raw(OsString::from("quote me *not*")),
@@ -29,6 +30,7 @@ fn test_make_command_line() {
     fn test_wrapper(prog: &str, args: &[&str], force_quotes: bool) -> String {
         let command_line = &make_command_line(
             OsStr::new(prog),
+            None,
             &args.iter().map(|a| Arg::Regular(OsString::from(a))).collect::<Vec<_>>(),
             force_quotes,
         )
@@ -70,6 +72,19 @@ fn test_wrapper(prog: &str, args: &[&str], force_quotes: bool) -> String {
     );
 }
 
+#[test]
+fn test_make_command_line_arg0() {
+    fn test_wrapper(prog: &str, arg0: &str) -> String {
+        let args = [Arg::Regular(OsString::from("a b"))];
+        let command_line =
+            &make_command_line(OsStr::new(prog), Some(OsStr::new(arg0)), &args, false).unwrap();
+        String::from_utf16(command_line).unwrap()
+    }
+
+    assert_eq!(test_wrapper("C:\\bin\\box.exe", "ls"), "\"ls\" \"a b\"");
+    assert_eq!(test_wrapper("script.bat", "ls"), "\"\"script.bat\" \"a b\"\"");
+}
+
 // On Windows, environment args are case preserving but comparisons are case-insensitive.
 // See: #85242
 #[test]
@@ -9,6 +9,11 @@
     &"the source path is neither a regular file nor a symlink to a regular file",
 );
 
+pub(crate) const TRUNCATE_APPEND_ERROR: Error = Error::new_const(
+    ErrorKind::InvalidInput,
+    &"cannot truncate a file opened in append mode; set only one of `truncate` and `append`",
+);
+
 pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
     let mut reader = fs::File::open(from)?;
     let metadata = reader.metadata()?;
@@ -2,6 +2,7 @@
 #![allow(unused_unsafe)] // thread_local with `const {}` triggers this liny
 
 use crate::cell::RefCell;
+use crate::ops::Range;
 use crate::sys::thread::guard::Guard;
 use crate::thread::Thread;
 
@@ -22,7 +23,7 @@ fn with<R, F>(f: F) -> Option<R>
                 let mut thread_info = thread_info.borrow_mut();
                 let thread_info = thread_info.get_or_insert_with(|| ThreadInfo {
                     stack_guard: None,
-                    thread: Thread::new(None),
+                    thread: Thread::new(None, None),
                 });
                 f(thread_info)
             })
@@ -38,6 +39,29 @@ pub fn stack_guard() -> Option<Guard> {
     ThreadInfo::with(|info| info.stack_guard.clone()).and_then(|o| o)
 }
 
+/// Returns the lowest address of the current thread's usable stack, just above
+/// its guard page, if the platform records one.
+pub fn stack_limit() -> Option<usize> {
+    stack_guard().map(GuardLimit::limit)
+}
+
+// `Guard` is a range of addresses where the platform has a stack guard and `!` elsewhere.
+trait GuardLimit {
+    fn limit(self) -> usize;
+}
+
+impl GuardLimit for Range<usize> {
+    fn limit(self) -> usize {
+        self.end
+    }
+}
+
+impl GuardLimit for ! {
+    fn limit(self) -> usize {
+        self
+    }
+}
+
 pub fn set(stack_guard: Option<Guard>, thread: Thread) {
     THREAD_INFO.with(move |thread_info| {
         let mut thread_info = thread_info.borrow_mut();
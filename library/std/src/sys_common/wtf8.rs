@@ -156,6 +156,21 @@ pub fn with_capacity(capacity: usize) -> Wtf8Buf {
         Wtf8Buf { bytes: Vec::with_capacity(capacity) }
     }
 
+    /// Creates a WTF-8 string from a WTF-8 byte vec.
+    ///
+    /// Since the byte vec is not checked for valid WTF-8, this functions is
+    /// marked unsafe.
+    #[inline]
+    pub unsafe fn from_bytes_unchecked(value: Vec<u8>) -> Wtf8Buf {
+        Wtf8Buf { bytes: value }
+    }
+
+    /// Converts this `Wtf8Buf` into its underlying WTF-8 byte vec.
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
     /// Creates a WTF-8 string from a UTF-8 `String`.
     ///
     /// This takes ownership of the `String` and does not copy.
@@ -482,6 +497,14 @@ fn write_str_escaped(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
 
 impl fmt::Display for Wtf8 {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Width and precision count each replaced surrogate as one character,
+        // which is easiest to get right by padding the converted string.
+        if (formatter.width().is_some() || formatter.precision().is_some())
+            && self.next_surrogate(0).is_some()
+        {
+            return formatter.pad(&self.to_string_lossy());
+        }
+
         let wtf8_bytes = &self.bytes;
         let mut pos = 0;
         loop {
@@ -516,10 +539,16 @@ pub fn from_str(value: &str) -> &Wtf8 {
     /// Since the byte slice is not checked for valid WTF-8, this functions is
     /// marked unsafe.
     #[inline]
-    unsafe fn from_bytes_unchecked(value: &[u8]) -> &Wtf8 {
+    pub unsafe fn from_bytes_unchecked(value: &[u8]) -> &Wtf8 {
         mem::transmute(value)
     }
 
+    /// Returns the underlying WTF-8 bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
     /// Creates a mutable WTF-8 slice from a mutable WTF-8 byte slice.
     ///
     /// Since the byte slice is not checked for valid WTF-8, this functions is
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests;
+
+use crate::future::Future;
+use crate::pin::Pin;
+use crate::sync::Arc;
+use crate::task::{Context, Poll, Wake, Waker};
+use crate::thread::{self, Thread};
+
+/// Blocks the current thread until the provided future is ready, returning
+/// its output.
+///
+/// The future is polled on the current thread, which is parked whenever the
+/// future returns [`Poll::Pending`] and unparked when the future's [`Waker`]
+/// is woken.
+///
+/// This is a minimal executor intended for tests, short command-line
+/// programs, and boundaries between synchronous and asynchronous code. It
+/// does not drive any I/O or timers of its own, so futures which rely on a
+/// particular runtime being present may never complete.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(task_block_on)]
+/// use std::task::block_on;
+///
+/// async fn add(a: u32, b: u32) -> u32 {
+///     a + b
+/// }
+///
+/// assert_eq!(block_on(add(1, 2)), 3);
+/// ```
+#[unstable(feature = "task_block_on", issue = "none")]
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = fut;
+    // SAFETY: `fut` is shadowed below, so it is never moved after being pinned.
+    let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match fut.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            // A spurious unpark only causes an extra poll.
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Wakes a blocked `block_on` call by unparking its thread.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
@@ -0,0 +1,48 @@
+use super::block_on;
+use crate::future::{self, Future};
+use crate::pin::Pin;
+use crate::sync::atomic::{AtomicBool, Ordering};
+use crate::sync::Arc;
+use crate::task::{Context, Poll};
+use crate::thread;
+use crate::time::Duration;
+
+#[test]
+fn ready() {
+    assert_eq!(block_on(future::ready(5)), 5);
+    assert_eq!(block_on(async { "hello" }), "hello");
+}
+
+/// A future that becomes ready once another thread sets a flag and wakes it.
+struct WokenFromThread {
+    done: Arc<AtomicBool>,
+    spawned: bool,
+}
+
+impl Future for WokenFromThread {
+    type Output = u32;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+        if self.done.load(Ordering::SeqCst) {
+            return Poll::Ready(7);
+        }
+        if !self.spawned {
+            self.spawned = true;
+            let done = self.done.clone();
+            let waker = cx.waker().clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10));
+                done.store(true, Ordering::SeqCst);
+                waker.wake();
+            });
+        }
+        Poll::Pending
+    }
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn woken_from_another_thread() {
+    let fut = WokenFromThread { done: Arc::new(AtomicBool::new(false)), spawned: false };
+    assert_eq!(block_on(fut), 7);
+}
@@ -136,6 +136,22 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 /// # fn main() {}
 /// ```
 ///
+/// If the initializer can be evaluated at compile time, it can be wrapped in
+/// a `const` block. This avoids lazy initialization entirely: on platforms
+/// with native thread-local storage, accessing the key compiles down to a
+/// direct access of the thread-local slot. If the type additionally does not
+/// need to be dropped, no destructor is ever registered for it.
+///
+/// ```
+/// use std::cell::Cell;
+/// thread_local! {
+///     static COUNTER: Cell<u32> = const { Cell::new(0) };
+/// }
+///
+/// COUNTER.with(|c| c.set(c.get() + 1));
+/// assert_eq!(COUNTER.with(Cell::get), 1);
+/// ```
+///
 /// See [`LocalKey` documentation][`std::thread::LocalKey`] for more
 /// information.
 ///
@@ -401,6 +417,28 @@ pub fn with<F, R>(&'static self, f: F) -> R
     ///
     /// This function will still `panic!()` if the key is uninitialized and the
     /// key's initializer panics.
+    ///
+    /// # Examples
+    ///
+    /// Code that may run while thread-locals are being torn down, such as the
+    /// destructor of another thread-local, can use this to degrade gracefully
+    /// instead of panicking:
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    ///
+    /// thread_local! {
+    ///     static MESSAGES: Cell<u64> = const { Cell::new(0) };
+    /// }
+    ///
+    /// fn record_message() {
+    ///     // Silently drop the statistic if the thread is shutting down.
+    ///     let _ = MESSAGES.try_with(|m| m.set(m.get() + 1));
+    /// }
+    ///
+    /// record_message();
+    /// assert_eq!(MESSAGES.try_with(Cell::get), Ok(1));
+    /// ```
     #[stable(feature = "thread_local_try_with", since = "1.26.0")]
     #[inline]
     pub fn try_with<F, R>(&'static self, f: F) -> Result<R, AccessError>
@@ -451,9 +451,12 @@ pub unsafe fn spawn_unchecked<'a, F, T>(self, f: F) -> io::Result<JoinHandle<T>>
 
         let stack_size = stack_size.unwrap_or_else(thread::min_stack);
 
-        let my_thread = Thread::new(name.map(|name| {
-            CString::new(name).expect("thread name may not contain interior null bytes")
-        }));
+        let my_thread = Thread::new(
+            name.map(|name| {
+                CString::new(name).expect("thread name may not contain interior null bytes")
+            }),
+            Some(stack_size),
+        );
         let their_thread = my_thread.clone();
 
         let my_packet: Arc<UnsafeCell<Option<Result<T>>>> = Arc::new(UnsafeCell::new(None));
@@ -652,6 +655,45 @@ pub fn current() -> Thread {
     )
 }
 
+/// Returns an estimate of how many bytes of the current thread's stack are
+/// still free, or `None` if the platform does not make its bounds known.
+///
+/// The estimate is the distance from the current stack frame down to the
+/// guard page that the runtime records for stack overflow detection, rounded
+/// down to stay on the safe side. It is meant for deeply recursive code, such
+/// as parsers or tree walkers, that would rather bail out than overflow.
+/// Leave a margin when comparing against it: the frames of any functions
+/// called afterwards need room too.
+///
+/// The bounds are currently known on Linux, macOS, FreeBSD, NetBSD, OpenBSD
+/// and Solaris, except for the main thread on Linux with musl. Elsewhere,
+/// including Windows, this returns `None`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(thread_stack_introspection)]
+/// use std::thread;
+///
+/// fn depth(n: u64) -> Option<u64> {
+///     match thread::remaining_stack() {
+///         Some(free) if free < 64 * 1024 => None,
+///         _ if n == 0 => Some(0),
+///         _ => depth(n - 1).map(|d| d + 1),
+///     }
+/// }
+///
+/// assert_eq!(depth(100), Some(100));
+/// ```
+#[unstable(feature = "thread_stack_introspection", issue = "none")]
+#[must_use]
+pub fn remaining_stack() -> Option<usize> {
+    let limit = thread_info::stack_limit()?;
+    // The address of a local is close enough to the stack pointer.
+    let here = 0u8;
+    Some((&here as *const u8 as usize).saturating_sub(limit))
+}
+
 /// Cooperatively gives up a timeslice to the OS scheduler.
 ///
 /// This calls the underlying OS scheduler's yield primitive, signaling
@@ -1045,6 +1087,7 @@ pub fn as_u64(&self) -> NonZeroU64 {
 struct Inner {
     name: Option<CString>, // Guaranteed to be UTF-8
     id: ThreadId,
+    stack_size: Option<usize>,
     parker: Parker,
 }
 
@@ -1075,8 +1118,10 @@ pub struct Thread {
 impl Thread {
     // Used only internally to construct a thread object without spawning
     // Panics if the name contains nuls.
-    pub(crate) fn new(name: Option<CString>) -> Thread {
-        Thread { inner: Arc::new(Inner { name, id: ThreadId::new(), parker: Parker::new() }) }
+    pub(crate) fn new(name: Option<CString>, stack_size: Option<usize>) -> Thread {
+        Thread {
+            inner: Arc::new(Inner { name, id: ThreadId::new(), stack_size, parker: Parker::new() }),
+        }
     }
 
     /// Atomically makes the handle's token available if it is not already.
@@ -1181,6 +1226,41 @@ pub fn name(&self) -> Option<&str> {
     fn cname(&self) -> Option<&CStr> {
         self.inner.name.as_deref()
     }
+
+    /// Gets the stack size that was requested for the thread.
+    ///
+    /// This is the size given to [`Builder::stack_size`], or the default
+    /// size if none was given. The platform may round it up, and may reserve
+    /// part of the stack for its own use, such as thread-local storage. Threads
+    /// that were not spawned by this module, including the main thread, return
+    /// `None`. Use [`remaining_stack`] to learn how much of the current
+    /// thread's stack is still free.
+    ///
+    /// For more information about the stack size for threads, see
+    /// [this module-level documentation][stack-size].
+    ///
+    /// [stack-size]: ./index.html#stack-size
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(thread_stack_introspection)]
+    /// use std::thread;
+    ///
+    /// let handler = thread::Builder::new()
+    ///     .stack_size(256 * 1024)
+    ///     .spawn(|| {
+    ///         assert_eq!(thread::current().stack_size(), Some(256 * 1024));
+    ///     })
+    ///     .unwrap();
+    ///
+    /// handler.join().unwrap();
+    /// ```
+    #[unstable(feature = "thread_stack_introspection", issue = "none")]
+    #[must_use]
+    pub fn stack_size(&self) -> Option<usize> {
+        self.inner.stack_size
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
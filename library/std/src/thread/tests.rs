@@ -42,6 +42,30 @@ fn test_invalid_named_thread() {
     let _ = Builder::new().name("ada l\0velace".to_string()).spawn(|| {});
 }
 
+#[test]
+fn test_stack_size() {
+    Builder::new()
+        .stack_size(128 * 1024)
+        .spawn(move || {
+            assert_eq!(thread::current().stack_size(), Some(128 * 1024));
+
+            // Where it is known, the free stack can be no larger than the whole stack, and
+            // shrinks as frames are pushed.
+            #[inline(never)]
+            fn nested() -> Option<usize> {
+                let buf = crate::hint::black_box([0u8; 4096]);
+                thread::remaining_stack().map(|free| free + buf[0] as usize)
+            }
+            if let Some(free) = thread::remaining_stack() {
+                assert!(free < 128 * 1024 * 2);
+                assert!(nested().unwrap() < free);
+            }
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
 #[test]
 fn test_run_basic() {
     let (tx, rx) = channel();
@@ -35,11 +35,12 @@
 #[cfg(test)]
 mod tests;
 
+use crate::convert::TryFrom;
 use crate::error::Error;
 use crate::fmt;
 use crate::ops::{Add, AddAssign, Sub, SubAssign};
 use crate::sys::time;
-use crate::sys_common::FromInner;
+use crate::sys_common::{FromInner, IntoInner};
 
 #[stable(feature = "time", since = "1.3.0")]
 pub use core::time::Duration;
@@ -60,6 +61,18 @@
 /// experience time dilation (slow down or speed up), but it will never go
 /// backwards.
 ///
+/// This guarantee holds even on platforms whose clocks are known to go
+/// backwards occasionally: unless the platform's clock is known to be
+/// reliable, [`Instant::now`] remembers the latest value it has returned and
+/// never returns anything earlier, across all threads.
+///
+/// Instants created by adding or subtracting durations can still be in each
+/// other's future, so subtracting instants in the "wrong" order is possible.
+/// [`duration_since`](Instant::duration_since), [`elapsed`](Instant::elapsed)
+/// and the `-` operator saturate to zero in that case rather than panicking;
+/// use [`checked_duration_since`](Instant::checked_duration_since) to detect
+/// it.
+///
 /// Instants are opaque types that can only be compared to one another. There is
 /// no method to get "the number of seconds" from an instant. Instead, it only
 /// allows measuring the duration between two instants (or comparing two
@@ -290,11 +303,15 @@ pub fn now() -> Instant {
         Instant(monotonic::monotonize(os_now))
     }
 
-    /// Returns the amount of time elapsed from another instant to this one.
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or zero duration if that instant is later than this one.
     ///
     /// # Panics
     ///
-    /// This function will panic if `earlier` is later than `self`.
+    /// Previous versions of this function panicked if `earlier` was later
+    /// than `self`. It now saturates to zero instead, like
+    /// [`saturating_duration_since`](Instant::saturating_duration_since), so
+    /// that timestamps racing with each other can't bring down a program.
     ///
     /// # Examples
     ///
@@ -310,7 +327,7 @@ pub fn now() -> Instant {
     #[must_use]
     #[stable(feature = "time2", since = "1.8.0")]
     pub fn duration_since(&self, earlier: Instant) -> Duration {
-        self.0.checked_sub_instant(&earlier.0).expect("supplied instant is later than self")
+        self.saturating_duration_since(earlier)
     }
 
     /// Returns the amount of time elapsed from another instant to this one,
@@ -355,13 +372,14 @@ pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
         self.checked_duration_since(earlier).unwrap_or_default()
     }
 
-    /// Returns the amount of time elapsed since this instant was created.
+    /// Returns the amount of time elapsed since this instant was created,
+    /// or zero duration if this instant is in the future, which can happen
+    /// if it was produced by adding a duration to another `Instant`.
     ///
     /// # Panics
     ///
-    /// This function may panic if the current time is earlier than this
-    /// instant, which is something that can happen if an `Instant` is
-    /// produced synthetically.
+    /// Previous versions of this function panicked if the current time was
+    /// earlier than this instant. It now saturates to zero instead.
     ///
     /// # Examples
     ///
@@ -395,6 +413,51 @@ pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
     pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
         self.0.checked_sub_duration(&duration).map(Instant)
     }
+
+    /// Returns the time `self + duration`, or the latest `Instant` that can be represented if
+    /// that is out of bounds.
+    ///
+    /// The range of `Instant` differs between platforms; on some, adding a few hundred years
+    /// already overflows. Saturating at [`Duration::MAX`] gives a portable "far future" to use
+    /// as a deadline that never expires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(time_saturating_ops)]
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let now = Instant::now();
+    /// let never = now.saturating_add(Duration::MAX);
+    /// assert!(never > now);
+    /// assert_eq!(never.saturating_add(Duration::from_secs(1)), never);
+    /// assert!(never.checked_add(Duration::from_secs(1)).is_none());
+    /// ```
+    #[must_use]
+    #[unstable(feature = "time_saturating_ops", issue = "none")]
+    pub fn saturating_add(&self, duration: Duration) -> Instant {
+        self.checked_add(duration).unwrap_or_else(|| saturate(*self, |t, d| t.checked_add(d)))
+    }
+
+    /// Returns the time `self - duration`, or the earliest `Instant` that can be represented if
+    /// that is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(time_saturating_ops)]
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let now = Instant::now();
+    /// let earliest = now.saturating_sub(Duration::MAX);
+    /// assert!(earliest <= now);
+    /// assert_eq!(earliest.saturating_sub(Duration::from_secs(1)), earliest);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "time_saturating_ops", issue = "none")]
+    pub fn saturating_sub(&self, duration: Duration) -> Instant {
+        self.checked_sub(duration).unwrap_or_else(|| saturate(*self, |t, d| t.checked_sub(d)))
+    }
 }
 
 #[stable(feature = "time2", since = "1.8.0")]
@@ -404,7 +467,8 @@ impl Add<Duration> for Instant {
     /// # Panics
     ///
     /// This function may panic if the resulting point in time cannot be represented by the
-    /// underlying data structure. See [`Instant::checked_add`] for a version without panic.
+    /// underlying data structure. See [`Instant::checked_add`] for a version without panic, and
+    /// [`Instant::saturating_add`] for one that stops at the latest representable instant.
     fn add(self, other: Duration) -> Instant {
         self.checked_add(other).expect("overflow when adding duration to instant")
     }
@@ -437,6 +501,13 @@ fn sub_assign(&mut self, other: Duration) {
 impl Sub<Instant> for Instant {
     type Output = Duration;
 
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or zero duration if that instant is later than this one.
+    ///
+    /// # Panics
+    ///
+    /// Previous versions of this function panicked when `other` was later
+    /// than `self`. It now saturates to zero instead.
     fn sub(self, other: Instant) -> Duration {
         self.duration_since(other)
     }
@@ -561,6 +632,184 @@ pub fn checked_add(&self, duration: Duration) -> Option<SystemTime> {
     pub fn checked_sub(&self, duration: Duration) -> Option<SystemTime> {
         self.0.checked_sub_duration(&duration).map(SystemTime)
     }
+
+    /// Returns the time `self + duration`, or the latest `SystemTime` that can be represented
+    /// if that is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(time_saturating_ops)]
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let latest = UNIX_EPOCH.saturating_add(Duration::MAX);
+    /// assert!(latest > UNIX_EPOCH);
+    /// assert_eq!(latest.saturating_add(Duration::from_secs(1)), latest);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "time_saturating_ops", issue = "none")]
+    pub fn saturating_add(&self, duration: Duration) -> SystemTime {
+        self.checked_add(duration).unwrap_or_else(|| saturate(*self, |t, d| t.checked_add(d)))
+    }
+
+    /// Returns the time `self - duration`, or the earliest `SystemTime` that can be represented
+    /// if that is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(time_saturating_ops)]
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let earliest = UNIX_EPOCH.saturating_sub(Duration::MAX);
+    /// assert!(earliest < UNIX_EPOCH);
+    /// assert_eq!(earliest.saturating_sub(Duration::from_secs(1)), earliest);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "time_saturating_ops", issue = "none")]
+    pub fn saturating_sub(&self, duration: Duration) -> SystemTime {
+        self.checked_sub(duration).unwrap_or_else(|| saturate(*self, |t, d| t.checked_sub(d)))
+    }
+
+    /// Returns the system time `secs` seconds after (or, if negative, before)
+    /// the [`UNIX_EPOCH`], or `None` if it cannot be represented as a
+    /// `SystemTime` on this platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(system_time_unix)]
+    /// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    ///
+    /// let t = SystemTime::from_unix_seconds(1_000_000_000).unwrap();
+    /// assert_eq!(t.duration_since(UNIX_EPOCH).unwrap(), Duration::from_secs(1_000_000_000));
+    /// assert_eq!(t.unix_seconds(), 1_000_000_000);
+    /// ```
+    #[unstable(feature = "system_time_unix", issue = "none")]
+    pub fn from_unix_seconds(secs: i64) -> Option<SystemTime> {
+        let offset = Duration::from_secs(secs.unsigned_abs());
+        if secs >= 0 { UNIX_EPOCH.checked_add(offset) } else { UNIX_EPOCH.checked_sub(offset) }
+    }
+
+    /// Returns the system time `nanos` nanoseconds after (or, if negative,
+    /// before) the [`UNIX_EPOCH`], or `None` if it cannot be represented as a
+    /// `SystemTime` on this platform.
+    ///
+    /// The precision of `SystemTime` is platform specific, so the result may
+    /// be rounded towards the epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(system_time_unix)]
+    /// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    ///
+    /// let t = SystemTime::from_unix_nanos(-1_500_000_000).unwrap();
+    /// assert_eq!(UNIX_EPOCH.duration_since(t).unwrap(), Duration::from_millis(1500));
+    /// ```
+    #[unstable(feature = "system_time_unix", issue = "none")]
+    pub fn from_unix_nanos(nanos: i128) -> Option<SystemTime> {
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+        let abs = nanos.unsigned_abs();
+        let secs = u64::try_from(abs / NANOS_PER_SEC).ok()?;
+        let offset = Duration::new(secs, (abs % NANOS_PER_SEC) as u32);
+        if nanos >= 0 { UNIX_EPOCH.checked_add(offset) } else { UNIX_EPOCH.checked_sub(offset) }
+    }
+
+    /// Returns the number of whole seconds between the [`UNIX_EPOCH`] and this
+    /// system time, which is negative for times before the epoch.
+    ///
+    /// Like a Unix `time_t`, this rounds towards negative infinity, so half a
+    /// second before the epoch is `-1`. Use [`unix_nanos`] to keep the
+    /// fractional part.
+    ///
+    /// The result saturates at the bounds of `i64`, which no platform's
+    /// `SystemTime` currently reaches.
+    ///
+    /// [`unix_nanos`]: SystemTime::unix_nanos
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(system_time_unix)]
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// assert_eq!(UNIX_EPOCH.unix_seconds(), 0);
+    /// assert_eq!((UNIX_EPOCH + Duration::from_millis(1500)).unix_seconds(), 1);
+    /// assert_eq!((UNIX_EPOCH - Duration::from_millis(500)).unix_seconds(), -1);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "system_time_unix", issue = "none")]
+    pub fn unix_seconds(&self) -> i64 {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => i64::try_from(after.as_secs()).unwrap_or(i64::MAX),
+            Err(e) => {
+                let before = e.duration();
+                let secs = before.as_secs() + (before.subsec_nanos() != 0) as u64;
+                i64::try_from(secs).map(|secs| -secs).unwrap_or(i64::MIN)
+            }
+        }
+    }
+
+    /// Returns the number of nanoseconds between the [`UNIX_EPOCH`] and this
+    /// system time, which is negative for times before the epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(system_time_unix)]
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// assert_eq!((UNIX_EPOCH + Duration::from_millis(1500)).unix_nanos(), 1_500_000_000);
+    /// assert_eq!((UNIX_EPOCH - Duration::from_millis(500)).unix_nanos(), -500_000_000);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "system_time_unix", issue = "none")]
+    pub fn unix_nanos(&self) -> i128 {
+        // A `Duration` holds at most about 2^94 nanoseconds, so neither
+        // conversion can overflow.
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => after.as_nanos() as i128,
+            Err(e) => -(e.duration().as_nanos() as i128),
+        }
+    }
+
+    /// Returns an object that implements [`Display`] for formatting this
+    /// system time in UTC as specified by [RFC 3339], such as
+    /// `2001-09-09T01:46:40.25Z`.
+    ///
+    /// The fractional seconds are omitted if zero, and otherwise printed with
+    /// trailing zeros removed. A precision, as in `{:.3}`, prints exactly that
+    /// many fractional digits (at most nine), truncating the rest. Formatting
+    /// does not allocate.
+    ///
+    /// Years outside `0000` to `9999` cannot be represented in RFC 3339; they
+    /// are printed with a sign and at least five digits, as in the expanded
+    /// format of ISO 8601. Leap seconds are never produced, since `SystemTime`
+    /// does not count them.
+    ///
+    /// [`Display`]: fmt::Display
+    /// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(system_time_rfc3339, system_time_unix)]
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let t = SystemTime::from_unix_seconds(1_000_000_000).unwrap();
+    /// assert_eq!(t.rfc3339().to_string(), "2001-09-09T01:46:40Z");
+    ///
+    /// let t = t + Duration::from_millis(250);
+    /// assert_eq!(t.rfc3339().to_string(), "2001-09-09T01:46:40.25Z");
+    /// assert_eq!(format!("{:.3}", t.rfc3339()), "2001-09-09T01:46:40.250Z");
+    /// assert_eq!(format!("{:.0}", t.rfc3339()), "2001-09-09T01:46:40Z");
+    /// ```
+    #[must_use = "this does not display the time, it returns an object that can be displayed"]
+    #[unstable(feature = "system_time_rfc3339", issue = "none")]
+    pub fn rfc3339(&self) -> Rfc3339 {
+        Rfc3339 { time: *self }
+    }
 }
 
 #[stable(feature = "time2", since = "1.8.0")]
@@ -570,7 +819,8 @@ impl Add<Duration> for SystemTime {
     /// # Panics
     ///
     /// This function may panic if the resulting point in time cannot be represented by the
-    /// underlying data structure. See [`SystemTime::checked_add`] for a version without panic.
+    /// underlying data structure. See [`SystemTime::checked_add`] for a version without panic,
+    /// and [`SystemTime::saturating_add`] for one that stops at the latest representable time.
     fn add(self, dur: Duration) -> SystemTime {
         self.checked_add(dur).expect("overflow when adding duration to instant")
     }
@@ -672,8 +922,124 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// Helper struct for formatting a [`SystemTime`] as specified by RFC 3339.
+///
+/// This `struct` is created by the [`rfc3339`] method on [`SystemTime`]. See
+/// its documentation for more.
+///
+/// [`rfc3339`]: SystemTime::rfc3339
+#[derive(Clone, Copy, Debug)]
+#[unstable(feature = "system_time_rfc3339", issue = "none")]
+pub struct Rfc3339 {
+    time: SystemTime,
+}
+
+#[unstable(feature = "system_time_rfc3339", issue = "none")]
+impl fmt::Display for Rfc3339 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const NANOS_PER_SEC: i128 = 1_000_000_000;
+        const SECS_PER_DAY: i64 = 86_400;
+
+        let nanos = self.time.unix_nanos();
+        // At most about 2^94 / 10^9 seconds, which fits in an `i64`.
+        let secs = nanos.div_euclid(NANOS_PER_SEC) as i64;
+        let subsec = nanos.rem_euclid(NANOS_PER_SEC) as u32;
+        let (days, secs_of_day) = (secs.div_euclid(SECS_PER_DAY), secs.rem_euclid(SECS_PER_DAY));
+        let (year, month, day) = civil_from_days(days);
+
+        if (0..=9999).contains(&year) {
+            write!(f, "{:04}", year)?;
+        } else {
+            write!(f, "{:+06}", year)?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}",
+            month,
+            day,
+            secs_of_day / 3600,
+            secs_of_day / 60 % 60,
+            secs_of_day % 60,
+        )?;
+
+        let digits = match f.precision() {
+            Some(precision) => precision.min(9),
+            None => {
+                // Leave out the trailing zeros.
+                let (mut digits, mut rest) = (9, subsec);
+                while digits > 0 && rest % 10 == 0 {
+                    rest /= 10;
+                    digits -= 1;
+                }
+                digits
+            }
+        };
+        if digits > 0 {
+            write!(f, ".{:0width$}", subsec / 10u32.pow(9 - digits as u32), width = digits)?;
+        }
+        f.write_str("Z")
+    }
+}
+
+/// Converts a number of days since 1970-01-01 to a year, month and day in the
+/// proleptic Gregorian calendar.
+///
+/// This is Howard Hinnant's `civil_from_days`, which works on eras of 400
+/// years that start on March 1st, so that leap days fall at the end of a year.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year, month as u32, day)
+}
+
+/// Moves `start` as far as `step` allows, returning the bound it reaches.
+///
+/// The platform layer does not expose the bounds of `Instant` or `SystemTime`, so the saturating
+/// operations find them by bisecting on `checked_add` or `checked_sub`, which succeed for every
+/// duration up to the bound and fail beyond it. A single `Duration` does not always span the
+/// distance to the bound (some platforms can't step by more than `i64::MAX` seconds at once), so
+/// keep stepping until no progress is made. This only runs once an operation has overflowed.
+fn saturate<T: Copy>(start: T, step: impl Fn(T, Duration) -> Option<T>) -> T {
+    let mut t = start;
+    loop {
+        let offset = saturating_offset(Duration::MAX, |d| step(t, d).is_some());
+        match step(t, offset) {
+            Some(next) if !offset.is_zero() => t = next,
+            _ => return t,
+        }
+    }
+}
+
+/// Returns the longest duration, no longer than `limit`, that `fits`.
+fn saturating_offset(limit: Duration, fits: impl Fn(Duration) -> bool) -> Duration {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+    // `limit` has at most about 2^94 nanoseconds, so converting back cannot overflow.
+    let from_nanos =
+        |n: u128| Duration::new((n / NANOS_PER_SEC) as u64, (n % NANOS_PER_SEC) as u32);
+    let (mut low, mut high) = (0, limit.as_nanos());
+    while low < high {
+        let mid = low + (high - low + 1) / 2;
+        if fits(from_nanos(mid)) { low = mid } else { high = mid - 1 }
+    }
+    from_nanos(low)
+}
+
 impl FromInner<time::SystemTime> for SystemTime {
     fn from_inner(time: time::SystemTime) -> SystemTime {
         SystemTime(time)
     }
 }
+
+impl IntoInner<time::SystemTime> for SystemTime {
+    fn into_inner(self) -> time::SystemTime {
+        self.0
+    }
+}
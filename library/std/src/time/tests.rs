@@ -90,10 +90,11 @@ fn instant_math_is_associative() {
 }
 
 #[test]
-#[should_panic]
-fn instant_duration_since_panic() {
+fn instant_duration_since_saturates() {
     let a = Instant::now();
-    let _ = (a - Duration::SECOND).duration_since(a);
+    assert_eq!((a - Duration::SECOND).duration_since(a), Duration::ZERO);
+    assert_eq!((a - Duration::SECOND) - a, Duration::ZERO);
+    assert_eq!((a + Duration::SECOND * 60).elapsed(), Duration::ZERO);
 }
 
 #[test]
@@ -113,6 +114,35 @@ fn instant_saturating_duration_since_nopanic() {
     assert_eq!(ret, Duration::ZERO);
 }
 
+#[test]
+fn instant_saturating_math() {
+    let now = Instant::now();
+    assert_eq!(now.saturating_add(Duration::SECOND), now + Duration::SECOND);
+    assert_eq!(now.saturating_sub(Duration::SECOND), now - Duration::SECOND);
+
+    let latest = now.saturating_add(Duration::MAX);
+    assert!(latest > now);
+    assert_eq!(latest.checked_add(Duration::SECOND), None);
+    assert_eq!(latest.saturating_add(Duration::SECOND), latest);
+
+    let earliest = now.saturating_sub(Duration::MAX);
+    assert!(earliest <= now);
+    assert_eq!(earliest.saturating_sub(Duration::SECOND), earliest);
+}
+
+#[test]
+fn system_time_saturating_math() {
+    let latest = UNIX_EPOCH.saturating_add(Duration::MAX);
+    assert!(latest > SystemTime::now());
+    assert_eq!(latest.checked_add(Duration::SECOND), None);
+    assert_eq!(latest.saturating_add(Duration::MAX), latest);
+
+    let earliest = UNIX_EPOCH.saturating_sub(Duration::MAX);
+    assert!(earliest < UNIX_EPOCH);
+    assert_eq!(earliest.checked_sub(Duration::SECOND), None);
+    assert_eq!(earliest.saturating_sub(Duration::SECOND), earliest);
+}
+
 #[test]
 fn system_time_math() {
     let a = SystemTime::now();
@@ -192,6 +222,43 @@ fn since_epoch() {
     assert!(a < hundred_twenty_years);
 }
 
+#[test]
+fn system_time_unix_round_trip() {
+    let before = SystemTime::from_unix_seconds(-1_000).unwrap();
+    assert_eq!(UNIX_EPOCH.duration_since(before).unwrap(), Duration::from_secs(1_000));
+    assert_eq!(before.unix_seconds(), -1_000);
+    assert_eq!(SystemTime::from_unix_seconds(1_000).unwrap().unix_seconds(), 1_000);
+    assert_eq!(SystemTime::from_unix_seconds(0), Some(UNIX_EPOCH));
+
+    let before = SystemTime::from_unix_nanos(-1_500_000_000).unwrap();
+    assert_eq!(UNIX_EPOCH.duration_since(before).unwrap(), Duration::from_millis(1_500));
+    assert_eq!(before.unix_nanos(), -1_500_000_000);
+    // Whole seconds round towards negative infinity.
+    assert_eq!(before.unix_seconds(), -2);
+
+    let after = UNIX_EPOCH + Duration::from_millis(1_500);
+    assert_eq!(after.unix_nanos(), 1_500_000_000);
+    assert_eq!(after.unix_seconds(), 1);
+    assert_eq!((UNIX_EPOCH - Duration::from_millis(500)).unix_seconds(), -1);
+}
+
+#[test]
+fn system_time_rfc3339() {
+    let fmt = |nanos: i128| SystemTime::from_unix_nanos(nanos).unwrap().rfc3339().to_string();
+    assert_eq!(fmt(0), "1970-01-01T00:00:00Z");
+    assert_eq!(fmt(951_782_400_000_000_000), "2000-02-29T00:00:00Z");
+    assert_eq!(fmt(1_000_000_000_123_400_000), "2001-09-09T01:46:40.1234Z");
+    assert_eq!(fmt(4_102_444_799_000_000_000), "2099-12-31T23:59:59Z");
+    // Times before the epoch count down from midnight.
+    assert_eq!(fmt(-1_500_000_000), "1969-12-31T23:59:58.5Z");
+    assert_eq!(fmt(-11_644_473_600_000_000_000), "1601-01-01T00:00:00Z");
+
+    let time = SystemTime::from_unix_nanos(1_500_000_000).unwrap();
+    assert_eq!(format!("{:.0}", time.rfc3339()), "1970-01-01T00:00:01Z");
+    assert_eq!(format!("{:.2}", time.rfc3339()), "1970-01-01T00:00:01.50Z");
+    assert_eq!(format!("{:.12}", time.rfc3339()), "1970-01-01T00:00:01.500000000Z");
+}
+
 #[cfg(all(target_has_atomic = "64", not(target_has_atomic = "128")))]
 #[test]
 fn monotonizer_wrapping_backslide() {
@@ -24,7 +24,6 @@
 #![feature(rustc_private)]
 #![feature(nll)]
 #![feature(available_parallelism)]
-#![feature(bench_black_box)]
 #![feature(internal_output_capture)]
 #![feature(panic_unwind)]
 #![feature(staged_api)]
@@ -0,0 +1,27 @@
+// run-pass
+// ignore-emscripten no processes
+// ignore-sgx no processes
+
+#![feature(alloc_error_hook)]
+
+use std::alloc::{handle_alloc_error, set_alloc_error_hook, Layout};
+use std::env;
+use std::process::{self, Command};
+use std::str;
+
+fn hook(layout: Layout) {
+    eprintln!("out of memory allocating {} bytes", layout.size());
+    process::exit(75);
+}
+
+fn main() {
+    if env::args().len() > 1 {
+        set_alloc_error_hook(hook);
+        handle_alloc_error(Layout::new::<[u8; 42]>())
+    }
+
+    let me = env::current_exe().unwrap();
+    let output = Command::new(&me).arg("next").output().unwrap();
+    assert_eq!(output.status.code(), Some(75));
+    assert_eq!(str::from_utf8(&output.stderr).unwrap(), "out of memory allocating 42 bytes\n");
+}
@@ -0,0 +1,38 @@
+// run-pass
+
+#![feature(alloc_stats)]
+
+use std::alloc::{thread_alloc_stats, Counting, System};
+
+#[global_allocator]
+static GLOBAL: Counting = Counting::new(System);
+
+fn main() {
+    let before = thread_alloc_stats();
+    let mut v: Vec<u32> = Vec::with_capacity(4);
+    let stats = thread_alloc_stats().since(&before);
+    assert_eq!(stats.allocations(), 1);
+    assert_eq!(stats.bytes_allocated(), 16);
+    assert_eq!(stats.deallocations(), 0);
+
+    let before = thread_alloc_stats();
+    v.reserve_exact(8);
+    let stats = thread_alloc_stats().since(&before);
+    assert_eq!(stats.allocations(), 0);
+    assert_eq!(stats.reallocations(), 1);
+    assert_eq!(stats.bytes_deallocated(), 16);
+    assert_eq!(stats.bytes_allocated(), 32);
+
+    let before = thread_alloc_stats();
+    drop(v);
+    let stats = thread_alloc_stats().since(&before);
+    assert_eq!(stats.deallocations(), 1);
+    assert_eq!(stats.bytes_deallocated(), 32);
+
+    // Zero-sized allocations never reach the global allocator.
+    let before = thread_alloc_stats();
+    let empty: Vec<u32> = Vec::with_capacity(0);
+    let boxed = Box::new(());
+    drop((empty, boxed));
+    assert_eq!(thread_alloc_stats().since(&before), Default::default());
+}
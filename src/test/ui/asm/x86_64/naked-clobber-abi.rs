@@ -0,0 +1,34 @@
+// run-pass
+// needs-asm-support
+// only-x86_64
+// only-linux
+
+// Checks that a naked trampoline can forward to a Rust function through a `sym` operand, and that
+// it can be called from `asm!` with `clobber_abi("C")` instead of a hand-written clobber list.
+
+#![feature(naked_functions, asm_sym)]
+
+use std::arch::asm;
+
+extern "C" fn handler(x: u64) -> u64 {
+    x * 3
+}
+
+#[naked]
+extern "C" fn trampoline(_x: u64) -> u64 {
+    unsafe { asm!("jmp {}", sym handler, options(noreturn)) }
+}
+
+fn main() {
+    let y: u64;
+    unsafe {
+        asm!(
+            "call {}",
+            sym trampoline,
+            in("rdi") 14u64,
+            lateout("rax") y,
+            clobber_abi("C"),
+        );
+    }
+    assert_eq!(y, 42);
+}
@@ -4,31 +4,27 @@
 //[nomiropt]compile-flags: -Z mir-opt-level=0
 
 // edition:2018
-// aux-build:arc_wake.rs
 
 #![feature(async_closure)]
 
-extern crate arc_wake;
-
 use std::pin::Pin;
 use std::future::Future;
 use std::sync::{
     Arc,
     atomic::{self, AtomicUsize},
 };
-use std::task::{Context, Poll};
-use arc_wake::ArcWake;
+use std::task::{Context, Poll, Wake, Waker};
 
 struct Counter {
     wakes: AtomicUsize,
 }
 
-impl ArcWake for Counter {
+impl Wake for Counter {
     fn wake(self: Arc<Self>) {
         Self::wake_by_ref(&self)
     }
-    fn wake_by_ref(arc_self: &Arc<Self>) {
-        arc_self.wakes.fetch_add(1, atomic::Ordering::SeqCst);
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.wakes.fetch_add(1, atomic::Ordering::SeqCst);
     }
 }
 
@@ -78,7 +74,7 @@ fn test_future_yields_once_then_returns<F, Fut>(f: F)
 {
     let mut fut = Box::pin(f(9));
     let counter = Arc::new(Counter { wakes: AtomicUsize::new(0) });
-    let waker = ArcWake::into_waker(counter.clone());
+    let waker = Waker::from(counter.clone());
     let mut cx = Context::from_waker(&waker);
     assert_eq!(0, counter.wakes.load(atomic::Ordering::SeqCst));
     assert_eq!(Poll::Pending, fut.as_mut().poll(&mut cx));
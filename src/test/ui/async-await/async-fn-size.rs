@@ -1,28 +1,24 @@
 // run-pass
-// aux-build:arc_wake.rs
 // edition:2018
 
-extern crate arc_wake;
-
 use std::pin::Pin;
 use std::future::Future;
 use std::sync::{
     Arc,
     atomic::{self, AtomicUsize},
 };
-use std::task::{Context, Poll};
-use arc_wake::ArcWake;
+use std::task::{Context, Poll, Wake, Waker};
 
 struct Counter {
     wakes: AtomicUsize,
 }
 
-impl ArcWake for Counter {
+impl Wake for Counter {
     fn wake(self: Arc<Self>) {
         Self::wake_by_ref(&self)
     }
-    fn wake_by_ref(arc_self: &Arc<Self>) {
-        arc_self.wakes.fetch_add(1, atomic::Ordering::SeqCst);
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.wakes.fetch_add(1, atomic::Ordering::SeqCst);
     }
 }
 
@@ -44,7 +40,7 @@ fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u8> {
 fn wait(fut: impl Future<Output = u8>) -> u8 {
     let mut fut = Box::pin(fut);
     let counter = Arc::new(Counter { wakes: AtomicUsize::new(0) });
-    let waker = ArcWake::into_waker(counter.clone());
+    let waker = Waker::from(counter.clone());
     let mut cx = Context::from_waker(&waker);
     loop {
         match fut.as_mut().poll(&mut cx) {
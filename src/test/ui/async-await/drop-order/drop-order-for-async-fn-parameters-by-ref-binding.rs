@@ -1,4 +1,3 @@
-// aux-build:arc_wake.rs
 // edition:2018
 // run-pass
 
@@ -8,19 +7,16 @@
 // parameters (used or unused) are not dropped until the async fn completes execution.
 // See also #54716.
 
-extern crate arc_wake;
-
-use arc_wake::ArcWake;
 use std::cell::RefCell;
 use std::future::Future;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::rc::Rc;
-use std::task::Context;
+use std::task::{Context, Wake, Waker};
 
 struct EmptyWaker;
 
-impl ArcWake for EmptyWaker {
+impl Wake for EmptyWaker {
     fn wake(self: Arc<Self>) {}
 }
 
@@ -175,7 +171,7 @@ fn assert_drop_order_after_poll<Fut: Future<Output = ()>>(
     g: impl FnOnce(DropOrderListPtr),
 ) {
     let empty = Arc::new(EmptyWaker);
-    let waker = ArcWake::into_waker(empty);
+    let waker = Waker::from(empty);
     let mut cx = Context::from_waker(&waker);
 
     let actual_order = Rc::new(RefCell::new(Vec::new()));
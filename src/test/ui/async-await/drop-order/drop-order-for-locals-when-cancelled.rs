@@ -1,4 +1,3 @@
-// aux-build:arc_wake.rs
 // edition:2018
 // run-pass
 
@@ -8,19 +7,17 @@
 #![allow(path_statements)]
 
 // Test that the drop order for locals in a fn and async fn matches up.
-extern crate arc_wake;
 
-use arc_wake::ArcWake;
 use std::cell::RefCell;
 use std::future::Future;
 use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::Arc;
-use std::task::{Context, Poll};
+use std::task::{Context, Poll, Wake, Waker};
 
 struct EmptyWaker;
 
-impl ArcWake for EmptyWaker {
+impl Wake for EmptyWaker {
     fn wake(self: Arc<Self>) {}
 }
 
@@ -139,7 +136,7 @@ fn assert_drop_order_after_cancel<Fut: Future<Output = ()>>(
     g: impl FnOnce(DropOrderListPtr),
 ) {
     let empty = Arc::new(EmptyWaker);
-    let waker = ArcWake::into_waker(empty);
+    let waker = Waker::from(empty);
     let mut cx = Context::from_waker(&waker);
 
     let actual_order = Rc::new(RefCell::new(Vec::new()));
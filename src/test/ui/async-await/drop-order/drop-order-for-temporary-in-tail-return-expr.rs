@@ -1,4 +1,3 @@
-// aux-build:arc_wake.rs
 // edition:2018
 // run-pass
 
@@ -12,18 +11,15 @@
 // body. In particular, check that this drop order is the same between
 // an `async fn` and an ordinary `fn`. See #64512.
 
-extern crate arc_wake;
-
-use arc_wake::ArcWake;
 use std::cell::RefCell;
 use std::future::Future;
 use std::sync::Arc;
 use std::rc::Rc;
-use std::task::Context;
+use std::task::{Context, Wake, Waker};
 
 struct EmptyWaker;
 
-impl ArcWake for EmptyWaker {
+impl Wake for EmptyWaker {
     fn wake(self: Arc<Self>) {}
 }
 
@@ -73,7 +69,7 @@ fn assert_drop_order_after_poll<Fut: Future<Output = ()>>(
     g: impl FnOnce(DropOrderListPtr),
 ) {
     let empty = Arc::new(EmptyWaker);
-    let waker = ArcWake::into_waker(empty);
+    let waker = Waker::from(empty);
     let mut cx = Context::from_waker(&waker);
 
     let actual_order = Rc::new(RefCell::new(Vec::new()));
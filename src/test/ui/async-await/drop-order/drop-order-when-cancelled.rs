@@ -1,4 +1,3 @@
-// aux-build:arc_wake.rs
 // edition:2018
 // run-pass
 
@@ -11,20 +10,17 @@
 
 #![allow(unused_variables)]
 
-extern crate arc_wake;
-
-use arc_wake::ArcWake;
 use std::cell::RefCell;
 use std::future::Future;
 use std::marker::PhantomData;
 use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::Arc;
-use std::task::{Context, Poll};
+use std::task::{Context, Poll, Wake, Waker};
 
 struct EmptyWaker;
 
-impl ArcWake for EmptyWaker {
+impl Wake for EmptyWaker {
     fn wake(self: Arc<Self>) {}
 }
 
@@ -192,7 +188,7 @@ fn assert_drop_order_after_cancel<Fut: Future<Output = ()>>(
     g: impl FnOnce(DropOrderListPtr),
 ) {
     let empty = Arc::new(EmptyWaker);
-    let waker = ArcWake::into_waker(empty);
+    let waker = Waker::from(empty);
     let mut cx = Context::from_waker(&waker);
 
     let actual_order = Rc::new(RefCell::new(Vec::new()));
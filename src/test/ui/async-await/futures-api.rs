@@ -1,9 +1,5 @@
 // run-pass
 
-// aux-build:arc_wake.rs
-
-extern crate arc_wake;
-
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::{
@@ -11,20 +7,19 @@
     atomic::{self, AtomicUsize},
 };
 use std::task::{
-    Context, Poll,
+    Context, Poll, Wake, Waker,
 };
-use arc_wake::ArcWake;
 
 struct Counter {
     wakes: AtomicUsize,
 }
 
-impl ArcWake for Counter {
+impl Wake for Counter {
     fn wake(self: Arc<Self>) {
         Self::wake_by_ref(&self)
     }
-    fn wake_by_ref(arc_self: &Arc<Self>) {
-        arc_self.wakes.fetch_add(1, atomic::Ordering::SeqCst);
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.wakes.fetch_add(1, atomic::Ordering::SeqCst);
     }
 }
 
@@ -45,7 +40,7 @@ fn test_waker() {
     let counter = Arc::new(Counter {
         wakes: AtomicUsize::new(0),
     });
-    let waker = ArcWake::into_waker(counter.clone());
+    let waker = Waker::from(counter.clone());
     assert_eq!(2, Arc::strong_count(&counter));
     {
         let mut context = Context::from_waker(&waker);
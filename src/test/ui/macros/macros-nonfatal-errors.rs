@@ -6,7 +6,6 @@
 #![feature(llvm_asm)]
 #![feature(trace_macros, concat_idents)]
 #![feature(stmt_expr_attributes, arbitrary_enum_discriminant)]
-#![feature(derive_default_enum)]
 #![allow(deprecated)] // llvm_asm!
 
 use std::arch::asm;
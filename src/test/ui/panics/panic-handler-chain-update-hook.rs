@@ -0,0 +1,41 @@
+// run-pass
+// needs-unwind
+
+// ignore-emscripten no threads support
+
+#![feature(panic_update_hook)]
+#![feature(panic_payload_as_str)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::panic;
+use std::thread;
+
+static A: AtomicUsize = AtomicUsize::new(0);
+static B: AtomicUsize = AtomicUsize::new(0);
+static C: AtomicUsize = AtomicUsize::new(0);
+
+fn main() {
+    panic::set_hook(Box::new(|_| { A.fetch_add(1, Ordering::SeqCst); }));
+    panic::update_hook(|prev, info| {
+        B.fetch_add(1, Ordering::SeqCst);
+        prev(info);
+    });
+    panic::update_hook(|prev, info| {
+        // Both kinds of string payload are visible without downcasting.
+        let msg = info.payload_as_str().unwrap();
+        assert!(msg == "static" || msg == "formatted 1");
+        C.fetch_add(1, Ordering::SeqCst);
+        prev(info);
+    });
+
+    let _ = thread::spawn(|| {
+        panic!("static");
+    }).join();
+    let _ = thread::spawn(|| {
+        panic!("formatted {}", 1);
+    }).join();
+
+    assert_eq!(2, A.load(Ordering::SeqCst));
+    assert_eq!(2, B.load(Ordering::SeqCst));
+    assert_eq!(2, C.load(Ordering::SeqCst));
+}
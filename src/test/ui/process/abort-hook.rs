@@ -0,0 +1,32 @@
+// run-pass
+// ignore-emscripten no processes
+// ignore-sgx no processes
+
+#![feature(abort_hook)]
+
+use std::env;
+use std::process::{self, Command};
+use std::str;
+
+fn hook() {
+    eprintln!("abort hook called");
+    // A nested abort must terminate the process instead of calling the hook again.
+    process::abort();
+}
+
+fn main() {
+    if env::args().len() > 1 {
+        process::set_abort_hook(hook);
+        process::abort();
+    }
+
+    assert!(process::take_abort_hook().is_none());
+    process::set_abort_hook(hook);
+    assert_eq!(process::take_abort_hook().map(|h| h as usize), Some(hook as usize));
+    assert!(process::take_abort_hook().is_none());
+
+    let me = env::current_exe().unwrap();
+    let output = Command::new(&me).arg("next").output().unwrap();
+    assert!(!output.status.success(), "{:?} is a success", output.status);
+    assert_eq!(str::from_utf8(&output.stderr).unwrap(), "abort hook called\n");
+}
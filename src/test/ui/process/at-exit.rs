@@ -0,0 +1,49 @@
+// run-pass
+// ignore-emscripten no processes
+// ignore-sgx no processes
+
+#![feature(process_at_exit)]
+
+use std::env;
+use std::io::{self, BufWriter, Write};
+use std::process::{self, Command};
+use std::str;
+
+fn main() {
+    match env::args().nth(1).as_deref() {
+        Some("return") => {
+            register();
+        }
+        Some("exit") => {
+            register();
+            // Never flushed by its own destructor.
+            let out = Box::leak(Box::new(BufWriter::new(io::stdout())));
+            write!(out, "buffered ").unwrap();
+            process::at_exit(move || out.flush().unwrap()).unwrap();
+            process::exit(3);
+        }
+        _ => {
+            let me = env::current_exe().unwrap();
+
+            let output = Command::new(&me).arg("return").output().unwrap();
+            assert!(output.status.success());
+            assert_eq!(str::from_utf8(&output.stdout).unwrap(), "second first ");
+            assert!(output.stderr.windows(5).any(|w| w == b"boom!"));
+
+            let output = Command::new(&me).arg("exit").output().unwrap();
+            assert_eq!(output.status.code(), Some(3));
+            assert_eq!(str::from_utf8(&output.stdout).unwrap(), "buffered second first ");
+        }
+    }
+}
+
+fn register() {
+    process::at_exit(|| print!("first ")).unwrap();
+    process::at_exit(|| panic!("boom!")).unwrap();
+    process::at_exit(|| {
+        print!("second ");
+        // Too late to register more.
+        assert!(process::at_exit(|| print!("never ")).is_err());
+    })
+    .unwrap();
+}
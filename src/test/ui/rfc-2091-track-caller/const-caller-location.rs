@@ -2,7 +2,7 @@
 // revisions: default mir-opt
 //[mir-opt] compile-flags: -Zmir-opt-level=4
 
-#![feature(const_caller_location)]
+#![feature(const_caller_location, const_location_fields)]
 
 use std::panic::Location;
 
@@ -40,4 +40,13 @@ fn main() {
     assert_eq!(CONTAINED.file(), file!());
     assert_eq!(CONTAINED.line(), 24);
     assert_eq!(CONTAINED.column(), 5);
+
+    assert_eq!(LOCATION_FILE, file!());
+    assert_eq!(LOCATION_LINE, 9);
+    assert_eq!(LOCATION_COLUMN, 29);
 }
+
+// The fields are readable in const contexts too.
+const LOCATION_FILE: &str = LOCATION.file();
+const LOCATION_LINE: u32 = LOCATION.line();
+const LOCATION_COLUMN: u32 = LOCATION.column();
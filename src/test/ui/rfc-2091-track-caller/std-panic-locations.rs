@@ -62,4 +62,11 @@ fn assert_panicked(f: impl FnOnce() + UnwindSafe) {
     let _conflicting = refcell.borrow_mut();
     assert_panicked(AssertUnwindSafe(|| { refcell.borrow(); }));
     assert_panicked(AssertUnwindSafe(|| { refcell.borrow_mut(); }));
+    assert_panicked(AssertUnwindSafe(|| { refcell.swap(&RefCell::new(())); }));
+
+    let mut vec = vec![0];
+    assert_panicked(AssertUnwindSafe(|| { vec.remove(1); }));
+    assert_panicked(AssertUnwindSafe(|| { vec.swap_remove(1); }));
+    assert_panicked(AssertUnwindSafe(|| { vec.insert(2, 0); }));
+    assert_panicked(AssertUnwindSafe(|| { let _ = vec.split_off(2); }));
 }
@@ -7,7 +7,6 @@
 // run-fail
 // error-pattern: HWAddressSanitizer: tag-mismatch
 
-#![feature(bench_black_box)]
 
 use std::hint::black_box;
 
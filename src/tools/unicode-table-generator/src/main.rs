@@ -91,7 +91,10 @@
     "Grapheme_Extend",
     "White_Space",
     "Cc",
+    "L",
+    "M",
     "N",
+    "P",
 ];
 
 struct UnicodeData {
@@ -146,16 +149,15 @@ fn load_data() -> UnicodeData {
     for row in ucd_parse::UnicodeDataExpander::new(
         ucd_parse::parse::<_, ucd_parse::UnicodeData>(&UNICODE_DIRECTORY).unwrap(),
     ) {
-        let general_category = if ["Nd", "Nl", "No"].contains(&row.general_category.as_str()) {
-            "N"
-        } else {
-            row.general_category.as_str()
-        };
-        if let Some(name) = PROPERTIES.iter().find(|prop| **prop == general_category) {
-            properties
-                .entry(*name)
-                .or_insert_with(Vec::new)
-                .push(Codepoints::Single(row.codepoint));
+        let general_category = row.general_category.as_str();
+        // Record both the general category itself and its major class (its first letter).
+        for category in [general_category, &general_category[..1]] {
+            if let Some(name) = PROPERTIES.iter().find(|prop| **prop == category) {
+                properties
+                    .entry(*name)
+                    .or_insert_with(Vec::new)
+                    .push(Codepoints::Single(row.codepoint));
+            }
         }
 
         if let Some(mapped) = row.simple_lowercase_mapping {